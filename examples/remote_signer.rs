@@ -0,0 +1,66 @@
+//! Reference remote signer for `sign-with-remote-signer`, meant for local testing.
+//!
+//! ```sh
+//! cargo run --example remote_signer -- --listen http://127.0.0.1:3045 --key-file ~/.near-credentials/testnet/alice.testnet.json
+//! cargo run --example remote_signer -- --listen unix:///tmp/near-signer.sock --key-file alice.json --api-key secret
+//! ```
+use clap::Parser;
+use color_eyre::eyre::{ContextCompat, WrapErr};
+
+#[derive(Debug, Parser)]
+struct Args {
+    /// Endpoint to listen on: http://<host>:<port> or unix://<socket path>
+    #[arg(long)]
+    listen: url::Url,
+    /// Access key file (`{"account_id": ..., "private_key": ...}`, e.g. from the legacy keychain)
+    #[arg(long, required = true)]
+    key_file: Vec<std::path::PathBuf>,
+    /// Require `Authorization: Bearer <api key>` on every request
+    #[arg(long)]
+    api_key: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct KeyFile {
+    account_id: near_primitives::types::AccountId,
+    private_key: near_crypto::SecretKey,
+}
+
+fn main() -> color_eyre::eyre::Result<()> {
+    let args = Args::parse();
+
+    let mut signer = near_cli_rs::remote_signer::ReferenceSigner::new(args.api_key);
+    for path in &args.key_file {
+        let key_file: KeyFile = serde_json::from_str(
+            &std::fs::read_to_string(path)
+                .wrap_err_with(|| format!("Failed to read the key file {path:?}"))?,
+        )
+        .wrap_err_with(|| format!("Failed to parse the key file {path:?}"))?;
+        eprintln!(
+            "Loaded the key {} of <{}>",
+            key_file.private_key.public_key(),
+            key_file.account_id
+        );
+        signer.add_key(key_file.account_id, key_file.private_key);
+    }
+
+    match args.listen.scheme() {
+        "http" => {
+            let address = args
+                .listen
+                .socket_addrs(|| None)?
+                .into_iter()
+                .next()
+                .wrap_err("Failed to resolve the listen address")?;
+            eprintln!("Listening on {}", args.listen);
+            signer.serve_tcp(std::net::TcpListener::bind(address)?)
+        }
+        #[cfg(unix)]
+        "unix" => {
+            let _ = std::fs::remove_file(args.listen.path());
+            eprintln!("Listening on {}", args.listen);
+            signer.serve_unix(std::os::unix::net::UnixListener::bind(args.listen.path())?)
+        }
+        scheme => color_eyre::eyre::bail!("Unsupported listen URL scheme <{scheme}>"),
+    }
+}
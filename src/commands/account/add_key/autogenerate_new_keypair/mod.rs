@@ -2,6 +2,7 @@ use std::str::FromStr;
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 mod print_keypair_to_terminal;
+mod save_keypair_to_encrypted_keystore;
 mod save_keypair_to_keychain;
 mod save_keypair_to_legacy_keychain;
 
@@ -9,6 +10,10 @@ mod save_keypair_to_legacy_keychain;
 #[interactive_clap(input_context = super::access_key_type::AccessTypeContext)]
 #[interactive_clap(output_context = GenerateKeypairContext)]
 pub struct GenerateKeypair {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Type of the key to generate: ed25519 (default) or secp256k1
+    key_type: Option<crate::types::key_type::KeyType>,
     #[interactive_clap(subcommand)]
     save_mode: SaveMode,
 }
@@ -25,10 +30,10 @@ pub struct GenerateKeypairContext {
 impl GenerateKeypairContext {
     pub fn from_previous_context(
         previous_context: super::access_key_type::AccessTypeContext,
-        _scope: &<GenerateKeypair as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+        scope: &<GenerateKeypair as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         let key_pair_properties: crate::common::KeyPairProperties =
-            crate::common::generate_keypair()?;
+            crate::common::generate_keypair(scope.key_type.unwrap_or_default().into())?;
         let public_key = near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?;
         Ok(Self {
             global_context: previous_context.global_context,
@@ -46,17 +51,24 @@ impl GenerateKeypairContext {
 /// Save an access key for this account:
 pub enum SaveMode {
     #[strum_discriminants(strum(
-        message = "save-to-keychain         - Save automatically generated key pair to keychain"
+        message = "save-to-keychain            - Save automatically generated key pair to keychain"
     ))]
     /// Save automatically generated key pair to keychain
     SaveToKeychain(self::save_keypair_to_keychain::SaveKeypairToKeychain),
     #[strum_discriminants(strum(
-        message = "save-to-legacy-keychain  - Save automatically generated key pair to the legacy keychain (compatible with JS CLI)"
+        message = "save-to-legacy-keychain     - Save automatically generated key pair to the legacy keychain (compatible with JS CLI)"
     ))]
     /// Save automatically generated key pair to the legacy keychain (compatible with JS CLI)
     SaveToLegacyKeychain(self::save_keypair_to_legacy_keychain::SaveKeypairToLegacyKeychain),
     #[strum_discriminants(strum(
-        message = "print-to-terminal        - Print automatically generated key pair in terminal"
+        message = "save-to-encrypted-keystore  - Save automatically generated key pair to the password-encrypted keystore"
+    ))]
+    /// Save automatically generated key pair to the password-encrypted keystore
+    SaveToEncryptedKeystore(
+        self::save_keypair_to_encrypted_keystore::SaveKeypairToEncryptedKeystore,
+    ),
+    #[strum_discriminants(strum(
+        message = "print-to-terminal           - Print automatically generated key pair in terminal"
     ))]
     /// Print automatically generated key pair in terminal
     PrintToTerminal(self::print_keypair_to_terminal::PrintKeypairToTerminal),
@@ -78,6 +78,7 @@ impl From<PrintKeypairToTerminalContext> for crate::commands::ActionContext {
             on_after_sending_transaction_callback: std::sync::Arc::new(
                 move |_outcome_view, _network_config| Ok(()),
             ),
+            transaction_bundle: None,
         }
     }
 }
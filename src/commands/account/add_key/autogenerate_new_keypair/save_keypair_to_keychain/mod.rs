@@ -42,7 +42,9 @@ impl From<SaveKeypairToKeychainContext> for crate::commands::ActionContext {
                 }
             });
         let on_before_sending_transaction_callback: crate::transaction_signature_options::OnBeforeSendingTransactionCallback =
-            std::sync::Arc::new(
+            std::sync::Arc::new({
+                let credentials_home_dir = item.0.global_context.config.credentials_home_dir.clone();
+
                 move |transaction, network_config| {
                     let account_id = match transaction {
                         crate::transaction_signature_options::SignedTransactionOrSignedDelegateAction::SignedTransaction(
@@ -54,12 +56,13 @@ impl From<SaveKeypairToKeychainContext> for crate::commands::ActionContext {
                     };
                     crate::common::save_access_key_to_keychain(
                         network_config.clone(),
+                        credentials_home_dir.clone(),
                         &serde_json::to_string(&item.0.key_pair_properties)?,
                         &item.0.key_pair_properties.public_key_str,
                         account_id.as_ref(),
                     )
-                },
-            );
+                }
+            });
 
         Self {
             global_context: item.0.global_context,
@@ -72,6 +75,7 @@ impl From<SaveKeypairToKeychainContext> for crate::commands::ActionContext {
             on_after_sending_transaction_callback: std::sync::Arc::new(
                 |_outcome_view, _network_config| Ok(()),
             ),
+            transaction_bundle: None,
         }
     }
 }
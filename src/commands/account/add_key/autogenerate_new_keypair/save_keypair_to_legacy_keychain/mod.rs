@@ -97,6 +97,7 @@ impl From<SaveKeypairToLegacyKeychainContext> for crate::commands::ActionContext
             on_after_sending_transaction_callback: std::sync::Arc::new(
                 |_outcome_view, _network_config| Ok(()),
             ),
+            transaction_bundle: None,
         }
     }
 }
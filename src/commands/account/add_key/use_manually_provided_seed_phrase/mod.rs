@@ -1,11 +1,13 @@
-use std::str::FromStr;
-
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(input_context = super::access_key_type::AccessTypeContext)]
 #[interactive_clap(output_context = AddAccessWithSeedPhraseActionContext)]
 pub struct AddAccessWithSeedPhraseAction {
     /// Enter the seed-phrase:
     master_seed_phrase: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Type of the key to derive from the seed phrase: ed25519 (default) or secp256k1
+    key_type: Option<crate::types::key_type::KeyType>,
     #[interactive_clap(named_arg)]
     /// Select network
     network_config: crate::network_for_transaction::NetworkForTransactionArgs,
@@ -24,10 +26,11 @@ impl AddAccessWithSeedPhraseActionContext {
         previous_context: super::access_key_type::AccessTypeContext,
         scope: &<AddAccessWithSeedPhraseAction as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let seed_phrase_hd_path_default = slipped10::BIP32Path::from_str("m/44'/397'/0'").unwrap();
+        let key_type = scope.key_type.unwrap_or_default().into();
         let public_key = crate::common::get_public_key_from_seed_phrase(
-            seed_phrase_hd_path_default,
+            crate::common::default_seed_phrase_hd_path(key_type).into(),
             &scope.master_seed_phrase,
+            key_type,
         )?;
         Ok(Self {
             global_context: previous_context.global_context,
@@ -74,6 +77,7 @@ impl From<AddAccessWithSeedPhraseActionContext> for crate::commands::ActionConte
             on_after_sending_transaction_callback: std::sync::Arc::new(
                 |_outcome_view, _network_config| Ok(()),
             ),
+            transaction_bundle: None,
         }
     }
 }
@@ -0,0 +1,448 @@
+use color_eyre::eyre::WrapErr;
+use prettytable::Table;
+
+use crate::common::JsonRpcClientExt;
+use crate::common::RpcQueryResponseExt;
+
+/// Function-call keys with less allowance left are flagged (unless `--min-allowance` is given).
+const DEFAULT_MIN_ALLOWANCE: near_token::NearToken = near_token::NearToken::from_millinear(50);
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = AuditKeysContext)]
+pub struct AuditKeys {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What Account ID do you need to audit the access keys of?
+    account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Flag function-call keys with less allowance left than this (default: 0.05 NEAR)
+    min_allowance: Option<crate::types::near_token::NearToken>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Also check the key of the Ledger device at this HD path (e.g. "44'/397'/0'/0'/1'")
+    ledger_hd_path: Option<crate::types::slip10::BIP32Path>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct AuditKeysContext(crate::network::NetworkContext);
+
+impl AuditKeysContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<AuditKeys as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+        let ledger_public_key = scope
+            .ledger_hd_path
+            .clone()
+            .map(get_ledger_public_key)
+            .transpose()?;
+        let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
+            std::sync::Arc::new({
+                let account_id = account_id.clone();
+                let min_allowance = scope
+                    .min_allowance
+                    .map_or(DEFAULT_MIN_ALLOWANCE, |min_allowance| min_allowance.into());
+                let credentials_home_dir = previous_context.config.credentials_home_dir.clone();
+                let output_format = previous_context.output_format.clone();
+
+                move |network_config| {
+                    let access_key_list = network_config
+                        .json_rpc_client()
+                        .blocking_call_view_access_key_list(
+                            &account_id,
+                            near_primitives::types::Finality::Final.into(),
+                        )
+                        .wrap_err_with(|| {
+                            format!("Failed to fetch query AccessKeyList for {}", &account_id)
+                        })?
+                        .access_key_list_view()?;
+
+                    let stored_keys =
+                        get_stored_keys(network_config, &credentials_home_dir, &account_id)?;
+                    let audited_keys = audit_keys(
+                        &access_key_list.keys,
+                        &stored_keys,
+                        ledger_public_key.as_ref(),
+                        min_allowance,
+                    );
+
+                    if output_format.is_json() {
+                        return crate::common::print_json_output(
+                            "access_key_audit",
+                            serde_json::json!({
+                                "account_id": account_id,
+                                "network_name": network_config.network_name,
+                                "min_allowance": min_allowance.as_yoctonear().to_string(),
+                                "keys": audited_keys,
+                            }),
+                        );
+                    }
+                    display_audited_keys(&account_id, network_config, &audited_keys);
+                    Ok(())
+                }
+            });
+
+        Ok(Self(crate::network::NetworkContext {
+            config: previous_context.config,
+            interacting_with_account_ids: vec![account_id],
+            on_after_getting_network_callback,
+        }))
+    }
+}
+
+impl From<AuditKeysContext> for crate::network::NetworkContext {
+    fn from(item: AuditKeysContext) -> Self {
+        item.0
+    }
+}
+
+impl AuditKeys {
+    pub fn input_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_non_signer_account_id_from_used_account_list(
+            &context.config.credentials_home_dir,
+            "What Account ID do you need to audit the access keys of?",
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "issue", rename_all = "snake_case")]
+enum KeyIssue {
+    /// The key is stored locally, but the account does not have it (anymore)
+    OrphanedLocalKey,
+    LowAllowance {
+        allowance: near_token::NearToken,
+    },
+    /// Nobody can sign with the key on this machine, so it may be forgotten or leaked
+    UnheldFullAccessKey,
+}
+
+impl std::fmt::Display for KeyIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OrphanedLocalKey => write!(f, "orphaned: the account does not have this key"),
+            Self::LowAllowance { allowance } => write!(
+                f,
+                "low allowance: {} left",
+                crate::types::near_token::NearToken::from(*allowance)
+            ),
+            Self::UnheldFullAccessKey => write!(f, "full access key is not held locally"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct AuditedKey {
+    public_key: near_crypto::PublicKey,
+    /// `None` for the keys that the account does not have
+    permission: Option<near_primitives::views::AccessKeyPermissionView>,
+    stored_in: Vec<crate::commands::credentials::inventory::KeyStore>,
+    /// Whether it is the key of the Ledger device (`--ledger-hd-path`)
+    on_ledger: bool,
+    issues: Vec<KeyIssue>,
+}
+
+/// The keys of the account saved by near CLI (see `credentials list`).
+fn get_stored_keys(
+    network_config: &crate::config::NetworkConfig,
+    credentials_home_dir: &std::path::Path,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Vec<crate::commands::credentials::inventory::StoredKey>> {
+    let inventory = crate::commands::credentials::inventory::find_stored_keys(
+        credentials_home_dir,
+        crate::commands::credentials::inventory::KeyFilter {
+            network_name: Some(&network_config.network_name),
+            account_id: Some(account_id),
+        },
+    )?;
+    crate::commands::credentials::inventory::print_missing_keychain_entries(
+        &inventory.missing_keychain_entries,
+    );
+    Ok(inventory.stored_keys)
+}
+
+#[cfg(feature = "ledger")]
+fn get_ledger_public_key(
+    seed_phrase_hd_path: crate::types::slip10::BIP32Path,
+) -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+    eprintln!(
+        "Please allow getting the PublicKey on Ledger device (HD Path: {})",
+        seed_phrase_hd_path
+    );
+    let public_key =
+        near_ledger::get_public_key(seed_phrase_hd_path.into()).map_err(|near_ledger_error| {
+            color_eyre::Report::msg(format!(
+                "An error occurred while trying to get PublicKey from Ledger device: {:?}",
+                near_ledger_error
+            ))
+        })?;
+    Ok(near_crypto::PublicKey::ED25519(
+        near_crypto::ED25519PublicKey::from(public_key.to_bytes()),
+    ))
+}
+
+#[cfg(not(feature = "ledger"))]
+fn get_ledger_public_key(
+    _seed_phrase_hd_path: crate::types::slip10::BIP32Path,
+) -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+    color_eyre::eyre::bail!("near CLI is built without Ledger support (the `ledger` feature)")
+}
+
+fn audit_keys(
+    on_chain_keys: &[near_primitives::views::AccessKeyInfoView],
+    stored_keys: &[crate::commands::credentials::inventory::StoredKey],
+    ledger_public_key: Option<&near_crypto::PublicKey>,
+    min_allowance: near_token::NearToken,
+) -> Vec<AuditedKey> {
+    let stored_in = |public_key: &near_crypto::PublicKey| {
+        let mut stores: Vec<crate::commands::credentials::inventory::KeyStore> = stored_keys
+            .iter()
+            .filter(|stored_key| &stored_key.public_key == public_key)
+            .map(|stored_key| stored_key.store)
+            .collect();
+        stores.sort();
+        stores.dedup();
+        stores
+    };
+    let is_on_ledger = |public_key: &near_crypto::PublicKey| ledger_public_key == Some(public_key);
+
+    let mut audited_keys: Vec<AuditedKey> = on_chain_keys
+        .iter()
+        .map(|access_key| {
+            let stored_in = stored_in(&access_key.public_key);
+            let on_ledger = is_on_ledger(&access_key.public_key);
+            let mut issues = vec![];
+            match &access_key.access_key.permission {
+                near_primitives::views::AccessKeyPermissionView::FullAccess => {
+                    if stored_in.is_empty() && !on_ledger {
+                        issues.push(KeyIssue::UnheldFullAccessKey);
+                    }
+                }
+                near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                    allowance: Some(allowance),
+                    ..
+                } => {
+                    if *allowance < min_allowance.as_yoctonear() {
+                        issues.push(KeyIssue::LowAllowance {
+                            allowance: near_token::NearToken::from_yoctonear(*allowance),
+                        });
+                    }
+                }
+                near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                    allowance: None,
+                    ..
+                } => {}
+            }
+            AuditedKey {
+                public_key: access_key.public_key.clone(),
+                permission: Some(access_key.access_key.permission.clone()),
+                stored_in,
+                on_ledger,
+                issues,
+            }
+        })
+        .collect();
+
+    let local_public_keys = stored_keys
+        .iter()
+        .map(|stored_key| &stored_key.public_key)
+        .chain(ledger_public_key);
+    for public_key in local_public_keys {
+        if audited_keys
+            .iter()
+            .any(|audited_key| &audited_key.public_key == public_key)
+        {
+            continue;
+        }
+        audited_keys.push(AuditedKey {
+            public_key: public_key.clone(),
+            permission: None,
+            stored_in: stored_in(public_key),
+            on_ledger: is_on_ledger(public_key),
+            issues: vec![KeyIssue::OrphanedLocalKey],
+        });
+    }
+    audited_keys
+}
+
+fn display_audited_keys(
+    account_id: &near_primitives::types::AccountId,
+    network_config: &crate::config::NetworkConfig,
+    audited_keys: &[AuditedKey],
+) {
+    let mut table = Table::new();
+    table
+        .set_titles(prettytable::row![Fg=>"#", "Public Key", "Permissions", "Stored In", "Issues"]);
+
+    for (index, audited_key) in audited_keys.iter().enumerate() {
+        let permissions_message = match &audited_key.permission {
+            None => "not on the account".to_string(),
+            Some(near_primitives::views::AccessKeyPermissionView::FullAccess) => {
+                "full access".to_string()
+            }
+            Some(near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                receiver_id,
+                ..
+            }) => format!("function calls on {receiver_id}"),
+        };
+        let stores: Vec<String> = audited_key
+            .stored_in
+            .iter()
+            .map(ToString::to_string)
+            .chain(audited_key.on_ledger.then(|| "ledger".to_string()))
+            .collect();
+        let stored_in_message = if stores.is_empty() {
+            "-".to_string()
+        } else {
+            stores.join(", ")
+        };
+        let issues_message = audited_key
+            .issues
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        table.add_row(prettytable::row![
+            Fg->index + 1,
+            audited_key.public_key,
+            permissions_message,
+            stored_in_message,
+            Fy->issues_message
+        ]);
+    }
+
+    eprintln!(
+        "\nAccess keys of <{account_id}> on network <{}>:",
+        network_config.network_name
+    );
+    table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.printstd();
+
+    let issues_count: usize = audited_keys
+        .iter()
+        .map(|audited_key| audited_key.issues.len())
+        .sum();
+    if issues_count == 0 {
+        eprintln!("\nNo issues found.");
+    } else {
+        eprintln!("\n{issues_count} issue(s) found. Use `account delete-keys` to delete the keys that are not needed anymore and `account rotate-key` to replace a full access key.");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn access_key(
+        public_key: &near_crypto::PublicKey,
+        permission: near_primitives::views::AccessKeyPermissionView,
+    ) -> near_primitives::views::AccessKeyInfoView {
+        near_primitives::views::AccessKeyInfoView {
+            public_key: public_key.clone(),
+            access_key: near_primitives::views::AccessKeyView {
+                nonce: 0,
+                permission,
+            },
+        }
+    }
+
+    #[test]
+    fn audit_keys_flags_issues() {
+        let public_key = |seed: &str| {
+            near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, seed).public_key()
+        };
+        let (held_full_access, ledger_full_access, unheld_full_access, low_allowance, orphaned) = (
+            public_key("held"),
+            public_key("ledger"),
+            public_key("unheld"),
+            public_key("low"),
+            public_key("orphaned"),
+        );
+        let function_call =
+            |allowance| near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                allowance,
+                receiver_id: "app.near".to_string(),
+                method_names: vec![],
+            };
+        let on_chain_keys = [
+            access_key(
+                &held_full_access,
+                near_primitives::views::AccessKeyPermissionView::FullAccess,
+            ),
+            access_key(
+                &ledger_full_access,
+                near_primitives::views::AccessKeyPermissionView::FullAccess,
+            ),
+            access_key(
+                &unheld_full_access,
+                near_primitives::views::AccessKeyPermissionView::FullAccess,
+            ),
+            access_key(&low_allowance, function_call(Some(10u128.pow(21)))),
+            access_key(&public_key("unlimited"), function_call(None)),
+        ];
+        let stored_key = |public_key: &near_crypto::PublicKey, store| {
+            crate::commands::credentials::inventory::StoredKey {
+                network_name: "mainnet".to_string(),
+                account_id: "alice.near".parse().unwrap(),
+                public_key: public_key.clone(),
+                store,
+                path: None,
+            }
+        };
+        let stored_keys = [
+            stored_key(
+                &held_full_access,
+                crate::commands::credentials::inventory::KeyStore::LegacyKeychain,
+            ),
+            stored_key(
+                &held_full_access,
+                crate::commands::credentials::inventory::KeyStore::Keychain,
+            ),
+            stored_key(
+                &orphaned,
+                crate::commands::credentials::inventory::KeyStore::EncryptedKeystore,
+            ),
+        ];
+
+        let audited_keys = audit_keys(
+            &on_chain_keys,
+            &stored_keys,
+            Some(&ledger_full_access),
+            DEFAULT_MIN_ALLOWANCE,
+        );
+        assert_eq!(
+            audited_keys[0].stored_in,
+            vec![
+                crate::commands::credentials::inventory::KeyStore::Keychain,
+                crate::commands::credentials::inventory::KeyStore::LegacyKeychain,
+            ]
+        );
+        assert!(audited_keys[1].on_ledger);
+        let issues: Vec<(near_crypto::PublicKey, Vec<KeyIssue>)> = audited_keys
+            .into_iter()
+            .filter(|audited_key| !audited_key.issues.is_empty())
+            .map(|audited_key| (audited_key.public_key, audited_key.issues))
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                (unheld_full_access, vec![KeyIssue::UnheldFullAccessKey]),
+                (
+                    low_allowance,
+                    vec![KeyIssue::LowAllowance {
+                        allowance: near_token::NearToken::from_millinear(1)
+                    }]
+                ),
+                (orphaned, vec![KeyIssue::OrphanedLocalKey]),
+            ]
+        );
+    }
+}
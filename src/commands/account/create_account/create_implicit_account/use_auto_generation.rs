@@ -6,6 +6,10 @@ use color_eyre::eyre::Context;
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = SaveWithUseAutoGenerationContext)]
 pub struct SaveWithUseAutoGeneration {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Type of the key to generate: ed25519 (default) or secp256k1
+    key_type: Option<crate::types::key_type::KeyType>,
     #[interactive_clap(named_arg)]
     /// Specify a folder to save the implicit account file
     save_to_folder: super::SaveToFolder,
@@ -17,12 +21,14 @@ struct SaveWithUseAutoGenerationContext(super::SaveImplicitAccountContext);
 impl SaveWithUseAutoGenerationContext {
     pub fn from_previous_context(
         previous_context: crate::GlobalContext,
-        _scope: &<SaveWithUseAutoGeneration as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+        scope: &<SaveWithUseAutoGeneration as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         let on_after_getting_folder_path_callback: super::OnAfterGettingFolderPathCallback =
             std::sync::Arc::new({
+                let key_type = scope.key_type.unwrap_or_default();
+
                 move |folder_path| {
-                    let key_pair_properties = crate::common::generate_keypair()?;
+                    let key_pair_properties = crate::common::generate_keypair(key_type.into())?;
                     let buf = serde_json::json!({
                         "master_seed_phrase": key_pair_properties.master_seed_phrase,
                         "seed_phrase_hd_path": key_pair_properties.seed_phrase_hd_path,
@@ -1,15 +1,19 @@
 use std::io::Write;
 
 use color_eyre::eyre::Context;
-use inquire::CustomType;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = SaveWithSeedPhraseContext)]
+#[interactive_clap(skip_default_from_cli)]
 pub struct SaveWithSeedPhrase {
     /// Enter the seed-phrase for this account:
     master_seed_phrase: String,
     #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Type of the key derived from the seed phrase: ed25519 (default) or secp256k1
+    key_type: Option<crate::types::key_type::KeyType>,
+    #[interactive_clap(long)]
     #[interactive_clap(skip_default_input_arg)]
     seed_phrase_hd_path: crate::types::slip10::BIP32Path,
     #[interactive_clap(named_arg)]
@@ -29,6 +33,7 @@ impl SaveWithSeedPhraseContext {
             crate::common::get_key_pair_properties_from_seed_phrase(
                 scope.seed_phrase_hd_path.clone(),
                 scope.master_seed_phrase.clone(),
+                scope.key_type.unwrap_or_default().into(),
             )?;
         let on_after_getting_folder_path_callback: super::OnAfterGettingFolderPathCallback =
             std::sync::Arc::new({
@@ -70,14 +75,97 @@ impl From<SaveWithSeedPhraseContext> for super::SaveImplicitAccountContext {
     }
 }
 
-impl SaveWithSeedPhrase {
-    pub fn input_seed_phrase_hd_path(
-        _context: &crate::GlobalContext,
-    ) -> color_eyre::eyre::Result<Option<crate::types::slip10::BIP32Path>> {
-        Ok(Some(
-            CustomType::new("Enter seed phrase HD Path (if you not sure leave blank for default):")
-                .with_starting_input("m/44'/397'/0'")
-                .prompt()?,
-        ))
+impl interactive_clap::FromCli for SaveWithSeedPhrase {
+    type FromCliContext = crate::GlobalContext;
+    type FromCliError = color_eyre::eyre::Error;
+
+    fn from_cli(
+        optional_clap_variant: Option<<SaveWithSeedPhrase as interactive_clap::ToCli>::CliVariant>,
+        context: Self::FromCliContext,
+    ) -> interactive_clap::ResultFromCli<
+        <Self as interactive_clap::ToCli>::CliVariant,
+        Self::FromCliError,
+    >
+    where
+        Self: Sized + interactive_clap::ToCli,
+    {
+        let mut clap_variant = optional_clap_variant.unwrap_or_default();
+
+        if clap_variant.master_seed_phrase.is_none() {
+            clap_variant.master_seed_phrase = match Self::input_master_seed_phrase(&context) {
+                Ok(Some(master_seed_phrase)) => Some(master_seed_phrase),
+                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+        }
+        let master_seed_phrase = clap_variant
+            .master_seed_phrase
+            .clone()
+            .expect("Unexpected error");
+
+        // The default HD path depends on the key type, so the key type is asked for only when
+        // the HD path has to be entered interactively as well.
+        if clap_variant.key_type.is_none() && clap_variant.seed_phrase_hd_path.is_none() {
+            clap_variant.key_type = match crate::common::input_key_type() {
+                Ok(Some(key_type)) => Some(key_type),
+                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+        }
+        let key_type = clap_variant.key_type;
+
+        if clap_variant.seed_phrase_hd_path.is_none() {
+            clap_variant.seed_phrase_hd_path =
+                match crate::common::input_seed_phrase_hd_path(key_type.unwrap_or_default().into())
+                {
+                    Ok(Some(seed_phrase_hd_path)) => Some(seed_phrase_hd_path),
+                    Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                    Err(err) => {
+                        return interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+                    }
+                };
+        }
+        let seed_phrase_hd_path = clap_variant
+            .seed_phrase_hd_path
+            .clone()
+            .expect("Unexpected error");
+
+        let new_context_scope = InteractiveClapContextScopeForSaveWithSeedPhrase {
+            master_seed_phrase,
+            key_type,
+            seed_phrase_hd_path,
+        };
+        let new_context =
+            match SaveWithSeedPhraseContext::from_previous_context(context, &new_context_scope) {
+                Ok(new_context) => new_context,
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+
+        let optional_field = clap_variant
+            .save_to_folder
+            .take()
+            .map(|ClapNamedArgSaveToFolderForSaveWithSeedPhrase::SaveToFolder(cli_arg)| cli_arg);
+        match <super::SaveToFolder as interactive_clap::FromCli>::from_cli(
+            optional_field,
+            new_context.into(),
+        ) {
+            interactive_clap::ResultFromCli::Ok(cli_field) => {
+                clap_variant.save_to_folder = Some(
+                    ClapNamedArgSaveToFolderForSaveWithSeedPhrase::SaveToFolder(cli_field),
+                );
+                interactive_clap::ResultFromCli::Ok(clap_variant)
+            }
+            interactive_clap::ResultFromCli::Cancel(optional_cli_field) => {
+                clap_variant.save_to_folder = optional_cli_field
+                    .map(ClapNamedArgSaveToFolderForSaveWithSeedPhrase::SaveToFolder);
+                interactive_clap::ResultFromCli::Cancel(Some(clap_variant))
+            }
+            interactive_clap::ResultFromCli::Back => interactive_clap::ResultFromCli::Back,
+            interactive_clap::ResultFromCli::Err(optional_cli_field, err) => {
+                clap_variant.save_to_folder = optional_cli_field
+                    .map(ClapNamedArgSaveToFolderForSaveWithSeedPhrase::SaveToFolder);
+                interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+            }
+        }
     }
 }
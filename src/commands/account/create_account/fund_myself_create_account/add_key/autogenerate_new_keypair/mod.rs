@@ -6,6 +6,10 @@ use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 #[interactive_clap(input_context = super::super::NewAccountContext)]
 #[interactive_clap(output_context = GenerateKeypairContext)]
 pub struct GenerateKeypair {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Type of the key to generate: ed25519 (default) or secp256k1
+    key_type: Option<crate::types::key_type::KeyType>,
     #[interactive_clap(subcommand)]
     save_mode: SaveMode,
 }
@@ -20,10 +24,10 @@ pub struct GenerateKeypairContext {
 impl GenerateKeypairContext {
     pub fn from_previous_context(
         previous_context: super::super::NewAccountContext,
-        _scope: &<GenerateKeypair as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+        scope: &<GenerateKeypair as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         let key_pair_properties: crate::common::KeyPairProperties =
-            crate::common::generate_keypair()?;
+            crate::common::generate_keypair(scope.key_type.unwrap_or_default().into())?;
         let public_key = near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?;
         let account_properties = super::super::AccountProperties {
             new_account_id: previous_context.new_account_id,
@@ -58,17 +62,22 @@ impl From<GenerateKeypairContext> for super::super::AccountPropertiesContext {
 /// Save an access key for this account:
 pub enum SaveMode {
     #[strum_discriminants(strum(
-        message = "save-to-keychain         - Save automatically generated key pair to keychain"
+        message = "save-to-keychain            - Save automatically generated key pair to keychain"
     ))]
     /// Save automatically generated key pair to keychain
     SaveToKeychain(SignAs),
     #[strum_discriminants(strum(
-        message = "save-to-legacy-keychain  - Save automatically generated key pair to the legacy keychain (compatible with JS CLI)"
+        message = "save-to-legacy-keychain     - Save automatically generated key pair to the legacy keychain (compatible with JS CLI)"
     ))]
     /// Save automatically generated key pair to the legacy keychain (compatible with JS CLI)
     SaveToLegacyKeychain(SignAs),
     #[strum_discriminants(strum(
-        message = "print-to-terminal        - Print automatically generated key pair in terminal"
+        message = "save-to-encrypted-keystore  - Save automatically generated key pair to the password-encrypted keystore"
+    ))]
+    /// Save automatically generated key pair to the password-encrypted keystore
+    SaveToEncryptedKeystore(SignAs),
+    #[strum_discriminants(strum(
+        message = "print-to-terminal           - Print automatically generated key pair in terminal"
     ))]
     /// Print automatically generated key pair in terminal
     PrintToTerminal(SignAs),
@@ -97,6 +106,7 @@ impl SaveModeContext {
                                 serde_json::to_string(&key_pair_properties)?;
                             crate::common::save_access_key_to_keychain(
                                 network_config.clone(),
+                                credentials_home_dir.clone(),
                                 &key_pair_properties_buf,
                                 &key_pair_properties.public_key_str,
                                 new_account_id.as_ref(),
@@ -113,6 +123,17 @@ impl SaveModeContext {
                                 new_account_id.as_ref(),
                             )
                         }
+                        SaveModeDiscriminants::SaveToEncryptedKeystore => {
+                            let key_pair_properties_buf =
+                                serde_json::to_string(&key_pair_properties)?;
+                            crate::common::save_access_key_to_encrypted_keystore(
+                                network_config.clone(),
+                                credentials_home_dir.clone(),
+                                &key_pair_properties_buf,
+                                &key_pair_properties.public_key_str,
+                                new_account_id.as_ref(),
+                            )
+                        }
                         SaveModeDiscriminants::PrintToTerminal => {
                             Ok(format!(
                                 "\n--------------------  Access key info ------------------
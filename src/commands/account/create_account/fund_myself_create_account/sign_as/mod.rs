@@ -155,6 +155,7 @@ impl From<SignerAccountIdContext> for crate::commands::ActionContext {
             ),
             on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
             on_after_sending_transaction_callback,
+            transaction_bundle: None,
         }
     }
 }
@@ -1,11 +1,13 @@
-use std::str::FromStr;
-
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(input_context = super::super::NewAccountContext)]
 #[interactive_clap(output_context = AddAccessWithSeedPhraseActionContext)]
 pub struct AddAccessWithSeedPhraseAction {
     /// Enter the seed-phrase for this account:
     master_seed_phrase: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Type of the key to derive from the seed phrase: ed25519 (default) or secp256k1
+    key_type: Option<crate::types::key_type::KeyType>,
     #[interactive_clap(named_arg)]
     /// Select network
     network_config: super::super::network::Network,
@@ -19,12 +21,13 @@ impl AddAccessWithSeedPhraseActionContext {
         previous_context: super::super::NewAccountContext,
         scope: &<AddAccessWithSeedPhraseAction as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        // This is the HD path that is used in NEAR Wallet for plaintext seed phrase generation and, subsequently, for account recovery by a seed phrase.
-        let near_wallet_seed_phrase_hd_path_default =
-            slipped10::BIP32Path::from_str("m/44'/397'/0'").unwrap();
+        // The HD path that NEAR Wallet uses for plaintext seed phrase generation and, subsequently,
+        // for account recovery by a seed phrase (the Ethereum one for secp256k1 keys).
+        let key_type = scope.key_type.unwrap_or_default().into();
         let public_key = crate::common::get_public_key_from_seed_phrase(
-            near_wallet_seed_phrase_hd_path_default,
+            crate::common::default_seed_phrase_hd_path(key_type).into(),
             &scope.master_seed_phrase,
+            key_type,
         )?;
 
         Ok(Self(super::super::SponsorServiceContext {
@@ -135,9 +135,9 @@ fn print_account_creation_status(
                         );
                         eprintln!("New account <{new_account_id}> created successfully.\n{storage_message}\n");
                     }
-                    eprintln!("Transaction ID: {id}\nTo see the transaction in the transaction explorer, please open this url in your browser:\n{path}{id}\n",
-                        id=account_creation_transaction.transaction_outcome.id,
-                        path=network_config.explorer_transaction_url
+                    crate::common::print_transaction_id(
+                        &account_creation_transaction.transaction_outcome.id,
+                        network_config,
                     );
                 }
                 near_primitives::views::FinalExecutionStatus::NotStarted
@@ -30,8 +30,8 @@ impl ExportAccountFromSeedPhraseContext {
                             serde_json::from_str::<crate::common::KeyPairProperties>(&password)
                         {
                             println!(
-                                "Here is the secret recovery seed phrase for account <{}>: \"{}\" (HD Path: {}).",
-                                account_id, key_pair_properties.master_seed_phrase, key_pair_properties.seed_phrase_hd_path
+                                "Here is the secret recovery seed phrase for account <{}>: \"{}\" (HD Path: {}, key type: {}).",
+                                account_id, key_pair_properties.master_seed_phrase, key_pair_properties.seed_phrase_hd_path, key_pair_properties.key_type()
                             );
                             return Ok(());
                         }
@@ -50,8 +50,8 @@ impl ExportAccountFromSeedPhraseContext {
                             format!("Error reading data from file: {:?}", &data_path)
                         })?;
                     println!(
-                        "Here is the secret recovery seed phrase for account <{}>: \"{}\" (HD Path: {}).",
-                        account_id, key_pair_properties.master_seed_phrase, key_pair_properties.seed_phrase_hd_path
+                        "Here is the secret recovery seed phrase for account <{}>: \"{}\" (HD Path: {}, key type: {}).",
+                        account_id, key_pair_properties.master_seed_phrase, key_pair_properties.seed_phrase_hd_path, key_pair_properties.key_type()
                     );
                     Ok(())
                 }
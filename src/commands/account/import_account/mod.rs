@@ -108,18 +108,22 @@ fn save_access_key(
             to_string = "Store the access key in my legacy keychain (compatible with the old near CLI)"
         )]
         SaveToLegacyKeychain,
+        #[strum(to_string = "Store the access key in my password-encrypted keystore")]
+        SaveToEncryptedKeystore,
     }
     let selection = Select::new(
         "Select a keychain to save the access key to:",
         vec![
             SelectStorage::SaveToKeychain,
             SelectStorage::SaveToLegacyKeychain,
+            SelectStorage::SaveToEncryptedKeystore,
         ],
     )
     .prompt()?;
     if let SelectStorage::SaveToKeychain = selection {
         let storage_message = crate::common::save_access_key_to_keychain(
             network_config,
+            credentials_home_dir,
             key_pair_properties_buf,
             public_key_str,
             account_id.as_ref(),
@@ -134,6 +138,24 @@ fn save_access_key(
         return Ok(());
     }
 
+    if let SelectStorage::SaveToEncryptedKeystore = selection {
+        let storage_message = crate::common::save_access_key_to_encrypted_keystore(
+            network_config,
+            credentials_home_dir,
+            key_pair_properties_buf,
+            public_key_str,
+            account_id.as_ref(),
+        )
+        .wrap_err_with(|| {
+            format!(
+                "Failed to save the access key <{}> to the encrypted keystore",
+                public_key_str
+            )
+        })?;
+        eprintln!("{}", storage_message);
+        return Ok(());
+    }
+
     let storage_message = crate::common::save_access_key_to_legacy_keychain(
         network_config,
         credentials_home_dir,
@@ -25,6 +25,7 @@ impl ViewListKeysContext {
     ) -> color_eyre::eyre::Result<Self> {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+            let output_format = previous_context.output_format.clone();
 
             move |network_config, block_reference| {
                 let access_key_list = network_config
@@ -41,6 +42,15 @@ impl ViewListKeysContext {
                     })?
                     .access_key_list_view()?;
 
+                if output_format.is_json() {
+                    return crate::common::print_json_output(
+                        "access_key_list",
+                        serde_json::json!({
+                            "account_id": account_id,
+                            "keys": access_key_list.keys,
+                        }),
+                    );
+                }
                 crate::common::display_access_key_list(&access_key_list.keys);
                 Ok(())
             }
@@ -1,15 +1,20 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 mod add_key;
+mod audit_keys;
 pub mod create_account;
 mod delete_account;
 mod delete_key;
 mod export_account;
 mod import_account;
 mod list_keys;
+mod multisig;
+mod rotate_key;
 pub mod storage_management;
 pub mod update_social_profile;
 mod view_account_summary;
+mod view_history;
+mod view_portfolio;
 
 pub const MIN_ALLOWED_TOP_LEVEL_ACCOUNT_LENGTH: usize = 32;
 
@@ -31,6 +36,16 @@ pub enum AccountActions {
     ))]
     /// View properties for an account
     ViewAccountSummary(self::view_account_summary::ViewAccountSummary),
+    #[strum_discriminants(strum(
+        message = "view-history            - View recent transactions, transfers and key changes of an account"
+    ))]
+    /// View recent transactions, transfers and key changes of an account (requires an indexer API)
+    ViewHistory(self::view_history::ViewHistory),
+    #[strum_discriminants(strum(
+        message = "view-portfolio          - View NEAR, staked, FT and NFT holdings of an account"
+    ))]
+    /// View NEAR, staked, FT and NFT holdings of an account with their USD value
+    ViewPortfolio(self::view_portfolio::ViewPortfolio),
     #[strum_discriminants(strum(
         message = "import-account          - Import existing account (a.k.a. \"sign in\")"
     ))]
@@ -65,9 +80,24 @@ pub enum AccountActions {
     ))]
     /// Delete access keys from an account
     DeleteKeys(self::delete_key::DeleteKeysCommand),
+    #[strum_discriminants(strum(
+        message = "rotate-key              - Replace a full access key with a new one in one transaction"
+    ))]
+    /// Replace a full access key with a new one in one transaction and update the stored credentials
+    RotateKey(self::rotate_key::RotateKey),
+    #[strum_discriminants(strum(
+        message = "audit-keys              - Check access keys of an account against the local key stores"
+    ))]
+    /// Check access keys of an account against the keychain, legacy keychain, encrypted keystore and Ledger
+    AuditKeys(self::audit_keys::AuditKeys),
     #[strum_discriminants(strum(
         message = "manage-storage-deposit  - Storage management: deposit, withdrawal, balance review"
     ))]
     /// Storage management for contract: deposit, withdrawal, balance review
     ManageStorageDeposit(self::storage_management::Contract),
+    #[strum_discriminants(strum(
+        message = "multisig                - Manage requests of a multisig account: propose, confirm, list"
+    ))]
+    /// Manage requests of a multisig account: propose, confirm, list
+    Multisig(self::multisig::MultisigCommands),
 }
@@ -0,0 +1,48 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ConfirmContext)]
+pub struct Confirm {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What is the multisig account ID?
+    multisig_account_id: crate::types::account_id::AccountId,
+    /// Enter the ID of the request to confirm:
+    request_id: crate::types::multisig_request_id::MultisigRequestId,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// The gas to attach to the call (by default, enough to execute the request)
+    prepaid_gas: Option<crate::common::NearGas>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_for_transaction::NetworkForTransactionArgs,
+}
+
+#[derive(Clone)]
+pub struct ConfirmContext(crate::commands::ActionContext);
+
+impl ConfirmContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<Confirm as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self(super::RequestMethod::Confirm.action_context(
+            previous_context,
+            scope.multisig_account_id.clone().into(),
+            scope.request_id,
+            scope.prepaid_gas,
+        )))
+    }
+}
+
+impl From<ConfirmContext> for crate::commands::ActionContext {
+    fn from(item: ConfirmContext) -> Self {
+        item.0
+    }
+}
+
+impl Confirm {
+    pub fn input_multisig_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        super::input_multisig_account_id(context)
+    }
+}
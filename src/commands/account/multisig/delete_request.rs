@@ -0,0 +1,48 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = DeleteRequestContext)]
+pub struct DeleteRequest {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What is the multisig account ID?
+    multisig_account_id: crate::types::account_id::AccountId,
+    /// Enter the ID of the request to delete:
+    request_id: crate::types::multisig_request_id::MultisigRequestId,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// The gas to attach to the call (default: 50 Tgas)
+    prepaid_gas: Option<crate::common::NearGas>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_for_transaction::NetworkForTransactionArgs,
+}
+
+#[derive(Clone)]
+pub struct DeleteRequestContext(crate::commands::ActionContext);
+
+impl DeleteRequestContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<DeleteRequest as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self(super::RequestMethod::DeleteRequest.action_context(
+            previous_context,
+            scope.multisig_account_id.clone().into(),
+            scope.request_id,
+            scope.prepaid_gas,
+        )))
+    }
+}
+
+impl From<DeleteRequestContext> for crate::commands::ActionContext {
+    fn from(item: DeleteRequestContext) -> Self {
+        item.0
+    }
+}
+
+impl DeleteRequest {
+    pub fn input_multisig_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        super::input_multisig_account_id(context)
+    }
+}
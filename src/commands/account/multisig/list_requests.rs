@@ -0,0 +1,150 @@
+use prettytable::Table;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ListRequestsContext)]
+pub struct ListRequests {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What is the multisig account ID?
+    multisig_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+#[derive(Clone)]
+pub struct ListRequestsContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl ListRequestsContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ListRequests as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
+            let multisig_account_id: near_primitives::types::AccountId = scope.multisig_account_id.clone().into();
+            let output_format = previous_context.output_format.clone();
+
+            move |network_config, block_reference| {
+                print_requests(network_config, block_reference, &multisig_account_id, &output_format)
+            }
+        });
+
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config: previous_context.config,
+            interacting_with_account_ids: vec![scope.multisig_account_id.clone().into()],
+            on_after_getting_block_reference_callback,
+        }))
+    }
+}
+
+impl From<ListRequestsContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: ListRequestsContext) -> Self {
+        item.0
+    }
+}
+
+fn print_requests(
+    network_config: &crate::config::NetworkConfig,
+    block_reference: &near_primitives::types::BlockReference,
+    multisig_account_id: &near_primitives::types::AccountId,
+    output_format: &crate::common::OutputFormat,
+) -> crate::CliResult {
+    let num_confirmations: u32 = super::request::view_multisig_function(
+        network_config,
+        multisig_account_id,
+        "get_num_confirmations",
+        serde_json::json!({}),
+        block_reference.clone(),
+    )?;
+    let mut request_ids: Vec<crate::types::multisig_request_id::MultisigRequestId> =
+        super::request::view_multisig_function(
+            network_config,
+            multisig_account_id,
+            "list_request_ids",
+            serde_json::json!({}),
+            block_reference.clone(),
+        )?;
+    request_ids.sort_unstable();
+
+    let requests = request_ids
+        .into_iter()
+        .map(|request_id| {
+            let request: super::request::MultisigRequest = super::request::view_multisig_function(
+                network_config,
+                multisig_account_id,
+                "get_request",
+                serde_json::json!({ "request_id": request_id }),
+                block_reference.clone(),
+            )?;
+            let confirmations: Vec<near_crypto::PublicKey> =
+                super::request::view_multisig_function(
+                    network_config,
+                    multisig_account_id,
+                    "get_confirmations",
+                    serde_json::json!({ "request_id": request_id }),
+                    block_reference.clone(),
+                )?;
+            Ok((request_id, request, confirmations))
+        })
+        .collect::<color_eyre::eyre::Result<Vec<_>>>()?;
+
+    if output_format.is_json() {
+        return crate::common::print_json_output(
+            "multisig_requests",
+            serde_json::json!({
+                "account_id": multisig_account_id,
+                "num_confirmations": num_confirmations,
+                "requests": requests
+                    .iter()
+                    .map(|(request_id, request, confirmations)| serde_json::json!({
+                        "request_id": request_id,
+                        "request": request,
+                        "confirmations": confirmations,
+                    }))
+                    .collect::<Vec<_>>(),
+            }),
+        );
+    }
+
+    if requests.is_empty() {
+        eprintln!("Multisig account <{multisig_account_id}> has no pending requests.");
+        return Ok(());
+    }
+    let mut table = Table::new();
+    table.set_titles(prettytable::row![Fg=>"ID", "Receiver", "Actions", "Confirmations"]);
+    for (request_id, request, confirmations) in &requests {
+        table.add_row(prettytable::row![
+            Fg->request_id,
+            request.receiver_id,
+            request
+                .actions
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n"),
+            format!(
+                "{}/{num_confirmations}\n{}",
+                confirmations.len(),
+                confirmations
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            ),
+        ]);
+    }
+    table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.printstd();
+    Ok(())
+}
+
+impl ListRequests {
+    pub fn input_multisig_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_non_signer_account_id_from_used_account_list(
+            &context.config.credentials_home_dir,
+            "What is the multisig account ID?",
+        )
+    }
+}
@@ -0,0 +1,180 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod confirm;
+mod delete_request;
+mod list_requests;
+mod propose_request;
+mod request;
+mod view_config;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+pub struct MultisigCommands {
+    #[interactive_clap(subcommand)]
+    multisig_actions: MultisigActions,
+}
+
+#[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+/// What do you want to do with a multisig account?
+pub enum MultisigActions {
+    #[strum_discriminants(strum(
+        message = "view-config      - View the number of confirmations and the confirming keys of a multisig account"
+    ))]
+    /// View the number of confirmations and the confirming keys of a multisig account
+    ViewConfig(self::view_config::ViewConfig),
+    #[strum_discriminants(strum(
+        message = "list-requests    - View pending requests with their confirmations"
+    ))]
+    /// View pending requests with their confirmations
+    ListRequests(self::list_requests::ListRequests),
+    #[strum_discriminants(strum(
+        message = "propose-request  - Propose a request from a transaction prepared with construct-transaction"
+    ))]
+    /// Propose a request from a transaction prepared with construct-transaction
+    ProposeRequest(self::propose_request::ProposeRequest),
+    #[strum_discriminants(strum(message = "confirm          - Confirm a pending request"))]
+    /// Confirm a pending request
+    Confirm(self::confirm::Confirm),
+    #[strum_discriminants(strum(message = "delete-request   - Delete a pending request"))]
+    /// Delete a pending request
+    DeleteRequest(self::delete_request::DeleteRequest),
+}
+
+/// The gas of a multisig method call that does not execute a request.
+const MULTISIG_METHOD_GAS: crate::common::NearGas = crate::common::NearGas::from_tgas(50);
+const MAX_PREPAID_GAS: crate::common::NearGas = crate::common::NearGas::from_tgas(300);
+
+/// Multisig methods can only be called by the access keys of the multisig account itself.
+pub fn multisig_function_call(
+    multisig_account_id: &near_primitives::types::AccountId,
+    method_name: &str,
+    args: serde_json::Value,
+    prepaid_gas: crate::common::NearGas,
+) -> color_eyre::eyre::Result<crate::commands::PrepopulatedTransaction> {
+    Ok(crate::commands::PrepopulatedTransaction {
+        signer_id: multisig_account_id.clone(),
+        receiver_id: multisig_account_id.clone(),
+        actions: vec![near_primitives::transaction::Action::FunctionCall(
+            Box::new(near_primitives::transaction::FunctionCallAction {
+                method_name: method_name.to_string(),
+                args: serde_json::to_vec(&args)?,
+                gas: prepaid_gas.as_gas(),
+                deposit: 0,
+            }),
+        )],
+    })
+}
+
+/// The multisig methods that act on a pending request by its ID.
+#[derive(Debug, Clone, Copy)]
+enum RequestMethod {
+    Confirm,
+    DeleteRequest,
+}
+
+impl RequestMethod {
+    fn method_name(self) -> &'static str {
+        match self {
+            Self::Confirm => "confirm",
+            Self::DeleteRequest => "delete_request",
+        }
+    }
+
+    /// The gas to attach if it is not set with `--prepaid-gas`. The confirmation that reaches the
+    /// required number of confirmations executes the request, so it gets the gas of the request.
+    fn default_prepaid_gas(
+        self,
+        global_context: &crate::GlobalContext,
+        network_config: &crate::config::NetworkConfig,
+        multisig_account_id: &near_primitives::types::AccountId,
+        request_id: crate::types::multisig_request_id::MultisigRequestId,
+    ) -> color_eyre::eyre::Result<crate::common::NearGas> {
+        match self {
+            Self::DeleteRequest => Ok(MULTISIG_METHOD_GAS),
+            Self::Confirm if global_context.offline => Ok(MAX_PREPAID_GAS),
+            Self::Confirm => {
+                let request: self::request::MultisigRequest =
+                    self::request::view_multisig_function(
+                        network_config,
+                        multisig_account_id,
+                        "get_request",
+                        serde_json::json!({ "request_id": request_id }),
+                        near_primitives::types::Finality::Final.into(),
+                    )?;
+                Ok(request.execution_gas())
+            }
+        }
+    }
+
+    fn action_context(
+        self,
+        global_context: crate::GlobalContext,
+        multisig_account_id: near_primitives::types::AccountId,
+        request_id: crate::types::multisig_request_id::MultisigRequestId,
+        prepaid_gas: Option<crate::common::NearGas>,
+    ) -> crate::commands::ActionContext {
+        let get_prepopulated_transaction_after_getting_network_callback: crate::commands::GetPrepopulatedTransactionAfterGettingNetworkCallback =
+            std::sync::Arc::new({
+                let global_context = global_context.clone();
+                let multisig_account_id = multisig_account_id.clone();
+
+                move |network_config| {
+                    let prepaid_gas = match prepaid_gas {
+                        Some(prepaid_gas) => prepaid_gas,
+                        None => self.default_prepaid_gas(
+                            &global_context,
+                            network_config,
+                            &multisig_account_id,
+                            request_id,
+                        )?,
+                    };
+                    multisig_function_call(
+                        &multisig_account_id,
+                        self.method_name(),
+                        serde_json::json!({ "request_id": request_id }),
+                        prepaid_gas,
+                    )
+                }
+            });
+
+        let on_after_sending_transaction_callback: crate::transaction_signature_options::OnAfterSendingTransactionCallback = std::sync::Arc::new({
+            let multisig_account_id = multisig_account_id.clone();
+
+            move |outcome_view, _network_config| {
+                if let near_primitives::views::FinalExecutionStatus::SuccessValue(_) = outcome_view.status {
+                    let action = match self {
+                        Self::Confirm => "confirmed",
+                        Self::DeleteRequest => "deleted",
+                    };
+                    eprintln!("Request #{request_id} of the multisig account <{multisig_account_id}> has been {action}.");
+                }
+                Ok(())
+            }
+        });
+
+        crate::commands::ActionContext {
+            global_context,
+            interacting_with_account_ids: vec![multisig_account_id],
+            get_prepopulated_transaction_after_getting_network_callback,
+            on_before_signing_callback: std::sync::Arc::new(
+                |_prepolulated_unsinged_transaction, _network_config| Ok(()),
+            ),
+            on_before_sending_transaction_callback: std::sync::Arc::new(
+                |_signed_transaction, _network_config| Ok(String::new()),
+            ),
+            on_after_sending_transaction_callback,
+            transaction_bundle: None,
+        }
+    }
+}
+
+fn input_multisig_account_id(
+    context: &crate::GlobalContext,
+) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+    crate::common::input_signer_account_id_from_used_account_list(
+        &context.config.credentials_home_dir,
+        "What is the multisig account ID?",
+    )
+}
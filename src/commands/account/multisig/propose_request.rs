@@ -0,0 +1,90 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ProposeRequestContext)]
+pub struct ProposeRequest {
+    /// Enter the transaction of the multisig account encoded in base64 (prepared with "construct-transaction ... sign-later"):
+    unsigned_transaction: crate::types::transaction::TransactionAsBase64,
+    #[interactive_clap(long)]
+    /// Only add the request without confirming it with the signing key
+    without_confirmation: bool,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// The gas to attach to the call (by default, enough to execute the request if it is confirmed)
+    prepaid_gas: Option<crate::common::NearGas>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_for_transaction::NetworkForTransactionArgs,
+}
+
+#[derive(Clone)]
+pub struct ProposeRequestContext(crate::commands::ActionContext);
+
+impl ProposeRequestContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ProposeRequest as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let prepopulated_transaction = crate::commands::PrepopulatedTransaction::from(
+            near_primitives::transaction::Transaction::from(scope.unsigned_transaction.clone()),
+        );
+        let multisig_account_id = prepopulated_transaction.signer_id.clone();
+        let request = super::request::MultisigRequest::try_from(prepopulated_transaction.clone())?;
+
+        let get_prepopulated_transaction_after_getting_network_callback: crate::commands::GetPrepopulatedTransactionAfterGettingNetworkCallback =
+            std::sync::Arc::new({
+                let multisig_account_id = multisig_account_id.clone();
+                let (method_name, default_prepaid_gas) = if scope.without_confirmation {
+                    ("add_request", super::MULTISIG_METHOD_GAS)
+                } else {
+                    // The request is executed right away if one confirmation is enough.
+                    ("add_request_and_confirm", request.execution_gas())
+                };
+                let prepaid_gas = scope.prepaid_gas.unwrap_or(default_prepaid_gas);
+
+                move |_network_config| {
+                    super::multisig_function_call(
+                        &multisig_account_id,
+                        method_name,
+                        serde_json::json!({ "request": request }),
+                        prepaid_gas,
+                    )
+                }
+            });
+
+        let on_after_sending_transaction_callback: crate::transaction_signature_options::OnAfterSendingTransactionCallback = std::sync::Arc::new({
+            let multisig_account_id = multisig_account_id.clone();
+
+            move |outcome_view, _network_config| {
+                if let near_primitives::views::FinalExecutionStatus::SuccessValue(result) = &outcome_view.status {
+                    if let Ok(request_id) = serde_json::from_slice::<crate::types::multisig_request_id::MultisigRequestId>(result) {
+                        eprintln!("Request #{request_id} has been proposed to the multisig account <{multisig_account_id}>.");
+                    }
+                }
+                Ok(())
+            }
+        });
+
+        Ok(Self(crate::commands::ActionContext {
+            global_context: previous_context,
+            interacting_with_account_ids: vec![
+                multisig_account_id,
+                prepopulated_transaction.receiver_id,
+            ],
+            get_prepopulated_transaction_after_getting_network_callback,
+            on_before_signing_callback: std::sync::Arc::new(
+                |_prepolulated_unsinged_transaction, _network_config| Ok(()),
+            ),
+            on_before_sending_transaction_callback: std::sync::Arc::new(
+                |_signed_transaction, _network_config| Ok(String::new()),
+            ),
+            on_after_sending_transaction_callback,
+            transaction_bundle: None,
+        }))
+    }
+}
+
+impl From<ProposeRequestContext> for crate::commands::ActionContext {
+    fn from(item: ProposeRequestContext) -> Self {
+        item.0
+    }
+}
@@ -0,0 +1,295 @@
+use color_eyre::eyre::WrapErr;
+
+use crate::common::{CallResultExt, JsonRpcClientExt};
+
+/// The request format of the NEAR multisig contract.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MultisigRequest {
+    pub receiver_id: near_primitives::types::AccountId,
+    pub actions: Vec<MultisigRequestAction>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type")]
+pub enum MultisigRequestAction {
+    Transfer {
+        #[serde(with = "near_primitives::serialize::dec_format")]
+        amount: near_primitives::types::Balance,
+    },
+    CreateAccount,
+    DeployContract {
+        /// base64-encoded contract code
+        code: String,
+    },
+    AddKey {
+        public_key: near_crypto::PublicKey,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        permission: Option<MultisigFunctionCallPermission>,
+    },
+    DeleteKey {
+        public_key: near_crypto::PublicKey,
+    },
+    FunctionCall {
+        method_name: String,
+        /// base64-encoded function arguments
+        args: String,
+        #[serde(with = "near_primitives::serialize::dec_format")]
+        deposit: near_primitives::types::Balance,
+        #[serde(with = "near_primitives::serialize::dec_format")]
+        gas: near_primitives::types::Gas,
+    },
+    SetNumConfirmations {
+        num_confirmations: u32,
+    },
+    SetActiveRequestsLimit {
+        active_requests_limit: u32,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MultisigFunctionCallPermission {
+    #[serde(
+        default,
+        with = "near_primitives::serialize::dec_format",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub allowance: Option<near_primitives::types::Balance>,
+    pub receiver_id: near_primitives::types::AccountId,
+    pub method_names: Vec<String>,
+}
+
+impl TryFrom<crate::commands::PrepopulatedTransaction> for MultisigRequest {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(
+        prepopulated_transaction: crate::commands::PrepopulatedTransaction,
+    ) -> Result<Self, Self::Error> {
+        use near_primitives::transaction::Action;
+
+        let actions = prepopulated_transaction
+            .actions
+            .into_iter()
+            .map(|action| {
+                Ok(match action {
+                    Action::Transfer(transfer_action) => MultisigRequestAction::Transfer {
+                        amount: transfer_action.deposit,
+                    },
+                    Action::CreateAccount(_) => MultisigRequestAction::CreateAccount,
+                    Action::DeployContract(deploy_contract_action) => {
+                        MultisigRequestAction::DeployContract {
+                            code: near_primitives::serialize::to_base64(
+                                &deploy_contract_action.code,
+                            ),
+                        }
+                    }
+                    Action::AddKey(add_key_action) => MultisigRequestAction::AddKey {
+                        public_key: add_key_action.public_key,
+                        permission: match add_key_action.access_key.permission {
+                            near_primitives::account::AccessKeyPermission::FullAccess => None,
+                            near_primitives::account::AccessKeyPermission::FunctionCall(
+                                function_call_permission,
+                            ) => Some(MultisigFunctionCallPermission {
+                                allowance: function_call_permission.allowance,
+                                receiver_id: function_call_permission.receiver_id.parse()?,
+                                method_names: function_call_permission.method_names,
+                            }),
+                        },
+                    },
+                    Action::DeleteKey(delete_key_action) => MultisigRequestAction::DeleteKey {
+                        public_key: delete_key_action.public_key,
+                    },
+                    Action::FunctionCall(function_call_action) => {
+                        MultisigRequestAction::FunctionCall {
+                            method_name: function_call_action.method_name,
+                            args: near_primitives::serialize::to_base64(&function_call_action.args),
+                            deposit: function_call_action.deposit,
+                            gas: function_call_action.gas,
+                        }
+                    }
+                    Action::Stake(_) | Action::DeleteAccount(_) | Action::Delegate(_) => {
+                        color_eyre::eyre::bail!(
+                            "The multisig contract does not support the action: {action:?}"
+                        )
+                    }
+                })
+            })
+            .collect::<color_eyre::eyre::Result<_>>()?;
+
+        Ok(Self {
+            receiver_id: prepopulated_transaction.receiver_id,
+            actions,
+        })
+    }
+}
+
+impl MultisigRequest {
+    /// The gas for a multisig call that executes the request: the gas of the multisig method and
+    /// the gas the request attaches to its function calls (up to the maximum prepaid gas).
+    pub fn execution_gas(&self) -> crate::common::NearGas {
+        let function_calls_gas: near_primitives::types::Gas = self
+            .actions
+            .iter()
+            .map(|action| match action {
+                MultisigRequestAction::FunctionCall { gas, .. } => *gas,
+                _ => 0,
+            })
+            .fold(0, near_primitives::types::Gas::saturating_add);
+        crate::common::NearGas::from_gas(
+            super::MULTISIG_METHOD_GAS
+                .as_gas()
+                .saturating_add(function_calls_gas)
+                .min(super::MAX_PREPAID_GAS.as_gas()),
+        )
+    }
+}
+
+impl std::fmt::Display for MultisigRequestAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Transfer { amount } => write!(
+                f,
+                "transfer {}",
+                crate::types::near_token::NearToken::from_yoctonear(*amount)
+            ),
+            Self::CreateAccount => write!(f, "create account"),
+            Self::DeployContract { code } => {
+                write!(f, "deploy contract ({} base64 characters)", code.len())
+            }
+            Self::AddKey {
+                public_key,
+                permission: None,
+            } => write!(f, "add full access key {public_key}"),
+            Self::AddKey {
+                public_key,
+                permission: Some(permission),
+            } => write!(
+                f,
+                "add function call key {public_key} for <{}>",
+                permission.receiver_id
+            ),
+            Self::DeleteKey { public_key } => write!(f, "delete key {public_key}"),
+            Self::FunctionCall {
+                method_name,
+                deposit,
+                ..
+            } => write!(
+                f,
+                "call {method_name} with deposit {}",
+                crate::types::near_token::NearToken::from_yoctonear(*deposit)
+            ),
+            Self::SetNumConfirmations { num_confirmations } => {
+                write!(f, "set number of confirmations to {num_confirmations}")
+            }
+            Self::SetActiveRequestsLimit {
+                active_requests_limit,
+            } => write!(f, "set active requests limit to {active_requests_limit}"),
+        }
+    }
+}
+
+pub fn view_multisig_function<T: serde::de::DeserializeOwned>(
+    network_config: &crate::config::NetworkConfig,
+    multisig_account_id: &near_primitives::types::AccountId,
+    method_name: &str,
+    args: serde_json::Value,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<T> {
+    network_config
+        .json_rpc_client()
+        .blocking_call_view_function(
+            multisig_account_id,
+            method_name,
+            serde_json::to_vec(&args)?,
+            block_reference,
+        )
+        .wrap_err_with(|| {
+            format!("Failed to call the <{method_name}> method of the multisig contract <{multisig_account_id}>")
+        })?
+        .parse_result_from_json::<T>()
+        .wrap_err_with(|| {
+            format!("Failed to parse the result of the <{method_name}> method of the multisig contract <{multisig_account_id}>")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multisig_request_from_prepopulated_transaction() {
+        let request = MultisigRequest::try_from(crate::commands::PrepopulatedTransaction {
+            signer_id: "treasury.testnet".parse().unwrap(),
+            receiver_id: "bob.testnet".parse().unwrap(),
+            actions: vec![
+                near_primitives::transaction::Action::Transfer(
+                    near_primitives::transaction::TransferAction { deposit: 10 },
+                ),
+                near_primitives::transaction::Action::FunctionCall(Box::new(
+                    near_primitives::transaction::FunctionCallAction {
+                        method_name: "ping".to_string(),
+                        args: b"{}".to_vec(),
+                        gas: 5,
+                        deposit: 1,
+                    },
+                )),
+            ],
+        })
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "receiver_id": "bob.testnet",
+                "actions": [
+                    {"type": "Transfer", "amount": "10"},
+                    {"type": "FunctionCall", "method_name": "ping", "args": "e30=", "deposit": "1", "gas": "5"},
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn execution_gas_covers_the_function_calls_of_the_request() {
+        let function_call = |gas| MultisigRequestAction::FunctionCall {
+            method_name: "ping".to_string(),
+            args: String::new(),
+            deposit: 0,
+            gas,
+        };
+        let request = |actions| MultisigRequest {
+            receiver_id: "bob.testnet".parse().unwrap(),
+            actions,
+        };
+        assert_eq!(
+            request(vec![MultisigRequestAction::Transfer { amount: 1 }]).execution_gas(),
+            crate::common::NearGas::from_tgas(50)
+        );
+        assert_eq!(
+            request(vec![
+                function_call(crate::common::NearGas::from_tgas(30).as_gas()),
+                function_call(crate::common::NearGas::from_tgas(100).as_gas()),
+            ])
+            .execution_gas(),
+            crate::common::NearGas::from_tgas(180)
+        );
+        assert_eq!(
+            request(vec![function_call(u64::MAX)]).execution_gas(),
+            crate::common::NearGas::from_tgas(300)
+        );
+    }
+
+    #[test]
+    fn multisig_request_rejects_unsupported_actions() {
+        assert!(
+            MultisigRequest::try_from(crate::commands::PrepopulatedTransaction {
+                signer_id: "treasury.testnet".parse().unwrap(),
+                receiver_id: "treasury.testnet".parse().unwrap(),
+                actions: vec![near_primitives::transaction::Action::DeleteAccount(
+                    near_primitives::transaction::DeleteAccountAction {
+                        beneficiary_id: "bob.testnet".parse().unwrap(),
+                    }
+                )],
+            })
+            .is_err()
+        );
+    }
+}
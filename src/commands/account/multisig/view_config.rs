@@ -0,0 +1,107 @@
+use color_eyre::eyre::Context;
+
+use crate::common::JsonRpcClientExt;
+use crate::common::RpcQueryResponseExt;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ViewConfigContext)]
+pub struct ViewConfig {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What is the multisig account ID?
+    multisig_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+#[derive(Clone)]
+pub struct ViewConfigContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl ViewConfigContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ViewConfig as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
+            let multisig_account_id: near_primitives::types::AccountId = scope.multisig_account_id.clone().into();
+            let output_format = previous_context.output_format.clone();
+
+            move |network_config, block_reference| {
+                let num_confirmations: u32 = super::request::view_multisig_function(
+                    network_config,
+                    &multisig_account_id,
+                    "get_num_confirmations",
+                    serde_json::json!({}),
+                    block_reference.clone(),
+                )?;
+
+                let access_key_list = network_config
+                    .json_rpc_client()
+                    .blocking_call_view_access_key_list(
+                        &multisig_account_id,
+                        block_reference.clone(),
+                    )
+                    .wrap_err_with(|| {
+                        format!(
+                            "Failed to fetch query AccessKeyList for {}",
+                            &multisig_account_id
+                        )
+                    })?
+                    .access_key_list_view()?;
+                // The confirming keys are the function call keys of the multisig account restricted to itself.
+                let confirming_keys = access_key_list
+                    .keys
+                    .into_iter()
+                    .filter(|access_key| {
+                        matches!(
+                            &access_key.access_key.permission,
+                            near_primitives::views::AccessKeyPermissionView::FunctionCall { receiver_id, .. }
+                                if receiver_id == multisig_account_id.as_str()
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                if output_format.is_json() {
+                    return crate::common::print_json_output(
+                        "multisig_config",
+                        serde_json::json!({
+                            "account_id": multisig_account_id,
+                            "num_confirmations": num_confirmations,
+                            "confirming_keys": confirming_keys,
+                        }),
+                    );
+                }
+                eprintln!(
+                    "Multisig account <{multisig_account_id}> requires {num_confirmations} confirmation(s) out of {} confirming key(s):",
+                    confirming_keys.len()
+                );
+                crate::common::display_access_key_list(&confirming_keys);
+                Ok(())
+            }
+        });
+
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config: previous_context.config,
+            interacting_with_account_ids: vec![scope.multisig_account_id.clone().into()],
+            on_after_getting_block_reference_callback,
+        }))
+    }
+}
+
+impl From<ViewConfigContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: ViewConfigContext) -> Self {
+        item.0
+    }
+}
+
+impl ViewConfig {
+    pub fn input_multisig_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_non_signer_account_id_from_used_account_list(
+            &context.config.credentials_home_dir,
+            "What is the multisig account ID?",
+        )
+    }
+}
@@ -0,0 +1,151 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::RotateKeyContext)]
+#[interactive_clap(output_context = GenerateKeypairContext)]
+pub struct GenerateKeypair {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Type of the key to generate: ed25519 (default) or secp256k1
+    key_type: Option<crate::types::key_type::KeyType>,
+    #[interactive_clap(subcommand)]
+    save_mode: SaveMode,
+}
+
+#[derive(Debug, Clone)]
+pub struct GenerateKeypairContext {
+    rotate_key_context: super::RotateKeyContext,
+    key_pair_properties: crate::common::KeyPairProperties,
+    public_key: near_crypto::PublicKey,
+}
+
+impl GenerateKeypairContext {
+    pub fn from_previous_context(
+        previous_context: super::RotateKeyContext,
+        scope: &<GenerateKeypair as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let key_pair_properties =
+            crate::common::generate_keypair(scope.key_type.unwrap_or_default().into())?;
+        let public_key = key_pair_properties.public_key_str.parse()?;
+        Ok(Self {
+            rotate_key_context: previous_context,
+            key_pair_properties,
+            public_key,
+        })
+    }
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = GenerateKeypairContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+/// Save the new access key:
+pub enum SaveMode {
+    #[strum_discriminants(strum(
+        message = "save-to-keychain            - Save automatically generated key pair to keychain"
+    ))]
+    /// Save automatically generated key pair to keychain
+    SaveToKeychain(SaveKeypairToKeychain),
+    #[strum_discriminants(strum(
+        message = "save-to-legacy-keychain     - Save automatically generated key pair to the legacy keychain (compatible with JS CLI)"
+    ))]
+    /// Save automatically generated key pair to the legacy keychain (compatible with JS CLI)
+    SaveToLegacyKeychain(SaveKeypairToLegacyKeychain),
+    #[strum_discriminants(strum(
+        message = "save-to-encrypted-keystore  - Save automatically generated key pair to the password-encrypted keystore"
+    ))]
+    /// Save automatically generated key pair to the password-encrypted keystore
+    SaveToEncryptedKeystore(SaveKeypairToEncryptedKeystore),
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = GenerateKeypairContext)]
+#[interactive_clap(output_context = SaveKeypairToKeychainContext)]
+pub struct SaveKeypairToKeychain {
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_for_transaction::NetworkForTransactionArgs,
+}
+
+#[derive(Debug, Clone)]
+pub struct SaveKeypairToKeychainContext(super::NewKeyContext);
+
+impl SaveKeypairToKeychainContext {
+    pub fn from_previous_context(
+        previous_context: GenerateKeypairContext,
+        _scope: &<SaveKeypairToKeychain as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self(super::NewKeyContext::new(
+            previous_context.rotate_key_context,
+            previous_context.public_key,
+            super::NewKeyStorage::Keychain(previous_context.key_pair_properties),
+        )?))
+    }
+}
+
+impl From<SaveKeypairToKeychainContext> for crate::commands::ActionContext {
+    fn from(item: SaveKeypairToKeychainContext) -> Self {
+        item.0.into()
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = GenerateKeypairContext)]
+#[interactive_clap(output_context = SaveKeypairToLegacyKeychainContext)]
+pub struct SaveKeypairToLegacyKeychain {
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_for_transaction::NetworkForTransactionArgs,
+}
+
+#[derive(Debug, Clone)]
+pub struct SaveKeypairToLegacyKeychainContext(super::NewKeyContext);
+
+impl SaveKeypairToLegacyKeychainContext {
+    pub fn from_previous_context(
+        previous_context: GenerateKeypairContext,
+        _scope: &<SaveKeypairToLegacyKeychain as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self(super::NewKeyContext::new(
+            previous_context.rotate_key_context,
+            previous_context.public_key,
+            super::NewKeyStorage::LegacyKeychain(previous_context.key_pair_properties),
+        )?))
+    }
+}
+
+impl From<SaveKeypairToLegacyKeychainContext> for crate::commands::ActionContext {
+    fn from(item: SaveKeypairToLegacyKeychainContext) -> Self {
+        item.0.into()
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = GenerateKeypairContext)]
+#[interactive_clap(output_context = SaveKeypairToEncryptedKeystoreContext)]
+pub struct SaveKeypairToEncryptedKeystore {
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_for_transaction::NetworkForTransactionArgs,
+}
+
+#[derive(Debug, Clone)]
+pub struct SaveKeypairToEncryptedKeystoreContext(super::NewKeyContext);
+
+impl SaveKeypairToEncryptedKeystoreContext {
+    pub fn from_previous_context(
+        previous_context: GenerateKeypairContext,
+        _scope: &<SaveKeypairToEncryptedKeystore as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self(super::NewKeyContext::new(
+            previous_context.rotate_key_context,
+            previous_context.public_key,
+            super::NewKeyStorage::EncryptedKeystore(previous_context.key_pair_properties),
+        )?))
+    }
+}
+
+impl From<SaveKeypairToEncryptedKeystoreContext> for crate::commands::ActionContext {
+    fn from(item: SaveKeypairToEncryptedKeystoreContext) -> Self {
+        item.0.into()
+    }
+}
@@ -0,0 +1,380 @@
+use color_eyre::eyre::WrapErr;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+use crate::common::JsonRpcClientExt;
+
+mod autogenerate_new_keypair;
+#[cfg(feature = "ledger")]
+mod use_ledger;
+mod use_manually_provided_seed_phrase;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = RotateKeyContext)]
+pub struct RotateKey {
+    #[interactive_clap(skip_default_input_arg)]
+    /// Which account do you want to rotate the access key for?
+    account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// The full access key to replace (by default, the key the transaction is signed with)
+    old_public_key: Option<crate::types::public_key::PublicKey>,
+    #[interactive_clap(subcommand)]
+    new_key_mode: NewKeyMode,
+}
+
+#[derive(Debug, Clone)]
+pub struct RotateKeyContext {
+    global_context: crate::GlobalContext,
+    account_id: near_primitives::types::AccountId,
+    old_public_key: Option<near_crypto::PublicKey>,
+}
+
+impl RotateKeyContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<RotateKey as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self {
+            global_context: previous_context,
+            account_id: scope.account_id.clone().into(),
+            old_public_key: scope.old_public_key.clone().map(Into::into),
+        })
+    }
+}
+
+impl RotateKey {
+    pub fn input_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_signer_account_id_from_used_account_list(
+            &context.config.credentials_home_dir,
+            "Which account do you want to rotate the access key for?",
+        )
+    }
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = RotateKeyContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+/// Select a new full access key:
+pub enum NewKeyMode {
+    #[strum_discriminants(strum(
+        message = "autogenerate-new-keypair          - Automatically generate a key pair"
+    ))]
+    /// Automatically generate a key pair
+    AutogenerateNewKeypair(self::autogenerate_new_keypair::GenerateKeypair),
+    #[strum_discriminants(strum(
+        message = "use-manually-provided-seed-phrase - Use the provided seed phrase manually"
+    ))]
+    /// Use the provided seed phrase manually
+    UseManuallyProvidedSeedPhrase(
+        self::use_manually_provided_seed_phrase::RotateWithSeedPhraseAction,
+    ),
+    #[cfg(feature = "ledger")]
+    #[strum_discriminants(strum(message = "use-ledger                        - Use a ledger"))]
+    /// Use the Ledger Hardware wallet
+    UseLedger(self::use_ledger::RotateWithLedgerAction),
+}
+
+/// Where the secret of the new key is kept.
+#[derive(Debug, Clone)]
+pub enum NewKeyStorage {
+    /// The key is held by the user (a seed phrase or a Ledger device)
+    External,
+    Keychain(crate::common::KeyPairProperties),
+    LegacyKeychain(crate::common::KeyPairProperties),
+    EncryptedKeystore(crate::common::KeyPairProperties),
+}
+
+#[derive(Debug, Clone)]
+pub struct NewKeyContext {
+    global_context: crate::GlobalContext,
+    account_id: near_primitives::types::AccountId,
+    old_public_key: Option<near_crypto::PublicKey>,
+    new_public_key: near_crypto::PublicKey,
+    new_key_storage: NewKeyStorage,
+}
+
+impl NewKeyContext {
+    fn new(
+        previous_context: RotateKeyContext,
+        new_public_key: near_crypto::PublicKey,
+        new_key_storage: NewKeyStorage,
+    ) -> color_eyre::eyre::Result<Self> {
+        if previous_context.old_public_key.as_ref() == Some(&new_public_key) {
+            color_eyre::eyre::bail!("The new access key is the same as the old one");
+        }
+        Ok(Self {
+            global_context: previous_context.global_context,
+            account_id: previous_context.account_id,
+            old_public_key: previous_context.old_public_key,
+            new_public_key,
+            new_key_storage,
+        })
+    }
+}
+
+impl From<NewKeyContext> for crate::commands::ActionContext {
+    fn from(item: NewKeyContext) -> Self {
+        let get_prepopulated_transaction_after_getting_network_callback: crate::commands::GetPrepopulatedTransactionAfterGettingNetworkCallback =
+            std::sync::Arc::new({
+                let account_id = item.account_id.clone();
+                let old_public_key = item.old_public_key.clone();
+                let new_public_key = item.new_public_key.clone();
+                let offline = item.global_context.offline;
+
+                move |network_config| {
+                    // The relayer does not return the transaction outcome, so the old key could
+                    // not be removed from the local stores after the transaction succeeds.
+                    if network_config.meta_transaction_relayer_url.is_some() {
+                        color_eyre::eyre::bail!(
+                            "The access key cannot be rotated through the meta-transaction relayer of network <{}>, use a network connection without `meta_transaction_relayer_url`",
+                            network_config.network_name
+                        );
+                    }
+                    let mut actions = vec![near_primitives::transaction::Action::AddKey(Box::new(
+                        near_primitives::transaction::AddKeyAction {
+                            public_key: new_public_key.clone(),
+                            access_key: near_primitives::account::AccessKey {
+                                nonce: 0,
+                                permission: near_primitives::account::AccessKeyPermission::FullAccess,
+                            },
+                        },
+                    ))];
+                    if let Some(old_public_key) = &old_public_key {
+                        if !offline {
+                            network_config
+                                .json_rpc_client()
+                                .blocking_call_view_access_key(
+                                    &account_id,
+                                    old_public_key,
+                                    near_primitives::types::Finality::Final.into(),
+                                )
+                                .wrap_err_with(|| {
+                                    format!(
+                                        "The access key <{old_public_key}> is not found for <{account_id}> on network <{}>",
+                                        network_config.network_name
+                                    )
+                                })?;
+                        }
+                        actions.push(near_primitives::transaction::Action::DeleteKey(Box::new(
+                            near_primitives::transaction::DeleteKeyAction {
+                                public_key: old_public_key.clone(),
+                            },
+                        )));
+                    }
+                    Ok(crate::commands::PrepopulatedTransaction {
+                        signer_id: account_id.clone(),
+                        receiver_id: account_id.clone(),
+                        actions,
+                    })
+                }
+            });
+
+        let on_before_signing_callback: crate::commands::OnBeforeSigningCallback =
+            std::sync::Arc::new({
+                let old_public_key = item.old_public_key.clone();
+                let new_public_key = item.new_public_key.clone();
+
+                move |unsigned_transaction, _network_config| {
+                    if old_public_key.is_none() {
+                        if unsigned_transaction.public_key == new_public_key {
+                            color_eyre::eyre::bail!(
+                                "The transaction is signed with the new access key, there is no old key to replace"
+                            );
+                        }
+                        unsigned_transaction.actions.push(
+                            near_primitives::transaction::Action::DeleteKey(Box::new(
+                                near_primitives::transaction::DeleteKeyAction {
+                                    public_key: unsigned_transaction.public_key.clone(),
+                                },
+                            )),
+                        );
+                        eprintln!(
+                            "The access key <{}> the transaction is signed with is deleted in the same transaction.",
+                            unsigned_transaction.public_key
+                        );
+                    }
+                    Ok(())
+                }
+            });
+
+        let on_before_sending_transaction_callback: crate::transaction_signature_options::OnBeforeSendingTransactionCallback =
+            std::sync::Arc::new({
+                let account_id = item.account_id.clone();
+                let credentials_home_dir = item.global_context.config.credentials_home_dir.clone();
+                let new_key_storage = item.new_key_storage.clone();
+
+                move |_signed_transaction, network_config| {
+                    save_new_key(
+                        &new_key_storage,
+                        network_config,
+                        &credentials_home_dir,
+                        &account_id,
+                    )
+                }
+            });
+
+        let on_after_sending_transaction_callback: crate::transaction_signature_options::OnAfterSendingTransactionCallback =
+            std::sync::Arc::new({
+                let account_id = item.account_id.clone();
+                let credentials_home_dir = item.global_context.config.credentials_home_dir.clone();
+                let new_key_storage = item.new_key_storage.clone();
+
+                move |outcome_view, network_config| {
+                    for action in &outcome_view.transaction.actions {
+                        if let near_primitives::views::ActionView::DeleteKey { public_key } = action
+                        {
+                            remove_old_key(
+                                network_config,
+                                &credentials_home_dir,
+                                &account_id,
+                                public_key,
+                            )?;
+                        }
+                    }
+                    // The account file of the legacy keychain could refer to the old key, it is
+                    // replaced with the new one.
+                    if let NewKeyStorage::LegacyKeychain(_) = new_key_storage {
+                        save_new_key(
+                            &new_key_storage,
+                            network_config,
+                            &credentials_home_dir,
+                            &account_id,
+                        )?;
+                    }
+                    Ok(())
+                }
+            });
+
+        Self {
+            global_context: item.global_context,
+            interacting_with_account_ids: vec![item.account_id],
+            get_prepopulated_transaction_after_getting_network_callback,
+            on_before_signing_callback,
+            on_before_sending_transaction_callback,
+            on_after_sending_transaction_callback,
+            transaction_bundle: None,
+        }
+    }
+}
+
+fn save_new_key(
+    new_key_storage: &NewKeyStorage,
+    network_config: &crate::config::NetworkConfig,
+    credentials_home_dir: &std::path::Path,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<String> {
+    match new_key_storage {
+        NewKeyStorage::External => Ok(String::new()),
+        NewKeyStorage::Keychain(key_pair_properties) => crate::common::save_access_key_to_keychain(
+            network_config.clone(),
+            credentials_home_dir.to_path_buf(),
+            &serde_json::to_string(key_pair_properties)?,
+            &key_pair_properties.public_key_str,
+            account_id.as_str(),
+        ),
+        NewKeyStorage::LegacyKeychain(key_pair_properties) => {
+            crate::common::save_access_key_to_legacy_keychain(
+                network_config.clone(),
+                credentials_home_dir.to_path_buf(),
+                &serde_json::to_string(key_pair_properties)?,
+                &key_pair_properties.public_key_str,
+                account_id.as_str(),
+            )
+        }
+        NewKeyStorage::EncryptedKeystore(key_pair_properties) => {
+            crate::common::save_access_key_to_encrypted_keystore(
+                network_config.clone(),
+                credentials_home_dir.to_path_buf(),
+                &serde_json::to_string(key_pair_properties)?,
+                &key_pair_properties.public_key_str,
+                account_id.as_str(),
+            )
+        }
+    }
+}
+
+/// The deleted key is removed from all the local stores.
+fn remove_old_key(
+    network_config: &crate::config::NetworkConfig,
+    credentials_home_dir: &std::path::Path,
+    account_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+) -> crate::CliResult {
+    if crate::common::remove_access_key_from_keychain(
+        &network_config.network_name,
+        credentials_home_dir,
+        public_key,
+        account_id,
+    )? {
+        eprintln!("The old access key <{public_key}> is removed from the keychain");
+    }
+    for path in crate::common::remove_access_key_from_legacy_keychain(
+        &network_config.network_name,
+        credentials_home_dir,
+        public_key,
+        account_id,
+    )? {
+        eprintln!(
+            "The old access key <{public_key}> is removed from the legacy keychain: {}",
+            path.display()
+        );
+    }
+    if let Some(path) = crate::encrypted_keystore::EncryptedKeystore::new(credentials_home_dir)
+        .remove(&network_config.network_name, account_id, public_key)?
+    {
+        eprintln!(
+            "The old access key <{public_key}> is removed from the encrypted keystore: {}",
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_key_context() -> NewKeyContext {
+        NewKeyContext {
+            global_context: crate::GlobalContext {
+                config: crate::config::Config::default(),
+                offline: true,
+                output_format: crate::common::OutputFormat::Plaintext,
+            },
+            account_id: "alice.testnet".parse().unwrap(),
+            old_public_key: None,
+            new_public_key: near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "new")
+                .public_key(),
+            new_key_storage: NewKeyStorage::External,
+        }
+    }
+
+    #[test]
+    fn rotation_through_a_relayer_is_refused() {
+        let action_context: crate::commands::ActionContext = new_key_context().into();
+        let mut network_config = crate::config::Config::default()
+            .network_connection
+            .get("testnet")
+            .unwrap()
+            .clone();
+        network_config.meta_transaction_relayer_url = None;
+        let prepopulated_transaction = (action_context
+            .get_prepopulated_transaction_after_getting_network_callback)(
+            &network_config
+        )
+        .unwrap();
+        assert_eq!(prepopulated_transaction.actions.len(), 1);
+
+        network_config.meta_transaction_relayer_url =
+            Some("https://relayer.example.com/".parse().unwrap());
+        assert!(
+            (action_context.get_prepopulated_transaction_after_getting_network_callback)(
+                &network_config
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,57 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::RotateKeyContext)]
+#[interactive_clap(output_context = RotateWithLedgerActionContext)]
+pub struct RotateWithLedgerAction {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    seed_phrase_hd_path: crate::types::slip10::BIP32Path,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_for_transaction::NetworkForTransactionArgs,
+}
+
+#[derive(Debug, Clone)]
+pub struct RotateWithLedgerActionContext(super::NewKeyContext);
+
+impl RotateWithLedgerActionContext {
+    pub fn from_previous_context(
+        previous_context: super::RotateKeyContext,
+        scope: &<RotateWithLedgerAction as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let seed_phrase_hd_path = scope.seed_phrase_hd_path.clone();
+        eprintln!(
+            "Please allow getting the PublicKey on Ledger device (HD Path: {})",
+            seed_phrase_hd_path
+        );
+        let public_key = near_ledger::get_public_key(seed_phrase_hd_path.into()).map_err(
+            |near_ledger_error| {
+                color_eyre::Report::msg(format!(
+                    "An error occurred while trying to get PublicKey from Ledger device: {:?}",
+                    near_ledger_error
+                ))
+            },
+        )?;
+        let public_key = near_crypto::PublicKey::ED25519(near_crypto::ED25519PublicKey::from(
+            public_key.to_bytes(),
+        ));
+        Ok(Self(super::NewKeyContext::new(
+            previous_context,
+            public_key,
+            super::NewKeyStorage::External,
+        )?))
+    }
+}
+
+impl From<RotateWithLedgerActionContext> for crate::commands::ActionContext {
+    fn from(item: RotateWithLedgerActionContext) -> Self {
+        item.0.into()
+    }
+}
+
+impl RotateWithLedgerAction {
+    pub fn input_seed_phrase_hd_path(
+        _context: &super::RotateKeyContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::slip10::BIP32Path>> {
+        crate::transaction_signature_options::sign_with_ledger::input_seed_phrase_hd_path()
+    }
+}
@@ -0,0 +1,42 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::RotateKeyContext)]
+#[interactive_clap(output_context = RotateWithSeedPhraseActionContext)]
+pub struct RotateWithSeedPhraseAction {
+    /// Enter the seed-phrase:
+    master_seed_phrase: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Type of the key to derive from the seed phrase: ed25519 (default) or secp256k1
+    key_type: Option<crate::types::key_type::KeyType>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_for_transaction::NetworkForTransactionArgs,
+}
+
+#[derive(Debug, Clone)]
+pub struct RotateWithSeedPhraseActionContext(super::NewKeyContext);
+
+impl RotateWithSeedPhraseActionContext {
+    pub fn from_previous_context(
+        previous_context: super::RotateKeyContext,
+        scope: &<RotateWithSeedPhraseAction as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let key_type = scope.key_type.unwrap_or_default().into();
+        let public_key = crate::common::get_public_key_from_seed_phrase(
+            crate::common::default_seed_phrase_hd_path(key_type).into(),
+            &scope.master_seed_phrase,
+            key_type,
+        )?;
+        Ok(Self(super::NewKeyContext::new(
+            previous_context,
+            public_key,
+            super::NewKeyStorage::External,
+        )?))
+    }
+}
+
+impl From<RotateWithSeedPhraseActionContext> for crate::commands::ActionContext {
+    fn from(item: RotateWithSeedPhraseActionContext) -> Self {
+        item.0.into()
+    }
+}
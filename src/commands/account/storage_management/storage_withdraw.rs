@@ -100,6 +100,7 @@ impl SignerAccountIdContext {
                 |_signed_transaction, _network_config| Ok(String::new()),
             ),
             on_after_sending_transaction_callback,
+            transaction_bundle: None,
         }))
     }
 }
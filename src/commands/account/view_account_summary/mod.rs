@@ -26,9 +26,10 @@ impl ViewAccountSummaryContext {
     ) -> color_eyre::eyre::Result<Self> {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+            let output_format = previous_context.output_format.clone();
 
             move |network_config, block_reference| {
-                get_account_inquiry(&account_id, network_config, block_reference)
+                get_account_inquiry(&account_id, network_config, block_reference, &output_format)
             }
         });
         Ok(Self(crate::network_view_at_block::ArgsForViewContext {
@@ -61,6 +62,7 @@ fn get_account_inquiry(
     account_id: &near_primitives::types::AccountId,
     network_config: &crate::config::NetworkConfig,
     block_reference: &near_primitives::types::BlockReference,
+    output_format: &crate::common::OutputFormat,
 ) -> crate::CliResult {
     let json_rpc_client = network_config.json_rpc_client();
 
@@ -100,26 +102,7 @@ fn get_account_inquiry(
                 .ok()
         });
 
-    let historically_delegated_validators =
-        network_config
-            .fastnear_url
-            .as_ref()
-            .and_then(|fastnear_url| {
-                crate::common::fetch_historically_delegated_staking_pools(fastnear_url, account_id)
-                    .ok()
-            });
-    let validators = if let Some(validators) = historically_delegated_validators {
-        validators
-    } else if let Some(staking_pools_factory_account_id) =
-        &network_config.staking_pools_factory_account_id
-    {
-        crate::common::fetch_currently_active_staking_pools(
-            &json_rpc_client,
-            staking_pools_factory_account_id,
-        )?
-    } else {
-        Default::default()
-    };
+    let validators = get_delegation_validators(network_config, account_id)?;
 
     let runtime = tokio::runtime::Builder::new_multi_thread()
         .enable_all()
@@ -155,6 +138,29 @@ fn get_account_inquiry(
         .ok()
         .flatten();
 
+    if output_format.is_json() {
+        return crate::common::print_json_output(
+            "account_summary",
+            serde_json::json!({
+                "account_id": account_id,
+                "block_height": rpc_query_response.block_height,
+                "block_hash": rpc_query_response.block_hash.to_string(),
+                "amount": account_view.amount.to_string(),
+                "locked": account_view.locked.to_string(),
+                "storage_usage": account_view.storage_usage,
+                "code_hash": account_view.code_hash.to_string(),
+                "delegated_stake": delegated_stake
+                    .iter()
+                    .map(|(validator_id, stake)| {
+                        (validator_id.to_string(), stake.as_yoctonear().to_string().into())
+                    })
+                    .collect::<serde_json::Map<String, serde_json::Value>>(),
+                "access_keys": access_key_list.as_ref().map(|access_key_list| &access_key_list.keys),
+                "profile": optional_account_profile.map(|account_profile| account_profile.profile),
+            }),
+        );
+    }
+
     crate::common::display_account_info(
         &rpc_query_response.block_hash,
         &rpc_query_response.block_height,
@@ -168,11 +174,39 @@ fn get_account_inquiry(
     Ok(())
 }
 
+/// Staking pools the account may have delegated to: the ones known to FastNEAR, or all the
+/// currently active pools of the staking pools factory.
+pub fn get_delegation_validators(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<std::collections::BTreeSet<near_primitives::types::AccountId>> {
+    let historically_delegated_validators =
+        network_config
+            .fastnear_url
+            .as_ref()
+            .and_then(|fastnear_url| {
+                crate::common::fetch_historically_delegated_staking_pools(fastnear_url, account_id)
+                    .ok()
+            });
+    if let Some(validators) = historically_delegated_validators {
+        Ok(validators)
+    } else if let Some(staking_pools_factory_account_id) =
+        &network_config.staking_pools_factory_account_id
+    {
+        crate::common::fetch_currently_active_staking_pools(
+            &network_config.json_rpc_client(),
+            staking_pools_factory_account_id,
+        )
+    } else {
+        Ok(Default::default())
+    }
+}
+
 #[tracing::instrument(
     name = "Receiving the delegated staked balance from validator",
     skip_all
 )]
-async fn get_delegated_staked_balance(
+pub async fn get_delegated_staked_balance(
     json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
     block_reference: &near_primitives::types::BlockReference,
     staking_pool_account_id: &near_primitives::types::AccountId,
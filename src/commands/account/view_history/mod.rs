@@ -0,0 +1,198 @@
+use color_eyre::eyre::WrapErr;
+
+const DEFAULT_PAGE_SIZE: u64 = 25;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ViewHistoryContext)]
+pub struct ViewHistory {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What Account ID do you need to view the history of?
+    account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Show the activity starting from this date (YYYY-MM-DD, UTC)
+    from_date: Option<crate::types::date::Date>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Show the activity up to this date inclusive (YYYY-MM-DD, UTC)
+    to_date: Option<crate::types::date::Date>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// The number of transactions and of FT transfers per page (default: 25)
+    limit: Option<u64>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Start from the page with this cursor (printed after every page)
+    cursor: Option<String>,
+    #[interactive_clap(long)]
+    /// Fetch all the pages instead of a single one
+    all_pages: bool,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Save the history in the CSV format to this file
+    export_csv_file_path: Option<crate::types::path_buf::PathBuf>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Save the history in the JSON format to this file
+    export_json_file_path: Option<crate::types::path_buf::PathBuf>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct ViewHistoryContext(crate::network::NetworkContext);
+
+impl ViewHistoryContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ViewHistory as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        if let (Some(from_date), Some(to_date)) = (&scope.from_date, &scope.to_date) {
+            if from_date > to_date {
+                color_eyre::eyre::bail!(
+                    "The start date {from_date} is later than the end date {to_date}"
+                );
+            }
+        }
+        let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+        let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
+            std::sync::Arc::new({
+                let account_id = account_id.clone();
+                let query = crate::indexer_api::ActivityQuery {
+                    limit: scope.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+                    cursor: scope.cursor.clone(),
+                    from_date: scope.from_date,
+                    to_date: scope.to_date,
+                };
+                let all_pages = scope.all_pages;
+                let export_csv_file_path: Option<std::path::PathBuf> =
+                    scope.export_csv_file_path.clone().map(Into::into);
+                let export_json_file_path: Option<std::path::PathBuf> =
+                    scope.export_json_file_path.clone().map(Into::into);
+                let output_format = previous_context.output_format.clone();
+
+                move |network_config| {
+                    let indexer_client =
+                        crate::indexer_api::IndexerClient::from_network_config(network_config)?;
+                    let mut query = query.clone();
+                    let mut items = vec![];
+                    let next_cursor = loop {
+                        let page = indexer_client.fetch_activity(&account_id, &query)?;
+                        items.extend(page.items);
+                        match page.next_cursor {
+                            Some(next_cursor) if all_pages => query.cursor = Some(next_cursor),
+                            next_cursor => break next_cursor,
+                        }
+                    };
+                    crate::indexer_api::sort_by_time(&mut items);
+
+                    if let Some(export_csv_file_path) = &export_csv_file_path {
+                        std::fs::write(export_csv_file_path, crate::indexer_api::to_csv(&items))
+                            .wrap_err_with(|| {
+                                format!("Failed to write to file: {:?}", export_csv_file_path)
+                            })?;
+                        eprintln!(
+                            "\nThe account history is saved in the file {:?}",
+                            export_csv_file_path
+                        );
+                    }
+                    if let Some(export_json_file_path) = &export_json_file_path {
+                        std::fs::write(export_json_file_path, serde_json::to_vec_pretty(&items)?)
+                            .wrap_err_with(|| {
+                            format!("Failed to write to file: {:?}", export_json_file_path)
+                        })?;
+                        eprintln!(
+                            "\nThe account history is saved in the file {:?}",
+                            export_json_file_path
+                        );
+                    }
+
+                    if output_format.is_json() {
+                        return crate::common::print_json_output(
+                            "account_history",
+                            serde_json::json!({
+                                "account_id": account_id,
+                                "items": items,
+                                "next_cursor": next_cursor,
+                            }),
+                        );
+                    }
+                    print_history(&account_id, &items);
+                    if let Some(next_cursor) = next_cursor {
+                        eprintln!(
+                            "\nThere are more items, use `--cursor {}` to view the next page (or `--all-pages` to view all of them).",
+                            shell_words::quote(&next_cursor)
+                        );
+                    }
+                    Ok(())
+                }
+            });
+
+        Ok(Self(crate::network::NetworkContext {
+            config: previous_context.config,
+            interacting_with_account_ids: vec![account_id],
+            on_after_getting_network_callback,
+        }))
+    }
+}
+
+impl From<ViewHistoryContext> for crate::network::NetworkContext {
+    fn from(item: ViewHistoryContext) -> Self {
+        item.0
+    }
+}
+
+impl ViewHistory {
+    pub fn input_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_non_signer_account_id_from_used_account_list(
+            &context.config.credentials_home_dir,
+            "What Account ID do you need to view the history of?",
+        )
+    }
+}
+
+fn print_history(
+    account_id: &near_primitives::types::AccountId,
+    items: &[crate::indexer_api::ActivityItem],
+) {
+    if items.is_empty() {
+        eprintln!("\nThere is no activity of <{account_id}> for the selected period.");
+        return;
+    }
+    let mut table = prettytable::Table::new();
+    table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+    table.set_titles(prettytable::row![Fy=>"Time (UTC)", "Activity", "Counterparty", "Details", "Transaction hash"]);
+    for item in items {
+        let counterparty = match (&item.counterparty_account_id, item.direction) {
+            (Some(counterparty_account_id), crate::indexer_api::Direction::Incoming) => {
+                format!("from {counterparty_account_id}")
+            }
+            (Some(counterparty_account_id), crate::indexer_api::Direction::Outgoing) => {
+                format!("to {counterparty_account_id}")
+            }
+            (None, _) => String::new(),
+        };
+        let details = [
+            item.amount_to_string(),
+            item.method_name.clone(),
+            item.public_key.as_ref().map(ToString::to_string),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("\n");
+        table.add_row(prettytable::row![
+            crate::types::date::format_timestamp(item.block_timestamp),
+            item.kind,
+            counterparty,
+            details,
+            item.transaction_hash,
+        ]);
+    }
+    eprintln!("\nActivity of <{account_id}>:");
+    table.printstd();
+}
@@ -0,0 +1,711 @@
+use color_eyre::eyre::Context;
+use futures::StreamExt;
+use tracing_indicatif::span_ext::IndicatifSpanExt;
+
+use crate::common::{CallResultExt, JsonRpcClientExt, RpcQueryResponseExt};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ViewPortfolioContext)]
+pub struct ViewPortfolio {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What Account ID do you need to view the portfolio of?
+    account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Comma-separated FT contracts to check (by default, the ones found with the FastNEAR API)
+    ft_contracts: Option<crate::types::vec_string::VecString>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Comma-separated NFT contracts to check (by default, the ones found with the FastNEAR API)
+    nft_contracts: Option<crate::types::vec_string::VecString>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+#[derive(Clone)]
+pub struct ViewPortfolioContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl ViewPortfolioContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ViewPortfolio as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let ft_contracts = parse_contract_list(scope.ft_contracts.as_ref())?;
+        let nft_contracts = parse_contract_list(scope.nft_contracts.as_ref())?;
+
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
+            let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+            let output_format = previous_context.output_format.clone();
+
+            move |network_config, block_reference| {
+                get_portfolio(
+                    &account_id,
+                    ft_contracts.as_ref(),
+                    nft_contracts.as_ref(),
+                    network_config,
+                    block_reference,
+                    &output_format,
+                )
+            }
+        });
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config: previous_context.config,
+            interacting_with_account_ids: vec![scope.account_id.clone().into()],
+            on_after_getting_block_reference_callback,
+        }))
+    }
+}
+
+impl From<ViewPortfolioContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: ViewPortfolioContext) -> Self {
+        item.0
+    }
+}
+
+impl ViewPortfolio {
+    pub fn input_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_non_signer_account_id_from_used_account_list(
+            &context.config.credentials_home_dir,
+            "What Account ID do you need to view the portfolio of?",
+        )
+    }
+}
+
+fn parse_contract_list(
+    contracts: Option<&crate::types::vec_string::VecString>,
+) -> color_eyre::eyre::Result<Option<std::collections::BTreeSet<near_primitives::types::AccountId>>>
+{
+    contracts
+        .map(|contracts| {
+            contracts
+                .0
+                .iter()
+                .map(|contract_account_id| {
+                    contract_account_id.parse().wrap_err_with(|| {
+                        format!("Invalid contract account ID: {contract_account_id}")
+                    })
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+/// Uses the contracts given by the user or, if there are none, the ones known to FastNEAR.
+fn get_token_contracts(
+    contracts: Option<&std::collections::BTreeSet<near_primitives::types::AccountId>>,
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    standard: &str,
+    fetch_contracts: fn(
+        &url::Url,
+        &near_primitives::types::AccountId,
+    ) -> color_eyre::Result<
+        std::collections::BTreeSet<near_primitives::types::AccountId>,
+    >,
+) -> std::collections::BTreeSet<near_primitives::types::AccountId> {
+    if let Some(contracts) = contracts {
+        return contracts.clone();
+    }
+    let Some(fastnear_url) = &network_config.fastnear_url else {
+        tracing::warn!(
+            "{standard} holdings are not shown: there is no `fastnear_url` for network <{}>, use `--{}-contracts` to list the contracts",
+            network_config.network_name,
+            standard.to_lowercase()
+        );
+        return Default::default();
+    };
+    fetch_contracts(fastnear_url, account_id)
+        .map_err(|err| {
+            tracing::warn!(
+                "{standard} holdings are not shown: failed to get the {standard} contracts of <{account_id}> from FastNEAR API: {:#}",
+                err
+            );
+        })
+        .unwrap_or_default()
+}
+
+#[tracing::instrument(name = "Receiving the portfolio of the account ...", skip_all)]
+fn get_portfolio(
+    account_id: &near_primitives::types::AccountId,
+    ft_contracts: Option<&std::collections::BTreeSet<near_primitives::types::AccountId>>,
+    nft_contracts: Option<&std::collections::BTreeSet<near_primitives::types::AccountId>>,
+    network_config: &crate::config::NetworkConfig,
+    block_reference: &near_primitives::types::BlockReference,
+    output_format: &crate::common::OutputFormat,
+) -> crate::CliResult {
+    let json_rpc_client = network_config.json_rpc_client();
+
+    let rpc_query_response = json_rpc_client
+        .blocking_call_view_account(account_id, block_reference.clone())
+        .wrap_err_with(|| {
+            format!(
+                "Failed to fetch query ViewAccount for account <{}> on network <{}>",
+                account_id, network_config.network_name
+            )
+        })?;
+    let account_view = rpc_query_response.account_view()?;
+    // All the balances are read at the same block, so the report is consistent.
+    let block_reference = near_primitives::types::BlockReference::BlockId(
+        near_primitives::types::BlockId::Hash(rpc_query_response.block_hash),
+    );
+
+    let validators =
+        super::view_account_summary::get_delegation_validators(network_config, account_id)?;
+    let ft_contracts = get_token_contracts(
+        ft_contracts,
+        network_config,
+        account_id,
+        "FT",
+        crate::common::fetch_ft_contracts,
+    );
+    let nft_contracts = get_token_contracts(
+        nft_contracts,
+        network_config,
+        account_id,
+        "NFT",
+        crate::common::fetch_nft_contracts,
+    );
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?;
+    let concurrency = 10;
+    let (delegated_stake, fungible_tokens, nft_counts) = runtime.block_on(async {
+        futures::join!(
+            futures::stream::iter(validators)
+                .map(|validator_account_id| async {
+                    let balance = super::view_account_summary::get_delegated_staked_balance(
+                        &json_rpc_client,
+                        &block_reference,
+                        &validator_account_id,
+                        account_id,
+                    )
+                    .await;
+                    (validator_account_id, balance)
+                })
+                .buffer_unordered(concurrency)
+                .collect::<Vec<_>>(),
+            futures::stream::iter(ft_contracts)
+                .map(|ft_contract_account_id| async {
+                    let balance = get_ft_balance(
+                        &json_rpc_client,
+                        &block_reference,
+                        &ft_contract_account_id,
+                        account_id,
+                    )
+                    .await;
+                    (ft_contract_account_id, balance)
+                })
+                .buffer_unordered(concurrency)
+                .collect::<Vec<_>>(),
+            futures::stream::iter(nft_contracts)
+                .map(|nft_contract_account_id| async {
+                    let count = get_nft_count(
+                        &json_rpc_client,
+                        &block_reference,
+                        &nft_contract_account_id,
+                        account_id,
+                    )
+                    .await;
+                    (nft_contract_account_id, count)
+                })
+                .buffer_unordered(concurrency)
+                .collect::<Vec<_>>(),
+        )
+    });
+
+    let portfolio = Portfolio::new(&account_view, delegated_stake, fungible_tokens, nft_counts);
+
+    let near_usd_exchange_rate = network_config
+        .coingecko_url
+        .as_ref()
+        .and_then(|coingecko_url| {
+            crate::common::get_near_usd_exchange_rate(coingecko_url)
+                .map_err(|err| {
+                    tracing::warn!("USD values are not shown: {:#}", err);
+                })
+                .ok()
+        });
+
+    if output_format.is_json() {
+        return crate::common::print_json_output(
+            "account_portfolio",
+            portfolio.to_json(
+                account_id,
+                rpc_query_response.block_height,
+                &rpc_query_response.block_hash,
+                near_usd_exchange_rate,
+            ),
+        );
+    }
+
+    let usd_value = |yoctonear: u128| -> String {
+        near_usd_exchange_rate
+            .and_then(|exchange_rate| crate::common::calculate_usd_amount(yoctonear, exchange_rate))
+            .map(|amount| format!("${:.2}", amount))
+            .unwrap_or_default()
+    };
+
+    let mut table = prettytable::Table::new();
+    table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+    table.set_titles(prettytable::row![Fy=>"Asset", "Contract / pool", "Balance", "USD"]);
+    table.add_row(prettytable::row![
+        "NEAR",
+        "",
+        near_token::NearToken::from_yoctonear(portfolio.amount),
+        usd_value(portfolio.amount),
+    ]);
+    if portfolio.locked != 0 {
+        table.add_row(prettytable::row![
+            "NEAR (validator stake)",
+            "",
+            near_token::NearToken::from_yoctonear(portfolio.locked),
+            usd_value(portfolio.locked),
+        ]);
+    }
+    for (validator_id, stake) in &portfolio.delegated_stake {
+        table.add_row(prettytable::row![
+            "NEAR (delegated stake)",
+            validator_id,
+            stake,
+            usd_value(stake.as_yoctonear()),
+        ]);
+    }
+    table.add_row(prettytable::row![
+        Fg->"Total NEAR",
+        "",
+        Fg->near_token::NearToken::from_yoctonear(portfolio.total_near()),
+        Fg->usd_value(portfolio.total_near()),
+    ]);
+    for (ft_contract_account_id, fungible_token) in &portfolio.fungible_tokens {
+        table.add_row(prettytable::row![
+            fungible_token.symbol(),
+            ft_contract_account_id,
+            fungible_token,
+            "",
+        ]);
+    }
+    for (nft_contract_account_id, count) in &portfolio.nft_counts {
+        table.add_row(prettytable::row![
+            "NFT",
+            nft_contract_account_id,
+            format!("{count} tokens"),
+            "",
+        ]);
+    }
+
+    eprintln!(
+        "\nPortfolio of <{account_id}> at block #{} ({}):",
+        rpc_query_response.block_height, rpc_query_response.block_hash
+    );
+    table.printstd();
+    if let Some(exchange_rate) = near_usd_exchange_rate {
+        eprintln!("\nUSD values use the ${exchange_rate:.2} USD/NEAR exchange rate; FT and NFT assets are not priced.");
+    }
+    Ok(())
+}
+
+/// The assets of the account; the contracts that failed to answer and the zero balances are
+/// left out.
+#[derive(Debug)]
+struct Portfolio {
+    amount: near_primitives::types::Balance,
+    locked: near_primitives::types::Balance,
+    delegated_stake:
+        std::collections::BTreeMap<near_primitives::types::AccountId, near_token::NearToken>,
+    fungible_tokens: std::collections::BTreeMap<
+        near_primitives::types::AccountId,
+        crate::types::ft_properties::FungibleToken,
+    >,
+    nft_counts: std::collections::BTreeMap<near_primitives::types::AccountId, u128>,
+}
+
+type ContractResults<T> = Vec<(
+    near_primitives::types::AccountId,
+    color_eyre::eyre::Result<T>,
+)>;
+
+impl Portfolio {
+    fn new(
+        account_view: &near_primitives::views::AccountView,
+        delegated_stake: ContractResults<near_token::NearToken>,
+        fungible_tokens: ContractResults<crate::types::ft_properties::FungibleToken>,
+        nft_counts: ContractResults<u128>,
+    ) -> Self {
+        // A contract that fails to answer is reported and left out, so it does not hide the rest
+        // of the portfolio.
+        Self {
+            amount: account_view.amount,
+            locked: account_view.locked,
+            delegated_stake: skip_failed(delegated_stake)
+                .filter(|(_, balance)| !balance.is_zero())
+                .collect(),
+            fungible_tokens: skip_failed(fungible_tokens)
+                .filter(|(_, fungible_token)| fungible_token.amount() != 0)
+                .collect(),
+            nft_counts: skip_failed(nft_counts)
+                .filter(|(_, count)| *count != 0)
+                .collect(),
+        }
+    }
+
+    /// The liquid, locked and delegated NEAR.
+    fn total_near(&self) -> u128 {
+        self.amount
+            + self.locked
+            + self
+                .delegated_stake
+                .values()
+                .map(near_token::NearToken::as_yoctonear)
+                .sum::<u128>()
+    }
+
+    fn to_json(
+        &self,
+        account_id: &near_primitives::types::AccountId,
+        block_height: near_primitives::types::BlockHeight,
+        block_hash: &near_primitives::hash::CryptoHash,
+        near_usd_exchange_rate: Option<f64>,
+    ) -> serde_json::Value {
+        let total_near = self.total_near();
+        serde_json::json!({
+            "account_id": account_id,
+            "block_height": block_height,
+            "block_hash": block_hash.to_string(),
+            "near_usd_exchange_rate": near_usd_exchange_rate,
+            "amount": self.amount.to_string(),
+            "locked": self.locked.to_string(),
+            "delegated_stake": self.delegated_stake
+                .iter()
+                .map(|(validator_id, stake)| {
+                    (validator_id.to_string(), stake.as_yoctonear().to_string().into())
+                })
+                .collect::<serde_json::Map<String, serde_json::Value>>(),
+            "total_near": total_near.to_string(),
+            "total_near_usd": near_usd_exchange_rate
+                .and_then(|exchange_rate| crate::common::calculate_usd_amount(total_near, exchange_rate))
+                .map(|amount| amount.round_dp(2).to_string()),
+            "fungible_tokens": self.fungible_tokens
+                .iter()
+                .map(|(ft_contract_account_id, fungible_token)| {
+                    serde_json::json!({
+                        "ft_contract_account_id": ft_contract_account_id,
+                        "amount": fungible_token.amount().to_string(),
+                        "decimals": fungible_token.decimals(),
+                        "symbol": fungible_token.symbol(),
+                    })
+                })
+                .collect::<Vec<_>>(),
+            "nft_assets": self.nft_counts
+                .iter()
+                .map(|(nft_contract_account_id, count)| {
+                    serde_json::json!({
+                        "nft_contract_account_id": nft_contract_account_id,
+                        "tokens_count": count.to_string(),
+                    })
+                })
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+fn skip_failed<T>(
+    results: ContractResults<T>,
+) -> impl Iterator<Item = (near_primitives::types::AccountId, T)> {
+    results
+        .into_iter()
+        .filter_map(|(contract_account_id, result)| match result {
+            Ok(value) => Some((contract_account_id, value)),
+            Err(err) => {
+                tracing::warn!("Skipping <{contract_account_id}>: {:#}", err);
+                None
+            }
+        })
+}
+
+async fn call_view_function<T>(
+    json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    block_reference: &near_primitives::types::BlockReference,
+    contract_account_id: &near_primitives::types::AccountId,
+    method_name: &str,
+    args: serde_json::Value,
+) -> color_eyre::eyre::Result<T>
+where
+    T: for<'de> serde::Deserialize<'de>,
+{
+    json_rpc_client
+        .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference: block_reference.clone(),
+            request: near_primitives::views::QueryRequest::CallFunction {
+                account_id: contract_account_id.clone(),
+                method_name: method_name.to_string(),
+                args: near_primitives::types::FunctionArgs::from(serde_json::to_vec(&args)?),
+            },
+        })
+        .await
+        .wrap_err_with(|| {
+            format!(
+                "Failed to fetch query for view method: '{method_name}' (contract <{contract_account_id}>)"
+            )
+        })?
+        .call_result()?
+        .parse_result_from_json()
+}
+
+#[tracing::instrument(name = "Receiving the FT balance from contract", skip_all)]
+async fn get_ft_balance(
+    json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    block_reference: &near_primitives::types::BlockReference,
+    ft_contract_account_id: &near_primitives::types::AccountId,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<crate::types::ft_properties::FungibleToken> {
+    tracing::Span::current().pb_set_message(ft_contract_account_id.as_str());
+    let crate::types::ft_properties::FtMetadata {
+        decimals, symbol, ..
+    } = call_view_function(
+        json_rpc_client,
+        block_reference,
+        ft_contract_account_id,
+        "ft_metadata",
+        serde_json::json!({}),
+    )
+    .await?;
+    let amount: String = call_view_function(
+        json_rpc_client,
+        block_reference,
+        ft_contract_account_id,
+        "ft_balance_of",
+        serde_json::json!({ "account_id": account_id }),
+    )
+    .await?;
+    Ok(crate::types::ft_properties::FungibleToken::from_params_ft(
+        amount.parse::<u128>()?,
+        decimals,
+        symbol,
+    ))
+}
+
+#[tracing::instrument(name = "Receiving the number of NFT tokens from contract", skip_all)]
+async fn get_nft_count(
+    json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    block_reference: &near_primitives::types::BlockReference,
+    nft_contract_account_id: &near_primitives::types::AccountId,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<u128> {
+    tracing::Span::current().pb_set_message(nft_contract_account_id.as_str());
+    let count: String = call_view_function(
+        json_rpc_client,
+        block_reference,
+        nft_contract_account_id,
+        "nft_supply_for_owner",
+        serde_json::json!({ "account_id": account_id }),
+    )
+    .await?;
+    Ok(count.parse()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NEAR: u128 = 10u128.pow(24);
+
+    fn account_view(amount: u128, locked: u128) -> near_primitives::views::AccountView {
+        serde_json::from_value(serde_json::json!({
+            "amount": amount.to_string(),
+            "locked": locked.to_string(),
+            "code_hash": near_primitives::hash::CryptoHash::default(),
+            "storage_usage": 182,
+            "storage_paid_at": 0,
+        }))
+        .unwrap()
+    }
+
+    fn usdc() -> crate::types::ft_properties::FungibleToken {
+        crate::types::ft_properties::FungibleToken::from_params_ft(1_500_000, 6, "USDC".to_string())
+    }
+
+    fn portfolio() -> Portfolio {
+        Portfolio::new(
+            &account_view(10 * NEAR, NEAR),
+            vec![
+                (
+                    "pool-a.poolv1.near".parse().unwrap(),
+                    Ok(near_token::NearToken::from_near(2)),
+                ),
+                (
+                    "pool-b.poolv1.near".parse().unwrap(),
+                    Ok(near_token::NearToken::from_near(0)),
+                ),
+                (
+                    "pool-c.poolv1.near".parse().unwrap(),
+                    Err(color_eyre::eyre::eyre!("the pool is paused")),
+                ),
+            ],
+            vec![
+                ("usdc.near".parse().unwrap(), Ok(usdc())),
+                (
+                    "empty.near".parse().unwrap(),
+                    Ok(crate::types::ft_properties::FungibleToken::from_params_ft(
+                        0,
+                        18,
+                        "EMPTY".to_string(),
+                    )),
+                ),
+                (
+                    "broken.near".parse().unwrap(),
+                    Err(color_eyre::eyre::eyre!("ft_metadata is not exported")),
+                ),
+            ],
+            vec![
+                ("nft.near".parse().unwrap(), Ok(3)),
+                ("nothing.near".parse().unwrap(), Ok(0)),
+                (
+                    "broken-nft.near".parse().unwrap(),
+                    Err(color_eyre::eyre::eyre!("nft_supply_for_owner failed")),
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn failed_contracts_and_zero_balances_are_left_out() {
+        let portfolio = portfolio();
+        assert_eq!(
+            portfolio
+                .delegated_stake
+                .keys()
+                .map(|account_id| account_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["pool-a.poolv1.near"]
+        );
+        assert_eq!(
+            portfolio
+                .fungible_tokens
+                .keys()
+                .map(|account_id| account_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["usdc.near"]
+        );
+        assert_eq!(
+            portfolio
+                .nft_counts
+                .iter()
+                .map(|(account_id, count)| (account_id.as_str(), *count))
+                .collect::<Vec<_>>(),
+            vec![("nft.near", 3)]
+        );
+    }
+
+    #[test]
+    fn total_includes_locked_and_delegated_near() {
+        assert_eq!(portfolio().total_near(), 13 * NEAR);
+    }
+
+    #[test]
+    fn json_output() {
+        let block_hash = near_primitives::hash::CryptoHash::hash_bytes(b"block");
+        assert_eq!(
+            portfolio().to_json(&"alice.near".parse().unwrap(), 100, &block_hash, Some(2.5)),
+            serde_json::json!({
+                "account_id": "alice.near",
+                "block_height": 100,
+                "block_hash": block_hash.to_string(),
+                "near_usd_exchange_rate": 2.5,
+                "amount": (10 * NEAR).to_string(),
+                "locked": NEAR.to_string(),
+                "delegated_stake": {"pool-a.poolv1.near": (2 * NEAR).to_string()},
+                "total_near": (13 * NEAR).to_string(),
+                "total_near_usd": "32.5",
+                "fungible_tokens": [{
+                    "ft_contract_account_id": "usdc.near",
+                    "amount": "1500000",
+                    "decimals": 6,
+                    "symbol": "USDC",
+                }],
+                "nft_assets": [{"nft_contract_account_id": "nft.near", "tokens_count": "3"}],
+            })
+        );
+
+        let json = Portfolio::new(&account_view(NEAR, 0), vec![], vec![], vec![]).to_json(
+            &"bob.near".parse().unwrap(),
+            100,
+            &block_hash,
+            None,
+        );
+        assert_eq!(json["near_usd_exchange_rate"], serde_json::Value::Null);
+        assert_eq!(json["total_near_usd"], serde_json::Value::Null);
+        assert_eq!(json["delegated_stake"], serde_json::json!({}));
+        assert_eq!(json["fungible_tokens"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn token_balances_are_read_from_the_contracts() {
+        let rpc_url = crate::test_utils::serve_json_rpc(|method, params| {
+            assert_eq!(method, "query");
+            let result = match (
+                params["account_id"].as_str().unwrap(),
+                params["method_name"].as_str().unwrap(),
+            ) {
+                ("usdc.near", "ft_metadata") => serde_json::json!({
+                    "spec": "ft-1.0.0", "name": "USD Coin", "symbol": "USDC", "decimals": 6
+                }),
+                ("usdc.near", "ft_balance_of") => serde_json::json!("1500000"),
+                ("nft.near", "nft_supply_for_owner") => serde_json::json!("3"),
+                (account_id, method_name) => {
+                    return serde_json::json!({
+                        "error": format!("wasm execution failed with error: FunctionCallError(MethodResolveError(MethodNotFound)) ({account_id} {method_name})"),
+                        "logs": [],
+                        "block_height": 1,
+                        "block_hash": near_primitives::hash::CryptoHash::default(),
+                    })
+                }
+            };
+            serde_json::json!({
+                "result": serde_json::to_vec(&result).unwrap(),
+                "logs": [],
+                "block_height": 1,
+                "block_hash": near_primitives::hash::CryptoHash::default(),
+            })
+        });
+        let json_rpc_client = crate::test_utils::network_config(rpc_url).json_rpc_client();
+        let block_reference = near_primitives::types::BlockReference::latest();
+        let account_id: near_primitives::types::AccountId = "alice.near".parse().unwrap();
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let fungible_token = runtime
+            .block_on(get_ft_balance(
+                &json_rpc_client,
+                &block_reference,
+                &"usdc.near".parse().unwrap(),
+                &account_id,
+            ))
+            .unwrap();
+        assert_eq!(fungible_token.amount(), 1_500_000);
+        assert_eq!(fungible_token.decimals(), 6);
+        assert_eq!(fungible_token.symbol(), "USDC");
+        assert_eq!(
+            runtime
+                .block_on(get_nft_count(
+                    &json_rpc_client,
+                    &block_reference,
+                    &"nft.near".parse().unwrap(),
+                    &account_id,
+                ))
+                .unwrap(),
+            3
+        );
+        assert!(runtime
+            .block_on(get_ft_balance(
+                &json_rpc_client,
+                &block_reference,
+                &"not-a-token.near".parse().unwrap(),
+                &account_id,
+            ))
+            .is_err());
+    }
+}
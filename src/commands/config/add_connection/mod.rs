@@ -43,6 +43,18 @@ pub struct AddNetworkConnection {
     #[interactive_clap(long)]
     #[interactive_clap(skip_default_input_arg)]
     coingecko_url: Option<crate::types::url::Url>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    remote_signer_url: Option<crate::types::url::Url>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    remote_signer_api_key: Option<crate::types::api_key::ApiKey>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    indexer_api_url: Option<crate::types::url::Url>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    indexer_api_key: Option<crate::types::api_key::ApiKey>,
 }
 
 #[derive(Debug, Clone)]
@@ -60,7 +72,7 @@ impl AddNetworkConnectionContext {
                 network_name: scope.network_name.clone(),
                 rpc_url: scope.rpc_url.clone().into(),
                 wallet_url: scope.wallet_url.clone().into(),
-                explorer_transaction_url: scope.explorer_transaction_url.0.clone(),
+                explorer_transaction_url: Some(scope.explorer_transaction_url.0.clone()),
                 rpc_api_key: scope.rpc_api_key.clone(),
                 linkdrop_account_id: scope
                     .linkdrop_account_id
@@ -91,6 +103,16 @@ impl AddNetworkConnectionContext {
                     .coingecko_url
                     .clone()
                     .map(|coingecko_url| coingecko_url.into()),
+                remote_signer_url: scope
+                    .remote_signer_url
+                    .clone()
+                    .map(|remote_signer_url| remote_signer_url.into()),
+                remote_signer_api_key: scope.remote_signer_api_key.clone(),
+                indexer_api_url: scope
+                    .indexer_api_url
+                    .clone()
+                    .map(|indexer_api_url| indexer_api_url.into()),
+                indexer_api_key: scope.indexer_api_key.clone(),
             },
         );
         eprintln!();
@@ -309,4 +331,106 @@ impl AddNetworkConnection {
             Ok(None)
         }
     }
+
+    fn input_remote_signer_url(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::url::Url>> {
+        eprintln!();
+        #[derive(strum_macros::Display)]
+        enum ConfirmOptions {
+            #[strum(to_string = "Yes, I want to sign transactions with a remote signer")]
+            Yes,
+            #[strum(to_string = "No, I don't want to use a remote signer")]
+            No,
+        }
+        let select_choose_input = Select::new(
+            "Do you want to enter the remote signer endpoint?",
+            vec![ConfirmOptions::Yes, ConfirmOptions::No],
+        )
+        .prompt()?;
+        if let ConfirmOptions::Yes = select_choose_input {
+            let remote_signer_url: crate::types::url::Url = CustomType::new(
+                "What is the remote signer endpoint (http(s)://... or unix://<socket path>)?",
+            )
+            .prompt()?;
+            Ok(Some(remote_signer_url))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn input_remote_signer_api_key(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::api_key::ApiKey>> {
+        eprintln!();
+        #[derive(strum_macros::Display)]
+        enum ConfirmOptions {
+            #[strum(to_string = "Yes, the remote signer requires API key")]
+            Yes,
+            #[strum(to_string = "No, the remote signer does not require API key")]
+            No,
+        }
+        let select_choose_input = Select::new(
+            "Do you want to input an API key for the remote signer?",
+            vec![ConfirmOptions::Yes, ConfirmOptions::No],
+        )
+        .prompt()?;
+        if let ConfirmOptions::Yes = select_choose_input {
+            let api_key: crate::types::api_key::ApiKey =
+                CustomType::new("Enter an API key for the remote signer").prompt()?;
+            Ok(Some(api_key))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn input_indexer_api_url(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::url::Url>> {
+        eprintln!();
+        #[derive(strum_macros::Display)]
+        enum ConfirmOptions {
+            #[strum(to_string = "Yes, I want to enter the indexer API url")]
+            Yes,
+            #[strum(to_string = "No, I don't want to enter the indexer API url")]
+            No,
+        }
+        let select_choose_input = Select::new(
+            "Do you want to enter the indexer API url (used to view the account history)?",
+            vec![ConfirmOptions::Yes, ConfirmOptions::No],
+        )
+        .prompt()?;
+        if let ConfirmOptions::Yes = select_choose_input {
+            let indexer_api_url: crate::types::url::Url =
+                CustomType::new("What is the indexer API url?").prompt()?;
+            Ok(Some(indexer_api_url))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn input_indexer_api_key(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::api_key::ApiKey>> {
+        eprintln!();
+        #[derive(strum_macros::Display)]
+        enum ConfirmOptions {
+            #[strum(to_string = "Yes, the indexer API requires API key")]
+            Yes,
+            #[strum(to_string = "No, the indexer API does not require API key")]
+            No,
+        }
+        let select_choose_input = Select::new(
+            "Do you want to input an API key for the indexer API?",
+            vec![ConfirmOptions::Yes, ConfirmOptions::No],
+        )
+        .prompt()?;
+        if let ConfirmOptions::Yes = select_choose_input {
+            let api_key: crate::types::api_key::ApiKey =
+                CustomType::new("Enter an API key for the indexer API").prompt()?;
+            Ok(Some(api_key))
+        } else {
+            Ok(None)
+        }
+    }
 }
@@ -0,0 +1,128 @@
+use color_eyre::eyre::WrapErr;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = MigrateLegacyKeychainContext)]
+pub struct MigrateLegacyKeychain {
+    #[interactive_clap(long)]
+    /// Keep the legacy key files after copying them into the encrypted keystore
+    keep_legacy_files: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct MigrateLegacyKeychainContext;
+
+impl MigrateLegacyKeychainContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<MigrateLegacyKeychain as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let credentials_home_dir = &previous_context.config.credentials_home_dir;
+        let keystore = crate::encrypted_keystore::EncryptedKeystore::new(credentials_home_dir);
+        let legacy_key_files = crate::common::find_legacy_key_files(credentials_home_dir)?;
+        if legacy_key_files.is_empty() {
+            eprintln!(
+                "There are no access keys in the legacy keychain {:?}",
+                credentials_home_dir
+            );
+            return Ok(Self);
+        }
+
+        let mut migrated_count = 0;
+        let mut failed_count = 0;
+        for legacy_key_file in legacy_key_files {
+            match migrate_legacy_key_file(&keystore, &legacy_key_file) {
+                Ok(message) => {
+                    migrated_count += 1;
+                    if !scope.keep_legacy_files {
+                        std::fs::remove_file(&legacy_key_file.path).wrap_err_with(|| {
+                            format!("Failed to remove the file {:?}", &legacy_key_file.path)
+                        })?;
+                    }
+                    eprintln!(
+                        "<{}> on network <{}>: {message} ({:?})",
+                        legacy_key_file.account_id,
+                        legacy_key_file.network_name,
+                        &legacy_key_file.path
+                    );
+                }
+                Err(err) => {
+                    failed_count += 1;
+                    eprintln!(
+                        "<{}> on network <{}>: the key file {:?} was skipped: {err:#}",
+                        legacy_key_file.account_id,
+                        legacy_key_file.network_name,
+                        &legacy_key_file.path
+                    );
+                }
+            }
+        }
+        if !scope.keep_legacy_files {
+            remove_empty_account_dirs(credentials_home_dir);
+        }
+
+        eprintln!(
+            "\n{migrated_count} legacy key file(s) {} the encrypted keystore, {failed_count} skipped.",
+            if scope.keep_legacy_files {
+                "copied into"
+            } else {
+                "moved into"
+            }
+        );
+        if failed_count > 0 {
+            color_eyre::eyre::bail!("Some of the legacy key files were not migrated");
+        }
+        Ok(Self)
+    }
+}
+
+fn migrate_legacy_key_file(
+    keystore: &crate::encrypted_keystore::EncryptedKeystore,
+    legacy_key_file: &crate::common::LegacyKeyFile,
+) -> color_eyre::eyre::Result<String> {
+    let data = std::fs::read_to_string(&legacy_key_file.path)
+        .wrap_err_with(|| format!("Failed to read the file {:?}", &legacy_key_file.path))?;
+    let account_key_pair: crate::transaction_signature_options::AccountKeyPair =
+        serde_json::from_str(&data).wrap_err("The file does not contain an access key")?;
+
+    if keystore.contains(
+        &legacy_key_file.network_name,
+        &legacy_key_file.account_id,
+        &account_key_pair.public_key,
+    ) {
+        return Ok(format!(
+            "the key {} is already in the encrypted keystore",
+            account_key_pair.public_key
+        ));
+    }
+    keystore.save(
+        &legacy_key_file.network_name,
+        &legacy_key_file.account_id,
+        &account_key_pair.public_key,
+        &data,
+    )?;
+    Ok(format!(
+        "the key {} is saved in the encrypted keystore",
+        account_key_pair.public_key
+    ))
+}
+
+fn remove_empty_account_dirs(credentials_home_dir: &std::path::Path) {
+    let Ok(network_dirs) = credentials_home_dir.read_dir() else {
+        return;
+    };
+    let keystore_path =
+        crate::encrypted_keystore::EncryptedKeystore::keystore_dir(credentials_home_dir);
+    for account_dir in network_dirs
+        .filter_map(Result::ok)
+        .filter(|network_dir| network_dir.path() != keystore_path)
+        .filter_map(|network_dir| network_dir.path().read_dir().ok())
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+    {
+        // Only succeeds for empty directories
+        let _ = std::fs::remove_dir(account_dir);
+    }
+}
@@ -3,6 +3,7 @@ use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 mod add_connection;
 mod delete_connection;
+mod migrate_legacy_keychain;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(context = crate::GlobalContext)]
@@ -18,18 +19,23 @@ pub struct ConfigCommands {
 /// What do you want to do with a near CLI config?
 pub enum ConfigActions {
     #[strum_discriminants(strum(
-        message = "show-connections       - Show a list of network connections"
+        message = "show-connections         - Show a list of network connections"
     ))]
     /// Show a list of network connections
     ShowConnections(ShowConnections),
-    #[strum_discriminants(strum(message = "add-connection         - Add a network connection"))]
+    #[strum_discriminants(strum(message = "add-connection           - Add a network connection"))]
     /// Add a network connection
     AddConnection(self::add_connection::AddNetworkConnection),
     #[strum_discriminants(strum(
-        message = "delete-connection      - Delete a network connection"
+        message = "delete-connection        - Delete a network connection"
     ))]
     /// Delete a network connection
     DeleteConnection(self::delete_connection::DeleteNetworkConnection),
+    #[strum_discriminants(strum(
+        message = "migrate-legacy-keychain  - Move the access keys of the legacy keychain into the password-encrypted keystore"
+    ))]
+    /// Move the access keys of the legacy keychain into the password-encrypted keystore
+    MigrateLegacyKeychain(self::migrate_legacy_keychain::MigrateLegacyKeychain),
 }
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -47,6 +47,7 @@ impl CallFunctionView {
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(input_context = CallFunctionViewContext)]
 #[interactive_clap(output_context = FunctionContext)]
+#[interactive_clap(skip_default_from_cli)]
 pub struct Function {
     #[interactive_clap(skip_default_input_arg)]
     /// What is the name of the function?
@@ -55,6 +56,7 @@ pub struct Function {
     #[interactive_clap(skip_default_input_arg)]
     /// How do you want to pass the function call arguments?
     function_args_type: super::call_function_args_type::FunctionArgsType,
+    #[interactive_clap(skip_default_input_arg)]
     /// Enter the arguments to this function:
     function_args: String,
     #[interactive_clap(named_arg)]
@@ -75,9 +77,10 @@ impl FunctionContext {
             let function_args_type = scope.function_args_type.clone();
             let account_id: near_primitives::types::AccountId = previous_context.contract_account_id.clone();
             let function_name = scope.function_name.clone();
+            let output_format = previous_context.global_context.output_format.clone();
 
             move |network_config, block_reference| {
-                call_view_function(network_config, &account_id, &function_name, function_args.clone(), function_args_type.clone(), block_reference)
+                call_view_function(network_config, &account_id, &function_name, function_args.clone(), function_args_type.clone(), block_reference, &output_format)
             }
         });
 
@@ -95,11 +98,104 @@ impl From<FunctionContext> for crate::network_view_at_block::ArgsForViewContext
     }
 }
 
+impl interactive_clap::FromCli for Function {
+    type FromCliContext = CallFunctionViewContext;
+    type FromCliError = color_eyre::eyre::Error;
+
+    fn from_cli(
+        optional_clap_variant: Option<<Function as interactive_clap::ToCli>::CliVariant>,
+        context: Self::FromCliContext,
+    ) -> interactive_clap::ResultFromCli<
+        <Self as interactive_clap::ToCli>::CliVariant,
+        Self::FromCliError,
+    >
+    where
+        Self: Sized + interactive_clap::ToCli,
+    {
+        let mut clap_variant = optional_clap_variant.unwrap_or_default();
+
+        if clap_variant.function_name.is_none() {
+            clap_variant.function_name = match Self::input_function_name(&context) {
+                Ok(Some(function_name)) => Some(function_name),
+                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+        }
+        let function_name = clap_variant
+            .function_name
+            .clone()
+            .expect("Unexpected error");
+
+        if clap_variant.function_args_type.is_none() {
+            clap_variant.function_args_type = match Self::input_function_args_type(&context) {
+                Ok(Some(function_args_type)) => Some(function_args_type),
+                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+        }
+        let function_args_type = clap_variant
+            .function_args_type
+            .clone()
+            .expect("Unexpected error");
+
+        // The arguments prompt depends on the function name (the contract ABI describes its
+        // parameters), so this struct does not use the default `from_cli` implementation.
+        if clap_variant.function_args.is_none() {
+            clap_variant.function_args =
+                match Self::input_function_args(&context, &function_name, &function_args_type) {
+                    Ok(Some(function_args)) => Some(function_args),
+                    Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                    Err(err) => {
+                        return interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+                    }
+                };
+        }
+        let function_args = clap_variant
+            .function_args
+            .clone()
+            .expect("Unexpected error");
+
+        let new_context_scope = InteractiveClapContextScopeForFunction {
+            function_name,
+            function_args_type,
+            function_args,
+        };
+        let new_context = match FunctionContext::from_previous_context(context, &new_context_scope)
+        {
+            Ok(new_context) => new_context,
+            Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+        };
+
+        let optional_field = clap_variant
+            .network_config
+            .take()
+            .map(|ClapNamedArgNetworkViewAtBlockArgsForFunction::NetworkConfig(cli_arg)| cli_arg);
+        match <crate::network_view_at_block::NetworkViewAtBlockArgs as interactive_clap::FromCli>::from_cli(
+            optional_field,
+            new_context.into(),
+        ) {
+            interactive_clap::ResultFromCli::Ok(cli_field) => {
+                clap_variant.network_config = Some(ClapNamedArgNetworkViewAtBlockArgsForFunction::NetworkConfig(cli_field));
+                interactive_clap::ResultFromCli::Ok(clap_variant)
+            }
+            interactive_clap::ResultFromCli::Cancel(optional_cli_field) => {
+                clap_variant.network_config = optional_cli_field.map(ClapNamedArgNetworkViewAtBlockArgsForFunction::NetworkConfig);
+                interactive_clap::ResultFromCli::Cancel(Some(clap_variant))
+            }
+            interactive_clap::ResultFromCli::Back => interactive_clap::ResultFromCli::Back,
+            interactive_clap::ResultFromCli::Err(optional_cli_field, err) => {
+                clap_variant.network_config = optional_cli_field.map(ClapNamedArgNetworkViewAtBlockArgsForFunction::NetworkConfig);
+                interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+            }
+        }
+    }
+}
+
 impl Function {
     fn input_function_args_type(
         _context: &CallFunctionViewContext,
     ) -> color_eyre::eyre::Result<Option<super::call_function_args_type::FunctionArgsType>> {
-        super::call_function_args_type::input_function_args_type()
+        super::call_function_args_type::input_call_function_args_type()
     }
 
     fn input_function_name(
@@ -107,6 +203,19 @@ impl Function {
     ) -> color_eyre::eyre::Result<Option<String>> {
         super::input_view_function_name(&context.global_context, &context.contract_account_id)
     }
+
+    fn input_function_args(
+        context: &CallFunctionViewContext,
+        function_name: &str,
+        function_args_type: &super::call_function_args_type::FunctionArgsType,
+    ) -> color_eyre::eyre::Result<Option<String>> {
+        super::function_abi::input_function_args(
+            &context.global_context,
+            &context.contract_account_id,
+            function_name,
+            function_args_type,
+        )
+    }
 }
 
 #[tracing::instrument(name = "Getting a response to a view method ...", skip_all)]
@@ -117,8 +226,22 @@ fn call_view_function(
     function_args: String,
     function_args_type: super::call_function_args_type::FunctionArgsType,
     block_reference: &near_primitives::types::BlockReference,
+    output_format: &crate::common::OutputFormat,
 ) -> crate::CliResult {
-    let args = super::call_function_args_type::function_args(function_args, function_args_type)?;
+    let args = super::function_abi::function_args(
+        network_config,
+        account_id,
+        function_name,
+        function_args,
+        function_args_type,
+    )?;
+    super::function_abi::check_function_call(
+        network_config,
+        account_id,
+        function_name,
+        &args,
+        near_abi::AbiFunctionKind::View,
+    )?;
     let call_result = network_config
         .json_rpc_client()
         .blocking_call_view_function(account_id, function_name, args, block_reference.clone())
@@ -129,9 +252,28 @@ fn call_view_function(
             )
         })?;
     call_result.print_logs();
+    let borsh_result =
+        super::function_abi::borsh_result_schema(network_config, account_id, function_name)?
+            .filter(|_| !call_result.result.is_empty())
+            .map(|schema| call_result.parse_result_from_borsh(&schema))
+            .transpose()?;
+    if output_format.is_json() {
+        return crate::common::print_json_output(
+            "view_function_result",
+            serde_json::json!({
+                "contract_account_id": account_id,
+                "method_name": function_name,
+                "logs": call_result.logs,
+                "result": borsh_result.clone().or_else(|| call_result.parse_result_from_json::<serde_json::Value>().ok()),
+                "result_base64": near_primitives::serialize::to_base64(&call_result.result),
+            }),
+        );
+    }
     eprintln!("Result:");
     if call_result.result.is_empty() {
         eprintln!("Empty result");
+    } else if let Some(borsh_result) = borsh_result {
+        println!("{}", serde_json::to_string_pretty(&borsh_result)?);
     } else if let Ok(json_result) = call_result.parse_result_from_json::<serde_json::Value>() {
         println!("{}", serde_json::to_string_pretty(&json_result)?);
     } else if let Ok(string_result) = String::from_utf8(call_result.result) {
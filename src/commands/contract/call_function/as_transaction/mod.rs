@@ -1,3 +1,4 @@
+use color_eyre::eyre::WrapErr;
 use inquire::CustomType;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -44,6 +45,7 @@ impl CallFunction {
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(input_context = CallFunctionContext)]
 #[interactive_clap(output_context = FunctionContext)]
+#[interactive_clap(skip_default_from_cli)]
 pub struct Function {
     #[interactive_clap(skip_default_input_arg)]
     /// What is the name of the function?
@@ -52,6 +54,7 @@ pub struct Function {
     #[interactive_clap(skip_default_input_arg)]
     /// How do you want to pass the function call arguments?
     function_args_type: super::call_function_args_type::FunctionArgsType,
+    #[interactive_clap(skip_default_input_arg)]
     /// Enter the arguments to this function:
     function_args: String,
     #[interactive_clap(named_arg)]
@@ -65,6 +68,7 @@ pub struct FunctionContext {
     contract_account_id: near_primitives::types::AccountId,
     function_name: String,
     function_args: Vec<u8>,
+    function_args_type: super::call_function_args_type::FunctionArgsType,
 }
 
 impl FunctionContext {
@@ -72,24 +76,125 @@ impl FunctionContext {
         previous_context: CallFunctionContext,
         scope: &<Function as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let function_args = super::call_function_args_type::function_args(
-            scope.function_args.clone(),
-            scope.function_args_type.clone(),
-        )?;
+        // Borsh arguments are kept as JSON until the network is selected, as they are serialized
+        // using the contract ABI.
+        let function_args = match scope.function_args_type {
+            super::call_function_args_type::FunctionArgsType::BorshArgs => {
+                super::call_function_args_type::json_args(scope.function_args.clone())?
+            }
+            _ => super::call_function_args_type::function_args(
+                scope.function_args.clone(),
+                scope.function_args_type.clone(),
+            )?,
+        };
         Ok(Self {
             global_context: previous_context.global_context,
             contract_account_id: previous_context.contract_account_id,
             function_name: scope.function_name.clone(),
             function_args,
+            function_args_type: scope.function_args_type.clone(),
         })
     }
 }
 
+impl interactive_clap::FromCli for Function {
+    type FromCliContext = CallFunctionContext;
+    type FromCliError = color_eyre::eyre::Error;
+
+    fn from_cli(
+        optional_clap_variant: Option<<Function as interactive_clap::ToCli>::CliVariant>,
+        context: Self::FromCliContext,
+    ) -> interactive_clap::ResultFromCli<
+        <Self as interactive_clap::ToCli>::CliVariant,
+        Self::FromCliError,
+    >
+    where
+        Self: Sized + interactive_clap::ToCli,
+    {
+        let mut clap_variant = optional_clap_variant.unwrap_or_default();
+
+        if clap_variant.function_name.is_none() {
+            clap_variant.function_name = match Self::input_function_name(&context) {
+                Ok(Some(function_name)) => Some(function_name),
+                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+        }
+        let function_name = clap_variant
+            .function_name
+            .clone()
+            .expect("Unexpected error");
+
+        if clap_variant.function_args_type.is_none() {
+            clap_variant.function_args_type = match Self::input_function_args_type(&context) {
+                Ok(Some(function_args_type)) => Some(function_args_type),
+                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+        }
+        let function_args_type = clap_variant
+            .function_args_type
+            .clone()
+            .expect("Unexpected error");
+
+        // The arguments prompt depends on the function name (the contract ABI describes its
+        // parameters), so this struct does not use the default `from_cli` implementation.
+        if clap_variant.function_args.is_none() {
+            clap_variant.function_args =
+                match Self::input_function_args(&context, &function_name, &function_args_type) {
+                    Ok(Some(function_args)) => Some(function_args),
+                    Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                    Err(err) => {
+                        return interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+                    }
+                };
+        }
+        let function_args = clap_variant
+            .function_args
+            .clone()
+            .expect("Unexpected error");
+
+        let new_context_scope = InteractiveClapContextScopeForFunction {
+            function_name,
+            function_args_type,
+            function_args,
+        };
+        let new_context = match FunctionContext::from_previous_context(context, &new_context_scope)
+        {
+            Ok(new_context) => new_context,
+            Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+        };
+
+        let optional_field = clap_variant
+            .prepaid_gas
+            .take()
+            .map(|ClapNamedArgPrepaidGasForFunction::PrepaidGas(cli_arg)| cli_arg);
+        match <PrepaidGas as interactive_clap::FromCli>::from_cli(optional_field, new_context) {
+            interactive_clap::ResultFromCli::Ok(cli_field) => {
+                clap_variant.prepaid_gas =
+                    Some(ClapNamedArgPrepaidGasForFunction::PrepaidGas(cli_field));
+                interactive_clap::ResultFromCli::Ok(clap_variant)
+            }
+            interactive_clap::ResultFromCli::Cancel(optional_cli_field) => {
+                clap_variant.prepaid_gas =
+                    optional_cli_field.map(ClapNamedArgPrepaidGasForFunction::PrepaidGas);
+                interactive_clap::ResultFromCli::Cancel(Some(clap_variant))
+            }
+            interactive_clap::ResultFromCli::Back => interactive_clap::ResultFromCli::Back,
+            interactive_clap::ResultFromCli::Err(optional_cli_field, err) => {
+                clap_variant.prepaid_gas =
+                    optional_cli_field.map(ClapNamedArgPrepaidGasForFunction::PrepaidGas);
+                interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+            }
+        }
+    }
+}
+
 impl Function {
     fn input_function_args_type(
         _context: &CallFunctionContext,
     ) -> color_eyre::eyre::Result<Option<super::call_function_args_type::FunctionArgsType>> {
-        super::call_function_args_type::input_function_args_type()
+        super::call_function_args_type::input_call_function_args_type()
     }
 
     fn input_function_name(
@@ -97,6 +202,19 @@ impl Function {
     ) -> color_eyre::eyre::Result<Option<String>> {
         super::input_call_function_name(&context.global_context, &context.contract_account_id)
     }
+
+    fn input_function_args(
+        context: &CallFunctionContext,
+        function_name: &str,
+        function_args_type: &super::call_function_args_type::FunctionArgsType,
+    ) -> color_eyre::eyre::Result<Option<String>> {
+        super::function_abi::input_function_args(
+            &context.global_context,
+            &context.contract_account_id,
+            function_name,
+            function_args_type,
+        )
+    }
 }
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -117,6 +235,7 @@ pub struct PrepaidGasContext {
     contract_account_id: near_primitives::types::AccountId,
     function_name: String,
     function_args: Vec<u8>,
+    function_args_type: super::call_function_args_type::FunctionArgsType,
     gas: crate::common::NearGas,
 }
 
@@ -130,6 +249,7 @@ impl PrepaidGasContext {
             contract_account_id: previous_context.contract_account_id,
             function_name: previous_context.function_name,
             function_args: previous_context.function_args,
+            function_args_type: previous_context.function_args_type,
             gas: scope.gas,
         })
     }
@@ -177,6 +297,7 @@ pub struct DepositContext {
     contract_account_id: near_primitives::types::AccountId,
     function_name: String,
     function_args: Vec<u8>,
+    function_args_type: super::call_function_args_type::FunctionArgsType,
     gas: crate::common::NearGas,
     deposit: crate::types::near_token::NearToken,
 }
@@ -191,6 +312,7 @@ impl DepositContext {
             contract_account_id: previous_context.contract_account_id,
             function_name: previous_context.function_name,
             function_args: previous_context.function_args,
+            function_args_type: previous_context.function_args_type,
             gas: previous_context.gas,
             deposit: scope.deposit,
         })
@@ -228,6 +350,7 @@ pub struct SignerAccountIdContext {
     contract_account_id: near_primitives::types::AccountId,
     function_name: String,
     function_args: Vec<u8>,
+    function_args_type: super::call_function_args_type::FunctionArgsType,
     gas: crate::common::NearGas,
     deposit: crate::types::near_token::NearToken,
     signer_account_id: near_primitives::types::AccountId,
@@ -243,6 +366,7 @@ impl SignerAccountIdContext {
             contract_account_id: previous_context.contract_account_id,
             function_name: previous_context.function_name,
             function_args: previous_context.function_args,
+            function_args_type: previous_context.function_args_type,
             gas: previous_context.gas,
             deposit: previous_context.deposit,
             signer_account_id: scope.signer_account_id.clone().into(),
@@ -252,19 +376,77 @@ impl SignerAccountIdContext {
 
 impl From<SignerAccountIdContext> for crate::commands::ActionContext {
     fn from(item: SignerAccountIdContext) -> Self {
+        let on_after_sending_transaction_callback: crate::transaction_signature_options::OnAfterSendingTransactionCallback =
+            std::sync::Arc::new({
+                let contract_account_id = item.contract_account_id.clone();
+                let function_name = item.function_name.clone();
+                let offline = item.global_context.offline;
+                let output_format = item.global_context.output_format.clone();
+
+                move |outcome_view, network_config| {
+                    if let near_primitives::views::FinalExecutionStatus::SuccessValue(result) =
+                        &outcome_view.status
+                    {
+                        if offline || output_format.is_json() || result.is_empty() {
+                            return Ok(());
+                        }
+                        if let Some(schema) = super::function_abi::borsh_result_schema(
+                            network_config,
+                            &contract_account_id,
+                            &function_name,
+                        )? {
+                            let borsh_result = super::borsh_schema::deserialize(result, &schema)
+                                .wrap_err(
+                                    "Failed to decode the Borsh return value of the function call",
+                                )?;
+                            eprintln!("--- Decoded Borsh result -----------");
+                            println!("{}", serde_json::to_string_pretty(&borsh_result)?);
+                            eprintln!("------------------------------------\n");
+                        }
+                    }
+                    Ok(())
+                }
+            });
+
         let get_prepopulated_transaction_after_getting_network_callback: crate::commands::GetPrepopulatedTransactionAfterGettingNetworkCallback =
             std::sync::Arc::new({
                 let signer_account_id = item.signer_account_id.clone();
                 let receiver_account_id = item.contract_account_id.clone();
+                let offline = item.global_context.offline;
 
-                move |_network_config| {
+                move |network_config| {
+                    let function_args = match item.function_args_type {
+                        super::call_function_args_type::FunctionArgsType::BorshArgs => {
+                            if offline {
+                                color_eyre::eyre::bail!(
+                                    "borsh-args are serialized using the contract ABI, which cannot be fetched in offline mode"
+                                );
+                            }
+                            super::function_abi::borsh_function_args(
+                                network_config,
+                                &receiver_account_id,
+                                &item.function_name,
+                                &item.function_args,
+                            )?
+                        }
+                        _ => item.function_args.clone(),
+                    };
+                    if !offline {
+                        super::function_abi::check_function_call(
+                            network_config,
+                            &receiver_account_id,
+                            &item.function_name,
+                            &function_args,
+                            near_abi::AbiFunctionKind::Call,
+                        )?;
+                    }
                     Ok(crate::commands::PrepopulatedTransaction {
                         signer_id: signer_account_id.clone(),
                         receiver_id: receiver_account_id.clone(),
                         actions: vec![near_primitives::transaction::Action::FunctionCall(
                             Box::new(near_primitives::transaction::FunctionCallAction {
                                 method_name: item.function_name.clone(),
-                                args: item.function_args.clone(),
+                                args: function_args,
                                 gas: item.gas.as_gas(),
                                 deposit: item.deposit.as_yoctonear(),
                             }),
@@ -283,9 +465,8 @@ impl From<SignerAccountIdContext> for crate::commands::ActionContext {
             on_before_sending_transaction_callback: std::sync::Arc::new(
                 |_signed_transaction, _network_config| Ok(String::new()),
             ),
-            on_after_sending_transaction_callback: std::sync::Arc::new(
-                |_outcome_view, _network_config| Ok(()),
-            ),
+            on_after_sending_transaction_callback,
+            transaction_bundle: None,
         }
     }
 }
@@ -0,0 +1,657 @@
+//! Conversion between JSON values and Borsh-serialized data described by a Borsh schema (the
+//! `AbiType::Borsh` type schemas published in the contract ABI).
+//!
+//! JSON representation of the Borsh types:
+//! - integers up to 64 bits and floats are JSON numbers, 128-bit integers are JSON strings
+//!   (numbers and strings are both accepted as input);
+//! - `String` is a JSON string, `()` is `null`;
+//! - sequences and tuples are JSON arrays;
+//! - structs with named fields are JSON objects, tuple structs are JSON arrays (a single-field
+//!   tuple struct is represented by its field), unit structs are `null`;
+//! - `Option` is `null` or the value, other enums are `"Variant"` (unit variants) or
+//!   `{"Variant": value}`.
+use borsh::schema::{BorshSchemaContainer, Declaration, Definition, Fields};
+use color_eyre::eyre::ContextCompat;
+
+/// Serializes the JSON value to Borsh according to the schema.
+pub fn serialize(
+    value: &serde_json::Value,
+    schema: &BorshSchemaContainer,
+) -> color_eyre::eyre::Result<Vec<u8>> {
+    validate_schema(schema)?;
+    let mut buffer = vec![];
+    serialize_declaration("value", value, schema.declaration(), schema, &mut buffer, 0)?;
+    Ok(buffer)
+}
+
+/// Deserializes the Borsh data into a JSON value according to the schema.
+pub fn deserialize(
+    bytes: &[u8],
+    schema: &BorshSchemaContainer,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    validate_schema(schema)?;
+    let mut reader = bytes;
+    let value = deserialize_declaration(schema.declaration(), schema, &mut reader, 0)?;
+    if !reader.is_empty() {
+        color_eyre::eyre::bail!(
+            "The Borsh data has {} unexpected trailing bytes",
+            reader.len()
+        );
+    }
+    Ok(value)
+}
+
+/// The nesting of the values is limited (as `serde_json` does), so that a recursive type from an
+/// untrusted contract ABI cannot overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+/// Checks the schema (e.g. from a contract ABI) against the rules of the Borsh serialization.
+pub fn validate_schema(schema: &BorshSchemaContainer) -> color_eyre::eyre::Result<()> {
+    schema
+        .validate()
+        .map_err(|err| color_eyre::eyre::eyre!("The Borsh schema is invalid: {err:?}"))
+}
+
+fn check_depth(depth: usize) -> color_eyre::eyre::Result<()> {
+    if depth > MAX_DEPTH {
+        color_eyre::eyre::bail!("The Borsh value is nested deeper than {MAX_DEPTH} levels");
+    }
+    Ok(())
+}
+
+fn is_unit(declaration: &Declaration, schema: &BorshSchemaContainer) -> bool {
+    declaration == "()"
+        || matches!(
+            schema.get_definition(declaration),
+            Some(Definition::Struct {
+                fields: Fields::Empty
+            })
+        )
+}
+
+/// Returns the type of the `Some` variant if the enum is an `Option`.
+fn option_type<'a>(
+    variants: &'a [(i64, String, Declaration)],
+    schema: &BorshSchemaContainer,
+) -> Option<&'a Declaration> {
+    match variants {
+        [(0, none, none_declaration), (1, some, some_declaration)]
+            if none == "None" && some == "Some" && is_unit(none_declaration, schema) =>
+        {
+            Some(some_declaration)
+        }
+        _ => None,
+    }
+}
+
+fn integer<T>(path: &str, value: &serde_json::Value) -> color_eyre::eyre::Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let number = match value {
+        serde_json::Value::Number(number) => number.to_string(),
+        serde_json::Value::String(number) => number.clone(),
+        _ => color_eyre::eyre::bail!("{path}: expected an integer, found {value}"),
+    };
+    number
+        .parse()
+        .map_err(|err| color_eyre::eyre::eyre!("{path}: invalid integer {value}: {err}"))
+}
+
+fn float(path: &str, value: &serde_json::Value) -> color_eyre::eyre::Result<f64> {
+    match value {
+        serde_json::Value::Number(number) => number.as_f64(),
+        serde_json::Value::String(number) => number.parse().ok(),
+        _ => None,
+    }
+    .wrap_err_with(|| format!("{path}: expected a number, found {value}"))
+}
+
+/// The little-endian bytes of a length prefix or an enum tag of the given width.
+fn fixed_width_bytes(value: u64, width: u8) -> color_eyre::eyre::Result<Vec<u8>> {
+    let width = usize::from(width);
+    if width > 8 || (width < 8 && value >> (width * 8) != 0) {
+        color_eyre::eyre::bail!("The value {value} does not fit into {width} bytes");
+    }
+    Ok(value.to_le_bytes()[..width].to_vec())
+}
+
+fn serialize_declaration(
+    path: &str,
+    value: &serde_json::Value,
+    declaration: &Declaration,
+    schema: &BorshSchemaContainer,
+    buffer: &mut Vec<u8>,
+    depth: usize,
+) -> color_eyre::eyre::Result<()> {
+    check_depth(depth)?;
+    match declaration.as_str() {
+        "u8" => buffer.extend(integer::<u8>(path, value)?.to_le_bytes()),
+        "u16" => buffer.extend(integer::<u16>(path, value)?.to_le_bytes()),
+        "u32" => buffer.extend(integer::<u32>(path, value)?.to_le_bytes()),
+        "u64" => buffer.extend(integer::<u64>(path, value)?.to_le_bytes()),
+        "u128" => buffer.extend(integer::<u128>(path, value)?.to_le_bytes()),
+        "i8" => buffer.extend(integer::<i8>(path, value)?.to_le_bytes()),
+        "i16" => buffer.extend(integer::<i16>(path, value)?.to_le_bytes()),
+        "i32" => buffer.extend(integer::<i32>(path, value)?.to_le_bytes()),
+        "i64" => buffer.extend(integer::<i64>(path, value)?.to_le_bytes()),
+        "i128" => buffer.extend(integer::<i128>(path, value)?.to_le_bytes()),
+        "f32" => buffer.extend((float(path, value)? as f32).to_le_bytes()),
+        "f64" => buffer.extend(float(path, value)?.to_le_bytes()),
+        "bool" => {
+            buffer.push(u8::from(value.as_bool().wrap_err_with(|| {
+                format!("{path}: expected a boolean, found {value}")
+            })?))
+        }
+        "()" => {}
+        "String" => {
+            let string = value
+                .as_str()
+                .wrap_err_with(|| format!("{path}: expected a string, found {value}"))?;
+            buffer.extend(fixed_width_bytes(string.len() as u64, 4)?);
+            buffer.extend(string.as_bytes());
+        }
+        _ => {
+            let definition = schema.get_definition(declaration).wrap_err_with(|| {
+                format!("The Borsh schema does not define the type <{declaration}>")
+            })?;
+            serialize_definition(path, value, declaration, definition, schema, buffer, depth)?;
+        }
+    }
+    Ok(())
+}
+
+fn serialize_definition(
+    path: &str,
+    value: &serde_json::Value,
+    declaration: &Declaration,
+    definition: &Definition,
+    schema: &BorshSchemaContainer,
+    buffer: &mut Vec<u8>,
+    depth: usize,
+) -> color_eyre::eyre::Result<()> {
+    match definition {
+        Definition::Primitive(_) => {
+            color_eyre::eyre::bail!("The Borsh primitive type <{declaration}> is not supported")
+        }
+        Definition::Sequence {
+            length_width,
+            length_range,
+            elements,
+        } => {
+            let items = value
+                .as_array()
+                .wrap_err_with(|| format!("{path}: expected an array, found {value}"))?;
+            let length = items.len() as u64;
+            if !length_range.contains(&length) {
+                color_eyre::eyre::bail!(
+                    "{path}: expected {}..={} items, found {length}",
+                    length_range.start(),
+                    length_range.end()
+                );
+            }
+            if *length_width > 0 {
+                buffer.extend(fixed_width_bytes(length, *length_width)?);
+            } else if length_range.start() != length_range.end() {
+                color_eyre::eyre::bail!(
+                    "The Borsh type <{declaration}> has a variable length without a length prefix, which is not supported"
+                );
+            }
+            for (index, item) in items.iter().enumerate() {
+                serialize_declaration(
+                    &format!("{path}[{index}]"),
+                    item,
+                    elements,
+                    schema,
+                    buffer,
+                    depth + 1,
+                )?;
+            }
+        }
+        Definition::Tuple { elements } => {
+            serialize_unnamed_fields(path, value, elements, schema, buffer, depth)?;
+        }
+        Definition::Enum {
+            tag_width,
+            variants,
+        } => {
+            if *tag_width == 0 || *tag_width > 8 {
+                color_eyre::eyre::bail!(
+                    "The Borsh enum <{declaration}> with a {tag_width}-byte tag is not supported"
+                );
+            }
+            let (variant_name, variant_value) = match (option_type(variants, schema), value) {
+                (Some(_), serde_json::Value::Null) => ("None", &serde_json::Value::Null),
+                (Some(_), value) => ("Some", value),
+                (None, serde_json::Value::String(variant_name)) => {
+                    (variant_name.as_str(), &serde_json::Value::Null)
+                }
+                (None, serde_json::Value::Object(object)) if object.len() == 1 => object
+                    .iter()
+                    .next()
+                    .map(|(variant_name, variant_value)| (variant_name.as_str(), variant_value))
+                    .expect("Unexpected error"),
+                _ => color_eyre::eyre::bail!(
+                    "{path}: expected a variant of <{declaration}> (\"Variant\" or {{\"Variant\": value}}), found {value}"
+                ),
+            };
+            let (discriminant, _, variant_declaration) = variants
+                .iter()
+                .find(|(_, name, _)| name == variant_name)
+                .wrap_err_with(|| {
+                    format!(
+                        "{path}: <{declaration}> does not have the variant <{variant_name}> (expected one of: {})",
+                        variants
+                            .iter()
+                            .map(|(_, name, _)| name.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                })?;
+            let tag = u64::try_from(*discriminant)
+                .ok()
+                .and_then(|discriminant| fixed_width_bytes(discriminant, *tag_width).ok())
+                .wrap_err_with(|| {
+                    format!("The tag {discriminant} of <{declaration}> does not fit into {tag_width} bytes")
+                })?;
+            buffer.extend(tag);
+            if !is_unit(variant_declaration, schema) {
+                serialize_declaration(
+                    &format!("{path}.{variant_name}"),
+                    variant_value,
+                    variant_declaration,
+                    schema,
+                    buffer,
+                    depth + 1,
+                )?;
+            }
+        }
+        Definition::Struct { fields } => match fields {
+            Fields::NamedFields(fields) => {
+                let object = value
+                    .as_object()
+                    .wrap_err_with(|| format!("{path}: expected an object, found {value}"))?;
+                if let Some(unknown_field) = object
+                    .keys()
+                    .find(|key| !fields.iter().any(|(name, _)| name == *key))
+                {
+                    color_eyre::eyre::bail!(
+                        "{path}: <{declaration}> does not have the field <{unknown_field}>"
+                    );
+                }
+                for (name, field_declaration) in fields {
+                    serialize_declaration(
+                        &format!("{path}.{name}"),
+                        object.get(name).unwrap_or(&serde_json::Value::Null),
+                        field_declaration,
+                        schema,
+                        buffer,
+                        depth + 1,
+                    )?;
+                }
+            }
+            Fields::UnnamedFields(fields) => match &fields[..] {
+                [field_declaration] => serialize_declaration(
+                    path,
+                    value,
+                    field_declaration,
+                    schema,
+                    buffer,
+                    depth + 1,
+                )?,
+                _ => serialize_unnamed_fields(path, value, fields, schema, buffer, depth)?,
+            },
+            Fields::Empty => {}
+        },
+    }
+    Ok(())
+}
+
+fn serialize_unnamed_fields(
+    path: &str,
+    value: &serde_json::Value,
+    declarations: &[Declaration],
+    schema: &BorshSchemaContainer,
+    buffer: &mut Vec<u8>,
+    depth: usize,
+) -> color_eyre::eyre::Result<()> {
+    let items = value
+        .as_array()
+        .filter(|items| items.len() == declarations.len())
+        .wrap_err_with(|| {
+            format!(
+                "{path}: expected an array of {} items, found {value}",
+                declarations.len()
+            )
+        })?;
+    for (index, (item, declaration)) in items.iter().zip(declarations).enumerate() {
+        serialize_declaration(
+            &format!("{path}[{index}]"),
+            item,
+            declaration,
+            schema,
+            buffer,
+            depth + 1,
+        )?;
+    }
+    Ok(())
+}
+
+fn read<'a>(reader: &mut &'a [u8], length: usize) -> color_eyre::eyre::Result<&'a [u8]> {
+    if reader.len() < length {
+        color_eyre::eyre::bail!("Unexpected end of the Borsh data");
+    }
+    let (bytes, rest) = reader.split_at(length);
+    *reader = rest;
+    Ok(bytes)
+}
+
+fn read_array<const N: usize>(reader: &mut &[u8]) -> color_eyre::eyre::Result<[u8; N]> {
+    Ok(read(reader, N)?.try_into().expect("Unexpected error"))
+}
+
+fn read_u64(reader: &mut &[u8], width: u8) -> color_eyre::eyre::Result<u64> {
+    if width > 8 {
+        color_eyre::eyre::bail!("The {width}-byte length or tag is not supported");
+    }
+    let mut bytes = [0u8; 8];
+    bytes[..usize::from(width)].copy_from_slice(read(reader, usize::from(width))?);
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn deserialize_declaration(
+    declaration: &Declaration,
+    schema: &BorshSchemaContainer,
+    reader: &mut &[u8],
+    depth: usize,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    check_depth(depth)?;
+    Ok(match declaration.as_str() {
+        "u8" => u8::from_le_bytes(read_array(reader)?).into(),
+        "u16" => u16::from_le_bytes(read_array(reader)?).into(),
+        "u32" => u32::from_le_bytes(read_array(reader)?).into(),
+        "u64" => u64::from_le_bytes(read_array(reader)?).into(),
+        "u128" => u128::from_le_bytes(read_array(reader)?).to_string().into(),
+        "i8" => i8::from_le_bytes(read_array(reader)?).into(),
+        "i16" => i16::from_le_bytes(read_array(reader)?).into(),
+        "i32" => i32::from_le_bytes(read_array(reader)?).into(),
+        "i64" => i64::from_le_bytes(read_array(reader)?).into(),
+        "i128" => i128::from_le_bytes(read_array(reader)?).to_string().into(),
+        "f32" => f32::from_le_bytes(read_array(reader)?).into(),
+        "f64" => f64::from_le_bytes(read_array(reader)?).into(),
+        "bool" => match read_array::<1>(reader)? {
+            [0] => false.into(),
+            [1] => true.into(),
+            [byte] => color_eyre::eyre::bail!("Invalid Borsh boolean value {byte}"),
+        },
+        "()" => serde_json::Value::Null,
+        "String" => {
+            let length = read_u64(reader, 4)?;
+            let bytes = read(reader, length as usize)?;
+            String::from_utf8(bytes.to_vec())
+                .map_err(|_| color_eyre::eyre::eyre!("Invalid UTF-8 string in the Borsh data"))?
+                .into()
+        }
+        _ => {
+            let definition = schema.get_definition(declaration).wrap_err_with(|| {
+                format!("The Borsh schema does not define the type <{declaration}>")
+            })?;
+            deserialize_definition(declaration, definition, schema, reader, depth)?
+        }
+    })
+}
+
+fn deserialize_definition(
+    declaration: &Declaration,
+    definition: &Definition,
+    schema: &BorshSchemaContainer,
+    reader: &mut &[u8],
+    depth: usize,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    Ok(match definition {
+        Definition::Primitive(_) => {
+            color_eyre::eyre::bail!("The Borsh primitive type <{declaration}> is not supported")
+        }
+        Definition::Sequence {
+            length_width,
+            length_range,
+            elements,
+        } => {
+            let length = if *length_width > 0 {
+                read_u64(reader, *length_width)?
+            } else if length_range.start() == length_range.end() {
+                *length_range.start()
+            } else {
+                color_eyre::eyre::bail!(
+                    "The Borsh type <{declaration}> has a variable length without a length prefix, which is not supported"
+                );
+            };
+            if !length_range.contains(&length) {
+                color_eyre::eyre::bail!(
+                    "The length {length} of <{declaration}> is out of the {}..={} range",
+                    length_range.start(),
+                    length_range.end()
+                );
+            }
+            let mut items = Vec::with_capacity((length as usize).min(reader.len()));
+            for _ in 0..length {
+                items.push(deserialize_declaration(
+                    elements,
+                    schema,
+                    reader,
+                    depth + 1,
+                )?);
+            }
+            serde_json::Value::Array(items)
+        }
+        Definition::Tuple { elements } => {
+            deserialize_unnamed_fields(elements, schema, reader, depth)?
+        }
+        Definition::Enum {
+            tag_width,
+            variants,
+        } => {
+            if *tag_width == 0 || *tag_width > 8 {
+                color_eyre::eyre::bail!(
+                    "The Borsh enum <{declaration}> with a {tag_width}-byte tag is not supported"
+                );
+            }
+            let discriminant = read_u64(reader, *tag_width)? as i64;
+            let (_, variant_name, variant_declaration) = variants
+                .iter()
+                .find(|(variant_discriminant, _, _)| *variant_discriminant == discriminant)
+                .wrap_err_with(|| {
+                    format!("<{declaration}> does not have a variant with the tag {discriminant}")
+                })?;
+            if let Some(some_declaration) = option_type(variants, schema) {
+                match variant_name.as_str() {
+                    "None" => serde_json::Value::Null,
+                    _ => deserialize_declaration(some_declaration, schema, reader, depth + 1)?,
+                }
+            } else if is_unit(variant_declaration, schema) {
+                serde_json::Value::String(variant_name.clone())
+            } else {
+                serde_json::json!({
+                    variant_name: deserialize_declaration(variant_declaration, schema, reader, depth + 1)?
+                })
+            }
+        }
+        Definition::Struct { fields } => match fields {
+            Fields::NamedFields(fields) => {
+                let mut object = serde_json::Map::new();
+                for (name, field_declaration) in fields {
+                    object.insert(
+                        name.clone(),
+                        deserialize_declaration(field_declaration, schema, reader, depth + 1)?,
+                    );
+                }
+                serde_json::Value::Object(object)
+            }
+            Fields::UnnamedFields(fields) => match &fields[..] {
+                [field_declaration] => {
+                    deserialize_declaration(field_declaration, schema, reader, depth + 1)?
+                }
+                _ => deserialize_unnamed_fields(fields, schema, reader, depth)?,
+            },
+            Fields::Empty => serde_json::Value::Null,
+        },
+    })
+}
+
+fn deserialize_unnamed_fields(
+    declarations: &[Declaration],
+    schema: &BorshSchemaContainer,
+    reader: &mut &[u8],
+    depth: usize,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    Ok(serde_json::Value::Array(
+        declarations
+            .iter()
+            .map(|declaration| deserialize_declaration(declaration, schema, reader, depth + 1))
+            .collect::<color_eyre::eyre::Result<_>>()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(borsh::BorshSerialize, borsh::BorshSchema)]
+    enum Kind {
+        Plain,
+        Tagged(String),
+    }
+
+    #[derive(borsh::BorshSerialize, borsh::BorshSchema)]
+    struct Args {
+        owner_id: String,
+        amount: u128,
+        limit: Option<u32>,
+        tags: Vec<(u8, bool)>,
+        kinds: Vec<Kind>,
+    }
+
+    #[test]
+    fn borsh_round_trip_matches_derived_serialization() {
+        let args = Args {
+            owner_id: "bob.near".to_string(),
+            amount: 10u128.pow(24),
+            limit: Some(7),
+            tags: vec![(1, true), (2, false)],
+            kinds: vec![Kind::Plain, Kind::Tagged("nft".to_string())],
+        };
+        let json = serde_json::json!({
+            "owner_id": "bob.near",
+            "amount": "1000000000000000000000000",
+            "limit": 7,
+            "tags": [[1, true], [2, false]],
+            "kinds": ["Plain", {"Tagged": "nft"}],
+        });
+        let schema = BorshSchemaContainer::for_type::<Args>();
+
+        let bytes = serialize(&json, &schema).unwrap();
+        assert_eq!(bytes, borsh::to_vec(&args).unwrap());
+        assert_eq!(deserialize(&bytes, &schema).unwrap(), json);
+    }
+
+    #[test]
+    fn borsh_serialization_reports_the_path_of_invalid_values() {
+        let schema = BorshSchemaContainer::for_type::<Args>();
+        let json = serde_json::json!({
+            "owner_id": "bob.near",
+            "amount": "1",
+            "tags": [[1, "yes"]],
+            "kinds": [],
+        });
+        assert_eq!(
+            serialize(&json, &schema).unwrap_err().to_string(),
+            "value.tags[0][1]: expected a boolean, found \"yes\""
+        );
+    }
+
+    fn schema(definitions: Vec<(&str, Definition)>) -> BorshSchemaContainer {
+        BorshSchemaContainer::new(
+            definitions[0].0.to_string(),
+            definitions
+                .into_iter()
+                .map(|(name, definition)| (name.to_string(), definition))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn borsh_schema_with_too_wide_tags_is_rejected() {
+        let sequence = schema(vec![(
+            "Wide",
+            Definition::Sequence {
+                length_width: 16,
+                length_range: 0..=u64::MAX,
+                elements: "u8".to_string(),
+            },
+        )]);
+        assert!(deserialize(&[0; 16], &sequence).is_err());
+        assert!(serialize(&serde_json::json!([]), &sequence).is_err());
+
+        let enumeration = schema(vec![(
+            "Wide",
+            Definition::Enum {
+                tag_width: 9,
+                variants: vec![(0, "A".to_string(), "()".to_string())],
+            },
+        )]);
+        assert!(deserialize(&[0; 9], &enumeration).is_err());
+        assert!(serialize(&serde_json::json!("A"), &enumeration).is_err());
+
+        assert!(read_u64(&mut &[0u8; 16][..], 16).is_err());
+        assert!(fixed_width_bytes(256, 1).is_err());
+        assert_eq!(fixed_width_bytes(255, 1).unwrap(), vec![255]);
+    }
+
+    #[test]
+    fn borsh_enum_tag_must_fit_into_the_tag_width() {
+        let enumeration = schema(vec![(
+            "Narrow",
+            Definition::Enum {
+                tag_width: 1,
+                variants: vec![(300, "A".to_string(), "()".to_string())],
+            },
+        )]);
+        assert!(serialize(&serde_json::json!("A"), &enumeration).is_err());
+    }
+
+    #[test]
+    fn recursive_borsh_schema_does_not_overflow_the_stack() {
+        // `struct Node(Node)` has no exit from the recursion and consumes no bytes
+        let node = schema(vec![(
+            "Node",
+            Definition::Struct {
+                fields: Fields::UnnamedFields(vec!["Node".to_string()]),
+            },
+        )]);
+        assert!(deserialize(&[], &node).is_err());
+        assert!(serialize(&serde_json::Value::Null, &node).is_err());
+
+        // A recursive list is fine as long as the data is not nested too deep
+        let list = schema(vec![
+            (
+                "List",
+                Definition::Enum {
+                    tag_width: 1,
+                    variants: vec![
+                        (0, "Nil".to_string(), "()".to_string()),
+                        (1, "Cons".to_string(), "List".to_string()),
+                    ],
+                },
+            ),
+            ("()", Definition::Primitive(0)),
+        ]);
+        assert_eq!(
+            deserialize(&[1, 1, 0], &list).unwrap(),
+            serde_json::json!({"Cons": {"Cons": "Nil"}})
+        );
+        let mut deep = vec![1; 10_000];
+        deep.push(0);
+        assert!(deserialize(&deep, &list).is_err());
+    }
+}
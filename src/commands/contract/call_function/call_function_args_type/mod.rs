@@ -13,6 +13,11 @@ pub enum FunctionArgsType {
     ))]
     /// Valid JSON arguments (e.g. {"token_id": "42"})
     JsonArgs,
+    #[strum_discriminants(strum(
+        message = "borsh-args   - JSON arguments serialized to Borsh using the contract ABI"
+    ))]
+    /// JSON arguments serialized to Borsh using the contract ABI
+    BorshArgs,
     #[strum_discriminants(strum(message = "text-args    - Arbitrary text arguments"))]
     /// Arbitrary text arguments
     TextArgs,
@@ -35,6 +40,7 @@ impl std::str::FromStr for FunctionArgsType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "json-args" => Ok(Self::JsonArgs),
+            "borsh-args" => Ok(Self::BorshArgs),
             "text-args" => Ok(Self::TextArgs),
             "base64-args" => Ok(Self::Base64Args),
             "file-args" => Ok(Self::FileArgs),
@@ -47,6 +53,7 @@ impl std::fmt::Display for FunctionArgsType {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::JsonArgs => write!(f, "json-args"),
+            Self::BorshArgs => write!(f, "borsh-args"),
             Self::TextArgs => write!(f, "text-args"),
             Self::Base64Args => write!(f, "base64-args"),
             Self::FileArgs => write!(f, "file-args"),
@@ -58,6 +65,7 @@ impl std::fmt::Display for FunctionArgsTypeDiscriminants {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::JsonArgs => write!(f, "json-args    - Valid JSON arguments (e.g. {{\"token_id\": \"42\"}} or {{}} if no arguments)"),
+            Self::BorshArgs => write!(f, "borsh-args   - JSON arguments serialized to Borsh using the contract ABI (e.g. {{\"amount\": \"42\"}})"),
             Self::TextArgs => write!(f, "text-args    - Arbitrary text arguments"),
             Self::Base64Args => write!(f, "base64-args  - Base64-encoded string (e.g. e30=)"),
             Self::FileArgs => write!(f, "file-args    - Read from file reusable JSON or binary data (e.g. ./args.json)"),
@@ -66,7 +74,21 @@ impl std::fmt::Display for FunctionArgsTypeDiscriminants {
 }
 
 pub fn input_function_args_type() -> color_eyre::eyre::Result<Option<FunctionArgsType>> {
-    let variants = FunctionArgsTypeDiscriminants::iter().collect::<Vec<_>>();
+    let variants = FunctionArgsTypeDiscriminants::iter()
+        .filter(|variant| variant != &FunctionArgsTypeDiscriminants::BorshArgs)
+        .collect::<Vec<_>>();
+    select_function_args_type(variants)
+}
+
+/// The same as [input_function_args_type], but also offers `borsh-args` (which need the contract
+/// ABI, so they are only supported by `contract call-function`).
+pub fn input_call_function_args_type() -> color_eyre::eyre::Result<Option<FunctionArgsType>> {
+    select_function_args_type(FunctionArgsTypeDiscriminants::iter().collect())
+}
+
+fn select_function_args_type(
+    variants: Vec<FunctionArgsTypeDiscriminants>,
+) -> color_eyre::eyre::Result<Option<FunctionArgsType>> {
     let selected = Select::new(
         "How would you like to pass the function arguments?",
         variants,
@@ -74,6 +96,7 @@ pub fn input_function_args_type() -> color_eyre::eyre::Result<Option<FunctionArg
     .prompt()?;
     match selected {
         FunctionArgsTypeDiscriminants::JsonArgs => Ok(Some(FunctionArgsType::JsonArgs)),
+        FunctionArgsTypeDiscriminants::BorshArgs => Ok(Some(FunctionArgsType::BorshArgs)),
         FunctionArgsTypeDiscriminants::TextArgs => Ok(Some(FunctionArgsType::TextArgs)),
         FunctionArgsTypeDiscriminants::Base64Args => Ok(Some(FunctionArgsType::Base64Args)),
         FunctionArgsTypeDiscriminants::FileArgs => Ok(Some(FunctionArgsType::FileArgs)),
@@ -85,10 +108,11 @@ pub fn function_args(
     function_args_type: FunctionArgsType,
 ) -> color_eyre::eyre::Result<Vec<u8>> {
     match function_args_type {
-        super::call_function_args_type::FunctionArgsType::JsonArgs => {
-            let data_json =
-                serde_json::Value::from_str(&args).wrap_err("Data not in JSON format!")?;
-            serde_json::to_vec(&data_json).wrap_err("Internal error!")
+        super::call_function_args_type::FunctionArgsType::JsonArgs => json_args(args),
+        super::call_function_args_type::FunctionArgsType::BorshArgs => {
+            color_eyre::eyre::bail!(
+                "borsh-args are serialized using the contract ABI and are only supported by `contract call-function`"
+            )
         }
         super::call_function_args_type::FunctionArgsType::TextArgs => Ok(args.into_bytes()),
         super::call_function_args_type::FunctionArgsType::Base64Args => {
@@ -103,3 +127,8 @@ pub fn function_args(
         }
     }
 }
+
+pub fn json_args(args: String) -> color_eyre::eyre::Result<Vec<u8>> {
+    let data_json = serde_json::Value::from_str(&args).wrap_err("Data not in JSON format!")?;
+    serde_json::to_vec(&data_json).wrap_err("Internal error!")
+}
@@ -0,0 +1,1204 @@
+//! Contract ABI support for `call-function`: field-by-field argument prompts, validation of JSON
+//! arguments against the JSON Schema of the function parameters and function kind warnings.
+//!
+//! Only a subset of JSON Schema that `schemars` generates for NEAR ABI is checked (types,
+//! `$ref`s to the ABI definitions, `enum`/`const`, object properties, array items, numeric bounds
+//! and `anyOf`/`oneOf`/`allOf`); other keywords are accepted as is.
+use color_eyre::eyre::WrapErr;
+use color_eyre::owo_colors::OwoColorize;
+use inquire::{Select, Text};
+
+type AbiCache = Vec<(
+    String,
+    near_primitives::types::AccountId,
+    Option<near_abi::AbiRoot>,
+)>;
+
+/// The maximum number of `$ref`s followed in a row (to stop at cyclic references).
+const MAX_REF_CHAIN: usize = 32;
+/// The maximum nesting of the schemas checked by [`validate`] (to stop at recursive schemas).
+const MAX_SCHEMA_DEPTH: usize = 128;
+
+static CONTRACT_ABI_CACHE: std::sync::Mutex<AbiCache> = std::sync::Mutex::new(Vec::new());
+
+/// Fetches the ABI of the contract; `None` if the contract does not expose one (the result is
+/// cached for the lifetime of the process). RPC failures and malformed ABIs are errors, so that
+/// the arguments are not silently left unchecked.
+pub fn get_contract_abi(
+    network_config: &crate::config::NetworkConfig,
+    contract_account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Option<near_abi::AbiRoot>> {
+    let mut cache = CONTRACT_ABI_CACHE
+        .lock()
+        .expect("ABI cache lock is not expected to be poisoned");
+    if let Some((_, _, contract_abi)) = cache.iter().find(|(network_name, account_id, _)| {
+        network_name == &network_config.network_name && account_id == contract_account_id
+    }) {
+        return Ok(contract_abi.clone());
+    }
+    let contract_abi =
+        match tokio::runtime::Runtime::new()?.block_on(super::super::inspect::get_contract_abi(
+            &network_config.json_rpc_client(),
+            &near_primitives::types::Finality::Final.into(),
+            contract_account_id,
+        )) {
+            Ok(contract_abi) => Some(contract_abi),
+            Err(err) if is_abi_not_supported(&err) => None,
+            Err(err) => {
+                return Err(color_eyre::Report::new(err)).wrap_err_with(|| {
+                    format!(
+                "Failed to fetch the ABI of the contract <{contract_account_id}> on network <{}>",
+                network_config.network_name
+            )
+                })
+            }
+        };
+    cache.push((
+        network_config.network_name.clone(),
+        contract_account_id.clone(),
+        contract_abi.clone(),
+    ));
+    Ok(contract_abi)
+}
+
+/// The contract does not have the `__contract_abi` function, or the account has no contract.
+fn is_abi_not_supported(err: &super::super::inspect::FetchAbiError) -> bool {
+    match err {
+        super::super::inspect::FetchAbiError::AbiNotSupported => true,
+        super::super::inspect::FetchAbiError::RpcError(
+            near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                    near_jsonrpc_primitives::types::query::RpcQueryError::ContractExecutionError {
+                        vm_error,
+                        ..
+                    },
+                ),
+            ),
+        ) => vm_error.contains("CodeDoesNotExist"),
+        _ => false,
+    }
+}
+
+/// Fetches the ABI of the contract from the first network where the contract account exists
+/// (used by the interactive prompts, before the network is selected).
+pub fn find_contract_abi(
+    global_context: &crate::GlobalContext,
+    contract_account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Option<near_abi::AbiRoot>> {
+    if global_context.offline {
+        return Ok(None);
+    }
+    let network_config = global_context
+        .config
+        .network_connection
+        .values()
+        .find(|network_config| {
+            CONTRACT_ABI_CACHE
+                .lock()
+                .expect("ABI cache lock is not expected to be poisoned")
+                .iter()
+                .any(|(network_name, account_id, contract_abi)| {
+                    network_name == &network_config.network_name
+                        && account_id == contract_account_id
+                        && contract_abi.is_some()
+                })
+        })
+        .cloned()
+        .or_else(|| {
+            crate::common::find_network_where_account_exist(
+                global_context,
+                contract_account_id.clone(),
+            )
+        });
+    match network_config {
+        Some(network_config) => get_contract_abi(&network_config, contract_account_id),
+        None => Ok(None),
+    }
+}
+
+/// Warns about calling a function of the wrong kind and validates the arguments against the
+/// contract ABI. Contracts without ABI are not checked.
+#[tracing::instrument(
+    name = "Checking the function call against the contract ABI ...",
+    skip_all
+)]
+pub fn check_function_call(
+    network_config: &crate::config::NetworkConfig,
+    contract_account_id: &near_primitives::types::AccountId,
+    function_name: &str,
+    function_args: &[u8],
+    expected_kind: near_abi::AbiFunctionKind,
+) -> crate::CliResult {
+    let Some(contract_abi) = get_contract_abi(network_config, contract_account_id)? else {
+        return Ok(());
+    };
+    let Some(function) = contract_abi
+        .body
+        .functions
+        .iter()
+        .find(|function| function.name == function_name)
+    else {
+        eprintln!(
+            "{}",
+            format!(
+                "Warning: the contract <{contract_account_id}> does not have the function <{function_name}> in its ABI."
+            )
+            .yellow()
+        );
+        return Ok(());
+    };
+    match (&function.kind, expected_kind) {
+        (near_abi::AbiFunctionKind::View, near_abi::AbiFunctionKind::Call) => eprintln!(
+            "{}",
+            format!("Warning: <{function_name}> is a view function according to the contract ABI. Calling it as a transaction costs gas, but does not change the contract state (use `as-read-only` instead).")
+                .yellow()
+        ),
+        (near_abi::AbiFunctionKind::Call, near_abi::AbiFunctionKind::View) => eprintln!(
+            "{}",
+            format!("Warning: <{function_name}> is a change function according to the contract ABI. A read-only call fails if the function modifies the contract state (use `as-transaction` instead).")
+                .yellow()
+        ),
+        _ => {}
+    }
+    validate_function_args(&contract_abi, function, function_args)
+}
+
+fn validate_function_args(
+    contract_abi: &near_abi::AbiRoot,
+    function: &near_abi::AbiFunction,
+    function_args: &[u8],
+) -> crate::CliResult {
+    let near_abi::AbiParameters::Json { args: params } = &function.params else {
+        return Ok(());
+    };
+    let args = if function_args.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_slice(function_args).wrap_err_with(|| {
+            format!(
+                "The arguments are not valid JSON, but the function <{}> expects JSON arguments according to the contract ABI",
+                function.name
+            )
+        })?
+    };
+    let definitions = definitions(contract_abi);
+    let schema = params_schema(params);
+
+    if let Some(args) = args.as_object() {
+        for name in args.keys() {
+            if !params.iter().any(|param| &param.name == name) {
+                eprintln!(
+                    "{}",
+                    format!(
+                        "Warning: the function <{}> does not have the parameter <{name}> according to the contract ABI.",
+                        function.name
+                    )
+                    .yellow()
+                );
+            }
+        }
+    }
+    let errors = validate(&args, &schema, &definitions);
+    if !errors.is_empty() {
+        color_eyre::eyre::bail!(
+            "The arguments do not match the parameters of the function <{}> in the contract ABI:\n  {}",
+            function.name,
+            errors.join("\n  ")
+        );
+    }
+    Ok(())
+}
+
+/// Converts the arguments into bytes; `borsh-args` are serialized using the contract ABI.
+pub fn function_args(
+    network_config: &crate::config::NetworkConfig,
+    contract_account_id: &near_primitives::types::AccountId,
+    function_name: &str,
+    function_args: String,
+    function_args_type: super::call_function_args_type::FunctionArgsType,
+) -> color_eyre::eyre::Result<Vec<u8>> {
+    match function_args_type {
+        super::call_function_args_type::FunctionArgsType::BorshArgs => borsh_function_args(
+            network_config,
+            contract_account_id,
+            function_name,
+            &super::call_function_args_type::json_args(function_args)?,
+        ),
+        function_args_type => {
+            super::call_function_args_type::function_args(function_args, function_args_type)
+        }
+    }
+}
+
+/// Serializes the JSON arguments to Borsh according to the Borsh parameters of the function in
+/// the contract ABI.
+pub fn borsh_function_args(
+    network_config: &crate::config::NetworkConfig,
+    contract_account_id: &near_primitives::types::AccountId,
+    function_name: &str,
+    function_args: &[u8],
+) -> color_eyre::eyre::Result<Vec<u8>> {
+    let contract_abi = get_contract_abi(network_config, contract_account_id)?.ok_or_else(|| {
+        color_eyre::eyre::eyre!(
+            "The contract <{contract_account_id}> does not expose its ABI on network <{}>, so the arguments cannot be serialized to Borsh",
+            network_config.network_name
+        )
+    })?;
+    let function = contract_abi
+        .body
+        .functions
+        .iter()
+        .find(|function| function.name == function_name)
+        .ok_or_else(|| {
+            color_eyre::eyre::eyre!(
+                "The contract <{contract_account_id}> does not have the function <{function_name}> in its ABI"
+            )
+        })?;
+    let near_abi::AbiParameters::Borsh { args: params } = &function.params else {
+        color_eyre::eyre::bail!(
+            "The function <{function_name}> expects JSON arguments according to the contract ABI (use `json-args` instead)"
+        );
+    };
+    let args: serde_json::Value = serde_json::from_slice(function_args)?;
+    let args = args.as_object().ok_or_else(|| {
+        color_eyre::eyre::eyre!(
+            "Borsh arguments must be entered as a JSON object with the parameter names as keys"
+        )
+    })?;
+    if let Some(unknown_param) = args
+        .keys()
+        .find(|name| !params.iter().any(|param| &param.name == *name))
+    {
+        color_eyre::eyre::bail!(
+            "The function <{function_name}> does not have the parameter <{unknown_param}> according to the contract ABI"
+        );
+    }
+    let mut borsh_args = vec![];
+    for param in params {
+        borsh_args.extend(
+            super::borsh_schema::serialize(
+                args.get(&param.name).unwrap_or(&serde_json::Value::Null),
+                &param.type_schema,
+            )
+            .wrap_err_with(|| {
+                format!(
+                    "Failed to serialize the parameter <{}> of the function <{function_name}> to Borsh",
+                    param.name
+                )
+            })?,
+        );
+    }
+    Ok(borsh_args)
+}
+
+/// The Borsh schema of the function result, if the contract ABI describes the result as
+/// Borsh-serialized.
+pub fn borsh_result_schema(
+    network_config: &crate::config::NetworkConfig,
+    contract_account_id: &near_primitives::types::AccountId,
+    function_name: &str,
+) -> color_eyre::eyre::Result<Option<borsh::schema::BorshSchemaContainer>> {
+    let Some(contract_abi) = get_contract_abi(network_config, contract_account_id)? else {
+        return Ok(None);
+    };
+    Ok(contract_abi
+        .body
+        .functions
+        .into_iter()
+        .find(|function| function.name == function_name)
+        .and_then(|function| function.result)
+        .and_then(|result| match result {
+            near_abi::AbiType::Borsh { type_schema } => Some(type_schema),
+            near_abi::AbiType::Json { .. } => None,
+        })
+        .filter(
+            |type_schema| match super::borsh_schema::validate_schema(type_schema) {
+                Ok(()) => true,
+                Err(err) => {
+                    eprintln!("Warning: the result of <{function_name}> cannot be decoded: {err}");
+                    false
+                }
+            },
+        ))
+}
+
+/// The Borsh schema of a type declared in the contract ABI (in the Borsh parameters or results of
+/// its functions), e.g. to decode the contract storage values.
+pub fn borsh_type_schema(
+    network_config: &crate::config::NetworkConfig,
+    contract_account_id: &near_primitives::types::AccountId,
+    type_name: &str,
+) -> color_eyre::eyre::Result<borsh::schema::BorshSchemaContainer> {
+    let contract_abi = get_contract_abi(network_config, contract_account_id)?.ok_or_else(|| {
+        color_eyre::eyre::eyre!(
+            "The contract <{contract_account_id}> does not expose its ABI on network <{}>, so the Borsh type <{type_name}> cannot be found",
+            network_config.network_name
+        )
+    })?;
+    let type_schemas = contract_abi
+        .body
+        .functions
+        .into_iter()
+        .flat_map(|function| {
+            let params = match function.params {
+                near_abi::AbiParameters::Borsh { args } => {
+                    args.into_iter().map(|param| param.type_schema).collect()
+                }
+                near_abi::AbiParameters::Json { .. } => vec![],
+            };
+            params
+                .into_iter()
+                .chain(function.result.and_then(|result| match result {
+                    near_abi::AbiType::Borsh { type_schema } => Some(type_schema),
+                    near_abi::AbiType::Json { .. } => None,
+                }))
+        })
+        .collect::<Vec<_>>();
+    let type_schema = type_schemas
+        .iter()
+        .find(|type_schema| type_schema.get_definition(type_name).is_some())
+        .ok_or_else(|| {
+            let mut type_names = type_schemas
+                .iter()
+                .flat_map(|type_schema| type_schema.definitions().map(|(name, _)| name.clone()))
+                .collect::<Vec<_>>();
+            type_names.sort();
+            type_names.dedup();
+            color_eyre::eyre::eyre!(
+                "The Borsh type <{type_name}> is not declared in the ABI of <{contract_account_id}> (available types: {})",
+                type_names.join(", ")
+            )
+        })?;
+    let type_schema = borsh::schema::BorshSchemaContainer::new(
+        type_name.to_string(),
+        type_schema
+            .definitions()
+            .map(|(name, definition)| (name.clone(), definition.clone()))
+            .collect(),
+    );
+    super::borsh_schema::validate_schema(&type_schema)?;
+    Ok(type_schema)
+}
+
+/// Prompts for the function arguments, field by field if JSON arguments are expected and the
+/// function is described in the contract ABI.
+pub fn input_function_args(
+    global_context: &crate::GlobalContext,
+    contract_account_id: &near_primitives::types::AccountId,
+    function_name: &str,
+    function_args_type: &super::call_function_args_type::FunctionArgsType,
+) -> color_eyre::eyre::Result<Option<String>> {
+    let is_json_args = matches!(
+        function_args_type,
+        super::call_function_args_type::FunctionArgsType::JsonArgs
+    );
+    if let Some(contract_abi) = is_json_args
+        .then(|| find_contract_abi(global_context, contract_account_id))
+        .transpose()?
+        .flatten()
+    {
+        if let Some(function) = contract_abi
+            .body
+            .functions
+            .iter()
+            .find(|function| function.name == function_name)
+        {
+            if let near_abi::AbiParameters::Json { args: params } = &function.params {
+                if params.is_empty() {
+                    eprintln!("The function <{function_name}> has no parameters according to the contract ABI.");
+                    return Ok(Some("{}".to_string()));
+                }
+                #[derive(strum_macros::Display, PartialEq)]
+                enum InputMode {
+                    #[strum(
+                        to_string = "Enter the arguments field by field (as described in the contract ABI)"
+                    )]
+                    FieldByField,
+                    #[strum(to_string = "Enter the arguments as a single value")]
+                    SingleValue,
+                }
+                let input_mode = Select::new(
+                    "How would you like to enter the arguments?",
+                    vec![InputMode::FieldByField, InputMode::SingleValue],
+                )
+                .prompt()?;
+                if input_mode == InputMode::FieldByField {
+                    let args = input_json_args(params, &definitions(&contract_abi))?;
+                    return Ok(Some(serde_json::to_string(&args)?));
+                }
+            }
+        }
+    }
+    Ok(Some(
+        Text::new("Enter the arguments to this function:").prompt()?,
+    ))
+}
+
+fn input_json_args(
+    params: &[near_abi::AbiJsonParameter],
+    definitions: &serde_json::Value,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    let mut args = serde_json::Map::new();
+    for param in params {
+        let schema = serde_json::to_value(&param.type_schema)?;
+        if let Some(value) = input_value(&param.name, &schema, definitions)? {
+            args.insert(param.name.clone(), value);
+        }
+    }
+    Ok(serde_json::Value::Object(args))
+}
+
+/// Prompts for a single value; returns `None` if an optional value is left empty.
+fn input_value(
+    name: &str,
+    schema: &serde_json::Value,
+    definitions: &serde_json::Value,
+) -> color_eyre::eyre::Result<Option<serde_json::Value>> {
+    let optional = is_optional(schema, definitions);
+    let resolved = non_null_schema(schema, definitions);
+    let description = resolved
+        .get("description")
+        .or_else(|| schema.get("description"))
+        .and_then(|description| description.as_str())
+        .map(str::to_string);
+
+    if let Some(variants) = resolved
+        .get("enum")
+        .and_then(|variants| variants.as_array())
+    {
+        let mut options = variants
+            .iter()
+            .map(|variant| variant.to_string())
+            .collect::<Vec<_>>();
+        if optional {
+            options.push("(skip)".to_string());
+        }
+        let selected = Select::new(&format!("Select {name}:"), options).prompt()?;
+        return Ok(if selected == "(skip)" {
+            None
+        } else {
+            Some(serde_json::from_str(&selected)?)
+        });
+    }
+
+    match instance_type(resolved) {
+        Some("boolean") => {
+            let mut options = vec!["true", "false"];
+            if optional {
+                options.push("(skip)");
+            }
+            let selected = Select::new(&format!("Select {name}:"), options).prompt()?;
+            Ok(match selected {
+                "(skip)" => None,
+                value => Some(serde_json::Value::Bool(value == "true")),
+            })
+        }
+        Some("object") if resolved.get("properties").is_some() => {
+            if optional {
+                let fill = Select::new(
+                    &format!("{name} is optional. Would you like to enter it?"),
+                    vec!["Yes", "No"],
+                )
+                .prompt()?;
+                if fill == "No" {
+                    return Ok(None);
+                }
+            }
+            let required = resolved
+                .get("required")
+                .and_then(|required| required.as_array())
+                .cloned()
+                .unwrap_or_default();
+            let mut object = serde_json::Map::new();
+            for (property_name, property_schema) in
+                resolved["properties"].as_object().into_iter().flatten()
+            {
+                let property_schema = if required.contains(&property_name.as_str().into()) {
+                    property_schema.clone()
+                } else {
+                    serde_json::json!({"anyOf": [property_schema, {"type": "null"}]})
+                };
+                if let Some(value) = input_value(
+                    &format!("{name}.{property_name}"),
+                    &property_schema,
+                    definitions,
+                )? {
+                    object.insert(property_name.clone(), value);
+                }
+            }
+            Ok(Some(serde_json::Value::Object(object)))
+        }
+        kind => {
+            let is_string = kind == Some("string");
+            let message = format!(
+                "Enter {name} ({}{}){}:",
+                describe_schema(schema, definitions),
+                if is_string { "" } else { ", as JSON" },
+                if optional {
+                    ", leave empty to skip"
+                } else {
+                    ""
+                }
+            );
+            let validator_schema = schema.clone();
+            let validator_definitions = definitions.clone();
+            let mut prompt = Text::new(&message).with_validator(move |input: &str| {
+                if input.is_empty() && optional {
+                    return Ok(inquire::validator::Validation::Valid);
+                }
+                let errors = match parse_input(input, is_string) {
+                    Ok(value) => validate(&value, &validator_schema, &validator_definitions),
+                    Err(err) => vec![format!("not valid JSON: {err}")],
+                };
+                Ok(if errors.is_empty() {
+                    inquire::validator::Validation::Valid
+                } else {
+                    inquire::validator::Validation::Invalid(errors.join("; ").into())
+                })
+            });
+            if let Some(description) = &description {
+                prompt = prompt.with_help_message(description);
+            }
+            let input = prompt.prompt()?;
+            if input.is_empty() && optional {
+                return Ok(None);
+            }
+            Ok(Some(parse_input(&input, is_string)?))
+        }
+    }
+}
+
+fn parse_input(input: &str, is_string: bool) -> serde_json::Result<serde_json::Value> {
+    if is_string {
+        Ok(serde_json::Value::String(input.to_string()))
+    } else {
+        serde_json::from_str(input)
+    }
+}
+
+fn definitions(contract_abi: &near_abi::AbiRoot) -> serde_json::Value {
+    serde_json::to_value(&contract_abi.body.root_schema)
+        .ok()
+        .and_then(|root_schema| root_schema.get("definitions").cloned())
+        .unwrap_or_else(|| serde_json::json!({}))
+}
+
+/// Combines the parameters into the schema of the arguments object (`Option` parameters can be
+/// omitted).
+fn params_schema(params: &[near_abi::AbiJsonParameter]) -> serde_json::Value {
+    let properties = params
+        .iter()
+        .map(|param| {
+            (
+                param.name.clone(),
+                serde_json::to_value(&param.type_schema).unwrap_or(serde_json::Value::Bool(true)),
+            )
+        })
+        .collect::<serde_json::Map<_, _>>();
+    serde_json::json!({
+        "type": "object",
+        "properties": properties,
+        "required": params
+            .iter()
+            .filter(|param| !is_optional(&properties[&param.name], &serde_json::Value::Null))
+            .map(|param| param.name.clone())
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// The name of the ABI definition the schema refers to.
+fn definition_name(schema: &serde_json::Value) -> Option<&str> {
+    schema
+        .get("$ref")
+        .and_then(|reference| reference.as_str())
+        .and_then(|reference| reference.strip_prefix("#/definitions/"))
+}
+
+/// Follows the `$ref`s to the ABI definitions. A cyclic or too long chain of `$ref`s stops at
+/// [`MAX_REF_CHAIN`] and the schema still having a `$ref` is returned.
+fn resolve<'a>(
+    schema: &'a serde_json::Value,
+    definitions: &'a serde_json::Value,
+) -> &'a serde_json::Value {
+    let mut schema = schema;
+    for _ in 0..MAX_REF_CHAIN {
+        match definition_name(schema).and_then(|name| definitions.get(name)) {
+            Some(definition) => schema = definition,
+            None => break,
+        }
+    }
+    schema
+}
+
+fn is_null_schema(schema: &serde_json::Value) -> bool {
+    schema.get("type").and_then(|kind| kind.as_str()) == Some("null")
+}
+
+fn is_optional(schema: &serde_json::Value, definitions: &serde_json::Value) -> bool {
+    let schema = resolve(schema, definitions);
+    let nullable_type = schema
+        .get("type")
+        .and_then(|kind| kind.as_array())
+        .map_or(false, |kinds| kinds.iter().any(|kind| kind == "null"));
+    let nullable_variant = ["anyOf", "oneOf"].iter().any(|keyword| {
+        schema
+            .get(keyword)
+            .and_then(|variants| variants.as_array())
+            .map_or(false, |variants| variants.iter().any(is_null_schema))
+    });
+    nullable_type || nullable_variant
+}
+
+/// The schema without the `null` alternative of `Option` values.
+fn non_null_schema<'a>(
+    schema: &'a serde_json::Value,
+    definitions: &'a serde_json::Value,
+) -> &'a serde_json::Value {
+    let schema = resolve(schema, definitions);
+    for keyword in ["anyOf", "oneOf"] {
+        if let Some(variants) = schema.get(keyword).and_then(|variants| variants.as_array()) {
+            let non_null_variants = variants
+                .iter()
+                .filter(|variant| !is_null_schema(variant))
+                .collect::<Vec<_>>();
+            if let [variant] = non_null_variants[..] {
+                return resolve(variant, definitions);
+            }
+        }
+    }
+    schema
+}
+
+fn instance_type(schema: &serde_json::Value) -> Option<&str> {
+    match schema.get("type")? {
+        serde_json::Value::String(kind) => Some(kind),
+        serde_json::Value::Array(kinds) => kinds
+            .iter()
+            .filter_map(|kind| kind.as_str())
+            .find(|kind| *kind != "null"),
+        _ => None,
+    }
+}
+
+fn describe_schema(schema: &serde_json::Value, definitions: &serde_json::Value) -> String {
+    let type_name = definition_name(schema).or_else(|| {
+        ["anyOf", "oneOf"].iter().find_map(|keyword| {
+            schema
+                .get(keyword)?
+                .as_array()?
+                .iter()
+                .find_map(definition_name)
+        })
+    });
+    let resolved = non_null_schema(schema, definitions);
+    let kind = match (instance_type(resolved), resolved.get("format")) {
+        (Some(kind), Some(serde_json::Value::String(format))) => format!("{kind}, {format}"),
+        (Some(kind), _) => kind.to_string(),
+        (None, _) => "any value".to_string(),
+    };
+    match type_name {
+        Some(type_name) => format!("{type_name}: {kind}"),
+        None => kind,
+    }
+}
+
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
+}
+
+fn matches_type(value: &serde_json::Value, kind: &str) -> bool {
+    match kind {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64()
+                || value.is_u64()
+                || value.as_f64().map_or(false, |number| number.fract() == 0.0)
+        }
+        _ => true,
+    }
+}
+
+/// Returns the list of validation errors (empty if the value matches the schema).
+pub fn validate(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    definitions: &serde_json::Value,
+) -> Vec<String> {
+    let mut errors = vec![];
+    validate_at("args", value, schema, definitions, 0, &mut errors);
+    errors
+}
+
+fn validate_at(
+    path: &str,
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    definitions: &serde_json::Value,
+    depth: usize,
+    errors: &mut Vec<String>,
+) {
+    if depth > MAX_SCHEMA_DEPTH {
+        errors.push(format!(
+            "{path}: the schema is nested too deeply (is it recursive?)"
+        ));
+        return;
+    }
+    let depth = depth + 1;
+    let schema = resolve(schema, definitions);
+    if let Some(name) = definition_name(schema) {
+        errors.push(if definitions.get(name).is_some() {
+            format!("{path}: the schema of <{name}> refers to itself")
+        } else {
+            format!("{path}: the type <{name}> is not defined in the contract ABI")
+        });
+        return;
+    }
+    match schema {
+        serde_json::Value::Bool(true) => return,
+        serde_json::Value::Bool(false) => {
+            errors.push(format!("{path}: no value is allowed here"));
+            return;
+        }
+        serde_json::Value::Object(_) => {}
+        _ => return,
+    }
+
+    if let Some(subschemas) = schema
+        .get("allOf")
+        .and_then(|subschemas| subschemas.as_array())
+    {
+        for subschema in subschemas {
+            validate_at(path, value, subschema, definitions, depth, errors);
+        }
+    }
+    for keyword in ["anyOf", "oneOf"] {
+        if let Some(subschemas) = schema
+            .get(keyword)
+            .and_then(|subschemas| subschemas.as_array())
+        {
+            let variant_errors = subschemas
+                .iter()
+                .map(|subschema| {
+                    let mut variant_errors = vec![];
+                    validate_at(
+                        path,
+                        value,
+                        subschema,
+                        definitions,
+                        depth,
+                        &mut variant_errors,
+                    );
+                    variant_errors
+                })
+                .collect::<Vec<_>>();
+            if variant_errors
+                .iter()
+                .all(|variant_errors| !variant_errors.is_empty())
+            {
+                let non_null_variant_errors = subschemas
+                    .iter()
+                    .zip(variant_errors)
+                    .filter(|(subschema, _)| !is_null_schema(subschema))
+                    .map(|(_, variant_errors)| variant_errors)
+                    .collect::<Vec<_>>();
+                match &non_null_variant_errors[..] {
+                    [single_variant_errors] => errors.extend(single_variant_errors.clone()),
+                    _ => errors.push(format!(
+                        "{path}: {} does not match any of the allowed variants",
+                        value
+                    )),
+                }
+            }
+        }
+    }
+    if let Some(variants) = schema.get("enum").and_then(|variants| variants.as_array()) {
+        if !variants.contains(value) {
+            errors.push(format!(
+                "{path}: expected one of {}, found {value}",
+                variants
+                    .iter()
+                    .map(|variant| variant.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+            return;
+        }
+    }
+    if let Some(constant) = schema.get("const") {
+        if constant != value {
+            errors.push(format!("{path}: expected {constant}, found {value}"));
+            return;
+        }
+    }
+
+    let kinds = match schema.get("type") {
+        Some(serde_json::Value::String(kind)) => vec![kind.as_str()],
+        Some(serde_json::Value::Array(kinds)) => {
+            kinds.iter().filter_map(|kind| kind.as_str()).collect()
+        }
+        _ => vec![],
+    };
+    if !kinds.is_empty() && !kinds.iter().any(|kind| matches_type(value, kind)) {
+        errors.push(format!(
+            "{path}: expected {}, found {} {value}",
+            kinds.join(" or "),
+            json_type_name(value)
+        ));
+        return;
+    }
+
+    match value {
+        serde_json::Value::Object(object) => {
+            let properties = schema
+                .get("properties")
+                .and_then(|properties| properties.as_object());
+            if let Some(required) = schema
+                .get("required")
+                .and_then(|required| required.as_array())
+            {
+                for name in required.iter().filter_map(|name| name.as_str()) {
+                    if !object.contains_key(name) {
+                        errors.push(format!("{path}.{name}: the value is required"));
+                    }
+                }
+            }
+            for (name, property_value) in object {
+                match properties.and_then(|properties| properties.get(name)) {
+                    Some(property_schema) => validate_at(
+                        &format!("{path}.{name}"),
+                        property_value,
+                        property_schema,
+                        definitions,
+                        depth,
+                        errors,
+                    ),
+                    None => {
+                        if let Some(additional_properties) = schema.get("additionalProperties") {
+                            validate_at(
+                                &format!("{path}.{name}"),
+                                property_value,
+                                additional_properties,
+                                definitions,
+                                depth,
+                                errors,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            if let Some(min_items) = schema.get("minItems").and_then(|min| min.as_u64()) {
+                if (items.len() as u64) < min_items {
+                    errors.push(format!("{path}: expected at least {min_items} items"));
+                }
+            }
+            if let Some(max_items) = schema.get("maxItems").and_then(|max| max.as_u64()) {
+                if (items.len() as u64) > max_items {
+                    errors.push(format!("{path}: expected at most {max_items} items"));
+                }
+            }
+            match schema.get("items") {
+                Some(serde_json::Value::Array(item_schemas)) => {
+                    for (index, (item, item_schema)) in items.iter().zip(item_schemas).enumerate() {
+                        validate_at(
+                            &format!("{path}[{index}]"),
+                            item,
+                            item_schema,
+                            definitions,
+                            depth,
+                            errors,
+                        );
+                    }
+                }
+                Some(item_schema) => {
+                    for (index, item) in items.iter().enumerate() {
+                        validate_at(
+                            &format!("{path}[{index}]"),
+                            item,
+                            item_schema,
+                            definitions,
+                            depth,
+                            errors,
+                        );
+                    }
+                }
+                None => {}
+            }
+        }
+        serde_json::Value::Number(number) => {
+            let number = number.as_f64().unwrap_or_default();
+            if let Some(minimum) = schema.get("minimum").and_then(|minimum| minimum.as_f64()) {
+                if number < minimum {
+                    errors.push(format!(
+                        "{path}: expected a value >= {minimum}, found {number}"
+                    ));
+                }
+            }
+            if let Some(maximum) = schema.get("maximum").and_then(|maximum| maximum.as_f64()) {
+                if number > maximum {
+                    errors.push(format!(
+                        "{path}: expected a value <= {maximum}, found {number}"
+                    ));
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_args_against_abi_schema() {
+        let definitions = serde_json::json!({
+            "AccountId": {"type": "string"},
+            "U128": {"type": "string"},
+        });
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "receiver_id": {"$ref": "#/definitions/AccountId"},
+                "amount": {"$ref": "#/definitions/U128"},
+                "memo": {"type": ["string", "null"]},
+                "limit": {"anyOf": [{"type": "integer", "format": "uint32", "minimum": 0.0}, {"type": "null"}]},
+            },
+            "required": ["receiver_id", "amount"],
+        });
+
+        let valid = serde_json::json!({"receiver_id": "bob.near", "amount": "100", "limit": 10});
+        assert!(validate(&valid, &schema, &definitions).is_empty());
+
+        let invalid = serde_json::json!({"receiver_id": 42, "limit": -1});
+        assert_eq!(
+            validate(&invalid, &schema, &definitions),
+            vec![
+                "args.amount: the value is required",
+                "args.limit: expected a value >= 0, found -1",
+                "args.receiver_id: expected string, found number 42",
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_refs() {
+        let definitions = serde_json::json!({
+            "Balance": {"$ref": "#/definitions/U128"},
+            "U128": {"type": "string"},
+            "Loop": {"$ref": "#/definitions/Loop"},
+            "Recursive": {"allOf": [{"$ref": "#/definitions/Recursive"}]},
+            "Tree": {
+                "type": "object",
+                "properties": {"children": {"type": "array", "items": {"$ref": "#/definitions/Tree"}}},
+            },
+        });
+
+        let balance = serde_json::json!({"$ref": "#/definitions/Balance"});
+        assert!(validate(&serde_json::json!("100"), &balance, &definitions).is_empty());
+        assert_eq!(
+            validate(&serde_json::json!(100), &balance, &definitions),
+            vec!["args: expected string, found number 100"]
+        );
+
+        let tree = serde_json::json!({"$ref": "#/definitions/Tree"});
+        assert!(validate(
+            &serde_json::json!({"children": [{"children": []}, {}]}),
+            &tree,
+            &definitions
+        )
+        .is_empty());
+        assert_eq!(
+            validate(
+                &serde_json::json!({"children": [{"children": [1]}]}),
+                &tree,
+                &definitions
+            ),
+            vec!["args.children[0].children[0]: expected object, found number 1"]
+        );
+
+        assert_eq!(
+            validate(
+                &serde_json::json!(1),
+                &serde_json::json!({"$ref": "#/definitions/Loop"}),
+                &definitions
+            ),
+            vec!["args: the schema of <Loop> refers to itself"]
+        );
+        assert_eq!(
+            validate(
+                &serde_json::json!(1),
+                &serde_json::json!({"$ref": "#/definitions/Recursive"}),
+                &definitions
+            ),
+            vec!["args: the schema is nested too deeply (is it recursive?)"]
+        );
+        assert_eq!(
+            validate(
+                &serde_json::json!(1),
+                &serde_json::json!({"$ref": "#/definitions/Missing"}),
+                &definitions
+            ),
+            vec!["args: the type <Missing> is not defined in the contract ABI"]
+        );
+        // The cyclic references do not hang the prompts either
+        assert!(!is_optional(
+            &serde_json::json!({"$ref": "#/definitions/Loop"}),
+            &definitions
+        ));
+    }
+
+    #[test]
+    fn validate_variants() {
+        let definitions = serde_json::json!({
+            "Action": {"oneOf": [
+                {"type": "string", "enum": ["Stop"]},
+                {
+                    "type": "object",
+                    "properties": {"Move": {"type": "integer"}},
+                    "required": ["Move"],
+                    "additionalProperties": false,
+                },
+            ]},
+        });
+        let action = serde_json::json!({"$ref": "#/definitions/Action"});
+        assert!(validate(&serde_json::json!("Stop"), &action, &definitions).is_empty());
+        assert!(validate(&serde_json::json!({"Move": 3}), &action, &definitions).is_empty());
+        assert_eq!(
+            validate(&serde_json::json!("Jump"), &action, &definitions),
+            vec!["args: \"Jump\" does not match any of the allowed variants"]
+        );
+
+        // The errors of the only non-null variant of an `Option` are reported as is
+        let optional_action =
+            serde_json::json!({"anyOf": [{"$ref": "#/definitions/Action"}, {"type": "null"}]});
+        assert!(validate(&serde_json::Value::Null, &optional_action, &definitions).is_empty());
+        let optional_integer =
+            serde_json::json!({"anyOf": [{"type": "integer"}, {"type": "null"}]});
+        assert_eq!(
+            validate(&serde_json::json!("1"), &optional_integer, &definitions),
+            vec!["args: expected integer, found string \"1\""]
+        );
+        assert!(is_optional(&optional_action, &definitions));
+        assert_eq!(
+            describe_schema(&optional_action, &definitions),
+            "Action: any value"
+        );
+    }
+
+    #[test]
+    fn validate_integer_bounds() {
+        let definitions = serde_json::json!({});
+        let uint8 = serde_json::json!({"type": "integer", "format": "uint8", "minimum": 0.0, "maximum": 255.0});
+        assert!(validate(&serde_json::json!(0), &uint8, &definitions).is_empty());
+        assert!(validate(&serde_json::json!(255), &uint8, &definitions).is_empty());
+        assert_eq!(
+            validate(&serde_json::json!(256), &uint8, &definitions),
+            vec!["args: expected a value <= 255, found 256"]
+        );
+        assert_eq!(
+            validate(&serde_json::json!(-1), &uint8, &definitions),
+            vec!["args: expected a value >= 0, found -1"]
+        );
+        assert_eq!(
+            validate(&serde_json::json!(1.5), &uint8, &definitions),
+            vec!["args: expected integer, found number 1.5"]
+        );
+        let uint64 = serde_json::json!({"type": "integer", "format": "uint64", "minimum": 0.0});
+        assert!(validate(&serde_json::json!(u64::MAX), &uint64, &definitions).is_empty());
+    }
+
+    #[test]
+    fn validate_function_args_errors() {
+        let contract_abi: near_abi::AbiRoot = serde_json::from_value(serde_json::json!({
+            "schema_version": "0.4.0",
+            "metadata": {},
+            "body": {
+                "functions": [{
+                    "name": "ft_transfer",
+                    "kind": "call",
+                    "params": {
+                        "serialization_type": "json",
+                        "args": [
+                            {"name": "receiver_id", "type_schema": {"$ref": "#/definitions/AccountId"}},
+                            {"name": "amount", "type_schema": {"type": "string"}},
+                            {"name": "memo", "type_schema": {"type": ["string", "null"]}},
+                        ],
+                    },
+                }],
+                "root_schema": {
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "title": "String",
+                    "type": "string",
+                    "definitions": {"AccountId": {"type": "string"}},
+                },
+            },
+        }))
+        .unwrap();
+        let function = &contract_abi.body.functions[0];
+
+        assert!(validate_function_args(
+            &contract_abi,
+            function,
+            br#"{"receiver_id": "bob.near", "amount": "1"}"#
+        )
+        .is_ok());
+        let err = validate_function_args(&contract_abi, function, b"not json").unwrap_err();
+        assert!(err.to_string().contains("The arguments are not valid JSON"));
+        let err = validate_function_args(&contract_abi, function, br#"{"amount": 1}"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The arguments do not match the parameters of the function <ft_transfer> in the contract ABI:\n  args.receiver_id: the value is required\n  args.amount: expected string, found number 1"
+        );
+    }
+
+    #[test]
+    fn only_missing_abi_function_means_no_abi() {
+        let rpc_url = crate::test_utils::serve_json_rpc(|method, params| {
+            assert_eq!(method, "query");
+            assert_eq!(params["method_name"], "__contract_abi");
+            let error = match params["account_id"].as_str().unwrap() {
+                "no-abi.testnet" => "wasm execution failed with error: FunctionCallError(MethodResolveError(MethodNotFound))",
+                "no-contract.testnet" => "wasm execution failed with error: FunctionCallError(CompilationError(CodeDoesNotExist { account_id: AccountId(\"no-contract.testnet\") }))",
+                _ => "wasm execution failed with error: FunctionCallError(HostError(GasLimitExceeded))",
+            };
+            serde_json::json!({
+                "error": error,
+                "logs": [],
+                "block_height": 1,
+                "block_hash": near_primitives::hash::CryptoHash::default(),
+            })
+        });
+        let network_config = crate::test_utils::network_config(rpc_url);
+
+        assert!(
+            get_contract_abi(&network_config, &"no-abi.testnet".parse().unwrap())
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            get_contract_abi(&network_config, &"no-contract.testnet".parse().unwrap())
+                .unwrap()
+                .is_none()
+        );
+        let err =
+            get_contract_abi(&network_config, &"failing-abi.testnet".parse().unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to fetch the ABI of the contract <failing-abi.testnet> on network <testnet>"
+        );
+    }
+}
@@ -0,0 +1,310 @@
+//! Checks of the contract code before it is deployed: the wasm file is validated locally, and then
+//! compared with the code that is already deployed on the account.
+use color_eyre::{eyre::Context, owo_colors::OwoColorize};
+use inquire::Select;
+use std::io::IsTerminal;
+
+use crate::common::{JsonRpcClientExt, RpcQueryResponseExt};
+
+/// Validates the wasm file and warns about imports and Wasm features that the NEAR runtime does
+/// not support.
+pub fn validate_contract_code(code: &[u8]) -> crate::CliResult {
+    let wasm_info = super::super::inspect_file::WasmInfo::parse(code)
+        .wrap_err("The file is not a valid WebAssembly contract")?;
+    for unsupported_usage in wasm_info.unsupported_usage() {
+        eprintln!(
+            "{}",
+            format!("Warning: the contract uses {unsupported_usage}, which is not supported by the NEAR runtime.").red()
+        );
+    }
+    Ok(())
+}
+
+/// Compares the contract code with the code deployed on the account and asks for confirmation
+/// before the deployed code is replaced (or unsupported code is deployed).
+pub fn check_contract_code(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    code: &[u8],
+    skip_confirmation: bool,
+) -> crate::CliResult {
+    let has_unsupported_usage = !super::super::inspect_file::WasmInfo::parse(code)?
+        .unsupported_usage()
+        .is_empty();
+    let json_rpc_client = network_config.json_rpc_client();
+    let account_view = match json_rpc_client
+        .blocking_call_view_account(account_id, near_primitives::types::Finality::Final.into())
+    {
+        Ok(response) => response.account_view()?,
+        Err(err) => {
+            eprintln!(
+                "{}",
+                format!("Warning: the contract code cannot be compared with the code deployed on <{account_id}> (network <{}>): {err}", network_config.network_name).yellow()
+            );
+            return confirm_deployment(has_unsupported_usage && !skip_confirmation);
+        }
+    };
+
+    if account_view.code_hash == near_primitives::hash::CryptoHash::hash_bytes(code) {
+        eprintln!(
+            "The contract code is unchanged: the same code (hash {}) is already deployed on <{account_id}>.",
+            account_view.code_hash
+        );
+        return Ok(());
+    }
+
+    let deployed_code = if account_view.code_hash == near_primitives::hash::CryptoHash::default() {
+        vec![]
+    } else {
+        get_deployed_code(network_config, account_id)?
+    };
+    let storage_amount_per_byte = json_rpc_client
+        .blocking_call(
+            near_jsonrpc_client::methods::EXPERIMENTAL_protocol_config::RpcProtocolConfigRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+            },
+        )
+        .wrap_err("Failed to fetch the protocol config")?
+        .runtime_config
+        .storage_amount_per_byte;
+    let code_diff = CodeDiff::new(
+        account_view.code_hash,
+        &deployed_code,
+        code,
+        storage_amount_per_byte,
+    )?;
+    eprintln!(
+        "\nThe contract code on <{account_id}> is going to change:\n{}",
+        code_diff.to_table()?
+    );
+
+    confirm_deployment(
+        (!code_diff.is_first_deployment() || has_unsupported_usage) && !skip_confirmation,
+    )
+}
+
+/// The changes of the contract code on the account.
+#[derive(Debug)]
+struct CodeDiff {
+    deployed_code_hash: near_primitives::hash::CryptoHash,
+    code_hash: near_primitives::hash::CryptoHash,
+    deployed_code_size: u64,
+    code_size: u64,
+    /// The storage cost change in yoctoNEAR (negative if the new code is smaller)
+    storage_cost_delta: i128,
+    added_functions: Vec<String>,
+    removed_functions: Vec<String>,
+}
+
+impl CodeDiff {
+    fn new(
+        deployed_code_hash: near_primitives::hash::CryptoHash,
+        deployed_code: &[u8],
+        code: &[u8],
+        storage_amount_per_byte: u128,
+    ) -> color_eyre::eyre::Result<Self> {
+        let deployed_functions = super::super::inspect_file::WasmInfo::parse(deployed_code)
+            .map(|wasm_info| wasm_info.exported_functions)
+            .unwrap_or_default();
+        let functions = super::super::inspect_file::WasmInfo::parse(code)?.exported_functions;
+        let size_delta = i128::try_from(code.len())? - i128::try_from(deployed_code.len())?;
+        Ok(Self {
+            deployed_code_hash,
+            code_hash: near_primitives::hash::CryptoHash::hash_bytes(code),
+            deployed_code_size: u64::try_from(deployed_code.len())?,
+            code_size: u64::try_from(code.len())?,
+            storage_cost_delta: size_delta
+                .checked_mul(i128::try_from(storage_amount_per_byte)?)
+                .ok_or_else(|| color_eyre::eyre::eyre!("The storage cost overflows"))?,
+            added_functions: functions
+                .iter()
+                .filter(|function| !deployed_functions.contains(function))
+                .cloned()
+                .collect(),
+            removed_functions: deployed_functions
+                .iter()
+                .filter(|function| !functions.contains(function))
+                .cloned()
+                .collect(),
+        })
+    }
+
+    fn is_first_deployment(&self) -> bool {
+        self.deployed_code_hash == near_primitives::hash::CryptoHash::default()
+    }
+
+    fn to_table(&self) -> color_eyre::eyre::Result<prettytable::Table> {
+        let sign = if self.storage_cost_delta < 0 {
+            "-"
+        } else {
+            "+"
+        };
+        let size_delta = self.code_size.abs_diff(self.deployed_code_size);
+        let storage_cost_delta = crate::types::near_token::NearToken::from_yoctonear(
+            self.storage_cost_delta.unsigned_abs(),
+        );
+
+        let mut table = prettytable::Table::new();
+        table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+        table.add_row(prettytable::row![
+            "Code hash",
+            if self.is_first_deployment() {
+                format!("no contract code -> {}", self.code_hash)
+            } else {
+                format!("{} -> {}", self.deployed_code_hash, self.code_hash)
+            }
+        ]);
+        table.add_row(prettytable::row![
+            "Code size",
+            format!(
+                "{} -> {}",
+                bytesize::ByteSize(self.deployed_code_size),
+                bytesize::ByteSize(self.code_size)
+            )
+        ]);
+        table.add_row(prettytable::row![
+            "Storage cost",
+            format!(
+                "{sign}{storage_cost_delta} ({sign}{})",
+                bytesize::ByteSize(size_delta)
+            )
+        ]);
+        table.add_row(prettytable::row![
+            "Added methods",
+            list_functions(&self.added_functions)
+        ]);
+        table.add_row(prettytable::row![
+            "Removed methods",
+            list_functions(&self.removed_functions)
+        ]);
+        Ok(table)
+    }
+}
+
+fn list_functions(functions: &[String]) -> String {
+    if functions.is_empty() {
+        "None".to_string()
+    } else {
+        functions.join("\n")
+    }
+}
+
+#[tracing::instrument(name = "Obtaining the deployed contract code ...", skip_all)]
+fn get_deployed_code(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Vec<u8>> {
+    let view_code_response = network_config
+        .json_rpc_client()
+        .blocking_call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::ViewCode {
+                account_id: account_id.clone(),
+            },
+        })
+        .wrap_err_with(|| {
+            format!(
+                "Failed to fetch query ViewCode for <{account_id}> on network <{}>",
+                network_config.network_name
+            )
+        })?;
+    if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(contract_code_view) =
+        view_code_response.kind
+    {
+        Ok(contract_code_view.code)
+    } else {
+        color_eyre::eyre::bail!(
+            "Internal error: Received unexpected query kind in response to a ViewCode query"
+        )
+    }
+}
+
+fn confirm_deployment(is_confirmation_needed: bool) -> crate::CliResult {
+    if !is_confirmation_needed {
+        return Ok(());
+    }
+    // Scripts and CI runs cannot answer the prompt, the deployment goes on as before
+    if !std::io::stdin().is_terminal() {
+        eprintln!(
+            "{}",
+            "Warning: the deployment is not confirmed because the input is not a terminal (use --skip-confirmation to hide this warning).".yellow()
+        );
+        return Ok(());
+    }
+    #[derive(strum_macros::Display, PartialEq)]
+    enum ConfirmOptions {
+        #[strum(to_string = "Yes, I want to deploy the new contract code.")]
+        Yes,
+        #[strum(to_string = "No, I want to cancel the deployment.")]
+        No,
+    }
+    let select_choose_input = tracing_indicatif::suspend_tracing_indicatif(|| {
+        Select::new(
+            "Do you want to continue?",
+            vec![ConfirmOptions::Yes, ConfirmOptions::No],
+        )
+        .prompt()
+    })?;
+    if select_choose_input == ConfirmOptions::No {
+        color_eyre::eyre::bail!("The contract deployment was cancelled");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A module with a single exported function `name` that does nothing.
+    fn wasm_with_export(name: &str) -> Vec<u8> {
+        let mut wasm = vec![
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+            0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // types
+            0x03, 0x02, 0x01, 0x00, // functions
+        ];
+        wasm.extend([0x07, u8::try_from(name.len() + 4).unwrap(), 0x01]);
+        wasm.push(u8::try_from(name.len()).unwrap());
+        wasm.extend(name.as_bytes());
+        wasm.extend([0x00, 0x00]); // exports
+        wasm.extend([0x0a, 0x04, 0x01, 0x02, 0x00, 0x0b]); // code
+        wasm
+    }
+
+    #[test]
+    fn code_diff_of_first_deployment() {
+        let code = wasm_with_export("main");
+        let code_diff =
+            CodeDiff::new(near_primitives::hash::CryptoHash::default(), &[], &code, 10).unwrap();
+        assert!(code_diff.is_first_deployment());
+        assert_eq!(
+            code_diff.code_hash,
+            near_primitives::hash::CryptoHash::hash_bytes(&code)
+        );
+        assert_eq!(code_diff.deployed_code_size, 0);
+        assert_eq!(code_diff.code_size, u64::try_from(code.len()).unwrap());
+        assert_eq!(
+            code_diff.storage_cost_delta,
+            i128::try_from(code.len()).unwrap() * 10
+        );
+        assert_eq!(code_diff.added_functions, vec!["main"]);
+        assert!(code_diff.removed_functions.is_empty());
+    }
+
+    #[test]
+    fn code_diff_of_redeployment() {
+        let deployed_code = wasm_with_export("old_method");
+        let code = wasm_with_export("new");
+        let code_diff = CodeDiff::new(
+            near_primitives::hash::CryptoHash::hash_bytes(&deployed_code),
+            &deployed_code,
+            &code,
+            10,
+        )
+        .unwrap();
+        assert!(!code_diff.is_first_deployment());
+        assert_eq!(code_diff.storage_cost_delta, -70);
+        assert_eq!(code_diff.added_functions, vec!["new"]);
+        assert_eq!(code_diff.removed_functions, vec!["old_method"]);
+        assert!(code_diff.to_table().unwrap().to_string().contains("(-7 B)"));
+    }
+}
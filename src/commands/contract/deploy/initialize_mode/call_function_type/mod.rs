@@ -24,6 +24,7 @@ pub struct CallFunctionActionContext {
     receiver_account_id: near_primitives::types::AccountId,
     signer_account_id: near_primitives::types::AccountId,
     code: Vec<u8>,
+    skip_confirmation: bool,
     function_name: String,
     function_args: Vec<u8>,
 }
@@ -43,6 +44,7 @@ impl CallFunctionActionContext {
             receiver_account_id: previous_context.receiver_account_id,
             signer_account_id: previous_context.signer_account_id,
             code: previous_context.code,
+            skip_confirmation: previous_context.skip_confirmation,
             function_name: scope.function_name.clone(),
             function_args,
         })
@@ -77,6 +79,7 @@ pub struct PrepaidGasContext {
     receiver_account_id: near_primitives::types::AccountId,
     signer_account_id: near_primitives::types::AccountId,
     code: Vec<u8>,
+    skip_confirmation: bool,
     function_name: String,
     function_args: Vec<u8>,
     gas: crate::common::NearGas,
@@ -92,6 +95,7 @@ impl PrepaidGasContext {
             receiver_account_id: previous_context.receiver_account_id,
             signer_account_id: previous_context.signer_account_id,
             code: previous_context.code,
+            skip_confirmation: previous_context.skip_confirmation,
             function_name: previous_context.function_name,
             function_args: previous_context.function_args,
             gas: scope.gas,
@@ -149,8 +153,16 @@ impl DepositContext {
             std::sync::Arc::new({
                 let signer_account_id = previous_context.signer_account_id.clone();
                 let receiver_account_id = previous_context.receiver_account_id.clone();
-
-                move |_network_config| {
+                let global_context = previous_context.global_context.clone();
+
+                move |network_config| {
+                    super::super::check_contract_code(
+                        &global_context,
+                        network_config,
+                        &receiver_account_id,
+                        &previous_context.code,
+                        previous_context.skip_confirmation,
+                    )?;
                     Ok(crate::commands::PrepopulatedTransaction {
                         signer_id: signer_account_id.clone(),
                         receiver_id: receiver_account_id.clone(),
@@ -189,6 +201,7 @@ impl DepositContext {
             on_after_sending_transaction_callback: std::sync::Arc::new(
                 |_outcome_view, _network_config| Ok(()),
             ),
+            transaction_bundle: None,
         }))
     }
 }
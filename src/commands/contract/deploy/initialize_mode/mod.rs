@@ -37,6 +37,7 @@ impl NoInitializeContext {
             receiver_account_id: previous_context.receiver_account_id,
             signer_account_id: previous_context.signer_account_id,
             code: previous_context.code,
+            skip_confirmation: previous_context.skip_confirmation,
         }))
     }
 }
@@ -47,8 +48,16 @@ impl From<NoInitializeContext> for crate::commands::ActionContext {
             std::sync::Arc::new({
                 let signer_account_id = item.0.signer_account_id.clone();
                 let receiver_account_id = item.0.receiver_account_id.clone();
+                let global_context = item.0.global_context.clone();
 
-                move |_network_config| {
+                move |network_config| {
+                    super::check_contract_code(
+                        &global_context,
+                        network_config,
+                        &receiver_account_id,
+                        &item.0.code,
+                        item.0.skip_confirmation,
+                    )?;
                     Ok(crate::commands::PrepopulatedTransaction {
                         signer_id: signer_account_id.clone(),
                         receiver_id: receiver_account_id.clone(),
@@ -77,6 +86,7 @@ impl From<NoInitializeContext> for crate::commands::ActionContext {
             on_after_sending_transaction_callback: std::sync::Arc::new(
                 |_outcome_view, _network_config| Ok(()),
             ),
+            transaction_bundle: None,
         }
     }
 }
@@ -1,5 +1,6 @@
 use color_eyre::eyre::Context;
 
+mod code_check;
 pub mod initialize_mode;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -51,6 +52,9 @@ impl Contract {
 pub struct ContractFile {
     /// What is a file location of the contract?
     pub file_path: crate::types::path_buf::PathBuf,
+    #[interactive_clap(long)]
+    /// Deploy without asking for confirmation when the deployed contract code is going to change
+    pub skip_confirmation: bool,
     #[interactive_clap(subcommand)]
     initialize: self::initialize_mode::InitializeMode,
 }
@@ -61,6 +65,7 @@ pub struct ContractFileContext {
     pub receiver_account_id: near_primitives::types::AccountId,
     pub signer_account_id: near_primitives::types::AccountId,
     pub code: Vec<u8>,
+    pub skip_confirmation: bool,
 }
 
 impl ContractFileContext {
@@ -71,11 +76,27 @@ impl ContractFileContext {
         let code = std::fs::read(&scope.file_path).wrap_err_with(|| {
             format!("Failed to open or read the file: {:?}.", &scope.file_path.0,)
         })?;
+        self::code_check::validate_contract_code(&code)?;
         Ok(Self {
             global_context: previous_context.global_context,
             receiver_account_id: previous_context.receiver_account_id,
             signer_account_id: previous_context.signer_account_id,
             code,
+            skip_confirmation: scope.skip_confirmation,
         })
     }
 }
+
+/// Compares the contract code with the code deployed on the account (skipped in offline mode).
+pub fn check_contract_code(
+    global_context: &crate::GlobalContext,
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    code: &[u8],
+    skip_confirmation: bool,
+) -> crate::CliResult {
+    if global_context.offline {
+        return Ok(());
+    }
+    self::code_check::check_contract_code(network_config, account_id, code, skip_confirmation)
+}
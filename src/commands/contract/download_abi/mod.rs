@@ -67,9 +67,10 @@ impl DownloadContractContext {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let account_id = previous_context.account_id.clone();
             let file_path: std::path::PathBuf = scope.file_path.clone().into();
+            let output_format = previous_context.global_context.output_format.clone();
 
             move |network_config, block_reference| {
-                download_contract_abi(&account_id, &file_path, network_config, block_reference)
+                download_contract_abi(&account_id, &file_path, network_config, block_reference, &output_format)
             }
         });
         Ok(Self(crate::network_view_at_block::ArgsForViewContext {
@@ -107,6 +108,7 @@ fn download_contract_abi(
     file_path: &std::path::PathBuf,
     network_config: &crate::config::NetworkConfig,
     block_reference: &near_primitives::types::BlockReference,
+    output_format: &crate::common::OutputFormat,
 ) -> crate::CliResult {
     let abi_root =
         tokio::runtime::Runtime::new()
@@ -116,10 +118,22 @@ fn download_contract_abi(
                 block_reference,
                 account_id,
             ))?;
+    let abi_json = serde_json::to_vec_pretty(&abi_root)?;
     std::fs::File::create(file_path)
         .wrap_err_with(|| format!("Failed to create file: {:?}", file_path))?
-        .write(&serde_json::to_vec_pretty(&abi_root)?)
+        .write(&abi_json)
         .wrap_err_with(|| format!("Failed to write to file: {:?}", file_path))?;
     eprintln!("\nThe file {:?} was downloaded successfully", file_path);
+    if output_format.is_json() {
+        crate::common::print_json_output(
+            "contract_abi_file",
+            serde_json::json!({
+                "account_id": account_id,
+                "file_path": file_path,
+                "size": abi_json.len(),
+                "schema_version": abi_root.schema_version,
+            }),
+        )?;
+    }
     Ok(())
 }
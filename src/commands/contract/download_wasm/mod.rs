@@ -69,9 +69,10 @@ impl DownloadContractContext {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let account_id = previous_context.account_id.clone();
             let file_path: std::path::PathBuf = scope.file_path.clone().into();
+            let output_format = previous_context.global_context.output_format.clone();
 
             move |network_config, block_reference| {
-                download_contract_code(&account_id, &file_path, network_config, block_reference.clone())
+                download_contract_code(&account_id, &file_path, network_config, block_reference.clone(), &output_format)
             }
         });
         Ok(Self(crate::network_view_at_block::ArgsForViewContext {
@@ -109,6 +110,7 @@ fn download_contract_code(
     file_path: &std::path::PathBuf,
     network_config: &crate::config::NetworkConfig,
     block_reference: near_primitives::types::BlockReference,
+    output_format: &crate::common::OutputFormat,
 ) -> crate::CliResult {
     let query_view_method_response = network_config
         .json_rpc_client()
@@ -137,6 +139,19 @@ fn download_contract_code(
         .write(&call_access_view.code)
         .wrap_err_with(|| format!("Failed to write to file: {:?}", file_path))?;
     eprintln!("\nThe file {:?} was downloaded successfully", file_path);
+    if output_format.is_json() {
+        crate::common::print_json_output(
+            "contract_code_file",
+            serde_json::json!({
+                "account_id": account_id,
+                "file_path": file_path,
+                "block_height": query_view_method_response.block_height,
+                "block_hash": query_view_method_response.block_hash,
+                "size": call_access_view.code.len(),
+                "sha256_hex": hex::encode(near_primitives::hash::CryptoHash::hash_bytes(&call_access_view.code).as_ref()),
+            }),
+        )?;
+    }
 
     Ok(())
 }
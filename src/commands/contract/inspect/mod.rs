@@ -46,9 +46,10 @@ impl ContractContext {
     ) -> color_eyre::eyre::Result<Self> {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let account_id: near_primitives::types::AccountId = scope.contract_account_id.clone().into();
+            let output_format = previous_context.output_format.clone();
 
             move |network_config, block_reference| {
-                inspect_contract(&account_id, network_config, block_reference)
+                inspect_contract(&account_id, network_config, block_reference, &output_format)
             }
         });
         Ok(Self(crate::network_view_at_block::ArgsForViewContext {
@@ -70,6 +71,7 @@ fn inspect_contract(
     account_id: &near_primitives::types::AccountId,
     network_config: &crate::config::NetworkConfig,
     block_reference: &near_primitives::types::BlockReference,
+    output_format: &crate::common::OutputFormat,
 ) -> crate::CliResult {
     let view_code_response = get_contract_code(account_id, network_config, block_reference)?;
 
@@ -79,6 +81,7 @@ fn inspect_contract(
             account_id,
             network_config,
             view_code_response,
+            output_format,
         ))
 }
 
@@ -109,6 +112,7 @@ async fn display_inspect_contract(
     account_id: &near_primitives::types::AccountId,
     network_config: &crate::config::NetworkConfig,
     view_code_response: near_jsonrpc_primitives::types::query::RpcQueryResponse,
+    output_format: &crate::common::OutputFormat,
 ) -> crate::CliResult {
     let json_rpc_client = network_config.json_rpc_client();
     let block_reference = BlockReference::from(BlockId::Hash(view_code_response.block_hash));
@@ -137,6 +141,41 @@ async fn display_inspect_contract(
     )
     .await?;
 
+    let contract_source_metadata =
+        get_contract_source_metadata(&json_rpc_client, &block_reference, account_id).await;
+    let contract_abi = get_contract_abi(&json_rpc_client, &block_reference, account_id).await;
+
+    if output_format.is_json() {
+        let full_access_keys_count = access_keys
+            .iter()
+            .filter(|access_key| {
+                matches!(
+                    access_key.access_key.permission,
+                    near_primitives::views::AccessKeyPermissionView::FullAccess
+                )
+            })
+            .count();
+        return crate::common::print_json_output(
+            "contract_inspect",
+            serde_json::json!({
+                "account_id": account_id,
+                "block_height": view_code_response.block_height,
+                "block_hash": view_code_response.block_hash,
+                "sha256_hex": (account_view.code_hash != near_primitives::hash::CryptoHash::default())
+                    .then(|| hex::encode(account_view.code_hash.as_ref())),
+                "storage_usage": account_view.storage_usage,
+                "code_size": contract_code_view.code.len(),
+                "full_access_keys": full_access_keys_count,
+                "function_call_access_keys": access_keys.len() - full_access_keys_count,
+                "contract_source_metadata": contract_source_metadata.as_ref().ok(),
+                "contract_source_metadata_error": contract_source_metadata.as_ref().err().map(|err| err.to_string()),
+                "abi": contract_abi.as_ref().ok(),
+                "abi_error": contract_abi.as_ref().err().map(|err| err.to_string()),
+                "exported_functions": get_exported_functions(account_id, &contract_code_view.code)?,
+            }),
+        );
+    }
+
     let mut table = prettytable::Table::new();
     table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
 
@@ -192,7 +231,7 @@ async fn display_inspect_contract(
         access_keys_summary
     ]);
 
-    match get_contract_source_metadata(&json_rpc_client, &block_reference, account_id).await {
+    match contract_source_metadata {
         Ok(contract_source_metadata) => {
             table.add_row(prettytable::row![
                 Fy->"Contract version",
@@ -244,7 +283,7 @@ async fn display_inspect_contract(
         }
     }
 
-    match get_contract_abi(&json_rpc_client, &block_reference, account_id).await {
+    match contract_abi {
         Ok(abi_root) => {
             table.add_row(prettytable::row![
                 Fy->"NEAR ABI version",
@@ -345,28 +384,13 @@ async fn display_inspect_contract(
                 "Functions:".yellow()
             );
 
-            let parser = wasmparser::Parser::new(0);
-            for payload in parser.parse_all(&contract_code_view.code) {
-                if let wasmparser::Payload::ExportSection(export_section) =
-                    payload.wrap_err_with(|| {
-                        format!(
-                            "Could not parse WebAssembly binary of the contract <{account_id}>."
-                        )
-                    })?
-                {
-                    for export in export_section {
-                        let export = export
-                            .wrap_err_with(|| format!("Could not parse WebAssembly export section of the contract <{account_id}>."))?;
-                        if let wasmparser::ExternalKind::Func = export.kind {
-                            println!(
-                                " fn {}({}) -> {}\n",
-                                export.name.green(),
-                                "...".yellow(),
-                                "...".blue()
-                            );
-                        }
-                    }
-                }
+            for function_name in get_exported_functions(account_id, &contract_code_view.code)? {
+                println!(
+                    " fn {}({}) -> {}\n",
+                    function_name.green(),
+                    "...".yellow(),
+                    "...".blue()
+                );
             }
         }
     }
@@ -374,6 +398,29 @@ async fn display_inspect_contract(
     Ok(())
 }
 
+fn get_exported_functions(
+    account_id: &near_primitives::types::AccountId,
+    code: &[u8],
+) -> color_eyre::eyre::Result<Vec<String>> {
+    let mut exported_functions = vec![];
+    let parser = wasmparser::Parser::new(0);
+    for payload in parser.parse_all(code) {
+        if let wasmparser::Payload::ExportSection(export_section) = payload.wrap_err_with(|| {
+            format!("Could not parse WebAssembly binary of the contract <{account_id}>.")
+        })? {
+            for export in export_section {
+                let export = export.wrap_err_with(|| {
+                    format!("Could not parse WebAssembly export section of the contract <{account_id}>.")
+                })?;
+                if let wasmparser::ExternalKind::Func = export.kind {
+                    exported_functions.push(export.name.to_string());
+                }
+            }
+        }
+    }
+    Ok(exported_functions)
+}
+
 #[tracing::instrument(name = "Getting information about", skip_all)]
 async fn get_account_view(
     network_name: &str,
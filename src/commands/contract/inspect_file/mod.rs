@@ -0,0 +1,444 @@
+use color_eyre::{eyre::Context, owo_colors::OwoColorize};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = InspectFileContext)]
+pub struct InspectFile {
+    /// What is a file location of the contract?
+    file_path: crate::types::path_buf::PathBuf,
+}
+
+#[derive(Debug, Clone)]
+pub struct InspectFileContext;
+
+impl InspectFileContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<InspectFile as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let code = std::fs::read(&scope.file_path).wrap_err_with(|| {
+            format!("Failed to open or read the file: {:?}.", &scope.file_path.0)
+        })?;
+        let wasm_info = WasmInfo::parse(&code).wrap_err_with(|| {
+            format!(
+                "Could not parse WebAssembly binary of the file: {:?}.",
+                &scope.file_path.0
+            )
+        })?;
+        if previous_context.output_format.is_json() {
+            crate::common::print_json_output("contract_inspect_file", wasm_info.to_json(&code))?;
+        } else {
+            wasm_info.print(&scope.file_path.0, &code);
+        }
+        Ok(Self)
+    }
+}
+
+/// The WebAssembly proposals checked in the contract code, with a flag whether the NEAR runtime
+/// accepts them (it supports the MVP feature set extended with mutable globals and sign-extension
+/// operators).
+const WASM_FEATURES: [(wasmparser::WasmFeatures, &str, bool); 15] = [
+    (
+        wasmparser::WasmFeatures::MUTABLE_GLOBAL,
+        "mutable-global",
+        true,
+    ),
+    (
+        wasmparser::WasmFeatures::SIGN_EXTENSION,
+        "sign-extension",
+        true,
+    ),
+    (
+        wasmparser::WasmFeatures::SATURATING_FLOAT_TO_INT,
+        "saturating-float-to-int",
+        false,
+    ),
+    (
+        wasmparser::WasmFeatures::REFERENCE_TYPES,
+        "reference-types",
+        false,
+    ),
+    (wasmparser::WasmFeatures::MULTI_VALUE, "multi-value", false),
+    (wasmparser::WasmFeatures::BULK_MEMORY, "bulk-memory", false),
+    (wasmparser::WasmFeatures::SIMD, "simd", false),
+    (
+        wasmparser::WasmFeatures::RELAXED_SIMD,
+        "relaxed-simd",
+        false,
+    ),
+    (wasmparser::WasmFeatures::THREADS, "threads", false),
+    (wasmparser::WasmFeatures::TAIL_CALL, "tail-call", false),
+    (
+        wasmparser::WasmFeatures::MULTI_MEMORY,
+        "multi-memory",
+        false,
+    ),
+    (wasmparser::WasmFeatures::EXCEPTIONS, "exceptions", false),
+    (wasmparser::WasmFeatures::MEMORY64, "memory64", false),
+    (
+        wasmparser::WasmFeatures::EXTENDED_CONST,
+        "extended-const",
+        false,
+    ),
+    (
+        wasmparser::WasmFeatures::FUNCTION_REFERENCES,
+        "function-references",
+        false,
+    ),
+];
+
+/// The module that provides NEAR host functions to contracts.
+const HOST_FUNCTIONS_MODULE: &str = "env";
+
+#[derive(Debug)]
+pub struct WasmInfo {
+    pub exported_functions: Vec<String>,
+    imported_functions: Vec<(String, String)>,
+    custom_sections: Vec<(String, usize)>,
+    contract_abi: Option<color_eyre::eyre::Result<near_abi::AbiRoot>>,
+    used_features: Vec<(&'static str, bool)>,
+}
+
+impl WasmInfo {
+    pub fn parse(code: &[u8]) -> color_eyre::eyre::Result<Self> {
+        let mut exported_functions = vec![];
+        let mut imported_functions = vec![];
+        let mut custom_sections = vec![];
+        let mut contract_abi = None;
+
+        for payload in wasmparser::Parser::new(0).parse_all(code) {
+            match payload? {
+                wasmparser::Payload::ExportSection(export_section) => {
+                    for export in export_section {
+                        let export =
+                            export.wrap_err("Could not parse WebAssembly export section")?;
+                        if let wasmparser::ExternalKind::Func = export.kind {
+                            exported_functions.push(export.name.to_string());
+                        }
+                    }
+                }
+                wasmparser::Payload::ImportSection(import_section) => {
+                    for import in import_section {
+                        let import =
+                            import.wrap_err("Could not parse WebAssembly import section")?;
+                        if let wasmparser::TypeRef::Func(_) = import.ty {
+                            imported_functions
+                                .push((import.module.to_string(), import.name.to_string()));
+                        }
+                    }
+                }
+                wasmparser::Payload::CustomSection(custom_section) => {
+                    if custom_section.name() == "near_abi" {
+                        contract_abi = Some(parse_contract_abi(custom_section.data()));
+                    }
+                    custom_sections.push((
+                        custom_section.name().to_string(),
+                        custom_section.data().len(),
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            exported_functions,
+            imported_functions,
+            custom_sections,
+            contract_abi,
+            used_features: used_features(code)?,
+        })
+    }
+
+    /// Imports and Wasm features that the NEAR runtime does not support.
+    pub fn unsupported_usage(&self) -> Vec<String> {
+        self.imported_functions
+            .iter()
+            .filter(|(module, _)| module != HOST_FUNCTIONS_MODULE)
+            .map(|(module, name)| format!("import of {module}::{name}"))
+            .chain(
+                self.used_features
+                    .iter()
+                    .filter(|(_, is_supported)| !is_supported)
+                    .map(|(feature, _)| format!("{feature} Wasm feature")),
+            )
+            .collect()
+    }
+
+    fn print(&self, file_path: &std::path::Path, code: &[u8]) {
+        let mut table = prettytable::Table::new();
+        table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+
+        table.add_row(prettytable::row![
+            Fg->file_path.display(),
+            bytesize::ByteSize(u64::try_from(code.len()).unwrap_or_default())
+        ]);
+        table.add_row(prettytable::row![
+            Fy->"SHA-256 checksum hex",
+            hex::encode(near_primitives::hash::CryptoHash::hash_bytes(code).as_ref())
+        ]);
+        table.add_row(prettytable::row![
+            Fy->"Contract Source Metadata",
+            if self.exported_functions.iter().any(|name| name == "contract_source_metadata") {
+                "Supported (`contract_source_metadata` is exported, use `inspect` after deploy to read it)"
+            } else {
+                "Not supported (`contract_source_metadata` is not exported)"
+            }
+        ]);
+        match &self.contract_abi {
+            Some(Ok(abi_root)) => {
+                table.add_row(prettytable::row![
+                    Fy->"NEAR ABI version",
+                    abi_root.schema_version
+                ]);
+                let metadata = &abi_root.metadata;
+                table.add_row(prettytable::row![
+                    Fy->"Contract name",
+                    metadata.name.clone().unwrap_or_else(|| "N/A".to_string())
+                ]);
+                table.add_row(prettytable::row![
+                    Fy->"Contract version",
+                    metadata.version.clone().unwrap_or_else(|| "N/A".to_string())
+                ]);
+                table.add_row(prettytable::row![
+                    Fy->"Authors",
+                    if metadata.authors.is_empty() {
+                        "N/A".to_string()
+                    } else {
+                        metadata.authors.join("\n")
+                    }
+                ]);
+                table.add_row(prettytable::row![
+                    Fy->"Build",
+                    metadata.build.as_ref().map_or_else(
+                        || "N/A".to_string(),
+                        |build| format!(
+                            "{} ({}){}",
+                            build.builder,
+                            build.compiler,
+                            build.image.as_ref().map(|image| format!("\n{image}")).unwrap_or_default()
+                        )
+                    )
+                ]);
+            }
+            Some(Err(err)) => {
+                table.add_row(prettytable::row![
+                    Fy->"NEAR ABI version",
+                    textwrap::fill(&format!("Warning: the embedded NEAR ABI cannot be read: {err:#}"), 80)
+                ]);
+            }
+            None => {
+                table.add_row(prettytable::row![
+                    Fy->"NEAR ABI version",
+                    "Info: the file does not have the embedded `near_abi` custom section"
+                ]);
+            }
+        }
+        table.add_row(prettytable::row![
+            Fy->"Custom sections",
+            if self.custom_sections.is_empty() {
+                "None".to_string()
+            } else {
+                self.custom_sections
+                    .iter()
+                    .map(|(name, size)| format!("{name} ({})", bytesize::ByteSize(*size as u64)))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+        ]);
+        table.add_row(prettytable::row![
+            Fy->"Wasm features",
+            if self.used_features.is_empty() {
+                "MVP".to_string()
+            } else {
+                self.used_features
+                    .iter()
+                    .map(|(feature, is_supported)| {
+                        if *is_supported {
+                            feature.to_string()
+                        } else {
+                            format!("{} (not supported by the NEAR runtime)", feature.red())
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+        ]);
+        table.printstd();
+
+        println!("\n {}", "Host functions (imports):".yellow());
+        for (module, name) in &self.imported_functions {
+            if module == HOST_FUNCTIONS_MODULE {
+                println!("  {name}");
+            } else {
+                println!(
+                    "  {}::{name} {}",
+                    module,
+                    "(not provided by the NEAR runtime)".red()
+                );
+            }
+        }
+
+        match &self.contract_abi {
+            Some(Ok(abi_root)) => {
+                println!(
+                    "\n {} (hint: the full JSON Schema is embedded in the `near_abi` custom section)",
+                    "Functions:".yellow()
+                );
+                for function in &abi_root.body.functions {
+                    println!(
+                        " fn {}({}) -> {} {}",
+                        function.name.green(),
+                        "...".yellow(),
+                        "...".blue(),
+                        match function.kind {
+                            near_abi::AbiFunctionKind::Call =>
+                                "read-write function - transcation required",
+                            near_abi::AbiFunctionKind::View => "read-only function",
+                        }
+                    );
+                }
+                let abi_function_names = abi_root
+                    .body
+                    .functions
+                    .iter()
+                    .map(|function| function.name.as_str())
+                    .collect::<std::collections::HashSet<_>>();
+                let other_functions = self
+                    .exported_functions
+                    .iter()
+                    .filter(|name| !abi_function_names.contains(name.as_str()))
+                    .collect::<Vec<_>>();
+                if !other_functions.is_empty() {
+                    println!("\n {}", "Other exported functions:".yellow());
+                    for name in other_functions {
+                        println!(
+                            " fn {}({}) -> {}",
+                            name.green(),
+                            "...".yellow(),
+                            "...".blue()
+                        );
+                    }
+                }
+            }
+            _ => {
+                println!(
+                    "\n {} (NEAR ABI is not available, so only function names are extracted)",
+                    "Functions:".yellow()
+                );
+                for name in &self.exported_functions {
+                    println!(
+                        " fn {}({}) -> {}",
+                        name.green(),
+                        "...".yellow(),
+                        "...".blue()
+                    );
+                }
+            }
+        }
+        if !self.unsupported_usage().is_empty() {
+            eprintln!(
+                "\n{}",
+                "Warning: the contract uses imports or Wasm features that are not supported by the NEAR runtime, so its deployment or execution is going to fail.".red()
+            );
+        }
+    }
+
+    fn to_json(&self, code: &[u8]) -> serde_json::Value {
+        serde_json::json!({
+            "size": code.len(),
+            "sha256_hex": hex::encode(near_primitives::hash::CryptoHash::hash_bytes(code).as_ref()),
+            "exported_functions": self.exported_functions,
+            "imported_functions": self.imported_functions
+                .iter()
+                .map(|(module, name)| serde_json::json!({
+                    "module": module,
+                    "name": name,
+                    "is_host_function": module == HOST_FUNCTIONS_MODULE,
+                }))
+                .collect::<Vec<_>>(),
+            "custom_sections": self.custom_sections
+                .iter()
+                .map(|(name, size)| serde_json::json!({"name": name, "size": size}))
+                .collect::<Vec<_>>(),
+            "abi": match &self.contract_abi {
+                Some(Ok(abi_root)) => serde_json::to_value(abi_root).unwrap_or_default(),
+                _ => serde_json::Value::Null,
+            },
+            "abi_error": match &self.contract_abi {
+                Some(Err(err)) => serde_json::Value::String(format!("{err:#}")),
+                _ => serde_json::Value::Null,
+            },
+            "wasm_features": self.used_features
+                .iter()
+                .map(|(feature, is_supported)| serde_json::json!({
+                    "name": feature,
+                    "is_supported": is_supported,
+                }))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+fn parse_contract_abi(data: &[u8]) -> color_eyre::eyre::Result<near_abi::AbiRoot> {
+    serde_json::from_slice::<near_abi::AbiRoot>(
+        &zstd::decode_all(data).wrap_err("Failed to 'zstd::decode_all' NEAR ABI")?,
+    )
+    .wrap_err("Failed to parse NEAR ABI schema")
+}
+
+/// Detects the Wasm proposals the code depends on: a feature is used if the code does not
+/// validate once the feature is disabled.
+fn used_features(code: &[u8]) -> color_eyre::eyre::Result<Vec<(&'static str, bool)>> {
+    let all_features = WASM_FEATURES.iter().fold(
+        wasmparser::WasmFeatures::default(),
+        |features, (feature, _, _)| features | *feature,
+    );
+    wasmparser::Validator::new_with_features(all_features)
+        .validate_all(code)
+        .wrap_err("The file is not a valid WebAssembly module")?;
+    Ok(WASM_FEATURES
+        .iter()
+        .filter(|(feature, _, _)| {
+            wasmparser::Validator::new_with_features(all_features - *feature)
+                .validate_all(code)
+                .is_err()
+        })
+        .map(|(_, name, is_supported)| (*name, *is_supported))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A module importing the `env.input` host function and using `i32.extend8_s` and
+    /// `memory.fill` (bulk memory) in the exported `main` function.
+    const WASM: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x01, 0x08, 0x02, 0x60, 0x01, 0x7e, 0x00, 0x60, 0x00, 0x00, // types
+        0x02, 0x0d, 0x01, 0x03, b'e', b'n', b'v', 0x05, b'i', b'n', b'p', b'u', b't', 0x00,
+        0x00, // imports
+        0x03, 0x02, 0x01, 0x01, // functions
+        0x05, 0x03, 0x01, 0x00, 0x01, // memory
+        0x07, 0x08, 0x01, 0x04, b'm', b'a', b'i', b'n', 0x00, 0x01, // exports
+        0x0a, 0x11, 0x01, 0x0f, 0x00, // code
+        0x41, 0x00, 0xc0, 0x1a, // i32.const 0; i32.extend8_s; drop
+        0x41, 0x00, 0x41, 0x00, 0x41, 0x00, 0xfc, 0x0b, 0x00, // memory.fill
+        0x0b,
+    ];
+
+    #[test]
+    fn inspect_wasm_exports_imports_and_features() {
+        let wasm_info = WasmInfo::parse(WASM).unwrap();
+        assert_eq!(wasm_info.exported_functions, vec!["main"]);
+        assert_eq!(
+            wasm_info.imported_functions,
+            vec![("env".to_string(), "input".to_string())]
+        );
+        assert!(wasm_info.contract_abi.is_none());
+        assert_eq!(
+            wasm_info.used_features,
+            vec![("sign-extension", true), ("bulk-memory", false)]
+        );
+    }
+}
@@ -5,6 +5,8 @@ pub mod deploy;
 mod download_abi;
 mod download_wasm;
 mod inspect;
+mod inspect_file;
+mod restore_snapshot;
 mod view_storage;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -33,6 +35,11 @@ pub enum ContractActions {
     ))]
     /// Get a list of available function names
     Inspect(self::inspect::Contract),
+    #[strum_discriminants(strum(
+        message = "inspect-file    - Get a list of functions, imports and the ABI of a local wasm file"
+    ))]
+    /// Get a list of functions, imports and the ABI of a local wasm file
+    InspectFile(self::inspect_file::InspectFile),
     #[strum_discriminants(strum(message = "download-abi    - Download contract ABI"))]
     /// Download contract ABI
     DownloadAbi(self::download_abi::Contract),
@@ -42,4 +49,9 @@ pub enum ContractActions {
     #[strum_discriminants(strum(message = "view-storage    - View contract storage state"))]
     /// View contract storage state
     ViewStorage(self::view_storage::ViewStorage),
+    #[strum_discriminants(strum(
+        message = "restore-snapshot - Restore a contract state snapshot to a local sandbox node"
+    ))]
+    /// Restore a contract state snapshot to a local sandbox node
+    RestoreSnapshot(self::restore_snapshot::RestoreSnapshot),
 }
@@ -0,0 +1,164 @@
+use color_eyre::eyre::Context;
+
+/// The `sandbox_patch_state` RPC payload with the state records of the contract.
+const SNAPSHOT_RECORDS_FILE: &str = "records.json";
+/// The description of the snapshot: the contract, the network and the block it was taken at.
+const SNAPSHOT_METADATA_FILE: &str = "snapshot.json";
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = RestoreSnapshotContext)]
+pub struct RestoreSnapshot {
+    /// What is the directory of the contract state snapshot?
+    snapshot_dir: crate::types::path_buf::PathBuf,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// The RPC endpoint of the sandbox node to restore the snapshot to (default: http://127.0.0.1:3030)
+    sandbox_rpc_url: Option<crate::types::url::Url>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RestoreSnapshotContext;
+
+impl RestoreSnapshotContext {
+    pub fn from_previous_context(
+        _previous_context: crate::GlobalContext,
+        scope: &<RestoreSnapshot as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let sandbox_rpc_url: url::Url = match &scope.sandbox_rpc_url {
+            Some(sandbox_rpc_url) => sandbox_rpc_url.0.clone(),
+            None => {
+                crate::transaction_signature_options::simulate::SANDBOX_RPC_URL_DEFAULT.parse()?
+            }
+        };
+        let (metadata, records) = read_snapshot(&scope.snapshot_dir.0)?;
+        let records_count = records.len();
+        crate::transaction_signature_options::simulate::patch_sandbox_state(
+            &sandbox_rpc_url,
+            records,
+        )?;
+
+        match metadata {
+            Some(metadata) => eprintln!(
+                "\nThe snapshot of <{}> taken on <{}> at block #{} ({}) was restored to the sandbox <{sandbox_rpc_url}> ({records_count} state records).",
+                metadata.contract_account_id,
+                metadata.network_name,
+                metadata.block_height,
+                metadata.block_hash
+            ),
+            None => eprintln!(
+                "\n{records_count} state records were restored to the sandbox <{sandbox_rpc_url}>."
+            ),
+        }
+        Ok(Self)
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct SnapshotMetadata {
+    pub contract_account_id: near_primitives::types::AccountId,
+    pub network_name: String,
+    pub block_height: near_primitives::types::BlockHeight,
+    pub block_hash: near_primitives::hash::CryptoHash,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SandboxPatchStatePayload {
+    records: Vec<near_primitives::state_record::StateRecord>,
+}
+
+/// Writes the snapshot into the directory: the state records are stored as the `sandbox_patch_state`
+/// RPC payload, so the file can also be sent to a sandbox node as is.
+pub fn write_snapshot(
+    snapshot_dir: &std::path::Path,
+    metadata: &SnapshotMetadata,
+    records: Vec<near_primitives::state_record::StateRecord>,
+) -> crate::CliResult {
+    std::fs::create_dir_all(snapshot_dir)
+        .wrap_err_with(|| format!("Failed to create the directory: {:?}", snapshot_dir))?;
+    let records_file = snapshot_dir.join(SNAPSHOT_RECORDS_FILE);
+    std::fs::write(
+        &records_file,
+        serde_json::to_vec_pretty(&SandboxPatchStatePayload { records })?,
+    )
+    .wrap_err_with(|| format!("Failed to write to file: {:?}", records_file))?;
+    let metadata_file = snapshot_dir.join(SNAPSHOT_METADATA_FILE);
+    std::fs::write(&metadata_file, serde_json::to_vec_pretty(metadata)?)
+        .wrap_err_with(|| format!("Failed to write to file: {:?}", metadata_file))?;
+    Ok(())
+}
+
+/// Reads the snapshot from the directory. The metadata file is optional, so a hand-written
+/// `sandbox_patch_state` payload can be restored as well.
+fn read_snapshot(
+    snapshot_dir: &std::path::Path,
+) -> color_eyre::eyre::Result<(
+    Option<SnapshotMetadata>,
+    Vec<near_primitives::state_record::StateRecord>,
+)> {
+    let records_file = snapshot_dir.join(SNAPSHOT_RECORDS_FILE);
+    let payload: SandboxPatchStatePayload = serde_json::from_slice(
+        &std::fs::read(&records_file)
+            .wrap_err_with(|| format!("Failed to open or read the file: {:?}.", records_file))?,
+    )
+    .wrap_err_with(|| format!("Failed to parse the state records from {:?}", records_file))?;
+
+    let metadata_file = snapshot_dir.join(SNAPSHOT_METADATA_FILE);
+    let metadata = if metadata_file.exists() {
+        Some(
+            serde_json::from_slice(&std::fs::read(&metadata_file).wrap_err_with(|| {
+                format!("Failed to open or read the file: {:?}.", metadata_file)
+            })?)
+            .wrap_err_with(|| format!("Failed to parse the snapshot from {:?}", metadata_file))?,
+        )
+    } else {
+        None
+    };
+    Ok((metadata, payload.records))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_round_trip() {
+        let snapshot_dir =
+            std::env::temp_dir().join(format!("near-cli-rs-snapshot-test-{}", std::process::id()));
+        let contract_account_id: near_primitives::types::AccountId =
+            "contract.testnet".parse().unwrap();
+        let records = vec![
+            near_primitives::state_record::StateRecord::Contract {
+                account_id: contract_account_id.clone(),
+                code: vec![0, 97, 115, 109],
+            },
+            near_primitives::state_record::StateRecord::Data {
+                account_id: contract_account_id.clone(),
+                data_key: b"STATE".to_vec().into(),
+                value: vec![1, 2, 3].into(),
+            },
+        ];
+        write_snapshot(
+            &snapshot_dir,
+            &SnapshotMetadata {
+                contract_account_id: contract_account_id.clone(),
+                network_name: "testnet".to_string(),
+                block_height: 42,
+                block_hash: near_primitives::hash::CryptoHash::default(),
+            },
+            records.clone(),
+        )
+        .unwrap();
+
+        let payload: serde_json::Value = serde_json::from_slice(
+            &std::fs::read(snapshot_dir.join(SNAPSHOT_RECORDS_FILE)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(payload["records"][1]["Data"]["data_key"], "U1RBVEU=");
+
+        let (metadata, restored_records) = read_snapshot(&snapshot_dir).unwrap();
+        std::fs::remove_dir_all(&snapshot_dir).unwrap();
+        assert_eq!(metadata.unwrap().contract_account_id, contract_account_id);
+        assert_eq!(restored_records, records);
+    }
+}
@@ -19,6 +19,7 @@ impl KeysStartWithStringContext {
         Ok(Self(super::KeysContext {
             global_context: previous_context.global_context,
             contract_account_id: previous_context.contract_account_id,
+            layout: previous_context.layout,
             prefix: near_primitives::types::StoreKey::from(
                 scope.keys_begin_with.clone().into_bytes(),
             ),
@@ -31,4 +31,5 @@ pub struct KeysContext {
     pub global_context: crate::GlobalContext,
     pub contract_account_id: near_primitives::types::AccountId,
     pub prefix: near_primitives::types::StoreKey,
+    pub layout: Option<super::layout::Layout>,
 }
@@ -0,0 +1,362 @@
+//! Decoding of the contract storage with a layout of near-sdk collections (`near_sdk::collections`).
+//!
+//! The layout file is a JSON object with the list of collections, e.g.:
+//!
+//! ```json
+//! {
+//!   "collections": [
+//!     {"name": "accounts", "kind": "LookupMap", "prefix": "a", "key_type": "AccountId", "value_type": "u128"},
+//!     {"name": "tokens", "kind": "Vector", "prefix": [1], "value_type": "Token"}
+//!   ]
+//! }
+//! ```
+//!
+//! The prefix is a string or an array of bytes (e.g. a Borsh-serialized enum variant). The key and
+//! value types are Borsh primitives (`bool`, `u8`-`u128`, `i8`-`i128`, `String`, `AccountId`),
+//! `bytes` for undecoded data or the name of a type declared in the contract ABI.
+//!
+//! The keys are laid out as in `near_sdk::collections`:
+//! - `LookupMap`: `prefix + key`;
+//! - `Vector`: `prefix + index` (u64, little-endian);
+//! - `UnorderedMap`: the keys and values are vectors with `prefix + "k"` and `prefix + "v"`
+//!   prefixes, and the index of every key is stored under `prefix + "i" + key`;
+//! - `TreeMap`: the values are a `LookupMap` with `prefix + "v"` prefix, and the tree nodes are a
+//!   vector with `prefix + "n"` prefix.
+use borsh::schema::BorshSchemaContainer;
+use color_eyre::eyre::Context;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct Layout {
+    collections: Vec<Collection>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct Collection {
+    name: String,
+    kind: CollectionKind,
+    prefix: Prefix,
+    #[serde(default)]
+    key_type: Option<String>,
+    value_type: String,
+}
+
+#[derive(Debug, Clone, Copy, serde::Deserialize, strum_macros::Display)]
+enum CollectionKind {
+    LookupMap,
+    UnorderedMap,
+    Vector,
+    TreeMap,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(untagged)]
+enum Prefix {
+    String(String),
+    Bytes(Vec<u8>),
+}
+
+impl Prefix {
+    fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::String(prefix) => prefix.as_bytes().to_vec(),
+            Self::Bytes(prefix) => prefix.clone(),
+        }
+    }
+}
+
+impl Layout {
+    pub fn read(file_path: &std::path::Path) -> color_eyre::eyre::Result<Self> {
+        let layout: Self = serde_json::from_slice(
+            &std::fs::read(file_path)
+                .wrap_err_with(|| format!("Failed to open or read the file: {:?}.", file_path))?,
+        )
+        .wrap_err_with(|| format!("Failed to parse the storage layout from {:?}", file_path))?;
+        for collection in &layout.collections {
+            match (collection.kind, &collection.key_type) {
+                (CollectionKind::Vector, Some(_)) => color_eyre::eyre::bail!(
+                    "The collection <{}> is a Vector, so it does not have `key_type`",
+                    collection.name
+                ),
+                (
+                    CollectionKind::LookupMap
+                    | CollectionKind::UnorderedMap
+                    | CollectionKind::TreeMap,
+                    None,
+                ) => color_eyre::eyre::bail!(
+                    "The collection <{}> is a {}, so `key_type` is required",
+                    collection.name,
+                    collection.kind
+                ),
+                _ => {}
+            }
+        }
+        Ok(layout)
+    }
+
+    /// Resolves the key and value types of the collections; the types that are not Borsh
+    /// primitives are looked up with `find_abi_type` (in the contract ABI).
+    pub fn resolve(
+        &self,
+        find_abi_type: impl Fn(&str) -> color_eyre::eyre::Result<BorshSchemaContainer>,
+    ) -> color_eyre::eyre::Result<ResolvedLayout> {
+        let type_schema =
+            |type_name: &str| -> color_eyre::eyre::Result<Option<BorshSchemaContainer>> {
+                Ok(match type_name {
+                    "bytes" => None,
+                    "bool" => Some(BorshSchemaContainer::for_type::<bool>()),
+                    "u8" => Some(BorshSchemaContainer::for_type::<u8>()),
+                    "u16" => Some(BorshSchemaContainer::for_type::<u16>()),
+                    "u32" => Some(BorshSchemaContainer::for_type::<u32>()),
+                    "u64" => Some(BorshSchemaContainer::for_type::<u64>()),
+                    "u128" => Some(BorshSchemaContainer::for_type::<u128>()),
+                    "i8" => Some(BorshSchemaContainer::for_type::<i8>()),
+                    "i16" => Some(BorshSchemaContainer::for_type::<i16>()),
+                    "i32" => Some(BorshSchemaContainer::for_type::<i32>()),
+                    "i64" => Some(BorshSchemaContainer::for_type::<i64>()),
+                    "i128" => Some(BorshSchemaContainer::for_type::<i128>()),
+                    "String" | "AccountId" => Some(BorshSchemaContainer::for_type::<String>()),
+                    _ => Some(find_abi_type(type_name)?),
+                })
+            };
+        let collections = self
+            .collections
+            .iter()
+            .map(|collection| {
+                Ok(ResolvedCollection {
+                    name: collection.name.clone(),
+                    kind: collection.kind,
+                    prefix: collection.prefix.to_bytes(),
+                    key_schema: collection
+                        .key_type
+                        .as_deref()
+                        .map(type_schema)
+                        .transpose()?
+                        .flatten(),
+                    value_schema: type_schema(&collection.value_type)?,
+                })
+            })
+            .collect::<color_eyre::eyre::Result<Vec<_>>>()?;
+        // The longest prefix wins if the prefixes of the collections overlap.
+        let mut match_order = (0..collections.len()).collect::<Vec<_>>();
+        match_order.sort_by_key(|&index| std::cmp::Reverse(collections[index].prefix.len()));
+        Ok(ResolvedLayout {
+            collections,
+            match_order,
+        })
+    }
+}
+
+struct ResolvedCollection {
+    name: String,
+    kind: CollectionKind,
+    prefix: Vec<u8>,
+    key_schema: Option<BorshSchemaContainer>,
+    value_schema: Option<BorshSchemaContainer>,
+}
+
+pub struct ResolvedLayout {
+    collections: Vec<ResolvedCollection>,
+    match_order: Vec<usize>,
+}
+
+/// The part of a collection a storage key belongs to.
+enum Part<'a> {
+    Entry(&'a [u8]),
+    Element(u64),
+    UnorderedMapKey(u64),
+    UnorderedMapValue(u64),
+    Internal,
+}
+
+impl ResolvedCollection {
+    fn part<'a>(&self, key: &'a [u8]) -> Option<Part<'a>> {
+        let suffix = key.strip_prefix(self.prefix.as_slice())?;
+        let index = |suffix: &[u8]| Some(u64::from_le_bytes(suffix.try_into().ok()?));
+        match self.kind {
+            CollectionKind::LookupMap => Some(Part::Entry(suffix)),
+            CollectionKind::Vector => index(suffix).map(Part::Element),
+            CollectionKind::UnorderedMap => match suffix.split_first()? {
+                (b'k', suffix) => index(suffix).map(Part::UnorderedMapKey),
+                (b'v', suffix) => index(suffix).map(Part::UnorderedMapValue),
+                (b'i', _) => Some(Part::Internal),
+                _ => None,
+            },
+            CollectionKind::TreeMap => match suffix.split_first()? {
+                (b'v', suffix) => Some(Part::Entry(suffix)),
+                (b'n', _) => Some(Part::Internal),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl ResolvedLayout {
+    /// Groups the storage values by the collections and decodes their keys and values. The values
+    /// that do not belong to any collection are returned in `other` as they are.
+    pub fn decode(&self, values: &[near_primitives::views::StateItem]) -> serde_json::Value {
+        let mut entries = vec![vec![]; self.collections.len()];
+        let mut unordered_map_entries = vec![
+                std::collections::BTreeMap::<u64, serde_json::Map<_, _>>::new();
+                self.collections.len()
+            ];
+        let mut other = vec![];
+        for state_item in values {
+            let Some((collection_index, collection, part)) =
+                self.match_order.iter().find_map(|&collection_index| {
+                    let collection = &self.collections[collection_index];
+                    Some((
+                        collection_index,
+                        collection,
+                        collection.part(&state_item.key)?,
+                    ))
+                })
+            else {
+                other.push(serde_json::json!(state_item));
+                continue;
+            };
+            let mut entry = serde_json::Map::new();
+            match part {
+                Part::Entry(key) => {
+                    insert_decoded(&mut entry, "key", key, collection.key_schema.as_ref());
+                    insert_decoded(
+                        &mut entry,
+                        "value",
+                        &state_item.value,
+                        collection.value_schema.as_ref(),
+                    );
+                    entries[collection_index].push(serde_json::Value::Object(entry));
+                }
+                Part::Element(index) => {
+                    entry.insert("index".to_string(), index.into());
+                    insert_decoded(
+                        &mut entry,
+                        "value",
+                        &state_item.value,
+                        collection.value_schema.as_ref(),
+                    );
+                    entries[collection_index].push(serde_json::Value::Object(entry));
+                }
+                Part::UnorderedMapKey(index) => insert_decoded(
+                    unordered_map_entries[collection_index]
+                        .entry(index)
+                        .or_default(),
+                    "key",
+                    &state_item.value,
+                    collection.key_schema.as_ref(),
+                ),
+                Part::UnorderedMapValue(index) => insert_decoded(
+                    unordered_map_entries[collection_index]
+                        .entry(index)
+                        .or_default(),
+                    "value",
+                    &state_item.value,
+                    collection.value_schema.as_ref(),
+                ),
+                Part::Internal => {}
+            }
+        }
+        for (collection_entries, unordered_map_entries) in
+            entries.iter_mut().zip(unordered_map_entries)
+        {
+            collection_entries.extend(
+                unordered_map_entries
+                    .into_values()
+                    .map(serde_json::Value::Object),
+            );
+        }
+
+        serde_json::json!({
+            "collections": self
+                .collections
+                .iter()
+                .zip(entries)
+                .map(|(collection, entries)| {
+                    serde_json::json!({
+                        "name": collection.name,
+                        "kind": collection.kind.to_string(),
+                        "entries": entries,
+                    })
+                })
+                .collect::<Vec<_>>(),
+            "other": other,
+        })
+    }
+}
+
+/// Inserts the data decoded with the Borsh schema (or in base64 if there is no schema). Data that
+/// cannot be decoded is inserted in base64 together with the error.
+fn insert_decoded(
+    entry: &mut serde_json::Map<String, serde_json::Value>,
+    name: &str,
+    data: &[u8],
+    schema: Option<&BorshSchemaContainer>,
+) {
+    let base64 = || serde_json::Value::String(near_primitives::serialize::to_base64(data));
+    match schema.map(|schema| super::super::call_function::borsh_schema::deserialize(data, schema))
+    {
+        Some(Ok(decoded)) => {
+            entry.insert(name.to_string(), decoded);
+        }
+        Some(Err(err)) => {
+            entry.insert(name.to_string(), base64());
+            entry.insert(
+                format!("{name}_error"),
+                serde_json::Value::String(format!("Failed to decode with Borsh: {err}")),
+            );
+        }
+        None => {
+            entry.insert(name.to_string(), base64());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_item(key: &[u8], value: &[u8]) -> near_primitives::views::StateItem {
+        near_primitives::views::StateItem {
+            key: key.to_vec().into(),
+            value: value.to_vec().into(),
+        }
+    }
+
+    #[test]
+    fn decode_collections() {
+        let layout: Layout = serde_json::from_value(serde_json::json!({
+            "collections": [
+                {"name": "balances", "kind": "LookupMap", "prefix": "b", "key_type": "AccountId", "value_type": "u128"},
+                {"name": "owners", "kind": "Vector", "prefix": [0], "value_type": "String"},
+                {"name": "tokens", "kind": "UnorderedMap", "prefix": "t", "key_type": "u32", "value_type": "bool"},
+            ]
+        }))
+        .unwrap();
+        let resolved_layout = layout
+            .resolve(|type_name| color_eyre::eyre::bail!("unexpected ABI type {type_name}"))
+            .unwrap();
+
+        let alice = borsh::to_vec("alice.near").unwrap();
+        let values = [
+            state_item(&[0, 1, 0, 0, 0, 0, 0, 0, 0], &alice),
+            state_item(b"STATE", &[]),
+            state_item(
+                &[b"b".as_slice(), &alice].concat(),
+                &borsh::to_vec(&100u128).unwrap(),
+            ),
+            state_item(&[b"ti".as_slice(), &[7, 0, 0, 0]].concat(), &[0; 8]),
+            state_item(b"tk\0\0\0\0\0\0\0\0", &[7, 0, 0, 0]),
+            state_item(b"tv\0\0\0\0\0\0\0\0", &[1]),
+        ];
+        assert_eq!(
+            resolved_layout.decode(&values),
+            serde_json::json!({
+                "collections": [
+                    {"name": "balances", "kind": "LookupMap", "entries": [{"key": "alice.near", "value": "100"}]},
+                    {"name": "owners", "kind": "Vector", "entries": [{"index": 1, "value": "alice.near"}]},
+                    {"name": "tokens", "kind": "UnorderedMap", "entries": [{"key": 7, "value": true}]},
+                ],
+                "other": [{"key": "U1RBVEU=", "value": ""}],
+            })
+        );
+    }
+}
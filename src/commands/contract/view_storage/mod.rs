@@ -1,4 +1,5 @@
 mod keys_to_view;
+mod layout;
 mod output_format;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -8,6 +9,10 @@ pub struct ViewStorage {
     #[interactive_clap(skip_default_input_arg)]
     /// What is the contract account ID?
     contract_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// The JSON file with the layout of near-sdk collections to decode the storage with
+    layout: Option<crate::types::path_buf::PathBuf>,
     #[interactive_clap(subcommand)]
     keys_to_view: self::keys_to_view::KeysToView,
 }
@@ -16,6 +21,7 @@ pub struct ViewStorage {
 pub struct ViewStorageContext {
     global_context: crate::GlobalContext,
     contract_account_id: near_primitives::types::AccountId,
+    layout: Option<self::layout::Layout>,
 }
 
 impl ViewStorageContext {
@@ -26,6 +32,11 @@ impl ViewStorageContext {
         Ok(Self {
             global_context: previous_context,
             contract_account_id: scope.contract_account_id.clone().into(),
+            layout: scope
+                .layout
+                .as_ref()
+                .map(|file_path| self::layout::Layout::read(&file_path.0))
+                .transpose()?,
         })
     }
 }
@@ -18,26 +18,38 @@ impl AsJsonContext {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let contract_account_id = previous_context.contract_account_id.clone();
             let prefix = previous_context.prefix;
+            let output_format = previous_context.global_context.output_format.clone();
+            let layout = previous_context.layout;
 
             move |network_config, block_reference| {
-                let query_view_method_response =
+                let contract_state =
                     super::get_contract_state(&contract_account_id, prefix.clone(), network_config, block_reference.clone())?;
 
-                if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(result) =
-                    query_view_method_response.kind
-                {
-                    eprintln!("Contract state (values):");
+                let decoded_contract_state = layout
+                    .as_ref()
+                    .map(|layout| super::decode_contract_state(layout, &contract_account_id, network_config, &contract_state.values))
+                    .transpose()?;
+
+                if output_format.is_json() {
+                    return super::print_contract_state_json_output(
+                        &contract_account_id,
+                        &contract_state,
+                        decoded_contract_state,
+                    );
+                }
+                if let Some(decoded_contract_state) = decoded_contract_state {
+                    eprintln!("Contract state (collections):");
                     println!(
                         "{}",
-                        serde_json::to_string_pretty(&result.values)?
-                    );
-                    eprintln!(
-                        "\nContract state (proof):\n{:#?}\n",
-                        &result.proof
+                        serde_json::to_string_pretty(&decoded_contract_state)?
                     );
-                } else {
-                    return Err(color_eyre::Report::msg("Error call result".to_string()));
-                };
+                    return Ok(());
+                }
+                eprintln!("Contract state (values):");
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&contract_state.values)?
+                );
                 Ok(())
             }
         });
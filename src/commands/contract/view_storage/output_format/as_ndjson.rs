@@ -0,0 +1,232 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::super::keys_to_view::KeysContext)]
+#[interactive_clap(output_context = AsNdjsonContext)]
+pub struct AsNdjson {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// How to decode the keys: utf8, hex or base64 (default: base64)
+    key_format: Option<DataFormat>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// How to decode the values: utf8, hex, base64 or borsh (default: base64)
+    value_format: Option<DataFormat>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// The name of the type from the contract ABI to decode the values with `--value-format borsh`
+    borsh_type: Option<String>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+#[derive(Clone)]
+pub struct AsNdjsonContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl AsNdjsonContext {
+    pub fn from_previous_context(
+        previous_context: super::super::keys_to_view::KeysContext,
+        scope: &<AsNdjson as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        if previous_context.layout.is_some() {
+            color_eyre::eyre::bail!(
+                "The storage layout (`--layout`) is not supported by `as-ndjson` (use `as-json` or `as-text`)"
+            );
+        }
+        let key_format = scope.key_format.clone().unwrap_or(DataFormat::Base64);
+        let value_format = scope.value_format.clone().unwrap_or(DataFormat::Base64);
+        if let DataFormat::Borsh = key_format {
+            color_eyre::eyre::bail!(
+                "The keys cannot be decoded with Borsh (use utf8, hex or base64 for `--key-format`)"
+            );
+        }
+        match (&value_format, &scope.borsh_type) {
+            (DataFormat::Borsh, None) => color_eyre::eyre::bail!(
+                "`--value-format borsh` requires `--borsh-type` with the name of the type from the contract ABI"
+            ),
+            (DataFormat::Utf8 | DataFormat::Hex | DataFormat::Base64, Some(_)) => {
+                color_eyre::eyre::bail!("`--borsh-type` can only be used with `--value-format borsh`")
+            }
+            _ => {}
+        }
+
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
+            let contract_account_id = previous_context.contract_account_id.clone();
+            let prefix = previous_context.prefix;
+            let borsh_type = scope.borsh_type.clone();
+
+            move |network_config, block_reference| {
+                let borsh_schema = borsh_type
+                    .as_deref()
+                    .map(|type_name| {
+                        super::super::super::call_function::function_abi::borsh_type_schema(
+                            network_config,
+                            &contract_account_id,
+                            type_name,
+                        )
+                    })
+                    .transpose()?;
+
+                let mut values_count = 0;
+                let (block_height, block_hash) = super::for_each_contract_state_chunk(
+                    &contract_account_id,
+                    prefix.clone(),
+                    network_config,
+                    block_reference.clone(),
+                    |chunk| {
+                        values_count += chunk.len();
+                        for state_item in chunk {
+                            println!(
+                                "{}",
+                                state_item_to_json(&state_item, &key_format, &value_format, borsh_schema.as_ref())
+                            );
+                        }
+                        Ok(())
+                    },
+                )?;
+                eprintln!(
+                    "\n{values_count} key-value pairs of <{contract_account_id}> at block #{block_height} ({block_hash})"
+                );
+                Ok(())
+            }
+        });
+
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config: previous_context.global_context.config,
+            interacting_with_account_ids: vec![previous_context.contract_account_id],
+            on_after_getting_block_reference_callback,
+        }))
+    }
+}
+
+impl From<AsNdjsonContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: AsNdjsonContext) -> Self {
+        item.0
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum DataFormat {
+    Utf8,
+    Hex,
+    Base64,
+    Borsh,
+}
+
+impl interactive_clap::ToCli for DataFormat {
+    type CliVariant = DataFormat;
+}
+
+impl std::str::FromStr for DataFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "utf8" => Ok(Self::Utf8),
+            "hex" => Ok(Self::Hex),
+            "base64" => Ok(Self::Base64),
+            "borsh" => Ok(Self::Borsh),
+            _ => Err(
+                "DataFormat: incorrect value entered (utf8, hex, base64 or borsh is expected)"
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for DataFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Utf8 => write!(f, "utf8"),
+            Self::Hex => write!(f, "hex"),
+            Self::Base64 => write!(f, "base64"),
+            Self::Borsh => write!(f, "borsh"),
+        }
+    }
+}
+
+/// Decodes the data in the format. Data that cannot be decoded is returned in base64 together with
+/// the error, so a single unexpected value does not break the stream.
+fn decode(
+    data: &[u8],
+    data_format: &DataFormat,
+    borsh_schema: Option<&borsh::schema::BorshSchemaContainer>,
+) -> Result<serde_json::Value, String> {
+    match (data_format, borsh_schema) {
+        (DataFormat::Utf8, _) => String::from_utf8(data.to_vec())
+            .map(serde_json::Value::String)
+            .map_err(|err| err.to_string()),
+        (DataFormat::Hex, _) => Ok(serde_json::Value::String(hex::encode(data))),
+        (DataFormat::Base64, _) => Ok(serde_json::Value::String(
+            near_primitives::serialize::to_base64(data),
+        )),
+        (DataFormat::Borsh, Some(borsh_schema)) => {
+            super::super::super::call_function::borsh_schema::deserialize(data, borsh_schema)
+                .map_err(|err| err.to_string())
+        }
+        (DataFormat::Borsh, None) => Err("The Borsh type is not specified".to_string()),
+    }
+}
+
+fn state_item_to_json(
+    state_item: &near_primitives::views::StateItem,
+    key_format: &DataFormat,
+    value_format: &DataFormat,
+    borsh_schema: Option<&borsh::schema::BorshSchemaContainer>,
+) -> serde_json::Value {
+    let mut json = serde_json::Map::new();
+    for (name, data, data_format) in [
+        ("key", state_item.key.as_slice(), key_format),
+        ("value", state_item.value.as_slice(), value_format),
+    ] {
+        match decode(data, data_format, borsh_schema) {
+            Ok(decoded) => {
+                json.insert(name.to_string(), decoded);
+            }
+            Err(err) => {
+                json.insert(
+                    name.to_string(),
+                    serde_json::Value::String(near_primitives::serialize::to_base64(data)),
+                );
+                json.insert(
+                    format!("{name}_error"),
+                    serde_json::Value::String(format!("Failed to decode as {data_format}: {err}")),
+                );
+            }
+        }
+    }
+    serde_json::Value::Object(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_item_decoding() {
+        let state_item = near_primitives::views::StateItem {
+            key: b"STATE".to_vec().into(),
+            value: vec![0xff, 0x01].into(),
+        };
+        assert_eq!(
+            state_item_to_json(&state_item, &DataFormat::Utf8, &DataFormat::Hex, None),
+            serde_json::json!({"key": "STATE", "value": "ff01"})
+        );
+        assert_eq!(
+            state_item_to_json(&state_item, &DataFormat::Hex, &DataFormat::Utf8, None),
+            serde_json::json!({
+                "key": "5354415445",
+                "value": "/wE=",
+                "value_error": "Failed to decode as utf8: invalid utf-8 sequence of 1 bytes from index 0"
+            })
+        );
+        let borsh_schema = borsh::schema::BorshSchemaContainer::for_type::<u16>();
+        assert_eq!(
+            state_item_to_json(
+                &state_item,
+                &DataFormat::Base64,
+                &DataFormat::Borsh,
+                Some(&borsh_schema)
+            ),
+            serde_json::json!({"key": "U1RBVEU=", "value": 511})
+        );
+    }
+}
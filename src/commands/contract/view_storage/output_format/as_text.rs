@@ -20,27 +20,34 @@ impl AsTextContext {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let contract_account_id = previous_context.contract_account_id.clone();
             let prefix = previous_context.prefix;
+            let output_format = previous_context.global_context.output_format.clone();
+            let layout = previous_context.layout;
 
             move |network_config, block_reference| {
-                let query_view_method_response =
+                let contract_state =
                     super::get_contract_state(&contract_account_id, prefix.clone(), network_config, block_reference.clone())?;
 
-                if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(result) =
-                    query_view_method_response.kind
-                {
-                    eprintln!("Contract state (values):");
-                    for value in &result.values {
-                        eprintln!("key:\n{}", key_value_to_string(&value.key)?.green());
-                        eprintln!("value:\n{}", key_value_to_string(&value.value)?.yellow());
-                        eprintln!("--------------------------------");
-                    }
-                    eprintln!(
-                        "\nContract state (proof):\n{:#?}\n",
-                        &result.proof
+                let decoded_contract_state = layout
+                    .as_ref()
+                    .map(|layout| super::decode_contract_state(layout, &contract_account_id, network_config, &contract_state.values))
+                    .transpose()?;
+
+                if output_format.is_json() {
+                    return super::print_contract_state_json_output(
+                        &contract_account_id,
+                        &contract_state,
+                        decoded_contract_state,
                     );
-                } else {
-                    return Err(color_eyre::Report::msg("Error call result".to_string()));
-                };
+                }
+                if let Some(decoded_contract_state) = decoded_contract_state {
+                    return print_decoded_contract_state(&decoded_contract_state);
+                }
+                eprintln!("Contract state (values):");
+                for value in &contract_state.values {
+                    eprintln!("key:\n{}", key_value_to_string(&value.key)?.green());
+                    eprintln!("value:\n{}", key_value_to_string(&value.value)?.yellow());
+                    eprintln!("--------------------------------");
+                }
                 Ok(())
             }
         });
@@ -59,6 +66,45 @@ impl From<AsTextContext> for crate::network_view_at_block::ArgsForViewContext {
     }
 }
 
+fn print_decoded_contract_state(decoded_contract_state: &serde_json::Value) -> crate::CliResult {
+    for collection in decoded_contract_state["collections"]
+        .as_array()
+        .into_iter()
+        .flatten()
+    {
+        eprintln!(
+            "{} ({}):",
+            collection["name"].as_str().unwrap_or_default(),
+            collection["kind"].as_str().unwrap_or_default()
+        );
+        for entry in collection["entries"].as_array().into_iter().flatten() {
+            let key = entry.get("key").or_else(|| entry.get("index"));
+            eprintln!(
+                "  {}: {}",
+                key.map(|key| key.to_string()).unwrap_or_default().green(),
+                entry
+                    .get("value")
+                    .map(|value| value.to_string())
+                    .unwrap_or_default()
+                    .yellow()
+            );
+        }
+        eprintln!("--------------------------------");
+    }
+    eprintln!("Other values:");
+    for value in decoded_contract_state["other"]
+        .as_array()
+        .into_iter()
+        .flatten()
+    {
+        let value: near_primitives::views::StateItem = serde_json::from_value(value.clone())?;
+        eprintln!("key:\n{}", key_value_to_string(&value.key)?.green());
+        eprintln!("value:\n{}", key_value_to_string(&value.value)?.yellow());
+        eprintln!("--------------------------------");
+    }
+    Ok(())
+}
+
 fn key_value_to_string(slice: &[u8]) -> color_eyre::eyre::Result<String> {
     String::from_utf8(
         slice
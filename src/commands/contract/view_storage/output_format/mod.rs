@@ -1,10 +1,14 @@
 use color_eyre::eyre::Context;
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
+use tracing_indicatif::span_ext::IndicatifSpanExt;
+
 use crate::common::JsonRpcClientExt;
 
 mod as_json;
+mod as_ndjson;
 mod as_text;
+mod save_snapshot;
 
 #[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(context = super::keys_to_view::KeysContext)]
@@ -12,38 +16,215 @@ mod as_text;
 /// Choose a format to view contract storage state:
 pub enum OutputFormat {
     #[strum_discriminants(strum(
-        message = "as-json    - View contract storage state in JSON format"
+        message = "as-json       - View contract storage state in JSON format"
     ))]
     /// View contract storage state in JSON format
     AsJson(self::as_json::AsJson),
     #[strum_discriminants(strum(
-        message = "as-text    - View contract storage state in the text"
+        message = "as-text       - View contract storage state in the text"
     ))]
     /// View contract storage state in the text
     AsText(self::as_text::AsText),
+    #[strum_discriminants(strum(
+        message = "as-ndjson     - Stream contract storage state as NDJSON (one key-value pair per line), decoding keys and values"
+    ))]
+    /// Stream contract storage state as NDJSON (one key-value pair per line), decoding keys and values
+    AsNdjson(self::as_ndjson::AsNdjson),
+    #[strum_discriminants(strum(
+        message = "save-snapshot - Save the account, code and storage state to a directory that can be restored to a sandbox"
+    ))]
+    /// Save the account, code and storage state to a directory that can be restored to a sandbox
+    SaveSnapshot(self::save_snapshot::SaveSnapshot),
+}
+
+/// Keys are never longer than this, so a prefix is not split beyond it.
+const MAX_KEY_LENGTH: usize = 2048;
+
+pub struct ContractState {
+    pub block_height: near_primitives::types::BlockHeight,
+    pub block_hash: near_primitives::hash::CryptoHash,
+    pub values: Vec<near_primitives::views::StateItem>,
 }
 
-#[tracing::instrument(name = "Obtaining the state of the contract ...", skip_all)]
 pub fn get_contract_state(
     contract_account_id: &near_primitives::types::AccountId,
     prefix: near_primitives::types::StoreKey,
     network_config: &crate::config::NetworkConfig,
     block_reference: near_primitives::types::BlockReference,
-) -> color_eyre::eyre::Result<near_jsonrpc_client::methods::query::RpcQueryResponse> {
-    network_config
-        .json_rpc_client()
-        .blocking_call(near_jsonrpc_client::methods::query::RpcQueryRequest {
-            block_reference,
-            request: near_primitives::views::QueryRequest::ViewState {
-                account_id: contract_account_id.clone(),
-                prefix,
-                include_proof: false,
-            },
-        })
-        .wrap_err_with(|| {
-            format!(
-                "Failed to fetch query ViewState for <{contract_account_id}> on network <{}>",
-                network_config.network_name
-            )
-        })
+) -> color_eyre::eyre::Result<ContractState> {
+    let mut values = vec![];
+    let (block_height, block_hash) = for_each_contract_state_chunk(
+        contract_account_id,
+        prefix,
+        network_config,
+        block_reference,
+        |chunk| {
+            values.extend(chunk);
+            Ok(())
+        },
+    )?;
+    Ok(ContractState {
+        block_height,
+        block_hash,
+        values,
+    })
+}
+
+/// Walks the contract state under the prefix in the ascending order of keys and passes it to
+/// `on_chunk` chunk by chunk.
+///
+/// RPC nodes refuse to return a state larger than their limit, so such a prefix is split into 256
+/// prefixes that are one byte longer. A key equal to the split prefix itself cannot be fetched this
+/// way (there is no RPC query for a single key), so the split prefixes are reported in a warning.
+/// All the queries are made at the block of the first one, which is returned.
+#[tracing::instrument(name = "Obtaining the state of the contract ...", skip_all)]
+pub fn for_each_contract_state_chunk(
+    contract_account_id: &near_primitives::types::AccountId,
+    prefix: near_primitives::types::StoreKey,
+    network_config: &crate::config::NetworkConfig,
+    block_reference: near_primitives::types::BlockReference,
+    mut on_chunk: impl FnMut(Vec<near_primitives::views::StateItem>) -> crate::CliResult,
+) -> color_eyre::eyre::Result<(
+    near_primitives::types::BlockHeight,
+    near_primitives::hash::CryptoHash,
+)> {
+    let json_rpc_client = network_config.json_rpc_client();
+    let mut block_reference = block_reference;
+    let mut block = None;
+    let mut prefixes = vec![Vec::<u8>::from(prefix)];
+    let mut split_prefixes = vec![];
+    while let Some(prefix) = prefixes.pop() {
+        tracing::Span::current().pb_set_message(&format!(
+            "{contract_account_id} (keys starting with {:?}) ...",
+            near_primitives::serialize::to_base64(&prefix)
+        ));
+        let query_result =
+            json_rpc_client.blocking_call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference: block_reference.clone(),
+                request: near_primitives::views::QueryRequest::ViewState {
+                    account_id: contract_account_id.clone(),
+                    prefix: prefix.clone().into(),
+                    include_proof: false,
+                },
+            });
+        let (block_height, block_hash) = match query_result {
+            Ok(query_view_method_response) => {
+                let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(view_state) =
+                    query_view_method_response.kind
+                else {
+                    color_eyre::eyre::bail!(
+                        "Internal error: Received unexpected query kind in response to a ViewState query"
+                    );
+                };
+                let mut values = view_state.values;
+                values.sort_by(|a, b| a.key.as_slice().cmp(b.key.as_slice()));
+                on_chunk(values)?;
+                (
+                    query_view_method_response.block_height,
+                    query_view_method_response.block_hash,
+                )
+            }
+            Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                    near_jsonrpc_primitives::types::query::RpcQueryError::TooLargeContractState {
+                        block_height,
+                        block_hash,
+                        ..
+                    },
+                ),
+            )) => {
+                if prefix.len() >= MAX_KEY_LENGTH {
+                    color_eyre::eyre::bail!(
+                        "The state of <{contract_account_id}> under the prefix {:?} is too large to be viewed",
+                        near_primitives::serialize::to_base64(&prefix)
+                    );
+                }
+                // The prefixes are popped from the end, so they are pushed in the descending order.
+                prefixes.extend((0..=u8::MAX).rev().map(|byte| {
+                    let mut longer_prefix = prefix.clone();
+                    longer_prefix.push(byte);
+                    longer_prefix
+                }));
+                split_prefixes.push(prefix);
+                (block_height, block_hash)
+            }
+            Err(err) => {
+                return Err(color_eyre::Report::from(err)).wrap_err_with(|| {
+                    format!(
+                    "Failed to fetch query ViewState for <{contract_account_id}> on network <{}>",
+                    network_config.network_name
+                )
+                })
+            }
+        };
+        if block.is_none() {
+            block = Some((block_height, block_hash));
+            block_reference = near_primitives::types::BlockReference::BlockId(
+                near_primitives::types::BlockId::Hash(block_hash),
+            );
+        }
+    }
+    if !split_prefixes.is_empty() {
+        warn_about_split_prefixes(contract_account_id, &split_prefixes);
+    }
+    block.ok_or_else(|| color_eyre::eyre::eyre!("Internal error: no ViewState query was made"))
+}
+
+fn warn_about_split_prefixes(
+    contract_account_id: &near_primitives::types::AccountId,
+    split_prefixes: &[Vec<u8>],
+) {
+    const MAX_LISTED_PREFIXES: usize = 10;
+    let mut listed_prefixes = split_prefixes
+        .iter()
+        .take(MAX_LISTED_PREFIXES)
+        .map(|prefix| format!("{:?}", near_primitives::serialize::to_base64(prefix)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    if split_prefixes.len() > MAX_LISTED_PREFIXES {
+        listed_prefixes.push_str(&format!(
+            " and {} more",
+            split_prefixes.len() - MAX_LISTED_PREFIXES
+        ));
+    }
+    eprintln!(
+        "WARNING: The state of <{contract_account_id}> under the prefixes {listed_prefixes} (base64) is too large to be fetched at once, so it was fetched in parts. A key that is exactly equal to one of these prefixes cannot be fetched in parts and, if it exists, is missing from the result."
+    );
+}
+
+/// Groups the contract state by the collections of the storage layout and decodes it (the types
+/// that are not Borsh primitives are taken from the contract ABI).
+pub fn decode_contract_state(
+    layout: &super::layout::Layout,
+    contract_account_id: &near_primitives::types::AccountId,
+    network_config: &crate::config::NetworkConfig,
+    values: &[near_primitives::views::StateItem],
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    let resolved_layout = layout.resolve(|type_name| {
+        super::super::call_function::function_abi::borsh_type_schema(
+            network_config,
+            contract_account_id,
+            type_name,
+        )
+    })?;
+    Ok(resolved_layout.decode(values))
+}
+
+pub fn print_contract_state_json_output(
+    contract_account_id: &near_primitives::types::AccountId,
+    contract_state: &ContractState,
+    decoded_contract_state: Option<serde_json::Value>,
+) -> crate::CliResult {
+    let mut json = serde_json::json!({
+        "contract_account_id": contract_account_id,
+        "block_height": contract_state.block_height,
+        "block_hash": contract_state.block_hash.to_string(),
+        "values": contract_state.values,
+    });
+    if let (Some(json), Some(serde_json::Value::Object(decoded_contract_state))) =
+        (json.as_object_mut(), decoded_contract_state)
+    {
+        json.extend(decoded_contract_state);
+    }
+    crate::common::print_json_output("contract_state", json)
 }
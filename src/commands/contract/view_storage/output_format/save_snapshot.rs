@@ -0,0 +1,136 @@
+use color_eyre::eyre::Context;
+
+use crate::common::{JsonRpcClientExt, RpcQueryResponseExt};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::super::keys_to_view::KeysContext)]
+#[interactive_clap(output_context = SaveSnapshotContext)]
+pub struct SaveSnapshot {
+    /// What is the directory to save the contract state snapshot to?
+    snapshot_dir: crate::types::path_buf::PathBuf,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+#[derive(Clone)]
+pub struct SaveSnapshotContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl SaveSnapshotContext {
+    pub fn from_previous_context(
+        previous_context: super::super::keys_to_view::KeysContext,
+        scope: &<SaveSnapshot as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        if previous_context.layout.is_some() {
+            color_eyre::eyre::bail!(
+                "The storage layout (`--layout`) is not supported by `save-snapshot` (use `as-json` or `as-text`)"
+            );
+        }
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
+            let contract_account_id = previous_context.contract_account_id.clone();
+            let prefix = previous_context.prefix;
+            let snapshot_dir: std::path::PathBuf = scope.snapshot_dir.clone().into();
+
+            move |network_config, block_reference| {
+                save_snapshot(&contract_account_id, prefix.clone(), &snapshot_dir, network_config, block_reference.clone())
+            }
+        });
+
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config: previous_context.global_context.config,
+            interacting_with_account_ids: vec![previous_context.contract_account_id],
+            on_after_getting_block_reference_callback,
+        }))
+    }
+}
+
+impl From<SaveSnapshotContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: SaveSnapshotContext) -> Self {
+        item.0
+    }
+}
+
+/// Saves the account, the contract code and the contract state at the same block, so the snapshot
+/// can be restored to a sandbox node with `contract restore-snapshot`.
+fn save_snapshot(
+    contract_account_id: &near_primitives::types::AccountId,
+    prefix: near_primitives::types::StoreKey,
+    snapshot_dir: &std::path::Path,
+    network_config: &crate::config::NetworkConfig,
+    block_reference: near_primitives::types::BlockReference,
+) -> crate::CliResult {
+    let contract_state =
+        super::get_contract_state(contract_account_id, prefix, network_config, block_reference)?;
+    let block_reference = near_primitives::types::BlockReference::BlockId(
+        near_primitives::types::BlockId::Hash(contract_state.block_hash),
+    );
+
+    let json_rpc_client = network_config.json_rpc_client();
+    let account_view = json_rpc_client
+        .blocking_call_view_account(contract_account_id, block_reference.clone())
+        .wrap_err_with(|| {
+            format!(
+                "Failed to fetch query ViewAccount for <{contract_account_id}> on network <{}>",
+                network_config.network_name
+            )
+        })?
+        .account_view()?;
+    let mut records = vec![near_primitives::state_record::StateRecord::Account {
+        account_id: contract_account_id.clone(),
+        account: (&account_view).into(),
+    }];
+
+    if account_view.code_hash != near_primitives::hash::CryptoHash::default() {
+        let view_code_response = json_rpc_client
+            .blocking_call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference,
+                request: near_primitives::views::QueryRequest::ViewCode {
+                    account_id: contract_account_id.clone(),
+                },
+            })
+            .wrap_err_with(|| {
+                format!(
+                    "Failed to fetch query ViewCode for <{contract_account_id}> on network <{}>",
+                    network_config.network_name
+                )
+            })?;
+        let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(contract_code_view) =
+            view_code_response.kind
+        else {
+            color_eyre::eyre::bail!(
+                "Internal error: Received unexpected query kind in response to a ViewCode query"
+            );
+        };
+        records.push(near_primitives::state_record::StateRecord::Contract {
+            account_id: contract_account_id.clone(),
+            code: contract_code_view.code,
+        });
+    }
+
+    let values_count = contract_state.values.len();
+    records.extend(contract_state.values.into_iter().map(|state_item| {
+        near_primitives::state_record::StateRecord::Data {
+            account_id: contract_account_id.clone(),
+            data_key: state_item.key,
+            value: state_item.value,
+        }
+    }));
+
+    super::super::super::restore_snapshot::write_snapshot(
+        snapshot_dir,
+        &super::super::super::restore_snapshot::SnapshotMetadata {
+            contract_account_id: contract_account_id.clone(),
+            network_name: network_config.network_name.clone(),
+            block_height: contract_state.block_height,
+            block_hash: contract_state.block_hash,
+        },
+        records,
+    )?;
+    eprintln!(
+        "\nThe snapshot of <{contract_account_id}> (account, contract code and {values_count} storage values) at block #{} ({}) was saved to {:?}",
+        contract_state.block_height,
+        contract_state.block_hash,
+        snapshot_dir
+    );
+    Ok(())
+}
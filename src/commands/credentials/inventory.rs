@@ -0,0 +1,495 @@
+//! The access keys saved by near CLI: the keychain, the legacy keychain and the password-encrypted
+//! keystore are the stores the signers read the keys from (the other signers get the key from
+//! the command line, a file, a Ledger device or a remote signer).
+use color_eyre::eyre::WrapErr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeyStore {
+    Keychain,
+    LegacyKeychain,
+    EncryptedKeystore,
+}
+
+impl std::fmt::Display for KeyStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Keychain => write!(f, "keychain"),
+            Self::LegacyKeychain => write!(f, "legacy keychain"),
+            Self::EncryptedKeystore => write!(f, "encrypted keystore"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct StoredKey {
+    pub network_name: String,
+    pub account_id: near_primitives::types::AccountId,
+    pub public_key: near_crypto::PublicKey,
+    pub store: KeyStore,
+    /// The file with the key (the keychain keeps the keys outside of the file system)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<std::path::PathBuf>,
+}
+
+impl StoredKey {
+    pub fn location(&self) -> String {
+        match &self.path {
+            Some(path) => path.display().to_string(),
+            None => format!("near-{}-{}", self.network_name, self.account_id),
+        }
+    }
+}
+
+impl std::fmt::Display for StoredKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} ({}: {})",
+            self.network_name,
+            self.public_key,
+            self.store,
+            self.location()
+        )
+    }
+}
+
+/// Narrows the search of the saved keys (and the number of the keychain lookups) to a network and
+/// an account.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KeyFilter<'a> {
+    pub network_name: Option<&'a str>,
+    pub account_id: Option<&'a near_primitives::types::AccountId>,
+}
+
+impl KeyFilter<'_> {
+    fn matches(&self, network_name: &str, account_id: &near_primitives::types::AccountId) -> bool {
+        self.network_name.map_or(true, |filter_network_name| {
+            filter_network_name == network_name
+        }) && self
+            .account_id
+            .map_or(true, |filter_account_id| filter_account_id == account_id)
+    }
+}
+
+/// The saved keys found by [`find_stored_keys`].
+#[derive(Debug, Clone, Default)]
+pub struct Inventory {
+    pub stored_keys: Vec<StoredKey>,
+    /// The keys of the keychain index that the keychain does not have (they were removed outside
+    /// of near CLI)
+    pub missing_keychain_entries: Vec<crate::common::KeychainEntry>,
+}
+
+/// Finds the keys of all the stores without changing anything. The keychain cannot be listed, so
+/// only the keys of the index of the keys near CLI has saved there are looked up in it.
+#[tracing::instrument(name = "Getting a list of the saved access keys ...", skip_all)]
+pub fn find_stored_keys(
+    credentials_home_dir: &std::path::Path,
+    key_filter: KeyFilter,
+) -> color_eyre::eyre::Result<Inventory> {
+    let mut inventory = Inventory {
+        stored_keys: find_file_stored_keys(credentials_home_dir)?
+            .into_iter()
+            .filter(|stored_key| {
+                key_filter.matches(&stored_key.network_name, &stored_key.account_id)
+            })
+            .collect(),
+        missing_keychain_entries: vec![],
+    };
+
+    for keychain_entry in crate::common::get_keychain_entry_list(credentials_home_dir)? {
+        if !key_filter.matches(&keychain_entry.network_name, &keychain_entry.account_id) {
+            continue;
+        }
+        let is_in_keychain = crate::common::is_access_key_in_keychain(
+            &keychain_entry.network_name,
+            &keychain_entry.public_key,
+            &keychain_entry.account_id,
+        )
+        .wrap_err_with(|| {
+            format!(
+                "Failed to check the access key {} of <{}> on network <{}> in the keychain",
+                keychain_entry.public_key, keychain_entry.account_id, keychain_entry.network_name
+            )
+        })?;
+        if !is_in_keychain {
+            inventory.missing_keychain_entries.push(keychain_entry);
+            continue;
+        }
+        inventory.stored_keys.push(StoredKey {
+            network_name: keychain_entry.network_name,
+            account_id: keychain_entry.account_id,
+            public_key: keychain_entry.public_key,
+            store: KeyStore::Keychain,
+            path: None,
+        });
+    }
+
+    inventory.stored_keys.sort_by(|a, b| {
+        (&a.network_name, &a.account_id, &a.public_key, a.store).cmp(&(
+            &b.network_name,
+            &b.account_id,
+            &b.public_key,
+            b.store,
+        ))
+    });
+    Ok(inventory)
+}
+
+/// Reports the keys of the keychain index that the keychain does not have.
+pub fn print_missing_keychain_entries(missing_keychain_entries: &[crate::common::KeychainEntry]) {
+    for keychain_entry in missing_keychain_entries {
+        eprintln!(
+            "Warning: the access key {} of <{}> on network <{}> is in the keychain index, but not in the keychain (it was removed outside of near CLI).",
+            keychain_entry.public_key, keychain_entry.account_id, keychain_entry.network_name
+        );
+    }
+    if !missing_keychain_entries.is_empty() {
+        eprintln!("Use `credentials list --repair` to remove them from the keychain index.");
+    }
+}
+
+/// Removes the keys that the keychain does not have from the keychain index.
+pub fn repair_keychain_index(
+    credentials_home_dir: &std::path::Path,
+    missing_keychain_entries: &[crate::common::KeychainEntry],
+) -> crate::CliResult {
+    for keychain_entry in missing_keychain_entries {
+        crate::common::remove_keychain_entry(credentials_home_dir, keychain_entry)?;
+    }
+    Ok(())
+}
+
+/// The keys of the legacy keychain and of the encrypted keystore (the stores kept in files).
+fn find_file_stored_keys(
+    credentials_home_dir: &std::path::Path,
+) -> color_eyre::eyre::Result<Vec<StoredKey>> {
+    let mut stored_keys = vec![];
+    for legacy_key_file in crate::common::find_legacy_key_files(credentials_home_dir)? {
+        match crate::common::get_public_key_from_legacy_key_file(&legacy_key_file.path) {
+            Ok(public_key) => stored_keys.push(StoredKey {
+                network_name: legacy_key_file.network_name,
+                account_id: legacy_key_file.account_id,
+                public_key,
+                store: KeyStore::LegacyKeychain,
+                path: Some(legacy_key_file.path),
+            }),
+            Err(err) => eprintln!(
+                "Warning: the file {:?} is skipped: {err:#}",
+                legacy_key_file.path
+            ),
+        }
+    }
+    for keystore_key in
+        crate::encrypted_keystore::EncryptedKeystore::new(credentials_home_dir).keys()?
+    {
+        stored_keys.push(StoredKey {
+            network_name: keystore_key.network_name,
+            account_id: keystore_key.account_id,
+            public_key: keystore_key.public_key,
+            store: KeyStore::EncryptedKeystore,
+            path: Some(keystore_key.path),
+        });
+    }
+    Ok(stored_keys)
+}
+
+pub fn remove_stored_key(
+    credentials_home_dir: &std::path::Path,
+    stored_key: &StoredKey,
+) -> crate::CliResult {
+    match (&stored_key.store, &stored_key.path) {
+        (KeyStore::Keychain, _) => {
+            crate::common::remove_access_key_from_keychain(
+                &stored_key.network_name,
+                credentials_home_dir,
+                &stored_key.public_key,
+                &stored_key.account_id,
+            )?;
+        }
+        (KeyStore::LegacyKeychain | KeyStore::EncryptedKeystore, Some(path)) => {
+            std::fs::remove_file(path)
+                .wrap_err_with(|| format!("Failed to remove the file {:?}", path))?;
+            if let Some(account_dir) = path
+                .parent()
+                .filter(|dir| dir.file_name() == Some(stored_key.account_id.as_str().as_ref()))
+            {
+                // Only succeeds for empty directories
+                let _ = std::fs::remove_dir(account_dir);
+            }
+        }
+        (KeyStore::LegacyKeychain | KeyStore::EncryptedKeystore, None) => {
+            unreachable!("The keys of the key files always have the path")
+        }
+    }
+    Ok(())
+}
+
+pub fn input_stored_account_id(
+    global_context: &crate::GlobalContext,
+    message: &str,
+) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+    let mut account_ids: Vec<near_primitives::types::AccountId> = find_stored_keys(
+        &global_context.config.credentials_home_dir,
+        KeyFilter::default(),
+    )?
+    .stored_keys
+    .into_iter()
+    .map(|stored_key| stored_key.account_id)
+    .collect();
+    account_ids.sort();
+    account_ids.dedup();
+    if account_ids.is_empty() {
+        color_eyre::eyre::bail!("There are no access keys saved by near CLI");
+    }
+    Ok(Some(
+        inquire::Select::new(message, account_ids).prompt()?.into(),
+    ))
+}
+
+/// Lets the user pick the keys to remove, all of them are selected by default.
+pub fn select_stored_keys(
+    message: &str,
+    stored_keys: Vec<StoredKey>,
+) -> color_eyre::eyre::Result<Vec<StoredKey>> {
+    let all_keys: Vec<usize> = (0..stored_keys.len()).collect();
+    Ok(inquire::MultiSelect::new(message, stored_keys)
+        .with_default(&all_keys)
+        .prompt()?)
+}
+
+pub fn print_stored_keys(stored_keys: &[StoredKey]) {
+    let mut table = prettytable::Table::new();
+    table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+    table.set_titles(
+        prettytable::row![Fy=>"Network", "Account ID", "Public Key", "Store", "Location"],
+    );
+    for stored_key in stored_keys {
+        table.add_row(prettytable::row![
+            stored_key.network_name,
+            stored_key.account_id,
+            stored_key.public_key,
+            stored_key.store,
+            stored_key.location(),
+        ]);
+    }
+    table.printstd();
+}
+
+/// Keeps `accounts.json` (the accounts suggested in the prompts) in sync with the stores once
+/// `removed_keys` are removed from `stored_keys` (all the saved keys of the affected accounts).
+pub fn sync_used_account_list(
+    credentials_home_dir: &std::path::Path,
+    stored_keys: &[StoredKey],
+    removed_keys: &[StoredKey],
+) -> crate::CliResult {
+    let remaining_keys: Vec<StoredKey> = stored_keys
+        .iter()
+        .filter(|stored_key| !removed_keys.contains(stored_key))
+        .cloned()
+        .collect();
+    let mut used_account_list = crate::common::get_used_account_list(credentials_home_dir);
+    if update_used_account_list(&mut used_account_list, &remaining_keys, removed_keys) {
+        crate::common::save_used_account_list(credentials_home_dir, &used_account_list)?;
+    }
+    Ok(())
+}
+
+/// The accounts with saved keys are suggested as signers, and the accounts whose keys were
+/// removed are no longer suggested as signers (unless they still have saved keys). Returns
+/// whether the list is changed.
+fn update_used_account_list(
+    used_account_list: &mut std::collections::VecDeque<crate::common::UsedAccount>,
+    remaining_keys: &[StoredKey],
+    removed_keys: &[StoredKey],
+) -> bool {
+    let has_stored_keys = |account_id: &near_primitives::types::AccountId| {
+        remaining_keys
+            .iter()
+            .any(|stored_key| &stored_key.account_id == account_id)
+    };
+
+    let mut is_changed = false;
+    for used_account in used_account_list.iter_mut() {
+        if used_account.used_as_signer
+            && !has_stored_keys(&used_account.account_id)
+            && removed_keys
+                .iter()
+                .any(|removed_key| removed_key.account_id == used_account.account_id)
+        {
+            used_account.used_as_signer = false;
+            is_changed = true;
+            eprintln!(
+                "<{}> has no saved access keys left, it is no longer suggested as a signer.",
+                used_account.account_id
+            );
+        }
+    }
+    for stored_key in remaining_keys {
+        match used_account_list
+            .iter_mut()
+            .find(|used_account| used_account.account_id == stored_key.account_id)
+        {
+            Some(used_account) if used_account.used_as_signer => {}
+            Some(used_account) => {
+                used_account.used_as_signer = true;
+                is_changed = true;
+            }
+            None => {
+                used_account_list.push_back(crate::common::UsedAccount {
+                    account_id: stored_key.account_id.clone(),
+                    used_as_signer: true,
+                });
+                is_changed = true;
+            }
+        }
+    }
+    is_changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUBLIC_KEY: &str = "ed25519:E1MTJoGHbV5qQD7QoeeeNPi2qgTJYCqe2bF4U3fopvLo";
+
+    fn stored_key(account_id: &str, store: KeyStore) -> StoredKey {
+        StoredKey {
+            network_name: "testnet".to_string(),
+            account_id: account_id.parse().unwrap(),
+            public_key: PUBLIC_KEY.parse().unwrap(),
+            store,
+            path: None,
+        }
+    }
+
+    fn used_account(account_id: &str, used_as_signer: bool) -> crate::common::UsedAccount {
+        crate::common::UsedAccount {
+            account_id: account_id.parse().unwrap(),
+            used_as_signer,
+        }
+    }
+
+    #[test]
+    fn key_filter_matches_network_and_account() {
+        let account_id: near_primitives::types::AccountId = "alice.testnet".parse().unwrap();
+        let other_account_id: near_primitives::types::AccountId = "bob.testnet".parse().unwrap();
+        assert!(KeyFilter::default().matches("mainnet", &other_account_id));
+        let key_filter = KeyFilter {
+            network_name: Some("testnet"),
+            account_id: Some(&account_id),
+        };
+        assert!(key_filter.matches("testnet", &account_id));
+        assert!(!key_filter.matches("mainnet", &account_id));
+        assert!(!key_filter.matches("testnet", &other_account_id));
+    }
+
+    #[test]
+    fn used_account_list_follows_removed_and_remaining_keys() {
+        let mut used_account_list: std::collections::VecDeque<_> = [
+            used_account("alice.testnet", true),
+            used_account("bob.testnet", true),
+            used_account("carol.testnet", false),
+            used_account("receiver.testnet", false),
+        ]
+        .into();
+        let remaining_keys = [
+            stored_key("bob.testnet", KeyStore::LegacyKeychain),
+            stored_key("carol.testnet", KeyStore::Keychain),
+            stored_key("dave.testnet", KeyStore::EncryptedKeystore),
+        ];
+        let removed_keys = [
+            stored_key("alice.testnet", KeyStore::Keychain),
+            stored_key("bob.testnet", KeyStore::Keychain),
+        ];
+
+        assert!(update_used_account_list(
+            &mut used_account_list,
+            &remaining_keys,
+            &removed_keys
+        ));
+        assert_eq!(
+            used_account_list.into_iter().collect::<Vec<_>>(),
+            vec![
+                used_account("alice.testnet", false),
+                used_account("bob.testnet", true),
+                used_account("carol.testnet", true),
+                used_account("receiver.testnet", false),
+                used_account("dave.testnet", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn used_account_list_is_unchanged_without_changes() {
+        let mut used_account_list: std::collections::VecDeque<_> =
+            [used_account("alice.testnet", true)].into();
+        assert!(!update_used_account_list(
+            &mut used_account_list,
+            &[stored_key("alice.testnet", KeyStore::Keychain)],
+            &[]
+        ));
+    }
+
+    #[test]
+    fn file_stored_keys_are_found_and_removed() {
+        let credentials_home_dir = std::env::temp_dir().join(format!(
+            "near-cli-rs-credentials-test-{}",
+            std::process::id()
+        ));
+        let account_dir = credentials_home_dir.join("testnet").join("alice.testnet");
+        std::fs::create_dir_all(&account_dir).unwrap();
+        let key_file_path = account_dir.join(format!("{}.json", PUBLIC_KEY.replace(':', "_")));
+        std::fs::write(
+            &key_file_path,
+            serde_json::json!({
+                "account_id": "alice.testnet",
+                "public_key": PUBLIC_KEY,
+                "private_key": "ed25519:3D4YudUahN1nawWogh8pAKSj92sUNMdbZGjn7kERKzYoTy8tnFQuwoGUC51DowKqorvkr2pytJSnwuSbsNVfqygr",
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let stored_keys = find_file_stored_keys(&credentials_home_dir).unwrap();
+        assert_eq!(
+            stored_keys,
+            vec![StoredKey {
+                path: Some(key_file_path.clone()),
+                ..stored_key("alice.testnet", KeyStore::LegacyKeychain)
+            }]
+        );
+        // Without the keychain index the keychain is not looked up
+        let inventory = find_stored_keys(
+            &credentials_home_dir,
+            KeyFilter {
+                network_name: Some("testnet"),
+                account_id: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(inventory.stored_keys, stored_keys);
+        assert!(inventory.missing_keychain_entries.is_empty());
+        assert!(find_stored_keys(
+            &credentials_home_dir,
+            KeyFilter {
+                network_name: Some("mainnet"),
+                account_id: None,
+            },
+        )
+        .unwrap()
+        .stored_keys
+        .is_empty());
+        assert!(!credentials_home_dir.join("keychain-entries.json").exists());
+
+        remove_stored_key(&credentials_home_dir, &stored_keys[0]).unwrap();
+        assert!(!key_file_path.exists());
+        assert!(!account_dir.exists());
+        assert!(find_file_stored_keys(&credentials_home_dir)
+            .unwrap()
+            .is_empty());
+
+        std::fs::remove_dir_all(&credentials_home_dir).unwrap();
+    }
+}
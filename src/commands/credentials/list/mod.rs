@@ -0,0 +1,107 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ListCommandContext)]
+pub struct ListCommand {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Only list the access keys of this network
+    network_name: Option<String>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Only list the access keys of this account
+    account_id: Option<crate::types::account_id::AccountId>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Remove the access keys that the keychain does not have from the keychain index
+    repair: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListCommandContext;
+
+impl ListCommandContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ListCommand as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let account_id: Option<near_primitives::types::AccountId> =
+            scope.account_id.clone().map(Into::into);
+        let credentials_home_dir = &previous_context.config.credentials_home_dir;
+        let super::inventory::Inventory {
+            stored_keys,
+            missing_keychain_entries,
+        } = super::inventory::find_stored_keys(
+            credentials_home_dir,
+            super::inventory::KeyFilter {
+                network_name: scope.network_name.as_deref(),
+                account_id: account_id.as_ref(),
+            },
+        )?;
+        if scope.repair {
+            super::inventory::repair_keychain_index(
+                credentials_home_dir,
+                &missing_keychain_entries,
+            )?;
+            for keychain_entry in &missing_keychain_entries {
+                eprintln!(
+                    "The access key {} of <{}> on network <{}> is removed from the keychain index.",
+                    keychain_entry.public_key,
+                    keychain_entry.account_id,
+                    keychain_entry.network_name
+                );
+            }
+        } else {
+            super::inventory::print_missing_keychain_entries(&missing_keychain_entries);
+        }
+        // The remote signers keep the keys outside of near CLI, they are only listed
+        let remote_signers: Vec<_> = previous_context
+            .config
+            .network_connection
+            .iter()
+            .filter(|(network_name, _)| {
+                scope
+                    .network_name
+                    .as_ref()
+                    .map_or(true, |name| &name == network_name)
+            })
+            .filter_map(|(network_name, network_config)| {
+                network_config
+                    .remote_signer_url
+                    .as_ref()
+                    .map(|url| (network_name, url))
+            })
+            .collect();
+
+        if previous_context.output_format.is_json() {
+            crate::common::print_json_output(
+                "credentials",
+                serde_json::json!({
+                    "keys": stored_keys,
+                    "missing_keychain_entries": missing_keychain_entries,
+                    "keychain_index_repaired": scope.repair,
+                    "remote_signers": remote_signers
+                        .iter()
+                        .map(|(network_name, url)| serde_json::json!({
+                            "network_name": network_name,
+                            "url": url,
+                        }))
+                        .collect::<Vec<_>>(),
+                }),
+            )?;
+            return Ok(Self);
+        }
+
+        if stored_keys.is_empty() {
+            eprintln!("\nThere are no access keys saved by near CLI.");
+        } else {
+            eprintln!();
+            super::inventory::print_stored_keys(&stored_keys);
+        }
+        for (network_name, url) in &remote_signers {
+            eprintln!(
+                "The network <{network_name}> uses the remote signer {url} (its keys are not listed)."
+            );
+        }
+        Ok(Self)
+    }
+}
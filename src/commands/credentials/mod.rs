@@ -0,0 +1,42 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+pub mod inventory;
+mod list;
+mod prune_deleted;
+mod remove;
+mod show;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+pub struct CredentialsCommands {
+    #[interactive_clap(subcommand)]
+    pub credentials_actions: CredentialsActions,
+}
+
+#[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[non_exhaustive]
+/// What do you want to do with the saved access keys?
+pub enum CredentialsActions {
+    #[strum_discriminants(strum(
+        message = "list                    - List the access keys saved in the keychain, the legacy keychain and the encrypted keystore"
+    ))]
+    /// List the access keys saved in the keychain, the legacy keychain and the encrypted keystore
+    List(self::list::ListCommand),
+    #[strum_discriminants(strum(
+        message = "show                    - Show the saved access keys of an account"
+    ))]
+    /// Show the saved access keys of an account
+    Show(self::show::ShowCommand),
+    #[strum_discriminants(strum(
+        message = "remove                  - Remove the saved access keys of an account"
+    ))]
+    /// Remove the saved access keys of an account
+    Remove(self::remove::RemoveCommand),
+    #[strum_discriminants(strum(
+        message = "prune-deleted           - Remove the saved access keys that no longer exist on-chain"
+    ))]
+    /// Remove the saved access keys that no longer exist on-chain
+    PruneDeleted(self::prune_deleted::PruneDeletedCommand),
+}
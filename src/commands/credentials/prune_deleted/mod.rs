@@ -0,0 +1,211 @@
+use crate::common::{JsonRpcClientExt, RpcQueryResponseExt};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = PruneDeletedCommandContext)]
+pub struct PruneDeletedCommand {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Only check the access keys of this network
+    network_name: Option<String>,
+    #[interactive_clap(long)]
+    /// Remove all the stale access keys without asking which ones
+    yes: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PruneDeletedCommandContext;
+
+impl PruneDeletedCommandContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<PruneDeletedCommand as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        if previous_context.offline {
+            color_eyre::eyre::bail!("The access keys cannot be checked on-chain in offline mode");
+        }
+        let credentials_home_dir = &previous_context.config.credentials_home_dir;
+        // All the keys are needed to tell whether the accounts still have saved keys
+        let stored_keys = super::inventory::find_stored_keys(
+            credentials_home_dir,
+            super::inventory::KeyFilter::default(),
+        )?
+        .stored_keys;
+        let checked_keys: Vec<_> = stored_keys
+            .iter()
+            .filter(|stored_key| {
+                scope.network_name.as_ref().map_or(true, |network_name| {
+                    &stored_key.network_name == network_name
+                })
+            })
+            .cloned()
+            .collect();
+        let stale_keys = find_stale_keys(&previous_context.config, &checked_keys);
+
+        if stale_keys.is_empty() {
+            if previous_context.output_format.is_json() {
+                crate::common::print_json_output(
+                    "removed_credentials",
+                    serde_json::json!({ "keys": stale_keys }),
+                )?;
+            } else {
+                eprintln!("\nAll the saved access keys exist on-chain.");
+            }
+            return Ok(Self);
+        }
+
+        let keys_to_remove = if scope.yes {
+            stale_keys
+        } else {
+            eprintln!("\nThe saved access keys that no longer exist on-chain:");
+            super::inventory::print_stored_keys(&stale_keys);
+            super::inventory::select_stored_keys(
+                "Which access keys do you want to remove?",
+                stale_keys,
+            )?
+        };
+        for stored_key in &keys_to_remove {
+            super::inventory::remove_stored_key(credentials_home_dir, stored_key)?;
+        }
+        super::inventory::sync_used_account_list(
+            credentials_home_dir,
+            &stored_keys,
+            &keys_to_remove,
+        )?;
+
+        if previous_context.output_format.is_json() {
+            crate::common::print_json_output(
+                "removed_credentials",
+                serde_json::json!({ "keys": keys_to_remove }),
+            )?;
+            return Ok(Self);
+        }
+        for stored_key in &keys_to_remove {
+            eprintln!(
+                "The access key {} of <{}> is removed from the {}.",
+                stored_key.public_key, stored_key.account_id, stored_key.store
+            );
+        }
+        Ok(Self)
+    }
+}
+
+/// A key is stale when its account does not exist or the account has no such key. The accounts
+/// that cannot be checked (e.g. the RPC server is unreachable) are skipped, their keys are kept.
+fn find_stale_keys(
+    config: &crate::config::Config,
+    stored_keys: &[super::inventory::StoredKey],
+) -> Vec<super::inventory::StoredKey> {
+    let mut accounts: Vec<(&str, &near_primitives::types::AccountId)> = stored_keys
+        .iter()
+        .map(|stored_key| (stored_key.network_name.as_str(), &stored_key.account_id))
+        .collect();
+    accounts.dedup();
+
+    let mut stale_keys = vec![];
+    for (network_name, account_id) in accounts {
+        let Some(network_config) = config.network_connection.get(network_name) else {
+            eprintln!(
+                "Warning: the access keys of <{account_id}> are not checked: the network <{network_name}> is not in the configuration file"
+            );
+            continue;
+        };
+        let account_keys: Vec<_> = stored_keys
+            .iter()
+            .filter(|stored_key| {
+                stored_key.network_name == network_name && &stored_key.account_id == account_id
+            })
+            .cloned()
+            .collect();
+        match network_config
+            .json_rpc_client()
+            .blocking_call_view_access_key_list(
+                account_id,
+                near_primitives::types::Finality::Final.into(),
+            ) {
+            Ok(rpc_query_response) => match rpc_query_response.access_key_list_view() {
+                Ok(access_key_list) => stale_keys.extend(get_stale_account_keys(
+                    account_keys,
+                    Some(&access_key_list.keys),
+                )),
+                Err(err) => {
+                    eprintln!(
+                        "Warning: the access keys of <{account_id}> are not checked: {err:#}"
+                    );
+                }
+            },
+            Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                    near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount { .. },
+                ),
+            )) => stale_keys.extend(get_stale_account_keys(account_keys, None)),
+            Err(err) => {
+                eprintln!(
+                    "Warning: the access keys of <{account_id}> are not checked on network <{network_name}>: {err}"
+                );
+            }
+        }
+    }
+    stale_keys
+}
+
+/// The saved keys of an account that are not among its on-chain keys (`None` if the account does
+/// not exist).
+fn get_stale_account_keys(
+    account_keys: Vec<super::inventory::StoredKey>,
+    on_chain_keys: Option<&[near_primitives::views::AccessKeyInfoView]>,
+) -> Vec<super::inventory::StoredKey> {
+    account_keys
+        .into_iter()
+        .filter(|stored_key| {
+            !on_chain_keys.is_some_and(|on_chain_keys| {
+                on_chain_keys
+                    .iter()
+                    .any(|access_key| access_key.public_key == stored_key.public_key)
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stored_key(public_key: &str) -> super::super::inventory::StoredKey {
+        super::super::inventory::StoredKey {
+            network_name: "testnet".to_string(),
+            account_id: "alice.testnet".parse().unwrap(),
+            public_key: public_key.parse().unwrap(),
+            store: super::super::inventory::KeyStore::Keychain,
+            path: None,
+        }
+    }
+
+    #[test]
+    fn stale_keys_are_the_keys_missing_on_chain() {
+        let existing_key = "ed25519:E1MTJoGHbV5qQD7QoeeeNPi2qgTJYCqe2bF4U3fopvLo";
+        let deleted_key = "ed25519:8h7kFK4quSUJRkUwo3LLiK83sraEm2jnQTECuZhWu8HC";
+        let account_keys = vec![stored_key(existing_key), stored_key(deleted_key)];
+        let on_chain_keys = [near_primitives::views::AccessKeyInfoView {
+            public_key: existing_key.parse().unwrap(),
+            access_key: near_primitives::views::AccessKeyView {
+                nonce: 0,
+                permission: near_primitives::views::AccessKeyPermissionView::FullAccess,
+            },
+        }];
+
+        assert_eq!(
+            get_stale_account_keys(account_keys.clone(), Some(&on_chain_keys)),
+            vec![stored_key(deleted_key)]
+        );
+        assert_eq!(
+            get_stale_account_keys(account_keys.clone(), Some(&[])),
+            account_keys
+        );
+        // All the keys of an account that does not exist are stale
+        assert_eq!(
+            get_stale_account_keys(account_keys.clone(), None),
+            account_keys
+        );
+    }
+}
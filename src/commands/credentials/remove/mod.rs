@@ -0,0 +1,103 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = RemoveCommandContext)]
+pub struct RemoveCommand {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What account do you want to remove the saved access keys for?
+    account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Only remove this access key
+    public_key: Option<crate::types::public_key::PublicKey>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Only remove the access keys of this network
+    network_name: Option<String>,
+    #[interactive_clap(long)]
+    /// Remove all the matching access keys without asking for confirmation
+    all: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoveCommandContext;
+
+impl RemoveCommandContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<RemoveCommand as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let credentials_home_dir = &previous_context.config.credentials_home_dir;
+        let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+        let public_key: Option<near_crypto::PublicKey> = scope.public_key.clone().map(Into::into);
+        // All the keys of the account are needed to tell whether it still has saved keys
+        let stored_keys = super::inventory::find_stored_keys(
+            credentials_home_dir,
+            super::inventory::KeyFilter {
+                network_name: None,
+                account_id: Some(&account_id),
+            },
+        )?
+        .stored_keys;
+        let matching_keys: Vec<_> = stored_keys
+            .iter()
+            .filter(|stored_key| {
+                public_key
+                    .as_ref()
+                    .map_or(true, |public_key| &stored_key.public_key == public_key)
+                    && scope.network_name.as_ref().map_or(true, |network_name| {
+                        &stored_key.network_name == network_name
+                    })
+            })
+            .cloned()
+            .collect();
+        if matching_keys.is_empty() {
+            color_eyre::eyre::bail!(
+                "There are no matching access keys of <{account_id}> saved by near CLI"
+            );
+        }
+
+        // The secret keys are deleted, so they are only removed without asking with `--all`
+        let keys_to_remove = if scope.all {
+            matching_keys
+        } else {
+            super::inventory::select_stored_keys(
+                &format!("Which access keys of <{account_id}> do you want to remove?"),
+                matching_keys,
+            )?
+        };
+        for stored_key in &keys_to_remove {
+            super::inventory::remove_stored_key(credentials_home_dir, stored_key)?;
+        }
+        super::inventory::sync_used_account_list(
+            credentials_home_dir,
+            &stored_keys,
+            &keys_to_remove,
+        )?;
+
+        if previous_context.output_format.is_json() {
+            crate::common::print_json_output(
+                "removed_credentials",
+                serde_json::json!({ "keys": keys_to_remove }),
+            )?;
+            return Ok(Self);
+        }
+        for stored_key in &keys_to_remove {
+            eprintln!(
+                "The access key {} of <{}> is removed from the {}.",
+                stored_key.public_key, stored_key.account_id, stored_key.store
+            );
+        }
+        Ok(Self)
+    }
+}
+
+impl RemoveCommand {
+    pub fn input_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        super::inventory::input_stored_account_id(
+            context,
+            "What account do you want to remove the saved access keys for?",
+        )
+    }
+}
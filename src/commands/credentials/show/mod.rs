@@ -0,0 +1,89 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ShowCommandContext)]
+pub struct ShowCommand {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What account do you want to show the saved access keys for?
+    account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Only show the access keys of this network
+    network_name: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ShowCommandContext;
+
+impl ShowCommandContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ShowCommand as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let credentials_home_dir = &previous_context.config.credentials_home_dir;
+        let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+        let super::inventory::Inventory {
+            stored_keys,
+            missing_keychain_entries,
+        } = super::inventory::find_stored_keys(
+            credentials_home_dir,
+            super::inventory::KeyFilter {
+                network_name: scope.network_name.as_deref(),
+                account_id: Some(&account_id),
+            },
+        )?;
+        super::inventory::print_missing_keychain_entries(&missing_keychain_entries);
+        let used_as_signer = crate::common::get_used_account_list(credentials_home_dir)
+            .iter()
+            .any(|used_account| {
+                used_account.account_id == account_id && used_account.used_as_signer
+            });
+
+        if previous_context.output_format.is_json() {
+            crate::common::print_json_output(
+                "credential_details",
+                serde_json::json!({
+                    "account_id": account_id,
+                    "keys": stored_keys,
+                    "missing_keychain_entries": missing_keychain_entries,
+                    "used_as_signer": used_as_signer,
+                }),
+            )?;
+            return Ok(Self);
+        }
+
+        if stored_keys.is_empty() {
+            eprintln!("\nThere are no access keys of <{account_id}> saved by near CLI.");
+        } else {
+            let mut table = prettytable::Table::new();
+            table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+            table.set_titles(prettytable::row![Fy=>"Network", "Public Key", "Store", "Location"]);
+            for stored_key in &stored_keys {
+                table.add_row(prettytable::row![
+                    stored_key.network_name,
+                    stored_key.public_key,
+                    stored_key.store,
+                    stored_key.location(),
+                ]);
+            }
+            eprintln!("\nThe access keys of <{account_id}> saved by near CLI:");
+            table.printstd();
+        }
+        if used_as_signer {
+            eprintln!("<{account_id}> is suggested as a signer.");
+        } else {
+            eprintln!("<{account_id}> is not suggested as a signer.");
+        }
+        Ok(Self)
+    }
+}
+
+impl ShowCommand {
+    pub fn input_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        super::inventory::input_stored_account_id(
+            context,
+            "What account do you want to show the saved access keys for?",
+        )
+    }
+}
@@ -0,0 +1,82 @@
+use color_eyre::eyre::ContextCompat;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = InstallCommandContext)]
+pub struct InstallCommand {
+    /// What extension do you want to install (a name from the registry, e.g. "validator", or a git URL)?
+    extension: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// The URL or the path of the extensions registry index (by default, the one bundled with the CLI)
+    registry: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InstallCommandContext;
+
+impl InstallCommandContext {
+    pub fn from_previous_context(
+        _previous_context: crate::GlobalContext,
+        scope: &<InstallCommand as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let extensions_dir = super::registry::get_extensions_dir()?;
+        let mut installed_extensions = super::registry::InstalledExtensions::load(&extensions_dir)?;
+
+        if is_git_url(&scope.extension) {
+            let installed_from_git =
+                super::registry::install_from_git(&scope.extension, &extensions_dir)?;
+            if installed_from_git.is_empty() {
+                color_eyre::eyre::bail!(
+                    "The repository <{}> does not contain new or updated `near-*` executables",
+                    scope.extension
+                );
+            }
+            for (name, installed_extension) in installed_from_git {
+                eprintln!(
+                    "The extension `near {name}` is installed from <{}>.",
+                    scope.extension
+                );
+                installed_extensions
+                    .extensions
+                    .insert(name, installed_extension);
+            }
+        } else {
+            let registry = scope
+                .registry
+                .as_deref()
+                .unwrap_or(super::registry::BUNDLED_REGISTRY);
+            let name = super::registry::extension_name(&scope.extension);
+            let registry_index = super::registry::Registry::load(registry)?;
+            let entry = registry_index.find(name).wrap_err_with(|| {
+                format!(
+                    "There is no <{name}> extension in the registry (available extensions: {})",
+                    registry_index
+                        .extensions
+                        .iter()
+                        .map(|entry| entry.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })?;
+            let installed_extension =
+                super::registry::install_from_registry(entry, registry, &extensions_dir)?;
+            eprintln!(
+                "The extension `near {name}` v{} is installed.",
+                entry.version
+            );
+            installed_extensions
+                .extensions
+                .insert(name.to_string(), installed_extension);
+        }
+
+        installed_extensions.save(&extensions_dir)?;
+        Ok(Self)
+    }
+}
+
+fn is_git_url(extension: &str) -> bool {
+    ["https://", "http://", "ssh://", "git://", "git@", "file://"]
+        .iter()
+        .any(|scheme| extension.starts_with(scheme))
+}
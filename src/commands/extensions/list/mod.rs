@@ -0,0 +1,133 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ListCommandContext)]
+pub struct ListCommand;
+
+#[derive(Debug, Clone)]
+pub struct ListCommandContext;
+
+impl ListCommandContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        _scope: &<ListCommand as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let extensions = get_extensions()?;
+
+        if previous_context.output_format.is_json() {
+            crate::common::print_json_output(
+                "extensions",
+                serde_json::json!({ "extensions": extensions }),
+            )?;
+            return Ok(Self);
+        }
+
+        if extensions.is_empty() {
+            eprintln!("\nThere are no extensions installed (see `near extension install`).");
+            return Ok(Self);
+        }
+        let mut table = prettytable::Table::new();
+        table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+        table.set_titles(prettytable::row![Fy=>"Extension", "Version", "Installed from", "Path"]);
+        for extension in &extensions {
+            let source = if extension.is_modified {
+                format!("{} (modified since installation)", extension.source)
+            } else {
+                extension.source.clone()
+            };
+            table.add_row(prettytable::row![
+                format!("near {}", extension.name),
+                extension.version.as_deref().unwrap_or("unknown"),
+                source,
+                extension.path.display(),
+            ]);
+        }
+        eprintln!();
+        table.printstd();
+        Ok(Self)
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct Extension {
+    name: String,
+    version: Option<String>,
+    source: String,
+    path: std::path::PathBuf,
+    is_modified: bool,
+}
+
+/// Lists the extensions the same way the dispatcher finds them: the managed ones first, then the
+/// ones on `PATH` (an extension shadowed by an earlier one is not shown).
+#[tracing::instrument(name = "Getting a list of extensions ...", skip_all)]
+fn get_extensions() -> color_eyre::eyre::Result<Vec<Extension>> {
+    let extensions_dir = super::registry::get_extensions_dir()?;
+    let installed_extensions = super::registry::InstalledExtensions::load(&extensions_dir)?;
+    let managed_bin_dir = extensions_dir.join("bin");
+
+    let mut extensions: std::collections::BTreeMap<String, Extension> = Default::default();
+    for dir in crate::common::path_directories() {
+        let Ok(read_dir) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for path in
+            read_dir.filter_map(|dir_entry| dir_entry.ok().map(|dir_entry| dir_entry.path()))
+        {
+            let Some(name) = path
+                .file_name()
+                .and_then(|file_name| file_name.to_str())
+                .and_then(|file_name| file_name.strip_suffix(std::env::consts::EXE_SUFFIX))
+                .and_then(|file_name| file_name.strip_prefix("near-"))
+            else {
+                continue;
+            };
+            if extensions.contains_key(name) || !crate::common::is_executable(&path) {
+                continue;
+            }
+            let installed_extension = installed_extensions
+                .extensions
+                .get(name)
+                .filter(|_| dir == managed_bin_dir);
+            let (source, is_modified) = match installed_extension {
+                Some(installed_extension) => (
+                    installed_extension.source.to_string(),
+                    super::registry::file_sha256_hex(&path)? != installed_extension.sha256,
+                ),
+                None if dir == managed_bin_dir => ("unknown".to_string(), false),
+                None => ("PATH".to_string(), false),
+            };
+            let version = get_version(&path).or_else(|| {
+                installed_extension
+                    .and_then(|installed_extension| installed_extension.version.clone())
+            });
+            extensions.insert(
+                name.to_string(),
+                Extension {
+                    name: name.to_string(),
+                    version,
+                    source,
+                    path,
+                    is_modified,
+                },
+            );
+        }
+    }
+    Ok(extensions.into_values().collect())
+}
+
+/// Extensions are expected to print their version as the last word of `--version`, as clap does.
+fn get_version(path: &std::path::Path) -> Option<String> {
+    let output = std::process::Command::new(path)
+        .arg("--version")
+        .stdin(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout)
+        .ok()?
+        .split_whitespace()
+        .last()
+        .map(str::to_string)
+}
@@ -1,6 +1,11 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
+mod install;
+mod list;
+mod registry;
 pub mod self_update;
+mod uninstall;
+mod update;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(context = crate::GlobalContext)]
@@ -18,4 +23,24 @@ pub enum ExtensionsActions {
     #[strum_discriminants(strum(message = "self-update             - Self update near CLI"))]
     /// Self update near CLI
     SelfUpdate(self::self_update::SelfUpdateCommand),
+    #[strum_discriminants(strum(
+        message = "install                 - Install an extension from the registry or a git repository"
+    ))]
+    /// Install an extension from the registry or a git repository
+    Install(self::install::InstallCommand),
+    #[strum_discriminants(strum(
+        message = "list                    - List the installed extensions with their versions"
+    ))]
+    /// List the installed extensions with their versions
+    List(self::list::ListCommand),
+    #[strum_discriminants(strum(
+        message = "update                  - Update the extensions installed with `extension install`"
+    ))]
+    /// Update the extensions installed with `extension install`
+    Update(self::update::UpdateCommand),
+    #[strum_discriminants(strum(
+        message = "uninstall               - Uninstall an extension installed with `extension install`"
+    ))]
+    /// Uninstall an extension installed with `extension install`
+    Uninstall(self::uninstall::UninstallCommand),
 }
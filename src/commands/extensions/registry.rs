@@ -0,0 +1,492 @@
+//! Extensions are `near-<name>` executables that `near <name> ...` dispatches to. The extensions
+//! managed by the CLI live in [`crate::common::get_extensions_dir`]: the executables in `bin/`
+//! (searched before `PATH`) and their sources and checksums in `installed.json`.
+//!
+//! The registry index is a JSON file given by a URL or a path (by default, [`BUNDLED_REGISTRY`]):
+//!
+//! ```json
+//! {
+//!   "extensions": [
+//!     {
+//!       "name": "validator",
+//!       "description": "Interact with NEAR Protocol validators",
+//!       "version": "0.1.0",
+//!       "git": "https://github.com/...",
+//!       "rev": "<the full hash of the git commit to build>",
+//!       "bin": "<the executable built from git, if it is not `near-<name>`>",
+//!       "binaries": {
+//!         "x86_64-unknown-linux-gnu": {
+//!           "url": "https://.../near-validator-x86_64-unknown-linux-gnu.tar.gz",
+//!           "sha256": "<hex of the downloaded file>",
+//!           "bin_path_in_archive": "near-validator-x86_64-unknown-linux-gnu/near-validator"
+//!         }
+//!       }
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! A prebuilt binary for the current target is preferred; its download is verified against the
+//! `sha256` checksum. Otherwise the extension is built from the `git` source at the pinned `rev`
+//! with `cargo install`. Entries that are not pinned this way are refused.
+//!
+//! The index of this repository ([extensions/registry.json](../extensions/registry.json)) is
+//! bundled into the CLI, so the default registry changes only with a new release of the CLI.
+use color_eyre::eyre::{ContextCompat, WrapErr};
+
+/// The name of the registry index bundled with the CLI (the default one).
+pub const BUNDLED_REGISTRY: &str = "bundled";
+
+const BUNDLED_REGISTRY_JSON: &str = include_str!("../../../extensions/registry.json");
+
+const INSTALLED_EXTENSIONS_FILE_NAME: &str = "installed.json";
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Registry {
+    pub extensions: Vec<RegistryEntry>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RegistryEntry {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git: Option<String>,
+    /// The full hash of the git commit to build from (required with `git`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rev: Option<String>,
+    /// The executable built from the git source, if it is not `near-<name>`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bin: Option<String>,
+    /// Prebuilt binaries by target triple
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub binaries: std::collections::BTreeMap<String, Binary>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Binary {
+    pub url: url::Url,
+    pub sha256: String,
+    /// The path of the executable in a `.tar.gz` archive; the download is the executable itself
+    /// if it is not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bin_path_in_archive: Option<String>,
+}
+
+impl Registry {
+    /// Loads the registry index bundled with the CLI, or from a URL or a local file.
+    #[tracing::instrument(name = "Getting the extensions registry ...", skip_all)]
+    pub fn load(registry: &str) -> color_eyre::eyre::Result<Self> {
+        if registry == BUNDLED_REGISTRY {
+            Self::bundled()
+        } else if registry.starts_with("https://") || registry.starts_with("http://") {
+            reqwest::blocking::get(registry)
+                .and_then(|response| response.error_for_status())
+                .and_then(|response| response.json())
+                .wrap_err_with(|| {
+                    format!("Failed to get the extensions registry from <{registry}>")
+                })
+        } else {
+            let registry_json = std::fs::read_to_string(registry)
+                .wrap_err_with(|| format!("Failed to read the extensions registry {registry:?}"))?;
+            serde_json::from_str(&registry_json)
+                .wrap_err_with(|| format!("Failed to parse the extensions registry {registry:?}"))
+        }
+    }
+
+    /// The registry index shipped with this version of the CLI.
+    pub fn bundled() -> color_eyre::eyre::Result<Self> {
+        serde_json::from_str(BUNDLED_REGISTRY_JSON)
+            .wrap_err("Failed to parse the extensions registry bundled with this CLI")
+    }
+
+    pub fn find(&self, name: &str) -> Option<&RegistryEntry> {
+        self.extensions.iter().find(|entry| entry.name == name)
+    }
+}
+
+impl RegistryEntry {
+    /// Checks that the entry cannot change under the user: the git source is pinned to a commit
+    /// and the binaries have valid checksums.
+    pub fn check_pinned(&self) -> crate::CliResult {
+        if self.git.is_some() {
+            match &self.rev {
+                Some(rev) if is_hex_of_len(rev, 40) => {}
+                Some(rev) => color_eyre::eyre::bail!(
+                    "The <{}> extension is pinned to {rev:?} in the registry, but only full git commit hashes are accepted",
+                    self.name
+                ),
+                None => color_eyre::eyre::bail!(
+                    "The <{}> extension is not pinned to a git commit (`rev`) in the registry",
+                    self.name
+                ),
+            }
+        }
+        for (target, binary) in &self.binaries {
+            if !is_hex_of_len(&binary.sha256, 64) {
+                color_eyre::eyre::bail!(
+                    "The {target} binary of the <{}> extension has no valid SHA-256 checksum in the registry",
+                    self.name
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_hex_of_len(value: &str, len: usize) -> bool {
+    value.len() == len && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Source {
+    Registry { registry: String },
+    Git { url: String },
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Registry { registry } => write!(f, "registry ({registry})"),
+            Self::Git { url } => write!(f, "git ({url})"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct InstalledExtension {
+    pub source: Source,
+    /// The version from the registry, if the extension was installed from it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// The checksum of the installed executable
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct InstalledExtensions {
+    #[serde(default)]
+    pub extensions: std::collections::BTreeMap<String, InstalledExtension>,
+}
+
+impl InstalledExtensions {
+    pub fn load(extensions_dir: &std::path::Path) -> color_eyre::eyre::Result<Self> {
+        let path = extensions_dir.join(INSTALLED_EXTENSIONS_FILE_NAME);
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+        let installed_extensions_json = std::fs::read_to_string(&path)
+            .wrap_err_with(|| format!("Failed to read the file: {path:?}"))?;
+        serde_json::from_str(&installed_extensions_json)
+            .wrap_err_with(|| format!("Failed to parse the file: {path:?}"))
+    }
+
+    pub fn save(&self, extensions_dir: &std::path::Path) -> crate::CliResult {
+        std::fs::create_dir_all(extensions_dir)
+            .wrap_err_with(|| format!("Failed to create the directory: {extensions_dir:?}"))?;
+        let path = extensions_dir.join(INSTALLED_EXTENSIONS_FILE_NAME);
+        std::fs::write(&path, serde_json::to_vec_pretty(self)?)
+            .wrap_err_with(|| format!("Failed to write to file: {path:?}"))
+    }
+}
+
+pub fn get_extensions_dir() -> color_eyre::eyre::Result<std::path::PathBuf> {
+    crate::common::get_extensions_dir().wrap_err("Impossible to get your data dir!")
+}
+
+/// `near-validator` and `validator` both refer to the `validator` extension.
+pub fn extension_name(name: &str) -> &str {
+    name.strip_prefix("near-").unwrap_or(name)
+}
+
+pub fn executable_name(name: &str) -> String {
+    format!("near-{}{}", name, std::env::consts::EXE_SUFFIX)
+}
+
+pub fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(ring::digest::digest(&ring::digest::SHA256, data))
+}
+
+pub fn file_sha256_hex(path: &std::path::Path) -> color_eyre::eyre::Result<String> {
+    Ok(sha256_hex(&std::fs::read(path).wrap_err_with(|| {
+        format!("Failed to read the file: {path:?}")
+    })?))
+}
+
+/// Installs the extension from the registry entry and returns what has to be recorded about it.
+pub fn install_from_registry(
+    entry: &RegistryEntry,
+    registry: &str,
+    extensions_dir: &std::path::Path,
+) -> color_eyre::eyre::Result<InstalledExtension> {
+    entry.check_pinned()?;
+    let bin_dir = extensions_dir.join("bin");
+    std::fs::create_dir_all(&bin_dir)
+        .wrap_err_with(|| format!("Failed to create the directory: {bin_dir:?}"))?;
+    let executable_path = bin_dir.join(executable_name(&entry.name));
+
+    if let Some(binary) = entry.binaries.get(self_update::get_target()) {
+        download_binary(binary, &executable_path)?;
+    } else if let Some(git) = &entry.git {
+        let bin = entry
+            .bin
+            .clone()
+            .unwrap_or_else(|| format!("near-{}", entry.name));
+        cargo_install(git, entry.rev.as_deref(), Some(&bin), extensions_dir)?;
+        let bin_path = bin_dir.join(format!("{bin}{}", std::env::consts::EXE_SUFFIX));
+        if bin_path != executable_path {
+            std::fs::rename(&bin_path, &executable_path)
+                .wrap_err_with(|| format!("Failed to move {bin_path:?} to {executable_path:?}"))?;
+        }
+    } else {
+        color_eyre::eyre::bail!(
+            "There is neither a binary for {} nor a git source for the <{}> extension in the registry",
+            self_update::get_target(),
+            entry.name
+        );
+    }
+
+    Ok(InstalledExtension {
+        source: Source::Registry {
+            registry: registry.to_string(),
+        },
+        version: Some(entry.version.clone()),
+        sha256: file_sha256_hex(&executable_path)?,
+    })
+}
+
+/// Builds the `near-*` executables of the git repository and returns the ones that are new or
+/// have changed.
+pub fn install_from_git(
+    url: &str,
+    extensions_dir: &std::path::Path,
+) -> color_eyre::eyre::Result<Vec<(String, InstalledExtension)>> {
+    let bin_dir = extensions_dir.join("bin");
+    let checksums_before = executables_checksums(&bin_dir)?;
+    cargo_install(url, None, None, extensions_dir)?;
+    Ok(executables_checksums(&bin_dir)?
+        .into_iter()
+        .filter(|(name, sha256)| checksums_before.get(name) != Some(sha256))
+        .map(|(name, sha256)| {
+            (
+                name,
+                InstalledExtension {
+                    source: Source::Git {
+                        url: url.to_string(),
+                    },
+                    version: None,
+                    sha256,
+                },
+            )
+        })
+        .collect())
+}
+
+/// The checksums of the `near-*` executables in the directory by extension name.
+pub fn executables_checksums(
+    bin_dir: &std::path::Path,
+) -> color_eyre::eyre::Result<std::collections::BTreeMap<String, String>> {
+    let mut checksums = std::collections::BTreeMap::new();
+    if !bin_dir.is_dir() {
+        return Ok(checksums);
+    }
+    for dir_entry in std::fs::read_dir(bin_dir)
+        .wrap_err_with(|| format!("Failed to read the directory: {bin_dir:?}"))?
+    {
+        let path = dir_entry?.path();
+        let Some(name) = path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .and_then(|file_name| file_name.strip_suffix(std::env::consts::EXE_SUFFIX))
+            .and_then(|file_name| file_name.strip_prefix("near-"))
+        else {
+            continue;
+        };
+        if crate::common::is_executable(&path) {
+            checksums.insert(name.to_string(), file_sha256_hex(&path)?);
+        }
+    }
+    Ok(checksums)
+}
+
+#[tracing::instrument(name = "Downloading the extension ...", skip_all)]
+fn download_binary(binary: &Binary, executable_path: &std::path::Path) -> crate::CliResult {
+    let download = reqwest::blocking::get(binary.url.clone())
+        .and_then(|response| response.error_for_status())
+        .and_then(|response| response.bytes())
+        .wrap_err_with(|| format!("Failed to download <{}>", binary.url))?;
+    let sha256 = sha256_hex(&download);
+    if !sha256.eq_ignore_ascii_case(&binary.sha256) {
+        color_eyre::eyre::bail!(
+            "The checksum of <{}> does not match the registry: expected {}, got {}",
+            binary.url,
+            binary.sha256,
+            sha256
+        );
+    }
+
+    let executable = if let Some(bin_path_in_archive) = &binary.bin_path_in_archive {
+        let tmp_dir = self_update::TempDir::new()?;
+        let archive_path = tmp_dir.path().join("extension.tar.gz");
+        std::fs::write(&archive_path, &download)?;
+        self_update::Extract::from_source(&archive_path)
+            .archive(self_update::ArchiveKind::Tar(Some(
+                self_update::Compression::Gz,
+            )))
+            .extract_file(tmp_dir.path(), bin_path_in_archive)
+            .wrap_err_with(|| {
+                format!(
+                    "Failed to extract {bin_path_in_archive:?} from <{}>",
+                    binary.url
+                )
+            })?;
+        std::fs::read(tmp_dir.path().join(bin_path_in_archive))?
+    } else {
+        download.to_vec()
+    };
+
+    std::fs::write(executable_path, executable)
+        .wrap_err_with(|| format!("Failed to write to file: {executable_path:?}"))?;
+    #[cfg(target_family = "unix")]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(executable_path, std::fs::Permissions::from_mode(0o755))?;
+    }
+    Ok(())
+}
+
+fn cargo_install(
+    git: &str,
+    rev: Option<&str>,
+    bin: Option<&str>,
+    extensions_dir: &std::path::Path,
+) -> crate::CliResult {
+    let mut cargo = cargo_util::ProcessBuilder::new("cargo");
+    cargo.args(&["install", "--locked", "--force", "--git", git]);
+    if let Some(rev) = rev {
+        cargo.args(&["--rev", rev]);
+    }
+    if let Some(bin) = bin {
+        cargo.args(&["--bin", bin]);
+    }
+    cargo.arg("--root").arg(extensions_dir);
+    cargo.exec().map_err(|err| {
+        color_eyre::eyre::eyre!(
+            "Failed to build the extension from <{git}> (is cargo installed?): {err:#}"
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_and_installed_extensions() {
+        let registry: Registry = serde_json::from_value(serde_json::json!({
+            "extensions": [
+                {
+                    "name": "validator",
+                    "version": "0.1.0",
+                    "binaries": {
+                        "x86_64-unknown-linux-gnu": {
+                            "url": "https://example.com/near-validator",
+                            "sha256": sha256_hex(b"near-validator"),
+                        }
+                    }
+                },
+                {
+                    "name": "social",
+                    "version": "0.2.0",
+                    "git": "https://example.com/near-social.git",
+                    "rev": "0123456789abcdef0123456789abcdef01234567"
+                }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(
+            sha256_hex(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            registry
+                .find(extension_name("near-validator"))
+                .unwrap()
+                .version,
+            "0.1.0"
+        );
+        assert!(registry.find("social").unwrap().binaries.is_empty());
+        assert!(registry.find("unknown").is_none());
+        for entry in &registry.extensions {
+            entry.check_pinned().unwrap();
+        }
+
+        let extensions_dir = self_update::TempDir::new().unwrap();
+        assert_eq!(
+            InstalledExtensions::load(extensions_dir.path()).unwrap(),
+            InstalledExtensions::default()
+        );
+        let mut installed_extensions = InstalledExtensions::default();
+        installed_extensions.extensions.insert(
+            "social".to_string(),
+            InstalledExtension {
+                source: Source::Git {
+                    url: "https://example.com/near-social.git".to_string(),
+                },
+                version: None,
+                sha256: sha256_hex(b""),
+            },
+        );
+        installed_extensions.save(extensions_dir.path()).unwrap();
+        assert_eq!(
+            InstalledExtensions::load(extensions_dir.path()).unwrap(),
+            installed_extensions
+        );
+    }
+
+    #[test]
+    fn unpinned_registry_entries_are_refused() {
+        let entry: RegistryEntry = serde_json::from_value(serde_json::json!({
+            "name": "social",
+            "version": "0.2.0",
+            "git": "https://example.com/near-social.git"
+        }))
+        .unwrap();
+        assert!(entry.check_pinned().is_err());
+
+        let entry = RegistryEntry {
+            rev: Some("main".to_string()),
+            ..entry
+        };
+        assert!(entry.check_pinned().is_err());
+
+        let mut entry = RegistryEntry {
+            rev: Some("0123456789abcdef0123456789abcdef01234567".to_string()),
+            ..entry
+        };
+        entry.check_pinned().unwrap();
+
+        entry.binaries.insert(
+            "x86_64-unknown-linux-gnu".to_string(),
+            Binary {
+                url: "https://example.com/near-social".parse().unwrap(),
+                sha256: "".to_string(),
+                bin_path_in_archive: None,
+            },
+        );
+        assert!(entry.check_pinned().is_err());
+    }
+
+    #[test]
+    fn bundled_registry_is_valid() {
+        let registry = Registry::load(BUNDLED_REGISTRY).unwrap();
+        assert!(registry.find("validator").is_some());
+        assert_eq!(registry.find("social").unwrap().bin.as_deref(), Some("bos"));
+        for entry in &registry.extensions {
+            entry.check_pinned().unwrap();
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use color_eyre::eyre::WrapErr;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = UninstallCommandContext)]
+pub struct UninstallCommand {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What extension do you want to uninstall?
+    extension: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct UninstallCommandContext;
+
+impl UninstallCommandContext {
+    pub fn from_previous_context(
+        _previous_context: crate::GlobalContext,
+        scope: &<UninstallCommand as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let extensions_dir = super::registry::get_extensions_dir()?;
+        let mut installed_extensions = super::registry::InstalledExtensions::load(&extensions_dir)?;
+        let name = super::registry::extension_name(&scope.extension);
+        let executable_path = extensions_dir
+            .join("bin")
+            .join(super::registry::executable_name(name));
+
+        if installed_extensions.extensions.remove(name).is_none() && !executable_path.exists() {
+            color_eyre::eyre::bail!(
+                "The extension `near {name}` is not installed with `near extension install` (extensions on PATH have to be removed manually)"
+            );
+        }
+        if executable_path.exists() {
+            std::fs::remove_file(&executable_path)
+                .wrap_err_with(|| format!("Failed to remove the file: {executable_path:?}"))?;
+        }
+        installed_extensions.save(&extensions_dir)?;
+        eprintln!("The extension `near {name}` is uninstalled.");
+        Ok(Self)
+    }
+}
+
+impl UninstallCommand {
+    pub fn input_extension(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<String>> {
+        let installed_extensions =
+            super::registry::InstalledExtensions::load(&super::registry::get_extensions_dir()?)?;
+        if installed_extensions.extensions.is_empty() {
+            color_eyre::eyre::bail!(
+                "There are no extensions installed with `near extension install`"
+            );
+        }
+        Ok(Some(
+            inquire::Select::new(
+                "What extension do you want to uninstall?",
+                installed_extensions.extensions.into_keys().collect(),
+            )
+            .prompt()?,
+        ))
+    }
+}
@@ -0,0 +1,86 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = UpdateCommandContext)]
+pub struct UpdateCommand {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// The URL or the path of the extensions registry index (by default, the one each extension was installed from)
+    registry: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateCommandContext;
+
+impl UpdateCommandContext {
+    pub fn from_previous_context(
+        _previous_context: crate::GlobalContext,
+        scope: &<UpdateCommand as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let extensions_dir = super::registry::get_extensions_dir()?;
+        let mut installed_extensions = super::registry::InstalledExtensions::load(&extensions_dir)?;
+        if installed_extensions.extensions.is_empty() {
+            eprintln!("There are no extensions installed with `near extension install`.");
+            return Ok(Self);
+        }
+
+        let mut registries: std::collections::HashMap<String, super::registry::Registry> =
+            Default::default();
+        let mut updated_git_urls: std::collections::HashSet<String> = Default::default();
+        let mut updates = vec![];
+        for (name, installed_extension) in &installed_extensions.extensions {
+            match &installed_extension.source {
+                super::registry::Source::Registry { registry } => {
+                    let registry = scope.registry.as_ref().unwrap_or(registry);
+                    if !registries.contains_key(registry) {
+                        registries
+                            .insert(registry.clone(), super::registry::Registry::load(registry)?);
+                    }
+                    let Some(entry) = registries[registry].find(name) else {
+                        eprintln!("The extension `near {name}` is no longer in the registry, skipping it.");
+                        continue;
+                    };
+                    let executable_path = extensions_dir
+                        .join("bin")
+                        .join(super::registry::executable_name(name));
+                    let is_modified = super::registry::file_sha256_hex(&executable_path)
+                        .map_or(true, |sha256| sha256 != installed_extension.sha256);
+                    if installed_extension.version.as_ref() == Some(&entry.version) && !is_modified
+                    {
+                        eprintln!(
+                            "The extension `near {name}` v{} is up to date.",
+                            entry.version
+                        );
+                        continue;
+                    }
+                    updates.push((
+                        name.clone(),
+                        super::registry::install_from_registry(entry, registry, &extensions_dir)?,
+                    ));
+                    eprintln!(
+                        "The extension `near {name}` is updated to v{}.",
+                        entry.version
+                    );
+                }
+                super::registry::Source::Git { url } => {
+                    // A repository may contain several extensions, they are rebuilt together.
+                    if !updated_git_urls.insert(url.clone()) {
+                        continue;
+                    }
+                    let installed_from_git =
+                        super::registry::install_from_git(url, &extensions_dir)?;
+                    if installed_from_git.is_empty() {
+                        eprintln!("The extensions from <{url}> are up to date.");
+                    }
+                    for (name, installed_extension) in installed_from_git {
+                        eprintln!("The extension `near {name}` is updated from <{url}>.");
+                        updates.push((name, installed_extension));
+                    }
+                }
+            }
+        }
+
+        installed_extensions.extensions.extend(updates);
+        installed_extensions.save(&extensions_dir)?;
+        Ok(Self)
+    }
+}
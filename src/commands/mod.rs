@@ -4,6 +4,7 @@ use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 pub mod account;
 mod config;
 pub mod contract;
+pub mod credentials;
 mod staking;
 mod tokens;
 pub mod transaction;
@@ -44,6 +45,9 @@ pub enum TopLevelCommand {
     ))]
     /// Use this to manage connections in a configuration file (config.toml).
     Config(self::config::ConfigCommands),
+    #[strum_discriminants(strum(message = "credentials - Manage access keys saved locally"))]
+    /// Use this to manage the access keys saved in the keychain, the legacy keychain and the encrypted keystore
+    Credentials(self::credentials::CredentialsCommands),
     #[cfg(feature = "self-update")]
     #[strum_discriminants(strum(message = "extension   - Manage near CLI and extensions"))]
     /// Use this to manage near CLI and extensions
@@ -89,6 +93,8 @@ pub struct ActionContext {
         crate::transaction_signature_options::OnBeforeSendingTransactionCallback,
     pub on_after_sending_transaction_callback:
         crate::transaction_signature_options::OnAfterSendingTransactionCallback,
+    /// The transaction bundle being signed (see `transaction prepare-offline`)
+    pub transaction_bundle: Option<crate::types::transaction_bundle::TransactionBundle>,
 }
 
 #[derive(Clone)]
@@ -101,4 +107,6 @@ pub struct TransactionContext {
         crate::transaction_signature_options::OnBeforeSendingTransactionCallback,
     pub on_after_sending_transaction_callback:
         crate::transaction_signature_options::OnAfterSendingTransactionCallback,
+    /// The nonce and the block hash are taken from the bundle (see `transaction prepare-offline`)
+    pub transaction_bundle: Option<crate::types::transaction_bundle::TransactionBundle>,
 }
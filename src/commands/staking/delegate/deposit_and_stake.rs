@@ -70,6 +70,7 @@ impl DepositAndStakeContext {
                 |_signed_transaction, _network_config| Ok(String::new()),
             ),
             on_after_sending_transaction_callback,
+            transaction_bundle: None,
         }))
     }
 }
@@ -72,6 +72,7 @@ impl StakeContext {
                 |_signed_transaction, _network_config| Ok(String::new()),
             ),
             on_after_sending_transaction_callback,
+            transaction_bundle: None,
         }))
     }
 }
@@ -66,6 +66,7 @@ impl StakeAllContext {
                 |_signed_transaction, _network_config| Ok(String::new()),
             ),
             on_after_sending_transaction_callback,
+            transaction_bundle: None,
         }))
     }
 }
@@ -26,11 +26,12 @@ impl ViewBalanceContext {
         let validator_account_id: near_primitives::types::AccountId =
             scope.validator_account_id.clone().into();
         let interacting_with_account_ids = vec![account_id.clone(), validator_account_id.clone()];
+        let output_format = previous_context.global_context.output_format.clone();
 
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
 
             move |network_config: &crate::config::NetworkConfig, block_reference: &near_primitives::types::BlockReference| {
-                calculation_delegated_stake_balance(&account_id, &validator_account_id, network_config, block_reference)
+                calculation_delegated_stake_balance(&account_id, &validator_account_id, network_config, block_reference, &output_format)
             }
         });
         Ok(Self(crate::network_view_at_block::ArgsForViewContext {
@@ -64,6 +65,7 @@ fn calculation_delegated_stake_balance(
     validator_account_id: &near_primitives::types::AccountId,
     network_config: &crate::config::NetworkConfig,
     block_reference: &near_primitives::types::BlockReference,
+    output_format: &crate::common::OutputFormat,
 ) -> crate::CliResult {
     let user_staked_balance: u128 = get_user_staked_balance(
         network_config,
@@ -83,18 +85,32 @@ fn calculation_delegated_stake_balance(
         validator_account_id,
         account_id,
     )?;
-    let withdrawal_availability_message =
-        match is_account_unstaked_balance_available_for_withdrawal(
+    let is_unstaked_balance_available_for_withdrawal =
+        is_account_unstaked_balance_available_for_withdrawal(
             network_config,
             validator_account_id,
             account_id,
-        )? {
-            true if user_unstaked_balance > 0 => "(available for withdrawal)",
-            false if user_unstaked_balance > 0 => {
-                "(not available for withdrawal in the current epoch)"
-            }
-            _ => "",
-        };
+        )?;
+
+    if output_format.is_json() {
+        return crate::common::print_json_output(
+            "delegated_stake_balance",
+            serde_json::json!({
+                "account_id": account_id,
+                "validator_account_id": validator_account_id,
+                "staked_balance": user_staked_balance.to_string(),
+                "unstaked_balance": user_unstaked_balance.to_string(),
+                "total_balance": user_total_balance.to_string(),
+                "unstaked_balance_available_for_withdrawal": is_unstaked_balance_available_for_withdrawal,
+            }),
+        );
+    }
+
+    let withdrawal_availability_message = match is_unstaked_balance_available_for_withdrawal {
+        true if user_unstaked_balance > 0 => "(available for withdrawal)",
+        false if user_unstaked_balance > 0 => "(not available for withdrawal in the current epoch)",
+        _ => "",
+    };
 
     eprintln!("Delegated stake balance with validator <{validator_account_id}> by <{account_id}>:");
     eprintln!(
@@ -18,7 +18,11 @@ impl ValidatorListContext {
         _scope: &<ValidatorList as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
-            std::sync::Arc::new(display_validators_info);
+            std::sync::Arc::new({
+                let output_format = previous_context.output_format.clone();
+
+                move |network_config| display_validators_info(network_config, &output_format)
+            });
         Ok(Self(crate::network::NetworkContext {
             config: previous_context.config,
             interacting_with_account_ids: vec![],
@@ -34,14 +38,36 @@ impl From<ValidatorListContext> for crate::network::NetworkContext {
 }
 
 #[tracing::instrument(name = "View the list of validators for delegation ...", skip_all)]
-fn display_validators_info(network_config: &crate::config::NetworkConfig) -> crate::CliResult {
+fn display_validators_info(
+    network_config: &crate::config::NetworkConfig,
+    output_format: &crate::common::OutputFormat,
+) -> crate::CliResult {
+    let validator_list = crate::common::get_validator_list(network_config)?;
+
+    if output_format.is_json() {
+        return crate::common::print_json_output(
+            "validator_list",
+            validator_list
+                .iter()
+                .map(|validator| {
+                    serde_json::json!({
+                        "validator_id": validator.validator_id,
+                        "fee": validator.fee.as_ref().map(|fee| serde_json::json!({
+                            "numerator": fee.numerator,
+                            "denominator": fee.denominator,
+                        })),
+                        "delegators": validator.delegators,
+                        "stake": validator.stake.to_string(),
+                    })
+                })
+                .collect(),
+        );
+    }
+
     let mut table = Table::new();
     table.set_titles(prettytable::row![Fg=>"#", "Validator Id", "Fee", "Delegators", "Stake"]);
 
-    for (index, validator) in crate::common::get_validator_list(network_config)?
-        .into_iter()
-        .enumerate()
-    {
+    for (index, validator) in validator_list.into_iter().enumerate() {
         let fee = if let Some(fee) = validator.fee {
             format!("{:>6.2} %", fee.numerator * 100 / fee.denominator)
         } else {
@@ -4,6 +4,7 @@ mod send_ft;
 mod send_near;
 mod send_nft;
 mod view_ft_balance;
+mod view_ft_metadata;
 mod view_near_balance;
 mod view_nft_assets;
 
@@ -74,6 +75,9 @@ pub enum TokensActions {
     #[strum_discriminants(strum(message = "view-ft-balance   - View the balance of FT tokens"))]
     /// View the balance of FT tokens
     ViewFtBalance(self::view_ft_balance::ViewFtBalance),
+    #[strum_discriminants(strum(message = "view-ft-metadata  - View the metadata of FT tokens"))]
+    /// View the metadata of FT tokens
+    ViewFtMetadata(self::view_ft_metadata::ViewFtMetadata),
     #[strum_discriminants(strum(message = "view-nft-assets   - View the balance of NFT tokens"))]
     /// View the balance of NFT tokens
     ViewNftAssets(self::view_nft_assets::ViewNftAssets),
@@ -23,6 +23,7 @@ pub struct AmountFtContext {
     signer_account_id: near_primitives::types::AccountId,
     ft_contract_account_id: near_primitives::types::AccountId,
     receiver_account_id: near_primitives::types::AccountId,
+    msg: Option<String>,
     amount_ft: crate::types::ft_properties::FungibleToken,
 }
 
@@ -52,6 +53,7 @@ impl AmountFtContext {
             signer_account_id: previous_context.signer_account_id,
             ft_contract_account_id: previous_context.ft_contract_account_id,
             receiver_account_id: previous_context.receiver_account_id,
+            msg: previous_context.msg,
             amount_ft: scope.amount_ft.normalize(&ft_metadata)?,
         })
     }
@@ -116,6 +118,7 @@ pub struct PrepaidGasContext {
     signer_account_id: near_primitives::types::AccountId,
     ft_contract_account_id: near_primitives::types::AccountId,
     receiver_account_id: near_primitives::types::AccountId,
+    msg: Option<String>,
     amount_ft: crate::types::ft_properties::FungibleToken,
     gas: crate::common::NearGas,
 }
@@ -130,6 +133,7 @@ impl PrepaidGasContext {
             signer_account_id: previous_context.signer_account_id,
             ft_contract_account_id: previous_context.ft_contract_account_id,
             receiver_account_id: previous_context.receiver_account_id,
+            msg: previous_context.msg,
             amount_ft: previous_context.amount_ft,
             gas: scope.gas,
         })
@@ -185,6 +189,7 @@ impl DepositContext {
                 let signer_account_id = previous_context.signer_account_id.clone();
                 let ft_contract_account_id = previous_context.ft_contract_account_id.clone();
                 let receiver_account_id = previous_context.receiver_account_id.clone();
+                let msg = previous_context.msg.clone();
                 let deposit = scope.deposit;
                 let amount_ft = previous_context.amount_ft.clone();
 
@@ -195,8 +200,9 @@ impl DepositContext {
                         &receiver_account_id,
                         &signer_account_id,
                         &amount_ft,
+                        msg.as_deref(),
                         &deposit,
-                        &previous_context.gas
+                        &previous_context.gas,
                     )
                 }
             });
@@ -206,11 +212,26 @@ impl DepositContext {
             let amount_ft = previous_context.amount_ft.clone();
             let ft_contract_account_id = previous_context.ft_contract_account_id.clone();
             let receiver_account_id = previous_context.receiver_account_id.clone();
+            let is_transfer_call = previous_context.msg.is_some();
 
             move |outcome_view, _network_config| {
-                if let near_primitives::views::FinalExecutionStatus::SuccessValue(_) = outcome_view.status {
+                if let near_primitives::views::FinalExecutionStatus::SuccessValue(value) = &outcome_view.status {
+                    // `ft_transfer_call` returns the amount the receiver contract has kept, the rest is refunded.
+                    let used_amount_ft = if is_transfer_call {
+                        serde_json::from_slice::<String>(value)
+                            .ok()
+                            .and_then(|amount| amount.parse::<u128>().ok())
+                            .map(|amount| crate::types::ft_properties::FungibleToken::from_params_ft(
+                                amount,
+                                amount_ft.decimals(),
+                                amount_ft.symbol().to_string(),
+                            ))
+                    } else {
+                        None
+                    };
                     eprintln!(
-                        "<{signer_account_id}> has successfully transferred {amount_ft} (FT-contract: {ft_contract_account_id}) to <{receiver_account_id}>.",
+                        "<{signer_account_id}> has successfully transferred {} (FT-contract: {ft_contract_account_id}) to <{receiver_account_id}>.",
+                        used_amount_ft.as_ref().unwrap_or(&amount_ft)
                     );
                 }
                 Ok(())
@@ -232,6 +253,7 @@ impl DepositContext {
                 |_signed_transaction, _network_config| Ok(String::new()),
             ),
             on_after_sending_transaction_callback,
+            transaction_bundle: None,
         }))
     }
 }
@@ -255,67 +277,332 @@ impl Deposit {
     }
 }
 
+fn get_ft_transfer_action(
+    receiver_account_id: &near_primitives::types::AccountId,
+    amount_ft: &crate::types::ft_properties::FungibleToken,
+    msg: Option<&str>,
+    deposit: &crate::types::near_token::NearToken,
+    gas: &crate::common::NearGas,
+) -> color_eyre::eyre::Result<near_primitives::transaction::Action> {
+    let (method_name, args) = if let Some(msg) = msg {
+        (
+            "ft_transfer_call",
+            json!({
+                "receiver_id": receiver_account_id.to_string(),
+                "amount": amount_ft.amount().to_string(),
+                "msg": msg,
+            }),
+        )
+    } else {
+        (
+            "ft_transfer",
+            json!({
+                "receiver_id": receiver_account_id.to_string(),
+                "amount": amount_ft.amount().to_string()
+            }),
+        )
+    };
+    Ok(near_primitives::transaction::Action::FunctionCall(
+        Box::new(near_primitives::transaction::FunctionCallAction {
+            method_name: method_name.to_string(),
+            args: serde_json::to_vec(&args)?,
+            gas: gas.as_gas(),
+            deposit: deposit.as_yoctonear(),
+        }),
+    ))
+}
+
+/// The gas of the `storage_deposit` call that registers the receiver; it is taken from the gas of
+/// the transfer, so the transaction stays within the gas the user has entered.
+const STORAGE_DEPOSIT_GAS: crate::common::NearGas = crate::common::NearGas::from_tgas(10);
+
 #[tracing::instrument(
     name = "Creating a pre-populated transaction for signature ...",
     skip_all
 )]
+#[allow(clippy::too_many_arguments)]
 fn get_prepopulated_transaction(
     network_config: &crate::config::NetworkConfig,
     ft_contract_account_id: &near_primitives::types::AccountId,
     receiver_account_id: &near_primitives::types::AccountId,
     signer_id: &near_primitives::types::AccountId,
     amount_ft: &crate::types::ft_properties::FungibleToken,
+    msg: Option<&str>,
     deposit: &crate::types::near_token::NearToken,
     gas: &crate::common::NearGas,
 ) -> color_eyre::eyre::Result<crate::commands::PrepopulatedTransaction> {
-    let action_ft_transfer = near_primitives::transaction::Action::FunctionCall(Box::new(
+    let storage_deposit = get_storage_deposit_for_receiver(
+        network_config,
+        ft_contract_account_id,
+        receiver_account_id,
+    )?;
+    Ok(crate::commands::PrepopulatedTransaction {
+        signer_id: signer_id.clone(),
+        receiver_id: ft_contract_account_id.clone(),
+        actions: get_ft_transfer_actions(
+            receiver_account_id,
+            amount_ft,
+            msg,
+            deposit,
+            gas,
+            storage_deposit,
+        )?,
+    })
+}
+
+/// The transfer, preceded by the registration of the receiver if it needs the storage deposit.
+fn get_ft_transfer_actions(
+    receiver_account_id: &near_primitives::types::AccountId,
+    amount_ft: &crate::types::ft_properties::FungibleToken,
+    msg: Option<&str>,
+    deposit: &crate::types::near_token::NearToken,
+    gas: &crate::common::NearGas,
+    storage_deposit: Option<near_token::NearToken>,
+) -> color_eyre::eyre::Result<Vec<near_primitives::transaction::Action>> {
+    let Some(storage_deposit) = storage_deposit else {
+        return Ok(vec![get_ft_transfer_action(
+            receiver_account_id,
+            amount_ft,
+            msg,
+            deposit,
+            gas,
+        )?]);
+    };
+    if gas <= &STORAGE_DEPOSIT_GAS {
+        color_eyre::eyre::bail!(
+            "<{receiver_account_id}> is not registered with the FT contract, so {STORAGE_DEPOSIT_GAS} of the {gas} entered are needed for `storage_deposit`. Enter more gas for the transfer."
+        );
+    }
+    let action_storage_deposit = near_primitives::transaction::Action::FunctionCall(Box::new(
         near_primitives::transaction::FunctionCallAction {
-            method_name: "ft_transfer".to_string(),
+            method_name: "storage_deposit".to_string(),
             args: serde_json::to_vec(&json!({
-                "receiver_id": receiver_account_id.to_string(),
-                "amount": amount_ft.amount().to_string()
+                "account_id": receiver_account_id.to_string(),
+                "registration_only": true,
             }))?,
-            gas: gas.as_gas(),
-            deposit: deposit.as_yoctonear(),
+            gas: STORAGE_DEPOSIT_GAS.as_gas(),
+            deposit: storage_deposit.as_yoctonear(),
         },
     ));
+    let transfer_gas =
+        crate::common::NearGas::from_gas(gas.as_gas() - STORAGE_DEPOSIT_GAS.as_gas());
+    Ok(vec![
+        action_storage_deposit,
+        get_ft_transfer_action(receiver_account_id, amount_ft, msg, deposit, &transfer_gas)?,
+    ])
+}
 
-    let args = serde_json::to_vec(&json!({"account_id": receiver_account_id.to_string()}))?;
+/// Returns the deposit required to register the receiver with the FT contract (NEP-145), or
+/// `None` if the receiver is already registered.
+fn get_storage_deposit_for_receiver(
+    network_config: &crate::config::NetworkConfig,
+    ft_contract_account_id: &near_primitives::types::AccountId,
+    receiver_account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Option<near_token::NearToken>> {
+    let json_rpc_client = network_config.json_rpc_client();
+    let storage_balance = json_rpc_client
+        .blocking_call_view_function(
+            ft_contract_account_id,
+            "storage_balance_of",
+            serde_json::to_vec(&json!({"account_id": receiver_account_id.to_string()}))?,
+            near_primitives::types::Finality::Final.into(),
+        )
+        .wrap_err_with(||{
+            format!("Failed to fetch query for view method: 'storage_balance_of' (contract <{}> on network <{}>)",
+                ft_contract_account_id,
+                network_config.network_name
+            )
+        })?
+        .parse_result_from_json::<Value>()?;
+    if !storage_balance.is_null() {
+        return Ok(None);
+    }
 
-    let call_result = network_config
-            .json_rpc_client()
-            .blocking_call_view_function(
+    let storage_balance_bounds = json_rpc_client
+        .blocking_call_view_function(
+            ft_contract_account_id,
+            "storage_balance_bounds",
+            vec![],
+            near_primitives::types::Finality::Final.into(),
+        )
+        .wrap_err_with(||{
+            format!("Failed to fetch query for view method: 'storage_balance_bounds' (contract <{}> on network <{}>)",
                 ft_contract_account_id,
-                "storage_balance_of",
-                args.clone(),
-                near_primitives::types::Finality::Final.into(),
+                network_config.network_name
             )
-            .wrap_err_with(||{
-                format!("Failed to fetch query for view method: 'storage_balance_of' (contract <{}> on network <{}>)",
-                    ft_contract_account_id,
-                    network_config.network_name
-                )
-            })?;
-
-    if call_result.parse_result_from_json::<Value>()?.is_null() {
-        let action_storage_deposit = near_primitives::transaction::Action::FunctionCall(Box::new(
-            near_primitives::transaction::FunctionCallAction {
-                method_name: "storage_deposit".to_string(),
-                args,
-                gas: gas.as_gas(),
-                deposit: near_token::NearToken::from_millinear(100).as_yoctonear(),
-            },
-        ));
-        return Ok(crate::commands::PrepopulatedTransaction {
-            signer_id: signer_id.clone(),
-            receiver_id: ft_contract_account_id.clone(),
-            actions: vec![action_storage_deposit, action_ft_transfer.clone()],
-        });
+        })?
+        .parse_result_from_json::<Value>()?;
+    let min_storage_deposit = storage_balance_bounds
+        .get("min")
+        .and_then(Value::as_str)
+        .wrap_err("Failed to parse the minimum storage deposit from 'storage_balance_bounds'")?
+        .parse::<u128>()
+        .wrap_err("Failed to parse the minimum storage deposit from 'storage_balance_bounds'")?;
+    Ok(Some(near_token::NearToken::from_yoctonear(
+        min_storage_deposit,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn function_call(action: &near_primitives::transaction::Action) -> (&str, Value, u64, u128) {
+        let near_primitives::transaction::Action::FunctionCall(function_call) = action else {
+            panic!("not a function call: {action:?}");
+        };
+        (
+            function_call.method_name.as_str(),
+            serde_json::from_slice(&function_call.args).unwrap(),
+            function_call.gas,
+            function_call.deposit,
+        )
     }
 
-    Ok(crate::commands::PrepopulatedTransaction {
-        signer_id: signer_id.clone(),
-        receiver_id: ft_contract_account_id.clone(),
-        actions: vec![action_ft_transfer.clone()],
-    })
+    fn amount_ft() -> crate::types::ft_properties::FungibleToken {
+        crate::types::ft_properties::FungibleToken::from_params_ft(1500000, 6, "USDC".to_string())
+    }
+
+    #[test]
+    fn ft_transfer_and_ft_transfer_call() {
+        let receiver_account_id = "bob.testnet".parse().unwrap();
+        let deposit = crate::types::near_token::NearToken::from_yoctonear(1);
+        let gas = crate::common::NearGas::from_tgas(100);
+
+        let action =
+            get_ft_transfer_action(&receiver_account_id, &amount_ft(), None, &deposit, &gas)
+                .unwrap();
+        assert_eq!(
+            function_call(&action),
+            (
+                "ft_transfer",
+                json!({"receiver_id": "bob.testnet", "amount": "1500000"}),
+                gas.as_gas(),
+                1
+            )
+        );
+
+        let action = get_ft_transfer_action(
+            &receiver_account_id,
+            &amount_ft(),
+            Some("swap"),
+            &deposit,
+            &gas,
+        )
+        .unwrap();
+        assert_eq!(
+            function_call(&action),
+            (
+                "ft_transfer_call",
+                json!({"receiver_id": "bob.testnet", "amount": "1500000", "msg": "swap"}),
+                gas.as_gas(),
+                1
+            )
+        );
+    }
+
+    #[test]
+    fn storage_deposit_takes_its_gas_from_the_transfer() {
+        let receiver_account_id = "bob.testnet".parse().unwrap();
+        let deposit = crate::types::near_token::NearToken::from_yoctonear(1);
+        let gas = crate::common::NearGas::from_tgas(300);
+
+        let actions = get_ft_transfer_actions(
+            &receiver_account_id,
+            &amount_ft(),
+            Some("swap"),
+            &deposit,
+            &gas,
+            None,
+        )
+        .unwrap();
+        assert_eq!(actions.len(), 1);
+        assert_eq!(function_call(&actions[0]).2, gas.as_gas());
+
+        let actions = get_ft_transfer_actions(
+            &receiver_account_id,
+            &amount_ft(),
+            Some("swap"),
+            &deposit,
+            &gas,
+            Some(near_token::NearToken::from_millinear(1)),
+        )
+        .unwrap();
+        let (method_name, args, storage_deposit_gas, storage_deposit) = function_call(&actions[0]);
+        assert_eq!(method_name, "storage_deposit");
+        assert_eq!(
+            args,
+            json!({"account_id": "bob.testnet", "registration_only": true})
+        );
+        assert_eq!(
+            storage_deposit,
+            near_token::NearToken::from_millinear(1).as_yoctonear()
+        );
+        let (method_name, _, transfer_gas, _) = function_call(&actions[1]);
+        assert_eq!(method_name, "ft_transfer_call");
+        assert_eq!(storage_deposit_gas + transfer_gas, gas.as_gas());
+
+        assert!(get_ft_transfer_actions(
+            &receiver_account_id,
+            &amount_ft(),
+            None,
+            &deposit,
+            &STORAGE_DEPOSIT_GAS,
+            Some(near_token::NearToken::from_millinear(1)),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn storage_deposit_is_only_needed_for_unregistered_receivers() {
+        let rpc_url = crate::test_utils::serve_json_rpc(|method, params| {
+            assert_eq!(method, "query");
+            assert_eq!(params["request_type"], "call_function");
+            let args =
+                near_primitives::serialize::from_base64(params["args_base64"].as_str().unwrap())
+                    .unwrap();
+            let args: Value = serde_json::from_slice(&args).unwrap_or_default();
+            let result = match (params["method_name"].as_str().unwrap(), &args["account_id"]) {
+                ("storage_balance_of", Value::String(account_id))
+                    if account_id == "alice.testnet" =>
+                {
+                    json!({"total": "1250000000000000000000", "available": "0"})
+                }
+                ("storage_balance_of", _) => Value::Null,
+                ("storage_balance_bounds", _) => {
+                    json!({"min": "1250000000000000000000", "max": "1250000000000000000000"})
+                }
+                (method_name, _) => panic!("unexpected call of {method_name}"),
+            };
+            json!({
+                "result": serde_json::to_vec(&result).unwrap(),
+                "logs": [],
+                "block_height": 100,
+                "block_hash": near_primitives::hash::CryptoHash::default(),
+            })
+        });
+        let network_config = crate::test_utils::network_config(rpc_url);
+        let ft_contract_account_id = "usdc.testnet".parse().unwrap();
+
+        assert_eq!(
+            get_storage_deposit_for_receiver(
+                &network_config,
+                &ft_contract_account_id,
+                &"alice.testnet".parse().unwrap(),
+            )
+            .unwrap(),
+            None
+        );
+        assert_eq!(
+            get_storage_deposit_for_receiver(
+                &network_config,
+                &ft_contract_account_id,
+                &"bob.testnet".parse().unwrap(),
+            )
+            .unwrap(),
+            Some(near_token::NearToken::from_yoctonear(
+                1_250_000_000_000_000_000_000
+            ))
+        );
+    }
 }
@@ -10,6 +10,10 @@ pub struct SendFtCommand {
     #[interactive_clap(skip_default_input_arg)]
     /// What is the receiver account ID?
     receiver_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Call `ft_transfer_call` with this message for the receiver contract instead of `ft_transfer`
+    with_msg: Option<String>,
     #[interactive_clap(named_arg)]
     /// Specify amount FT
     amount_ft: self::amount_ft::AmountFt,
@@ -21,6 +25,7 @@ pub struct SendFtCommandContext {
     signer_account_id: near_primitives::types::AccountId,
     ft_contract_account_id: near_primitives::types::AccountId,
     receiver_account_id: near_primitives::types::AccountId,
+    msg: Option<String>,
 }
 
 impl SendFtCommandContext {
@@ -33,6 +38,7 @@ impl SendFtCommandContext {
             signer_account_id: previous_context.owner_account_id,
             ft_contract_account_id: scope.ft_contract_account_id.clone().into(),
             receiver_account_id: scope.receiver_account_id.clone().into(),
+            msg: scope.with_msg.clone(),
         })
     }
 }
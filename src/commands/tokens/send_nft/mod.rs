@@ -113,6 +113,7 @@ impl From<SendNftCommandContext> for crate::commands::ActionContext {
                 |_signed_transaction, _network_config| Ok(String::new()),
             ),
             on_after_sending_transaction_callback,
+            transaction_bundle: None,
         }
     }
 }
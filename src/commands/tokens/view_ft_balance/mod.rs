@@ -26,11 +26,12 @@ impl ViewFtBalanceContext {
     ) -> color_eyre::eyre::Result<Self> {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let owner_account_id = previous_context.owner_account_id.clone();
+            let output_format = previous_context.global_context.output_format.clone();
             let ft_contract_account_id: near_primitives::types::AccountId =
                 scope.ft_contract_account_id.clone().into();
 
             move |network_config, block_reference| {
-                let crate::types::ft_properties::FtMetadata { decimals, symbol } = crate::types::ft_properties::params_ft_metadata(
+                let crate::types::ft_properties::FtMetadata { decimals, symbol, .. } = crate::types::ft_properties::params_ft_metadata(
                     ft_contract_account_id.clone(),
                     network_config,
                     block_reference.clone(),
@@ -41,6 +42,18 @@ impl ViewFtBalanceContext {
                 let call_result = get_ft_balance(network_config, &ft_contract_account_id, args, block_reference.clone())?;
                 call_result.print_logs();
                 let amount: String = call_result.parse_result_from_json()?;
+                if output_format.is_json() {
+                    return crate::common::print_json_output(
+                        "ft_balance",
+                        serde_json::json!({
+                            "account_id": owner_account_id,
+                            "ft_contract_account_id": ft_contract_account_id,
+                            "amount": amount,
+                            "decimals": decimals,
+                            "symbol": symbol,
+                        }),
+                    );
+                }
                 let fungible_token = crate::types::ft_properties::FungibleToken::from_params_ft(
                     amount.parse::<u128>()?,
                     decimals,
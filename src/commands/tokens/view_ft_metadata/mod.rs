@@ -0,0 +1,101 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::TokensCommandsContext)]
+#[interactive_clap(output_context = ViewFtMetadataContext)]
+pub struct ViewFtMetadata {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What is the ft-contract account ID?
+    ft_contract_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+#[derive(Clone)]
+pub struct ViewFtMetadataContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl ViewFtMetadataContext {
+    pub fn from_previous_context(
+        previous_context: super::TokensCommandsContext,
+        scope: &<ViewFtMetadata as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
+            let output_format = previous_context.global_context.output_format.clone();
+            let ft_contract_account_id: near_primitives::types::AccountId =
+                scope.ft_contract_account_id.clone().into();
+
+            move |network_config, block_reference| {
+                let ft_metadata = crate::types::ft_properties::params_ft_metadata(
+                    ft_contract_account_id.clone(),
+                    network_config,
+                    block_reference.clone(),
+                )?;
+                if output_format.is_json() {
+                    return crate::common::print_json_output(
+                        "ft_metadata",
+                        serde_json::json!({
+                            "ft_contract_account_id": ft_contract_account_id,
+                            "metadata": ft_metadata,
+                        }),
+                    );
+                }
+                print_ft_metadata(&ft_contract_account_id, &ft_metadata);
+                Ok(())
+            }
+        });
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config: previous_context.global_context.config,
+            on_after_getting_block_reference_callback,
+            interacting_with_account_ids: vec![scope.ft_contract_account_id.clone().into()],
+        }))
+    }
+}
+
+impl From<ViewFtMetadataContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: ViewFtMetadataContext) -> Self {
+        item.0
+    }
+}
+
+impl ViewFtMetadata {
+    pub fn input_ft_contract_account_id(
+        context: &super::TokensCommandsContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_non_signer_account_id_from_used_account_list(
+            &context.global_context.config.credentials_home_dir,
+            "What is the ft-contract account ID?",
+        )
+    }
+}
+
+fn print_ft_metadata(
+    ft_contract_account_id: &near_primitives::types::AccountId,
+    ft_metadata: &crate::types::ft_properties::FtMetadata,
+) {
+    let mut table = prettytable::Table::new();
+    table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+    table.add_row(prettytable::row![Fg->"Name", Fy->ft_metadata.name]);
+    table.add_row(prettytable::row![Fg->"Symbol", Fy->ft_metadata.symbol]);
+    table.add_row(prettytable::row![Fg->"Decimals", Fy->ft_metadata.decimals]);
+    table.add_row(prettytable::row![Fg->"Spec", Fy->ft_metadata.spec]);
+    // Icons are usually data URLs of a few kilobytes, which do not fit in a terminal.
+    let icon = match &ft_metadata.icon {
+        Some(icon) if icon.starts_with("data:") => format!(
+            "{} ({})",
+            icon.split(',').next().unwrap_or_default(),
+            bytesize::ByteSize(icon.len().try_into().unwrap_or(u64::MAX))
+        ),
+        Some(icon) => icon.clone(),
+        None => String::new(),
+    };
+    table.add_row(prettytable::row![Fg->"Icon", Fy->icon]);
+    table.add_row(prettytable::row![
+        Fg->"Reference",
+        Fy->ft_metadata.reference.as_deref().unwrap_or_default()
+    ]);
+    table.add_row(prettytable::row![
+        Fg->"Reference hash",
+        Fy->ft_metadata.reference_hash.as_deref().unwrap_or_default()
+    ]);
+    eprintln!("\nMetadata of the FT-contract <{ft_contract_account_id}>:");
+    table.printstd();
+}
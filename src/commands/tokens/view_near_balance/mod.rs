@@ -17,6 +17,7 @@ impl ViewNearBalanceContext {
     ) -> color_eyre::eyre::Result<Self> {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let owner_account_id = previous_context.owner_account_id.clone();
+            let output_format = previous_context.global_context.output_format.clone();
 
             move |network_config, block_reference| {
                 let account_transfer_allowance = tokio::runtime::Runtime::new()
@@ -26,6 +27,18 @@ impl ViewNearBalanceContext {
                     owner_account_id.clone(),
                     block_reference.clone(),
                 ))?;
+                if output_format.is_json() {
+                    return crate::common::print_json_output(
+                        "near_balance",
+                        serde_json::json!({
+                            "account_id": owner_account_id,
+                            "liquid_balance": account_transfer_allowance.account_liquid_balance().as_yoctonear().to_string(),
+                            "locked_balance": account_transfer_allowance.account_locked_balance().as_yoctonear().to_string(),
+                            "liquid_storage_stake": account_transfer_allowance.liquid_storage_stake().as_yoctonear().to_string(),
+                            "transfer_allowance": account_transfer_allowance.transfer_allowance().as_yoctonear().to_string(),
+                        }),
+                    );
+                }
                 eprintln!("{account_transfer_allowance}");
                 Ok(())
             }
@@ -26,6 +26,7 @@ impl ViewNftAssetsContext {
     ) -> color_eyre::eyre::Result<Self> {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let owner_account_id = previous_context.owner_account_id.clone();
+            let output_format = previous_context.global_context.output_format.clone();
             let nft_contract_account_id: near_primitives::types::AccountId =
                 scope.nft_contract_account_id.clone().into();
 
@@ -36,6 +37,16 @@ impl ViewNftAssetsContext {
                 let call_result = get_nft_balance(network_config, &nft_contract_account_id, args, block_reference.clone())?;
                 call_result.print_logs();
                 let serde_call_result: serde_json::Value = call_result.parse_result_from_json()?;
+                if output_format.is_json() {
+                    return crate::common::print_json_output(
+                        "nft_assets",
+                        serde_json::json!({
+                            "account_id": owner_account_id,
+                            "nft_contract_account_id": nft_contract_account_id,
+                            "tokens": serde_call_result,
+                        }),
+                    );
+                }
 
                 eprintln!("\n{} account has NFT tokens:", owner_account_id);
                 eprintln!("{}", serde_json::to_string_pretty(&serde_call_result)?);
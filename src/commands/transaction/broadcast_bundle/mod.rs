@@ -0,0 +1,95 @@
+use color_eyre::eyre::{ContextCompat, WrapErr};
+
+use crate::common::{JsonRpcClientExt, RpcQueryResponseExt};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = BroadcastBundleContext)]
+pub struct BroadcastBundle {
+    /// Enter the signed transaction bundle (the path to the file or the text):
+    transaction_bundle: crate::types::transaction_bundle::SignedTransactionBundle,
+}
+
+#[derive(Debug, Clone)]
+pub struct BroadcastBundleContext;
+
+impl BroadcastBundleContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<BroadcastBundle as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let transaction_bundle = &scope.transaction_bundle.transaction_bundle;
+        let signed_transaction = &scope.transaction_bundle.signed_transaction;
+        let network_config = previous_context
+            .config
+            .network_connection
+            .get(&transaction_bundle.network_name)
+            .wrap_err_with(|| {
+                format!(
+                    "The transaction bundle is prepared for the network <{}>, which is not in your configuration",
+                    transaction_bundle.network_name
+                )
+            })?;
+
+        check_transaction_is_valid(
+            network_config,
+            signed_transaction,
+            transaction_bundle.expires_at_block_height,
+        )?;
+
+        let transaction_info =
+            crate::transaction_signature_options::send::sending_signed_transaction(
+                network_config,
+                signed_transaction,
+            )?;
+
+        crate::common::print_transaction_status(
+            &transaction_info,
+            network_config,
+            &previous_context.output_format,
+        )?;
+        Ok(Self)
+    }
+}
+
+/// The network would reject an expired transaction or a transaction with a used nonce with a less
+/// helpful error, so both are checked before sending it.
+#[tracing::instrument(name = "Checking the transaction bundle ...", skip_all)]
+fn check_transaction_is_valid(
+    network_config: &crate::config::NetworkConfig,
+    signed_transaction: &near_primitives::transaction::SignedTransaction,
+    expires_at_block_height: near_primitives::types::BlockHeight,
+) -> crate::CliResult {
+    let transaction = &signed_transaction.transaction;
+    let rpc_query_response = network_config
+        .json_rpc_client()
+        .blocking_call_view_access_key(
+            &transaction.signer_id,
+            &transaction.public_key,
+            near_primitives::types::Finality::Final.into(),
+        )
+        .wrap_err_with(|| {
+            format!(
+                "Failed to fetch the access key <{}> of <{}> on network <{}>",
+                transaction.public_key, transaction.signer_id, network_config.network_name
+            )
+        })?;
+    if rpc_query_response.block_height > expires_at_block_height {
+        color_eyre::eyre::bail!(
+            "The transaction has expired: it had to be sent before block #{expires_at_block_height}, and the latest final block is #{}. Prepare and sign the transaction again.",
+            rpc_query_response.block_height
+        );
+    }
+    let current_nonce = rpc_query_response
+        .access_key_view()
+        .wrap_err("Error current_nonce")?
+        .nonce;
+    if current_nonce >= transaction.nonce {
+        color_eyre::eyre::bail!(
+            "The access key <{}> has been used since the transaction bundle was prepared (its nonce is {current_nonce}, the transaction nonce is {}). Prepare and sign the transaction again.",
+            transaction.public_key,
+            transaction.nonce
+        );
+    }
+    Ok(())
+}
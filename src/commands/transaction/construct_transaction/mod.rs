@@ -1,7 +1,4 @@
-pub mod add_action_1;
-pub mod add_action_2;
-pub mod add_action_3;
-pub mod add_action_last;
+pub mod next_action;
 pub mod skip_action;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -15,7 +12,7 @@ pub struct ConstructTransaction {
     /// What is the receiver account ID?
     pub receiver_account_id: crate::types::account_id::AccountId,
     #[interactive_clap(subcommand)]
-    pub next_actions: self::add_action_1::NextAction,
+    pub next_actions: self::next_action::NextAction,
 }
 
 #[derive(Debug, Clone)]
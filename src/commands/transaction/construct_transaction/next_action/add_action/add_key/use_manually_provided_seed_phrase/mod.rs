@@ -7,7 +7,7 @@ pub struct AddAccessWithSeedPhraseAction {
     /// Enter the seed_phrase:
     master_seed_phrase: String,
     #[interactive_clap(subcommand)]
-    next_action: super::super::super::super::add_action_2::NextAction,
+    next_action: super::super::super::BoxedNextAction,
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +24,7 @@ impl AddAccessWithSeedPhraseActionContext {
         let public_key = crate::common::get_public_key_from_seed_phrase(
             seed_phrase_hd_path_default,
             &scope.master_seed_phrase,
+            near_crypto::KeyType::ED25519,
         )?;
         let access_key = near_primitives::account::AccessKey {
             nonce: 0,
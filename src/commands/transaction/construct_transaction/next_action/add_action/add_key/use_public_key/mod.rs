@@ -5,7 +5,7 @@ pub struct AddAccessKeyAction {
     /// Enter the public key:
     public_key: crate::types::public_key::PublicKey,
     #[interactive_clap(subcommand)]
-    next_action: super::super::super::super::add_action_2::NextAction,
+    next_action: super::super::super::BoxedNextAction,
 }
 
 #[derive(Debug, Clone)]
@@ -142,7 +142,7 @@ pub struct Deposit {
     /// Enter deposit for a function call:
     deposit: crate::types::near_token::NearToken,
     #[interactive_clap(subcommand)]
-    next_action: super::super::super::add_action_2::NextAction,
+    next_action: super::super::BoxedNextAction,
 }
 
 #[derive(Debug, Clone)]
@@ -3,7 +3,7 @@
 #[interactive_clap(output_context = CreateAccountActionContext)]
 pub struct CreateAccountAction {
     #[interactive_clap(subcommand)]
-    next_action: super::super::super::add_action_3::NextAction,
+    next_action: super::super::BoxedNextAction,
 }
 
 #[derive(Debug, Clone)]
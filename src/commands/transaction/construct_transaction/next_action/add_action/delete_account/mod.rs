@@ -10,7 +10,7 @@ pub struct DeleteAccountAction {
     /// Enter the beneficiary ID to delete this account ID:
     beneficiary_id: crate::types::account_id::AccountId,
     #[interactive_clap(subcommand)]
-    next_action: super::super::super::add_action_2::NextAction,
+    next_action: super::super::BoxedNextAction,
 }
 
 #[derive(Debug, Clone)]
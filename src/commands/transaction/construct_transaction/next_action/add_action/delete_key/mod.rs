@@ -5,7 +5,7 @@ pub struct DeleteKeyAction {
     /// Enter the public key You wish to delete:
     public_key: crate::types::public_key::PublicKey,
     #[interactive_clap(subcommand)]
-    next_action: super::super::super::add_action_3::NextAction,
+    next_action: super::super::BoxedNextAction,
 }
 
 #[derive(Debug, Clone)]
@@ -17,5 +17,5 @@ pub enum InitializeMode {
 #[interactive_clap(context = super::ContractFileContext)]
 pub struct NoInitialize {
     #[interactive_clap(subcommand)]
-    next_action: super::super::super::super::add_action_3::NextAction,
+    next_action: super::super::super::BoxedNextAction,
 }
@@ -7,7 +7,7 @@ pub struct StakeAction {
     /// Enter the public key of the validator key pair used on your NEAR node (see validator_key.json):
     public_key: crate::types::public_key::PublicKey,
     #[interactive_clap(subcommand)]
-    next_action: super::super::super::add_action_2::NextAction,
+    next_action: super::super::BoxedNextAction,
 }
 
 #[derive(Debug, Clone)]
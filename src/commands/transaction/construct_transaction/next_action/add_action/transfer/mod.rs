@@ -5,7 +5,7 @@ pub struct TransferAction {
     /// How many NEAR Tokens do you want to transfer? (example: 10NEAR or 0.5near or 10000yoctonear)
     pub amount_in_near: crate::types::near_token::NearToken,
     #[interactive_clap(subcommand)]
-    pub next_action: super::super::super::add_action_2::NextAction,
+    pub next_action: super::super::BoxedNextAction,
 }
 
 #[derive(Debug, Clone)]
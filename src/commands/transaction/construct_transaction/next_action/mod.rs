@@ -0,0 +1,129 @@
+#![allow(clippy::enum_variant_names, clippy::large_enum_variant)]
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+pub mod add_action;
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = super::ConstructTransactionContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+/// Select an action that you want to add to the action:
+pub enum NextAction {
+    #[strum_discriminants(strum(message = "add-action   - Select a new action"))]
+    /// Choose next action
+    AddAction(self::add_action::AddAction),
+    #[strum_discriminants(strum(message = "skip         - Skip adding a new action"))]
+    /// Go to transaction signing
+    Skip(super::skip_action::SkipAction),
+}
+
+/// Every action refers back to [`NextAction`], so a transaction may contain any number of actions.
+/// The recursion is boxed, and the clap subcommands are only built once they are reached,
+/// otherwise the command tree would be infinite.
+#[derive(Debug, Clone)]
+pub struct BoxedNextAction(pub Box<NextAction>);
+
+#[derive(Debug, Clone)]
+pub struct CliBoxedNextAction(pub Box<CliNextAction>);
+
+impl interactive_clap::ToCli for BoxedNextAction {
+    type CliVariant = CliBoxedNextAction;
+}
+
+impl From<BoxedNextAction> for CliBoxedNextAction {
+    fn from(item: BoxedNextAction) -> Self {
+        Self(Box::new((*item.0).into()))
+    }
+}
+
+impl From<CliNextAction> for CliBoxedNextAction {
+    fn from(item: CliNextAction) -> Self {
+        Self(Box::new(item))
+    }
+}
+
+impl interactive_clap::ToCliArgs for CliBoxedNextAction {
+    fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.0.to_cli_args()
+    }
+}
+
+impl clap::FromArgMatches for CliBoxedNextAction {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        <CliNextAction as clap::FromArgMatches>::from_arg_matches(matches).map(Self::from)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        self.0.update_from_arg_matches(matches)
+    }
+}
+
+impl clap::Subcommand for CliBoxedNextAction {
+    fn augment_subcommands(cmd: clap::Command) -> clap::Command {
+        cmd.defer(<CliNextAction as clap::Subcommand>::augment_subcommands)
+    }
+
+    fn augment_subcommands_for_update(cmd: clap::Command) -> clap::Command {
+        cmd.defer(<CliNextAction as clap::Subcommand>::augment_subcommands_for_update)
+    }
+
+    fn has_subcommand(name: &str) -> bool {
+        <CliNextAction as clap::Subcommand>::has_subcommand(name)
+    }
+}
+
+impl interactive_clap::FromCli for BoxedNextAction {
+    type FromCliContext = super::ConstructTransactionContext;
+    type FromCliError = color_eyre::eyre::Error;
+
+    fn from_cli(
+        optional_clap_variant: Option<<Self as interactive_clap::ToCli>::CliVariant>,
+        context: Self::FromCliContext,
+    ) -> interactive_clap::ResultFromCli<
+        <Self as interactive_clap::ToCli>::CliVariant,
+        Self::FromCliError,
+    >
+    where
+        Self: Sized + interactive_clap::ToCli,
+    {
+        match <NextAction as interactive_clap::FromCli>::from_cli(
+            optional_clap_variant.map(|cli_next_action| *cli_next_action.0),
+            context,
+        ) {
+            interactive_clap::ResultFromCli::Ok(cli_next_action) => {
+                interactive_clap::ResultFromCli::Ok(cli_next_action.into())
+            }
+            interactive_clap::ResultFromCli::Cancel(optional_cli_next_action) => {
+                interactive_clap::ResultFromCli::Cancel(optional_cli_next_action.map(Into::into))
+            }
+            interactive_clap::ResultFromCli::Back => interactive_clap::ResultFromCli::Back,
+            interactive_clap::ResultFromCli::Err(optional_cli_next_action, err) => {
+                interactive_clap::ResultFromCli::Err(optional_cli_next_action.map(Into::into), err)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use interactive_clap::ToCliArgs;
+
+    #[test]
+    fn parse_more_than_three_actions() {
+        let public_key = "ed25519:8nDkVwdpkE3Ft2sCtdH1dYjUqNcbUDDE8Ew4wmSUiupd";
+        let args = format!(
+            "construct-transaction alice.testnet bob.testnet \
+            add-action create-account \
+            add-action delete-key {public_key} \
+            add-action delete-key {public_key} \
+            add-action delete-key {public_key} \
+            add-action delete-account --beneficiary-id carol.testnet \
+            skip network-config testnet"
+        );
+        let cli_construct_transaction =
+            super::super::ConstructTransaction::try_parse_from(&args).unwrap();
+        assert_eq!(
+            cli_construct_transaction.to_cli_args(),
+            args.split(' ').skip(1).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,597 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use color_eyre::eyre::WrapErr;
+use futures::StreamExt;
+use prettytable::Table;
+
+use crate::common::{JsonRpcClientExt, RpcQueryResponseExt};
+
+const CONCURRENCY_DEFAULT: u64 = 4;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ExecuteBatchContext)]
+pub struct ExecuteBatch {
+    /// What is the location of the batch manifest file (TOML or JSON)?
+    manifest_file_path: crate::types::path_buf::PathBuf,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// How many signers may send their transactions at the same time (default: 4)
+    concurrency: Option<u64>,
+    #[interactive_clap(long)]
+    /// Stop sending the remaining transactions after the first failure
+    fail_fast: bool,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Where to save the per-transaction result report (default: <manifest>.report.json)
+    report_file_path: Option<crate::types::path_buf::PathBuf>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct ExecuteBatchContext(crate::network::NetworkContext);
+
+impl ExecuteBatchContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ExecuteBatch as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let manifest_file_path: std::path::PathBuf = scope.manifest_file_path.clone().into();
+        let manifest = BatchManifest::from_file(&manifest_file_path)?;
+        let report_file_path: std::path::PathBuf = scope
+            .report_file_path
+            .clone()
+            .map(Into::into)
+            .unwrap_or_else(|| manifest_file_path.with_extension("report.json"));
+
+        let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
+            std::sync::Arc::new({
+                let credentials_home_dir = previous_context.config.credentials_home_dir.clone();
+                let output_format = previous_context.output_format.clone();
+                let concurrency = scope.concurrency.unwrap_or(CONCURRENCY_DEFAULT).max(1);
+                let fail_fast = scope.fail_fast;
+
+                move |network_config| {
+                    let report = execute_batch(
+                        network_config,
+                        &credentials_home_dir,
+                        manifest.clone(),
+                        concurrency as usize,
+                        fail_fast,
+                    )?;
+                    std::fs::write(&report_file_path, serde_json::to_vec_pretty(&report)?)
+                        .wrap_err_with(|| {
+                            format!("Failed to write to file: {:?}", &report_file_path)
+                        })?;
+
+                    if output_format.is_json() {
+                        crate::common::print_json_output(
+                            "batch_report",
+                            serde_json::to_value(&report)?,
+                        )?;
+                    } else {
+                        display_batch_report(&report);
+                    }
+                    eprintln!(
+                        "\nThe batch report is saved in the file {:?}",
+                        &report_file_path
+                    );
+
+                    let failed_count = report
+                        .iter()
+                        .filter(|entry| entry.status != BatchEntryStatus::Success)
+                        .count();
+                    if failed_count > 0 {
+                        color_eyre::eyre::bail!(
+                            "{failed_count} of {} transactions in the batch were not executed successfully",
+                            report.len()
+                        );
+                    }
+                    Ok(())
+                }
+            });
+
+        Ok(Self(crate::network::NetworkContext {
+            config: previous_context.config,
+            interacting_with_account_ids: vec![],
+            on_after_getting_network_callback,
+        }))
+    }
+}
+
+impl From<ExecuteBatchContext> for crate::network::NetworkContext {
+    fn from(item: ExecuteBatchContext) -> Self {
+        item.0
+    }
+}
+
+/// The batch manifest. Actions use the same representation as the JSON RPC
+/// `ActionView` (e.g. `{"Transfer": {"deposit": "1000000000000000000000000"}}`).
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct BatchManifest {
+    pub transactions: Vec<BatchTransaction>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct BatchTransaction {
+    pub signer_id: near_primitives::types::AccountId,
+    pub receiver_id: near_primitives::types::AccountId,
+    pub actions: Vec<near_primitives::views::ActionView>,
+}
+
+impl BatchManifest {
+    pub fn from_file(manifest_file_path: &std::path::Path) -> color_eyre::eyre::Result<Self> {
+        let data = std::fs::read_to_string(manifest_file_path)
+            .wrap_err_with(|| format!("Failed to open or read the file: {manifest_file_path:?}"))?;
+        let is_toml = manifest_file_path
+            .extension()
+            .map_or(false, |extension| extension == "toml");
+        Self::from_str_with_format(&data, is_toml)
+            .wrap_err_with(|| format!("Failed to parse the batch manifest: {manifest_file_path:?}"))
+    }
+
+    fn from_str_with_format(data: &str, is_toml: bool) -> color_eyre::eyre::Result<Self> {
+        let manifest: Self = if is_toml {
+            toml::from_str(data)?
+        } else {
+            serde_json::from_str(data)?
+        };
+        if manifest.transactions.is_empty() {
+            color_eyre::eyre::bail!("The batch manifest does not contain any transactions");
+        }
+        Ok(manifest)
+    }
+
+    pub fn prepopulated_transactions(
+        &self,
+    ) -> color_eyre::eyre::Result<Vec<crate::commands::PrepopulatedTransaction>> {
+        self.transactions
+            .iter()
+            .enumerate()
+            .map(|(index, transaction)| {
+                Ok(crate::commands::PrepopulatedTransaction {
+                    signer_id: transaction.signer_id.clone(),
+                    receiver_id: transaction.receiver_id.clone(),
+                    actions: transaction
+                        .actions
+                        .iter()
+                        .cloned()
+                        .map(near_primitives::transaction::Action::try_from)
+                        .collect::<Result<_, _>>()
+                        .map_err(|err| {
+                            color_eyre::eyre::eyre!(
+                                "Transaction #{} has an invalid action: {err}",
+                                index + 1
+                            )
+                        })?,
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchEntryStatus {
+    Success,
+    Failure,
+    Error,
+    /// The RPC answered before the transaction was executed
+    Pending,
+    Skipped,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BatchEntryReport {
+    pub index: usize,
+    pub signer_id: near_primitives::types::AccountId,
+    pub receiver_id: near_primitives::types::AccountId,
+    pub nonce: Option<near_primitives::types::Nonce>,
+    pub transaction_hash: Option<String>,
+    pub status: BatchEntryStatus,
+    pub error: Option<String>,
+}
+
+struct SignedBatchEntry {
+    index: usize,
+    signed_transaction: near_primitives::transaction::SignedTransaction,
+}
+
+#[tracing::instrument(name = "Executing the batch of transactions ...", skip_all)]
+fn execute_batch(
+    network_config: &crate::config::NetworkConfig,
+    credentials_home_dir: &std::path::Path,
+    manifest: BatchManifest,
+    concurrency: usize,
+    fail_fast: bool,
+) -> color_eyre::eyre::Result<Vec<BatchEntryReport>> {
+    let prepopulated_transactions = manifest.prepopulated_transactions()?;
+    let json_rpc_client = network_config.json_rpc_client();
+
+    let mut report: Vec<BatchEntryReport> = prepopulated_transactions
+        .iter()
+        .enumerate()
+        .map(|(index, transaction)| BatchEntryReport {
+            index,
+            signer_id: transaction.signer_id.clone(),
+            receiver_id: transaction.receiver_id.clone(),
+            nonce: None,
+            transaction_hash: None,
+            status: BatchEntryStatus::Skipped,
+            error: None,
+        })
+        .collect();
+
+    // Transactions of the same signer must reach the network in the nonce order,
+    // so they are sent sequentially, while different signers are processed concurrently.
+    let mut chains: Vec<Vec<SignedBatchEntry>> = Vec::new();
+    for (signer_id, indexes) in group_by_signer(&prepopulated_transactions) {
+        let key_pair = match find_signer_key_pair(network_config, credentials_home_dir, &signer_id)
+        {
+            Ok(key_pair) => key_pair,
+            Err(err) => {
+                mark_error(&mut report, &indexes, &err);
+                if fail_fast {
+                    return Ok(report);
+                }
+                continue;
+            }
+        };
+        let rpc_query_response = match json_rpc_client
+            .blocking_call_view_access_key(
+                &signer_id,
+                &key_pair.public_key,
+                near_primitives::types::BlockReference::latest(),
+            )
+            .wrap_err_with(|| {
+                format!(
+                    "Failed to fetch the most recent nonce value for <{signer_id}> on network <{}>",
+                    network_config.network_name
+                )
+            })
+            .and_then(|response| Ok((response.access_key_view()?.nonce, response.block_hash)))
+        {
+            Ok(response) => response,
+            Err(err) => {
+                mark_error(&mut report, &indexes, &err);
+                if fail_fast {
+                    return Ok(report);
+                }
+                continue;
+            }
+        };
+        let (current_nonce, block_hash) = rpc_query_response;
+
+        let chain = indexes
+            .into_iter()
+            .zip(consecutive_nonces(current_nonce))
+            .map(|(index, nonce)| {
+                let prepopulated_transaction = prepopulated_transactions[index].clone();
+                let unsigned_transaction = near_primitives::transaction::Transaction {
+                    public_key: key_pair.public_key.clone(),
+                    block_hash,
+                    nonce,
+                    signer_id: prepopulated_transaction.signer_id,
+                    receiver_id: prepopulated_transaction.receiver_id,
+                    actions: prepopulated_transaction.actions,
+                };
+                let signature = key_pair
+                    .private_key
+                    .sign(unsigned_transaction.get_hash_and_size().0.as_ref());
+                report[index].nonce = Some(nonce);
+                SignedBatchEntry {
+                    index,
+                    signed_transaction: near_primitives::transaction::SignedTransaction::new(
+                        signature,
+                        unsigned_transaction,
+                    ),
+                }
+            })
+            .collect();
+        chains.push(chain);
+    }
+
+    let is_stopped = AtomicBool::new(false);
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?;
+    let results: Vec<(usize, BatchEntryReportUpdate)> = runtime.block_on(
+        futures::stream::iter(chains)
+            .map(|chain| {
+                let json_rpc_client = &json_rpc_client;
+                let is_stopped = &is_stopped;
+                async move {
+                    let mut results = Vec::new();
+                    for entry in chain {
+                        if is_stopped.load(Ordering::SeqCst) {
+                            break;
+                        }
+                        let update =
+                            send_signed_transaction(json_rpc_client, entry.signed_transaction)
+                                .await;
+                        if fail_fast && update.status != BatchEntryStatus::Success {
+                            is_stopped.store(true, Ordering::SeqCst);
+                        }
+                        results.push((entry.index, update));
+                    }
+                    results
+                }
+            })
+            .buffer_unordered(concurrency)
+            .flat_map(futures::stream::iter)
+            .collect(),
+    );
+
+    for (index, update) in results {
+        report[index].transaction_hash = Some(update.transaction_hash);
+        report[index].status = update.status;
+        report[index].error = update.error;
+    }
+    Ok(report)
+}
+
+struct BatchEntryReportUpdate {
+    transaction_hash: String,
+    status: BatchEntryStatus,
+    error: Option<String>,
+}
+
+async fn send_signed_transaction(
+    json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    signed_transaction: near_primitives::transaction::SignedTransaction,
+) -> BatchEntryReportUpdate {
+    let transaction_hash = signed_transaction.get_hash().to_string();
+    match json_rpc_client
+        .call(
+            near_jsonrpc_client::methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
+                signed_transaction,
+            },
+        )
+        .await
+    {
+        Ok(transaction_info) => report_update(transaction_hash, transaction_info.status),
+        Err(err) => BatchEntryReportUpdate {
+            transaction_hash,
+            status: BatchEntryStatus::Error,
+            error: Some(err.to_string()),
+        },
+    }
+}
+
+fn report_update(
+    transaction_hash: String,
+    status: near_primitives::views::FinalExecutionStatus,
+) -> BatchEntryReportUpdate {
+    match status {
+        near_primitives::views::FinalExecutionStatus::SuccessValue(_) => BatchEntryReportUpdate {
+            transaction_hash,
+            status: BatchEntryStatus::Success,
+            error: None,
+        },
+        near_primitives::views::FinalExecutionStatus::NotStarted
+        | near_primitives::views::FinalExecutionStatus::Started => BatchEntryReportUpdate {
+            error: Some(format!(
+                "The transaction is not executed yet ({status:?}), check its status with `near transaction view-status {transaction_hash}`"
+            )),
+            transaction_hash,
+            status: BatchEntryStatus::Pending,
+        },
+        near_primitives::views::FinalExecutionStatus::Failure(
+            near_primitives::errors::TxExecutionError::ActionError(action_error),
+        ) => BatchEntryReportUpdate {
+            transaction_hash,
+            status: BatchEntryStatus::Failure,
+            error: crate::common::convert_action_error_to_cli_result(&action_error)
+                .err()
+                .map(|err| err.to_string()),
+        },
+        near_primitives::views::FinalExecutionStatus::Failure(
+            near_primitives::errors::TxExecutionError::InvalidTxError(invalid_tx_error),
+        ) => BatchEntryReportUpdate {
+            transaction_hash,
+            status: BatchEntryStatus::Failure,
+            error: crate::common::convert_invalid_tx_error_to_cli_result(&invalid_tx_error)
+                .err()
+                .map(|err| err.to_string()),
+        },
+    }
+}
+
+/// Groups transaction indexes by signer preserving the manifest order.
+fn group_by_signer(
+    transactions: &[crate::commands::PrepopulatedTransaction],
+) -> Vec<(near_primitives::types::AccountId, Vec<usize>)> {
+    let mut groups: Vec<(near_primitives::types::AccountId, Vec<usize>)> = Vec::new();
+    for (index, transaction) in transactions.iter().enumerate() {
+        if let Some((_, indexes)) = groups
+            .iter_mut()
+            .find(|(signer_id, _)| signer_id == &transaction.signer_id)
+        {
+            indexes.push(index);
+        } else {
+            groups.push((transaction.signer_id.clone(), vec![index]));
+        }
+    }
+    groups
+}
+
+fn consecutive_nonces(
+    current_nonce: near_primitives::types::Nonce,
+) -> impl Iterator<Item = near_primitives::types::Nonce> {
+    (1..).map(move |offset| current_nonce + offset)
+}
+
+fn mark_error(report: &mut [BatchEntryReport], indexes: &[usize], err: &color_eyre::eyre::Report) {
+    for index in indexes {
+        report[*index].status = BatchEntryStatus::Error;
+        report[*index].error = Some(format!("{err:#}"));
+    }
+}
+
+/// Looks up a full access key of the signer as `sign-with-keychain` does: in the keychain and then
+/// in the legacy keychain.
+fn find_signer_key_pair(
+    network_config: &crate::config::NetworkConfig,
+    credentials_home_dir: &std::path::Path,
+    signer_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<crate::transaction_signature_options::AccountKeyPair> {
+    if let Some(key_pair) =
+        crate::transaction_signature_options::sign_with_keychain::get_full_access_key_pair(
+            network_config,
+            signer_id,
+        )?
+    {
+        return Ok(key_pair);
+    }
+    let data_path =
+        crate::transaction_signature_options::sign_with_legacy_keychain::find_full_access_key_file(
+            credentials_home_dir,
+            network_config,
+            signer_id,
+        )?;
+    let data = std::fs::read_to_string(&data_path).wrap_err_with(|| {
+        format!(
+            "There are no full access keys for <{signer_id}> on network <{}> in the keychain or legacy keychain",
+            network_config.network_name
+        )
+    })?;
+    serde_json::from_str(&data)
+        .wrap_err_with(|| format!("Error reading data from file: {:?}", &data_path))
+}
+
+fn display_batch_report(report: &[BatchEntryReport]) {
+    let mut table = Table::new();
+    table.set_titles(prettytable::row![Fg=>"#", "Signer", "Receiver", "Nonce", "Status", "Transaction hash / Error"]);
+
+    for entry in report {
+        table.add_row(prettytable::row![
+            Fg->entry.index + 1,
+            entry.signer_id,
+            entry.receiver_id,
+            entry.nonce.map(|nonce| nonce.to_string()).unwrap_or_default(),
+            format!("{:?}", entry.status),
+            match &entry.error {
+                Some(error) => error.clone(),
+                None => entry.transaction_hash.clone().unwrap_or_default(),
+            }
+        ]);
+    }
+
+    table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    eprintln!();
+    table.print(&mut std::io::stderr()).ok();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_json_manifest() {
+        let manifest = BatchManifest::from_str_with_format(
+            r#"{
+                "transactions": [
+                    {
+                        "signer_id": "alice.testnet",
+                        "receiver_id": "bob.testnet",
+                        "actions": [{"Transfer": {"deposit": "1000000000000000000000000"}}]
+                    },
+                    {
+                        "signer_id": "alice.testnet",
+                        "receiver_id": "new.alice.testnet",
+                        "actions": ["CreateAccount", {"Transfer": {"deposit": "1"}}]
+                    }
+                ]
+            }"#,
+            false,
+        )
+        .unwrap();
+        let transactions = manifest.prepopulated_transactions().unwrap();
+        assert_eq!(transactions.len(), 2);
+        assert_eq!(transactions[1].actions.len(), 2);
+        assert!(matches!(
+            transactions[1].actions[0],
+            near_primitives::transaction::Action::CreateAccount(_)
+        ));
+    }
+
+    #[test]
+    fn parse_toml_manifest() {
+        let manifest = BatchManifest::from_str_with_format(
+            r#"
+            [[transactions]]
+            signer_id = "alice.testnet"
+            receiver_id = "counter.testnet"
+            actions = [
+                { FunctionCall = { method_name = "increment", args = "e30=", gas = 30000000000000, deposit = "0" } },
+            ]
+            "#,
+            true,
+        )
+        .unwrap();
+        let transactions = manifest.prepopulated_transactions().unwrap();
+        assert_eq!(transactions.len(), 1);
+        match &transactions[0].actions[0] {
+            near_primitives::transaction::Action::FunctionCall(function_call_action) => {
+                assert_eq!(function_call_action.method_name, "increment");
+                assert_eq!(function_call_action.args, b"{}");
+            }
+            action => panic!("Unexpected action: {action:?}"),
+        }
+    }
+
+    #[test]
+    fn empty_manifest_is_rejected() {
+        assert!(BatchManifest::from_str_with_format(r#"{"transactions": []}"#, false).is_err());
+    }
+
+    #[test]
+    fn transactions_are_grouped_by_signer_in_manifest_order() {
+        let transaction = |signer_id: &str| crate::commands::PrepopulatedTransaction {
+            signer_id: signer_id.parse().unwrap(),
+            receiver_id: "bob.testnet".parse().unwrap(),
+            actions: vec![],
+        };
+        let groups = group_by_signer(&[
+            transaction("alice.testnet"),
+            transaction("carol.testnet"),
+            transaction("alice.testnet"),
+        ]);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].1, vec![0, 2]);
+        assert_eq!(groups[1].1, vec![1]);
+        assert_eq!(
+            consecutive_nonces(41).take(3).collect::<Vec<_>>(),
+            vec![42, 43, 44]
+        );
+    }
+
+    #[test]
+    fn only_executed_transactions_are_reported_as_successful() {
+        let status = |status| report_update("hash".to_string(), status).status;
+        assert_eq!(
+            status(near_primitives::views::FinalExecutionStatus::SuccessValue(
+                vec![]
+            )),
+            BatchEntryStatus::Success
+        );
+        assert_eq!(
+            status(near_primitives::views::FinalExecutionStatus::NotStarted),
+            BatchEntryStatus::Pending
+        );
+        assert_eq!(
+            status(near_primitives::views::FinalExecutionStatus::Started),
+            BatchEntryStatus::Pending
+        );
+        assert_eq!(
+            status(near_primitives::views::FinalExecutionStatus::Failure(
+                near_primitives::errors::TxExecutionError::InvalidTxError(
+                    near_primitives::errors::InvalidTxError::Expired
+                )
+            )),
+            BatchEntryStatus::Failure
+        );
+    }
+}
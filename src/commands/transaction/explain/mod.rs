@@ -0,0 +1,594 @@
+use std::fmt::Write;
+
+use color_eyre::eyre::WrapErr;
+
+use super::view_status::receipt_tree::{describe_action, describe_status, render_tree, TreeNode};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ExplainContext)]
+pub struct Explain {
+    /// Enter the hash of the transaction you need to explain:
+    transaction_hash: crate::types::crypto_hash::CryptoHash,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Save the receipt graph in the DOT format (Graphviz) to this file
+    export_dot_file_path: Option<crate::types::path_buf::PathBuf>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Save the receipt graph in the JSON format to this file
+    export_json_file_path: Option<crate::types::path_buf::PathBuf>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct ExplainContext(crate::network::NetworkContext);
+
+impl ExplainContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<Explain as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
+            std::sync::Arc::new({
+                let tx_hash: near_primitives::hash::CryptoHash = scope.transaction_hash.into();
+                let export_dot_file_path: Option<std::path::PathBuf> =
+                    scope.export_dot_file_path.clone().map(Into::into);
+                let export_json_file_path: Option<std::path::PathBuf> =
+                    scope.export_json_file_path.clone().map(Into::into);
+                let output_format = previous_context.output_format.clone();
+
+                move |network_config| {
+                    let response = super::view_status::get_transaction_info_with_receipts(
+                        network_config,
+                        tx_hash,
+                        near_primitives::views::TxExecutionStatus::Final,
+                    )?;
+                    let Some(near_primitives::views::FinalExecutionOutcomeViewEnum::FinalExecutionOutcomeWithReceipt(outcome)) =
+                        response.final_execution_outcome
+                    else {
+                        color_eyre::eyre::bail!(
+                            "The RPC server did not return the receipts of the transaction {tx_hash}"
+                        );
+                    };
+                    let receipt_graph =
+                        ReceiptGraph::new(&outcome.final_outcome, &outcome.receipts);
+
+                    if let Some(export_dot_file_path) = &export_dot_file_path {
+                        std::fs::write(export_dot_file_path, receipt_graph.to_dot())
+                            .wrap_err_with(|| {
+                                format!("Failed to write to file: {:?}", export_dot_file_path)
+                            })?;
+                        eprintln!(
+                            "\nThe receipt graph is saved in the file {:?}",
+                            export_dot_file_path
+                        );
+                    }
+                    if let Some(export_json_file_path) = &export_json_file_path {
+                        std::fs::write(
+                            export_json_file_path,
+                            serde_json::to_vec_pretty(&receipt_graph)?,
+                        )
+                        .wrap_err_with(|| {
+                            format!("Failed to write to file: {:?}", export_json_file_path)
+                        })?;
+                        eprintln!(
+                            "\nThe receipt graph is saved in the file {:?}",
+                            export_json_file_path
+                        );
+                    }
+
+                    if output_format.is_json() {
+                        return crate::common::print_json_output(
+                            "transaction_explanation",
+                            serde_json::to_value(&receipt_graph)?,
+                        );
+                    }
+                    eprintln!("\n{}", receipt_graph.to_text());
+                    Ok(())
+                }
+            });
+
+        Ok(Self(crate::network::NetworkContext {
+            config: previous_context.config,
+            interacting_with_account_ids: vec![],
+            on_after_getting_network_callback,
+        }))
+    }
+}
+
+impl From<ExplainContext> for crate::network::NetworkContext {
+    fn from(item: ExplainContext) -> Self {
+        item.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiptNodeKind {
+    Transaction,
+    Receipt,
+    Refund,
+}
+
+/// A hop of the transaction: the transaction itself or one of the receipts it produced.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ReceiptNode {
+    pub id: near_primitives::hash::CryptoHash,
+    pub kind: ReceiptNodeKind,
+    pub parent_id: Option<near_primitives::hash::CryptoHash>,
+    pub children: Vec<near_primitives::hash::CryptoHash>,
+    pub predecessor_id: near_primitives::types::AccountId,
+    pub receiver_id: near_primitives::types::AccountId,
+    pub actions: Vec<String>,
+    pub gas_burnt: near_primitives::types::Gas,
+    #[serde(with = "near_primitives::serialize::dec_format")]
+    pub tokens_burnt: near_primitives::types::Balance,
+    #[serde(with = "near_primitives::serialize::dec_format")]
+    pub refund: near_primitives::types::Balance,
+    pub status: String,
+    pub failed: bool,
+    pub logs: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ReceiptFailure {
+    pub id: near_primitives::hash::CryptoHash,
+    pub executor_id: near_primitives::types::AccountId,
+    pub explanation: String,
+}
+
+/// The parent/child graph of the receipts of a transaction, the nodes are listed in depth-first order.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ReceiptGraph {
+    pub transaction_hash: near_primitives::hash::CryptoHash,
+    pub total_gas_burnt: near_primitives::types::Gas,
+    #[serde(with = "near_primitives::serialize::dec_format")]
+    pub total_tokens_burnt: near_primitives::types::Balance,
+    #[serde(with = "near_primitives::serialize::dec_format")]
+    pub total_refund: near_primitives::types::Balance,
+    pub first_failure: Option<ReceiptFailure>,
+    pub nodes: Vec<ReceiptNode>,
+}
+
+impl ReceiptGraph {
+    pub fn new(
+        outcome: &near_primitives::views::FinalExecutionOutcomeView,
+        receipts: &[near_primitives::views::ReceiptView],
+    ) -> Self {
+        let transaction = &outcome.transaction;
+        let transaction_outcome = &outcome.transaction_outcome.outcome;
+        let mut nodes = vec![ReceiptNode {
+            id: transaction.hash,
+            kind: ReceiptNodeKind::Transaction,
+            parent_id: None,
+            children: transaction_outcome.receipt_ids.clone(),
+            predecessor_id: transaction.signer_id.clone(),
+            receiver_id: transaction.receiver_id.clone(),
+            actions: transaction.actions.iter().map(describe_action).collect(),
+            gas_burnt: transaction_outcome.gas_burnt,
+            tokens_burnt: transaction_outcome.tokens_burnt,
+            refund: 0,
+            status: describe_status(&transaction_outcome.status),
+            failed: matches!(
+                transaction_outcome.status,
+                near_primitives::views::ExecutionStatusView::Failure(_)
+            ),
+            logs: transaction_outcome.logs.clone(),
+        }];
+        let mut stack: Vec<_> = transaction_outcome
+            .receipt_ids
+            .iter()
+            .rev()
+            .map(|receipt_id| (*receipt_id, transaction.hash))
+            .collect();
+        while let Some((receipt_id, parent_id)) = stack.pop() {
+            if nodes.iter().any(|node| node.id == receipt_id) {
+                continue;
+            }
+            let receipt = receipts
+                .iter()
+                .find(|receipt| receipt.receipt_id == receipt_id);
+            let receipt_outcome = outcome
+                .receipts_outcome
+                .iter()
+                .find(|receipt_outcome| receipt_outcome.id == receipt_id)
+                .map(|receipt_outcome| &receipt_outcome.outcome);
+            let actions = match receipt.map(|receipt| &receipt.receipt) {
+                Some(near_primitives::views::ReceiptEnumView::Action { actions, .. }) => {
+                    actions.as_slice()
+                }
+                _ => &[],
+            };
+            let is_refund = receipt.map_or(false, |receipt| receipt.predecessor_id.is_system())
+                && !actions.is_empty()
+                && actions.iter().all(|action| {
+                    matches!(action, near_primitives::views::ActionView::Transfer { .. })
+                });
+            let refund = if is_refund {
+                actions
+                    .iter()
+                    .map(|action| match action {
+                        near_primitives::views::ActionView::Transfer { deposit } => *deposit,
+                        _ => 0,
+                    })
+                    .sum()
+            } else {
+                0
+            };
+            let children = receipt_outcome
+                .map(|receipt_outcome| receipt_outcome.receipt_ids.clone())
+                .unwrap_or_default();
+            stack.extend(
+                children
+                    .iter()
+                    .rev()
+                    .map(|child_id| (*child_id, receipt_id)),
+            );
+            nodes.push(ReceiptNode {
+                id: receipt_id,
+                kind: if is_refund {
+                    ReceiptNodeKind::Refund
+                } else {
+                    ReceiptNodeKind::Receipt
+                },
+                parent_id: Some(parent_id),
+                children,
+                predecessor_id: receipt
+                    .map(|receipt| receipt.predecessor_id.clone())
+                    .unwrap_or_else(|| transaction.signer_id.clone()),
+                receiver_id: receipt
+                    .map(|receipt| receipt.receiver_id.clone())
+                    .or_else(|| {
+                        receipt_outcome.map(|receipt_outcome| receipt_outcome.executor_id.clone())
+                    })
+                    .unwrap_or_else(|| transaction.receiver_id.clone()),
+                actions: actions.iter().map(describe_action).collect(),
+                gas_burnt: receipt_outcome.map_or(0, |receipt_outcome| receipt_outcome.gas_burnt),
+                tokens_burnt: receipt_outcome
+                    .map_or(0, |receipt_outcome| receipt_outcome.tokens_burnt),
+                refund,
+                status: receipt_outcome.map_or_else(
+                    || "pending".to_string(),
+                    |receipt_outcome| describe_status(&receipt_outcome.status),
+                ),
+                failed: receipt_outcome.map_or(false, |receipt_outcome| {
+                    matches!(
+                        receipt_outcome.status,
+                        near_primitives::views::ExecutionStatusView::Failure(_)
+                    )
+                }),
+                logs: receipt_outcome
+                    .map(|receipt_outcome| receipt_outcome.logs.clone())
+                    .unwrap_or_default(),
+            });
+        }
+
+        Self {
+            transaction_hash: transaction.hash,
+            total_gas_burnt: nodes.iter().map(|node| node.gas_burnt).sum(),
+            total_tokens_burnt: nodes.iter().map(|node| node.tokens_burnt).sum(),
+            total_refund: nodes.iter().map(|node| node.refund).sum(),
+            first_failure: first_failure(outcome),
+            nodes,
+        }
+    }
+
+    fn node(&self, id: &near_primitives::hash::CryptoHash) -> Option<&ReceiptNode> {
+        self.nodes.iter().find(|node| &node.id == id)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = match self.nodes.first() {
+            Some(root) => render_tree(self.tree_node(root), |id| {
+                self.node(id).map(|node| self.tree_node(node))
+            }),
+            None => String::new(),
+        };
+        let _ = writeln!(
+            text,
+            "\nGas burned: {}\nTransaction fee: {}\nRefunded: {}",
+            crate::common::NearGas::from_gas(self.total_gas_burnt),
+            crate::types::near_token::NearToken::from_yoctonear(self.total_tokens_burnt),
+            crate::types::near_token::NearToken::from_yoctonear(self.total_refund),
+        );
+        match &self.first_failure {
+            Some(failure) => {
+                let _ = writeln!(
+                    text,
+                    "\nThe first failure happened in {} executed by <{}>:\n{}",
+                    failure.id, failure.executor_id, failure.explanation
+                );
+            }
+            None => {
+                let _ = writeln!(text, "\nAll receipts were executed successfully.");
+            }
+        }
+        text
+    }
+
+    /// The node as rendered by the receipt tree of `view-status`, with the first failure marked.
+    fn tree_node(&self, node: &ReceiptNode) -> TreeNode {
+        let marker = match &self.first_failure {
+            Some(failure) if failure.id == node.id => "✖ ",
+            _ => "",
+        };
+        let title = match node.kind {
+            ReceiptNodeKind::Transaction => "Transaction",
+            ReceiptNodeKind::Receipt => "Receipt",
+            ReceiptNodeKind::Refund => "Refund",
+        };
+        TreeNode {
+            title: format!(
+                "{marker}{title} {}: {} → {}",
+                node.id, node.predecessor_id, node.receiver_id
+            ),
+            actions: node.actions.clone(),
+            gas_burnt: node.gas_burnt,
+            status: node.status.clone(),
+            logs: node.logs.clone(),
+            children: node.children.clone(),
+        }
+    }
+
+    /// The graph in the DOT language, to be rendered with Graphviz (e.g. `dot -Tsvg`).
+    pub fn to_dot(&self) -> String {
+        let mut dot = format!(
+            "digraph \"{}\" {{\n    node [shape=box, fontname=\"monospace\"];\n",
+            self.transaction_hash
+        );
+        for node in &self.nodes {
+            let label = std::iter::once(format!(
+                "{:?} {}\n{} → {}",
+                node.kind, node.id, node.predecessor_id, node.receiver_id
+            ))
+            .chain(node.actions.iter().cloned())
+            .chain([
+                format!(
+                    "gas burnt: {}",
+                    crate::common::NearGas::from_gas(node.gas_burnt)
+                ),
+                node.status.clone(),
+            ])
+            .flat_map(|line| line.lines().map(escape_dot_string).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+            .join("\\n");
+            let style = match (node.failed, node.kind) {
+                (true, _) => ", color=red, fontcolor=red",
+                (false, ReceiptNodeKind::Refund) => ", style=dashed",
+                (false, _) => "",
+            };
+            let _ = writeln!(dot, "    \"{}\" [label=\"{label}\"{style}];", node.id);
+        }
+        for node in &self.nodes {
+            for child_id in &node.children {
+                let _ = writeln!(dot, "    \"{}\" -> \"{child_id}\";", node.id);
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Escapes the text for a double-quoted DOT string.
+fn escape_dot_string(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Finds the failure that happened first in the execution order and explains it.
+fn first_failure(
+    outcome: &near_primitives::views::FinalExecutionOutcomeView,
+) -> Option<ReceiptFailure> {
+    std::iter::once(&outcome.transaction_outcome)
+        .chain(outcome.receipts_outcome.iter())
+        .find_map(
+            |execution_outcome| match &execution_outcome.outcome.status {
+                near_primitives::views::ExecutionStatusView::Failure(tx_execution_error) => {
+                    let result = match tx_execution_error {
+                        near_primitives::errors::TxExecutionError::ActionError(action_error) => {
+                            crate::common::convert_action_error_to_cli_result(action_error)
+                        }
+                        near_primitives::errors::TxExecutionError::InvalidTxError(
+                            invalid_tx_error,
+                        ) => {
+                            crate::common::convert_invalid_tx_error_to_cli_result(invalid_tx_error)
+                        }
+                    };
+                    Some(ReceiptFailure {
+                        id: execution_outcome.id,
+                        executor_id: execution_outcome.outcome.executor_id.clone(),
+                        explanation: match result {
+                            Err(report) => report.to_string(),
+                            Ok(()) => tx_execution_error.to_string(),
+                        },
+                    })
+                }
+                _ => None,
+            },
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn execution_outcome(
+        id: near_primitives::hash::CryptoHash,
+        executor_id: &str,
+        receipt_ids: &[near_primitives::hash::CryptoHash],
+        status: serde_json::Value,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "proof": [],
+            "block_hash": near_primitives::hash::CryptoHash::default(),
+            "id": id,
+            "outcome": {
+                "logs": [],
+                "receipt_ids": receipt_ids,
+                "gas_burnt": 1_000_000_000_000u64,
+                "tokens_burnt": "100",
+                "executor_id": executor_id,
+                "status": status,
+            }
+        })
+    }
+
+    fn receipt(
+        id: near_primitives::hash::CryptoHash,
+        predecessor_id: &str,
+        receiver_id: &str,
+        action: serde_json::Value,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "predecessor_id": predecessor_id,
+            "receiver_id": receiver_id,
+            "receipt_id": id,
+            "receipt": {"Action": {
+                "signer_id": "alice.testnet",
+                "signer_public_key": "ed25519:8nDkVwdpkE3Ft2sCtdH1dYjUqNcbUDDE8Ew4wmSUiupd",
+                "gas_price": "100000000",
+                "output_data_receivers": [],
+                "input_data_ids": [],
+                "actions": [action],
+            }},
+        })
+    }
+
+    #[test]
+    fn receipt_graph_flags_the_failing_cross_contract_call() {
+        let transaction_hash = near_primitives::hash::CryptoHash::hash_bytes(b"transaction");
+        let call_id = near_primitives::hash::CryptoHash::hash_bytes(b"call");
+        let cross_contract_call_id = near_primitives::hash::CryptoHash::hash_bytes(b"xcc");
+        let refund_id = near_primitives::hash::CryptoHash::hash_bytes(b"refund");
+        let function_call = serde_json::json!({"FunctionCall": {
+            "method_name": "swap", "args": "e30=", "gas": 30_000_000_000_000u64, "deposit": "0"
+        }});
+        let outcome: near_primitives::views::FinalExecutionOutcomeView =
+            serde_json::from_value(serde_json::json!({
+                "status": {"SuccessValue": ""},
+                "transaction": {
+                    "signer_id": "alice.testnet",
+                    "public_key": "ed25519:8nDkVwdpkE3Ft2sCtdH1dYjUqNcbUDDE8Ew4wmSUiupd",
+                    "nonce": 1,
+                    "receiver_id": "dex.testnet",
+                    "actions": [function_call],
+                    "signature": "ed25519:3s1dvZdQtcAjBksMHFrysqvF63wnyMHPA4owNQmCJZ2EBakZEKdtMsLqrHdKWQjJbSRN6kRknN2WdwSBLWGCokXj",
+                    "hash": transaction_hash,
+                },
+                "transaction_outcome": execution_outcome(
+                    transaction_hash, "alice.testnet", &[call_id], serde_json::json!({"SuccessReceiptId": call_id})
+                ),
+                "receipts_outcome": [
+                    execution_outcome(call_id, "dex.testnet", &[cross_contract_call_id], serde_json::json!({"SuccessValue": ""})),
+                    execution_outcome(cross_contract_call_id, "token.testnet", &[refund_id], serde_json::json!({"Failure": {"ActionError": {
+                        "index": 0,
+                        "kind": {"AccountDoesNotExist": {"account_id": "token.testnet"}}
+                    }}})),
+                    execution_outcome(refund_id, "dex.testnet", &[], serde_json::json!({"SuccessValue": ""})),
+                ],
+            }))
+            .unwrap();
+        let receipts: Vec<near_primitives::views::ReceiptView> =
+            serde_json::from_value(serde_json::json!([
+                receipt(
+                    call_id,
+                    "alice.testnet",
+                    "dex.testnet",
+                    function_call.clone()
+                ),
+                receipt(
+                    cross_contract_call_id,
+                    "dex.testnet",
+                    "token.testnet",
+                    function_call
+                ),
+                receipt(
+                    refund_id,
+                    "system",
+                    "dex.testnet",
+                    serde_json::json!({"Transfer": {"deposit": "5"}})
+                ),
+            ]))
+            .unwrap();
+
+        let receipt_graph = ReceiptGraph::new(&outcome, &receipts);
+        assert_eq!(
+            receipt_graph
+                .nodes
+                .iter()
+                .map(|node| (node.id, node.kind, node.parent_id))
+                .collect::<Vec<_>>(),
+            vec![
+                (transaction_hash, ReceiptNodeKind::Transaction, None),
+                (call_id, ReceiptNodeKind::Receipt, Some(transaction_hash)),
+                (
+                    cross_contract_call_id,
+                    ReceiptNodeKind::Receipt,
+                    Some(call_id)
+                ),
+                (
+                    refund_id,
+                    ReceiptNodeKind::Refund,
+                    Some(cross_contract_call_id)
+                ),
+            ]
+        );
+        assert_eq!(receipt_graph.total_gas_burnt, 4_000_000_000_000);
+        assert_eq!(receipt_graph.total_refund, 5);
+        let first_failure = receipt_graph.first_failure.as_ref().unwrap();
+        assert_eq!(first_failure.id, cross_contract_call_id);
+        assert!(first_failure
+            .explanation
+            .contains("TX receiver ID <token.testnet> doesn't exist"));
+
+        let dot = receipt_graph.to_dot();
+        assert!(dot.contains(&format!("\"{call_id}\" -> \"{cross_contract_call_id}\";")));
+        assert!(dot.contains("color=red"));
+        assert!(dot.contains("style=dashed"));
+
+        let text = receipt_graph.to_text();
+        assert!(text.starts_with(&format!(
+            "Transaction {transaction_hash}: alice.testnet → dex.testnet\n\
+             │   actions:   call swap (gas: 30.0 Tgas, deposit: 0 NEAR)\n"
+        )));
+        assert!(text.contains(&format!(
+            "    └── ✖ Receipt {cross_contract_call_id}: dex.testnet → token.testnet\n"
+        )));
+        assert!(text.contains(&format!(
+            "        └── Refund {refund_id}: system → dex.testnet\n\
+             \x20               actions:   transfer 5 yoctoNEAR\n"
+        )));
+    }
+
+    #[test]
+    fn dot_labels_are_escaped() {
+        let id = near_primitives::hash::CryptoHash::hash_bytes(b"transaction");
+        let receipt_graph = ReceiptGraph {
+            transaction_hash: id,
+            total_gas_burnt: 0,
+            total_tokens_burnt: 0,
+            total_refund: 0,
+            first_failure: None,
+            nodes: vec![ReceiptNode {
+                id,
+                kind: ReceiptNodeKind::Transaction,
+                parent_id: None,
+                children: vec![],
+                predecessor_id: "alice.testnet".parse().unwrap(),
+                receiver_id: "bob.testnet".parse().unwrap(),
+                actions: vec![],
+                gas_burnt: 0,
+                tokens_burnt: 0,
+                refund: 0,
+                status: r#"success, returned {"path":"C:\dir\"}"#.to_string() + "\nline",
+                failed: false,
+                logs: vec![],
+            }],
+        };
+        assert!(receipt_graph.to_dot().contains(
+            r#"\ngas burnt: 0 Tgas\nsuccess, returned {\"path\":\"C:\\dir\\\"}\nline"];"#
+        ));
+    }
+}
@@ -1,7 +1,11 @@
 #![allow(clippy::enum_variant_names, clippy::large_enum_variant)]
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
+mod broadcast_bundle;
 pub mod construct_transaction;
+mod execute_batch;
+mod explain;
+mod prepare_offline;
 mod print_transaction;
 mod reconstruct_transaction;
 mod send_meta_transaction;
@@ -27,6 +31,11 @@ pub enum TransactionActions {
     ))]
     /// Execute function (contract method)
     ViewStatus(self::view_status::TransactionInfo),
+    #[strum_discriminants(strum(
+        message = "explain                  - Explain the receipts of a transaction and find where it failed (with DOT/JSON export)"
+    ))]
+    /// Explain the receipts of a transaction and find where it failed (with DOT/JSON export)
+    Explain(self::explain::Explain),
     #[strum_discriminants(strum(
         message = "reconstruct-transaction  - Use any existing transaction from the chain to construct NEAR CLI command (helpful tool for re-submitting similar transactions)"
     ))]
@@ -37,6 +46,11 @@ pub enum TransactionActions {
     ))]
     /// Construct a new transaction
     ConstructTransaction(self::construct_transaction::ConstructTransaction),
+    #[strum_discriminants(strum(
+        message = "prepare-offline          - Prepare an unsigned transaction (nonce, recent block hash) to sign it on an offline machine"
+    ))]
+    /// Prepare an unsigned transaction (nonce, recent block hash) to sign it on an offline machine
+    PrepareOffline(self::prepare_offline::PrepareOffline),
     #[strum_discriminants(strum(
         message = "sign-transaction         - Sign previously prepared unsigned transaction"
     ))]
@@ -52,9 +66,19 @@ pub enum TransactionActions {
     ))]
     /// Send a signed transaction
     SendSignedTransaction(self::send_signed_transaction::SignedTransaction),
+    #[strum_discriminants(strum(
+        message = "broadcast-bundle         - Send a transaction bundle signed on an offline machine"
+    ))]
+    /// Send a transaction bundle signed on an offline machine
+    BroadcastBundle(self::broadcast_bundle::BroadcastBundle),
     #[strum_discriminants(strum(
         message = "send-meta-transaction    - Act as a relayer to send a signed delegate action (meta-transaction)"
     ))]
     /// Act as a relayer to send a signed delegate action (meta-transaction)
     SendMetaTransaction(self::send_meta_transaction::SendMetaTransaction),
+    #[strum_discriminants(strum(
+        message = "execute-batch            - Sign and send a batch of transactions described in a manifest file (TOML or JSON)"
+    ))]
+    /// Sign and send a batch of transactions described in a manifest file (TOML or JSON)
+    ExecuteBatch(self::execute_batch::ExecuteBatch),
 }
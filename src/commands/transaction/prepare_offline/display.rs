@@ -0,0 +1,54 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::PrepareOfflineContext)]
+#[interactive_clap(output_context = DisplayContext)]
+pub struct Display {
+    #[interactive_clap(long)]
+    /// Print a QR code as well (e.g. to take the transaction bundle to an air-gapped machine)
+    qr_code: bool,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct DisplayContext(crate::network::NetworkContext);
+
+impl DisplayContext {
+    pub fn from_previous_context(
+        previous_context: super::PrepareOfflineContext,
+        scope: &<Display as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let qr_code = scope.qr_code;
+        let output_format = previous_context.global_context.output_format.clone();
+        Ok(Self(previous_context.network_context(
+            move |transaction_bundle| {
+                if output_format.is_json() {
+                    crate::common::print_json_output(
+                        "transaction_bundle",
+                        serde_json::to_value(&transaction_bundle)?,
+                    )?;
+                }
+                let transaction_bundle_payload = transaction_bundle.to_payload();
+                eprintln!("\nTransaction bundle:\n{transaction_bundle_payload}\n");
+                if qr_code {
+                    eprintln!(
+                        "{}",
+                        crate::common::render_qr_code(&transaction_bundle_payload)?
+                    );
+                }
+                eprintln!(
+                    "Take this transaction bundle to the offline machine and sign the transaction there with:\n$ {} --offline transaction sign-transaction '<transaction bundle>' network-config {}\n",
+                    crate::common::get_near_exec_path(),
+                    transaction_bundle.network_name
+                );
+                Ok(())
+            },
+        )))
+    }
+}
+
+impl From<DisplayContext> for crate::network::NetworkContext {
+    fn from(item: DisplayContext) -> Self {
+        item.0
+    }
+}
@@ -0,0 +1,153 @@
+use color_eyre::eyre::WrapErr;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+use crate::common::{JsonRpcClientExt, RpcQueryResponseExt};
+
+mod display;
+mod save_to_file;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = PrepareOfflineContext)]
+pub struct PrepareOffline {
+    /// Enter the unsigned transaction encoded in base64 (its nonce and block hash will be replaced):
+    unsigned_transaction: crate::types::transaction::TransactionAsBase64,
+    #[interactive_clap(subcommand)]
+    output: Output,
+}
+
+#[derive(Debug, Clone)]
+pub struct PrepareOfflineContext {
+    global_context: crate::GlobalContext,
+    unsigned_transaction: near_primitives::transaction::Transaction,
+}
+
+impl PrepareOfflineContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<PrepareOffline as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self {
+            global_context: previous_context,
+            unsigned_transaction: scope.unsigned_transaction.inner.clone(),
+        })
+    }
+
+    /// The network context that prepares the transaction bundle on the selected network and
+    /// passes it to the output.
+    fn network_context(
+        self,
+        output: impl Fn(crate::types::transaction_bundle::TransactionBundle) -> crate::CliResult
+            + 'static,
+    ) -> crate::network::NetworkContext {
+        let interacting_with_account_ids = vec![
+            self.unsigned_transaction.signer_id.clone(),
+            self.unsigned_transaction.receiver_id.clone(),
+        ];
+        let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
+            std::sync::Arc::new({
+                let unsigned_transaction = self.unsigned_transaction;
+                let output_format = self.global_context.output_format.clone();
+
+                move |network_config| {
+                    output(prepare_transaction_bundle(
+                        network_config,
+                        unsigned_transaction.clone(),
+                        &output_format,
+                    )?)
+                }
+            });
+        crate::network::NetworkContext {
+            config: self.global_context.config,
+            interacting_with_account_ids,
+            on_after_getting_network_callback,
+        }
+    }
+}
+
+#[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = PrepareOfflineContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+/// How would you like to proceed?
+pub enum Output {
+    #[strum_discriminants(strum(
+        message = "save-to-file     - Save the transaction bundle to file"
+    ))]
+    /// Save the transaction bundle to file
+    SaveToFile(self::save_to_file::SaveToFile),
+    #[strum_discriminants(strum(
+        message = "display          - Print the transaction bundle to terminal (as text or QR code)"
+    ))]
+    /// Print the transaction bundle to terminal (as text or QR code)
+    Display(self::display::Display),
+}
+
+#[tracing::instrument(name = "Preparing the transaction for offline signing ...", skip_all)]
+fn prepare_transaction_bundle(
+    network_config: &crate::config::NetworkConfig,
+    unsigned_transaction: near_primitives::transaction::Transaction,
+    output_format: &crate::common::OutputFormat,
+) -> color_eyre::eyre::Result<crate::types::transaction_bundle::TransactionBundle> {
+    let rpc_query_response = network_config
+        .json_rpc_client()
+        .blocking_call_view_access_key(
+            &unsigned_transaction.signer_id,
+            &unsigned_transaction.public_key,
+            near_primitives::types::Finality::Final.into(),
+        )
+        .wrap_err_with(|| {
+            format!(
+                "Failed to fetch the access key <{}> of <{}> on network <{}>",
+                unsigned_transaction.public_key,
+                unsigned_transaction.signer_id,
+                network_config.network_name
+            )
+        })?;
+    let current_nonce = rpc_query_response
+        .access_key_view()
+        .wrap_err("Error current_nonce")?
+        .nonce;
+    let transaction_validity_period = network_config
+        .json_rpc_client()
+        .blocking_call(
+            near_jsonrpc_client::methods::EXPERIMENTAL_protocol_config::RpcProtocolConfigRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+            },
+        )
+        .wrap_err_with(|| {
+            format!(
+                "Failed to fetch the protocol config on network <{}>",
+                network_config.network_name
+            )
+        })?
+        .transaction_validity_period;
+
+    let transaction_bundle = crate::types::transaction_bundle::TransactionBundle {
+        version: crate::types::transaction_bundle::TRANSACTION_BUNDLE_VERSION,
+        network_name: network_config.network_name.clone(),
+        block_height: rpc_query_response.block_height,
+        expires_at_block_height: rpc_query_response.block_height + transaction_validity_period,
+        unsigned_transaction: near_primitives::transaction::Transaction {
+            nonce: current_nonce + 1,
+            block_hash: rpc_query_response.block_hash,
+            ..unsigned_transaction
+        }
+        .into(),
+        signed_transaction: None,
+    };
+
+    if !output_format.is_json() {
+        eprintln!(
+            "\nThe transaction is prepared with nonce {} and block #{} ({}).",
+            current_nonce + 1,
+            transaction_bundle.block_height,
+            rpc_query_response.block_hash
+        );
+        eprintln!(
+            "It has to be signed and sent to the network before block #{} (in {transaction_validity_period} blocks), and before any other transaction signed with the access key <{}>.",
+            transaction_bundle.expires_at_block_height,
+            transaction_bundle.unsigned_transaction.inner.public_key
+        );
+    }
+    Ok(transaction_bundle)
+}
@@ -0,0 +1,59 @@
+use inquire::CustomType;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::PrepareOfflineContext)]
+#[interactive_clap(output_context = SaveToFileContext)]
+pub struct SaveToFile {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What is the location of the file to save the transaction bundle (path/to/transaction-bundle.json)?
+    file_path: crate::types::path_buf::PathBuf,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct SaveToFileContext(crate::network::NetworkContext);
+
+impl SaveToFileContext {
+    pub fn from_previous_context(
+        previous_context: super::PrepareOfflineContext,
+        scope: &<SaveToFile as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let file_path: std::path::PathBuf = scope.file_path.clone().into();
+        Ok(Self(previous_context.network_context(
+            move |transaction_bundle| {
+                transaction_bundle.save_to_file(&file_path)?;
+                eprintln!(
+                    "\nThe file {:?} was created successfully. It has the transaction bundle.",
+                    &file_path
+                );
+                eprintln!(
+                    "Take this file to the offline machine and sign the transaction there with:\n$ {} --offline transaction sign-transaction {:?} network-config {}\n",
+                    crate::common::get_near_exec_path(),
+                    &file_path,
+                    transaction_bundle.network_name
+                );
+                Ok(())
+            },
+        )))
+    }
+}
+
+impl From<SaveToFileContext> for crate::network::NetworkContext {
+    fn from(item: SaveToFileContext) -> Self {
+        item.0
+    }
+}
+
+impl SaveToFile {
+    fn input_file_path(
+        _context: &super::PrepareOfflineContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::path_buf::PathBuf>> {
+        Ok(Some(
+            CustomType::new("What is the location of the file to save the transaction bundle?")
+                .with_starting_input("transaction-bundle.json")
+                .prompt()?,
+        ))
+    }
+}
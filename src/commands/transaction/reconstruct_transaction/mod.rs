@@ -22,7 +22,7 @@ impl TransactionInfoContext {
         previous_context: crate::GlobalContext,
         scope: &<TransactionInfo as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        use super::construct_transaction::{add_action_1, skip_action, CliConstructTransaction};
+        use super::construct_transaction::{next_action, skip_action, CliConstructTransaction};
         use super::{CliTransactionActions, CliTransactionCommands};
 
         let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
@@ -91,15 +91,15 @@ impl TransactionInfoContext {
                     let mut cmd_cli_args = cmd.to_cli_args();
 
                     for transaction_action in prepopulated_transaction.actions {
-                        let next_actions = add_action_1::CliNextAction::AddAction(
-                            add_action_1::add_action::CliAddAction {
+                        let next_actions = next_action::CliNextAction::AddAction(
+                            next_action::add_action::CliAddAction {
                                 action: action_transformation(transaction_action)?,
                             },
                         );
                         cmd_cli_args.extend(next_actions.to_cli_args());
                     }
 
-                    let skip_action = add_action_1::CliNextAction::Skip(skip_action::CliSkipAction {
+                    let skip_action = next_action::CliNextAction::Skip(skip_action::CliSkipAction {
                         network_config: Some(
                             skip_action::ClapNamedArgNetworkForTransactionArgsForSkipAction::NetworkConfig(
                                 crate::network_for_transaction::CliNetworkForTransactionArgs {
@@ -138,11 +138,11 @@ impl From<TransactionInfoContext> for crate::network::NetworkContext {
 fn action_transformation(
     archival_action: near_primitives::transaction::Action,
 ) -> color_eyre::eyre::Result<
-    Option<super::construct_transaction::add_action_1::add_action::CliActionSubcommand>,
+    Option<super::construct_transaction::next_action::add_action::CliActionSubcommand>,
 > {
     use near_primitives::transaction::Action;
 
-    use super::construct_transaction::add_action_1::add_action;
+    use super::construct_transaction::next_action::add_action;
 
     match archival_action {
         Action::CreateAccount(_) => {
@@ -243,9 +243,9 @@ fn get_access_key_permission(
     public_key: near_crypto::PublicKey,
     access_key_permission: near_primitives::account::AccessKeyPermission,
 ) -> color_eyre::eyre::Result<
-    Option<super::construct_transaction::add_action_1::add_action::add_key::CliAccessKeyPermission>,
+    Option<super::construct_transaction::next_action::add_action::add_key::CliAccessKeyPermission>,
 > {
-    use super::construct_transaction::add_action_1::add_action::add_key;
+    use super::construct_transaction::next_action::add_action::add_key;
 
     match access_key_permission {
         near_primitives::account::AccessKeyPermission::FullAccess => {
@@ -14,6 +14,7 @@ pub struct SignedTransaction {
 #[derive(Debug, Clone)]
 pub struct SignedTransactionContext {
     config: crate::config::Config,
+    output_format: crate::common::OutputFormat,
     signed_transaction: near_primitives::transaction::SignedTransaction,
 }
 
@@ -24,6 +25,7 @@ impl SignedTransactionContext {
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
             config: previous_context.config,
+            output_format: previous_context.output_format,
             signed_transaction: scope.signed_action.inner.clone(),
         })
     }
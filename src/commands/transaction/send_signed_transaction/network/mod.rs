@@ -16,6 +16,7 @@ pub struct Network {
 pub struct NetworkContext {
     signed_transaction: near_primitives::transaction::SignedTransaction,
     network_config: crate::config::NetworkConfig,
+    output_format: crate::common::OutputFormat,
 }
 
 impl NetworkContext {
@@ -33,6 +34,7 @@ impl NetworkContext {
         Ok(Self {
             signed_transaction: previous_context.signed_transaction,
             network_config,
+            output_format: previous_context.output_format,
         })
     }
 }
@@ -73,6 +75,10 @@ impl SubmitContext {
                 &previous_context.signed_transaction,
             )?;
 
-        crate::common::print_transaction_status(&transaction_info, &previous_context.network_config)
+        crate::common::print_transaction_status(
+            &transaction_info,
+            &previous_context.network_config,
+            &previous_context.output_format,
+        )
     }
 }
@@ -2,8 +2,8 @@
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = SignTransactionContext)]
 pub struct SignTransaction {
-    /// Enter the transaction encoded in base64:
-    unsigned_transaction: crate::types::transaction::TransactionAsBase64,
+    /// Enter the transaction encoded in base64 or the transaction bundle (the path to the file or the text prepared with `transaction prepare-offline`):
+    unsigned_transaction: crate::types::transaction_bundle::UnsignedTransactionOrBundle,
     #[interactive_clap(named_arg)]
     /// Select network
     network_config: crate::network_for_transaction::NetworkForTransactionArgs,
@@ -17,10 +17,10 @@ impl SignTransactionContext {
         previous_context: crate::GlobalContext,
         scope: &<SignTransaction as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
+        let unsigned_transaction = scope.unsigned_transaction.unsigned_transaction.clone();
         let get_prepopulated_transaction_after_getting_network_callback: crate::commands::GetPrepopulatedTransactionAfterGettingNetworkCallback =
             std::sync::Arc::new({
-                let unsigned_transaction: near_primitives::transaction::Transaction =
-                    scope.unsigned_transaction.clone().into();
+                let unsigned_transaction = unsigned_transaction.clone();
 
                 move |_network_config| {
                     Ok(crate::commands::PrepopulatedTransaction::from(
@@ -32,8 +32,8 @@ impl SignTransactionContext {
         Ok(Self(crate::commands::ActionContext {
             global_context: previous_context,
             interacting_with_account_ids: vec![
-                scope.unsigned_transaction.inner.signer_id.clone(),
-                scope.unsigned_transaction.inner.receiver_id.clone(),
+                unsigned_transaction.signer_id.clone(),
+                unsigned_transaction.receiver_id.clone(),
             ],
             get_prepopulated_transaction_after_getting_network_callback,
             on_before_signing_callback: std::sync::Arc::new(
@@ -45,6 +45,7 @@ impl SignTransactionContext {
             on_after_sending_transaction_callback: std::sync::Arc::new(
                 |_outcome_view, _network_config| Ok(()),
             ),
+            transaction_bundle: scope.unsigned_transaction.transaction_bundle.clone(),
         }))
     }
 }
@@ -3,12 +3,17 @@ use tracing_indicatif::span_ext::IndicatifSpanExt;
 
 use crate::common::JsonRpcClientExt;
 
+pub mod receipt_tree;
+
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = TransactionInfoContext)]
 pub struct TransactionInfo {
     /// Enter the hash of the transaction you need to view:
     transaction_hash: crate::types::crypto_hash::CryptoHash,
+    #[interactive_clap(long)]
+    /// Follow the transaction through the Included, Executed and Final stages, reporting every receipt as it completes
+    follow: bool,
     #[interactive_clap(named_arg)]
     /// Select network
     network_config: crate::network::Network,
@@ -25,11 +30,26 @@ impl TransactionInfoContext {
         let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
             std::sync::Arc::new({
                 let tx_hash: near_primitives::hash::CryptoHash = scope.transaction_hash.into();
+                let follow = scope.follow;
+                let output_format = previous_context.output_format.clone();
 
                 move |network_config| {
-                    let query_view_transaction_status =
-                        get_transaction_info(network_config, tx_hash)?;
-                    eprintln!("\nTransaction status: {:#?}", query_view_transaction_status);
+                    let query_view_transaction_status = if follow {
+                        follow_transaction(network_config, tx_hash)?
+                    } else {
+                        get_transaction_info_with_receipts(
+                            network_config,
+                            tx_hash,
+                            near_primitives::views::TxExecutionStatus::Final,
+                        )?
+                    };
+                    if output_format.is_json() {
+                        return crate::common::print_json_output(
+                            "transaction_status",
+                            serde_json::to_value(&query_view_transaction_status)?,
+                        );
+                    }
+                    print_transaction_tree(&query_view_transaction_status);
                     Ok(())
                 }
             });
@@ -73,3 +93,272 @@ pub fn get_transaction_info(
             )
         })
 }
+
+/// Fetches the transaction together with its receipts (predecessors and actions included).
+#[tracing::instrument(name = "Getting information about transaction", skip_all)]
+pub fn get_transaction_info_with_receipts(
+    network_config: &crate::config::NetworkConfig,
+    tx_hash: near_primitives::hash::CryptoHash,
+    wait_until: near_primitives::views::TxExecutionStatus,
+) -> color_eyre::eyre::Result<near_jsonrpc_client::methods::tx::RpcTransactionResponse> {
+    tracing::Span::current().pb_set_message(&format!("{tx_hash} ..."));
+    call_experimental_tx_status(network_config, tx_hash, wait_until).wrap_err_with(|| {
+        format!(
+            "Failed to fetch query for view transaction on network <{}>",
+            network_config.network_name
+        )
+    })
+}
+
+/// The `EXPERIMENTAL_tx_status` response. `RpcTransactionResponse` cannot be deserialized directly
+/// since its untagged outcome enum tries the variant without receipts first, which always matches
+/// and drops the receipts.
+#[derive(serde::Deserialize)]
+struct TransactionStatusWithReceipts {
+    #[serde(flatten)]
+    final_execution_outcome: Option<near_primitives::views::FinalExecutionOutcomeWithReceiptView>,
+    final_execution_status: near_primitives::views::TxExecutionStatus,
+}
+
+impl near_jsonrpc_client::methods::RpcHandlerResponse for TransactionStatusWithReceipts {}
+
+fn call_experimental_tx_status(
+    network_config: &crate::config::NetworkConfig,
+    tx_hash: near_primitives::hash::CryptoHash,
+    wait_until: near_primitives::views::TxExecutionStatus,
+) -> Result<
+    near_jsonrpc_client::methods::EXPERIMENTAL_tx_status::RpcTransactionResponse,
+    near_jsonrpc_client::errors::JsonRpcError<
+        near_jsonrpc_client::methods::EXPERIMENTAL_tx_status::RpcTransactionError,
+    >,
+> {
+    let params = serde_json::to_value(
+        near_jsonrpc_primitives::types::transactions::RpcTransactionStatusRequest {
+            transaction_info:
+                near_jsonrpc_client::methods::EXPERIMENTAL_tx_status::TransactionInfo::TransactionId {
+                    tx_hash,
+                    sender_account_id: "near".parse().expect("valid account id"),
+                },
+            wait_until,
+        },
+    )
+    .expect("the request params are serializable");
+    let response =
+        network_config
+            .json_rpc_client()
+            .blocking_call(near_jsonrpc_client::methods::any::<
+                Result<
+                    TransactionStatusWithReceipts,
+                    near_jsonrpc_client::methods::EXPERIMENTAL_tx_status::RpcTransactionError,
+                >,
+            >("EXPERIMENTAL_tx_status", params))?;
+    Ok(
+        near_jsonrpc_client::methods::EXPERIMENTAL_tx_status::RpcTransactionResponse {
+            final_execution_outcome: response.final_execution_outcome.map(
+                near_primitives::views::FinalExecutionOutcomeViewEnum::FinalExecutionOutcomeWithReceipt,
+            ),
+            final_execution_status: response.final_execution_status,
+        },
+    )
+}
+
+/// How long `--follow` waits for an unknown transaction to show up before giving up (a mistyped
+/// hash or the wrong network would otherwise be polled forever).
+const FOLLOW_INCLUSION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(120);
+
+/// Polls the transaction status until it is final, reporting every stage reached
+/// (Included, Executed, Final) and every receipt once it has been executed.
+fn follow_transaction(
+    network_config: &crate::config::NetworkConfig,
+    tx_hash: near_primitives::hash::CryptoHash,
+) -> color_eyre::eyre::Result<near_jsonrpc_client::methods::tx::RpcTransactionResponse> {
+    eprintln!(
+        "\nFollowing transaction {tx_hash} on network <{}> ...",
+        network_config.network_name
+    );
+    let mut reported_status = None;
+    let mut reported_receipt_ids = std::collections::HashSet::new();
+    let started_at = std::time::Instant::now();
+    loop {
+        let response = match call_experimental_tx_status(
+            network_config,
+            tx_hash,
+            near_primitives::views::TxExecutionStatus::Included,
+        ) {
+            Ok(response) => response,
+            Err(err)
+                if matches!(
+                    err.handler_error(),
+                    Some(
+                        near_jsonrpc_client::methods::EXPERIMENTAL_tx_status::RpcTransactionError::UnknownTransaction { .. }
+                            | near_jsonrpc_client::methods::EXPERIMENTAL_tx_status::RpcTransactionError::TimeoutError
+                    )
+                ) =>
+            {
+                if started_at.elapsed() >= FOLLOW_INCLUSION_TIMEOUT {
+                    color_eyre::eyre::bail!(
+                        "Transaction {tx_hash} was not found on network <{}> within {} seconds. Check the transaction hash and the network.",
+                        network_config.network_name,
+                        FOLLOW_INCLUSION_TIMEOUT.as_secs()
+                    );
+                }
+                if reported_status.is_none() {
+                    eprintln!("Waiting for the transaction to be included into a block ...");
+                    reported_status = Some(near_primitives::views::TxExecutionStatus::None);
+                }
+                std::thread::sleep(std::time::Duration::from_secs(1));
+                continue;
+            }
+            Err(err) => {
+                return Err(err).wrap_err_with(|| {
+                    format!(
+                        "Failed to fetch query for view transaction on network <{}>",
+                        network_config.network_name
+                    )
+                })
+            }
+        };
+
+        if reported_status.as_ref() != Some(&response.final_execution_status) {
+            eprintln!("Transaction status: {:?}", response.final_execution_status);
+            reported_status = Some(response.final_execution_status.clone());
+        }
+        if let Some(outcome) = &response.final_execution_outcome {
+            let (final_outcome, receipts) = split_outcome(outcome);
+            for receipt_outcome in &final_outcome.receipts_outcome {
+                if matches!(
+                    receipt_outcome.outcome.status,
+                    near_primitives::views::ExecutionStatusView::Unknown
+                ) || !reported_receipt_ids.insert(receipt_outcome.id)
+                {
+                    continue;
+                }
+                eprintln!(
+                    "  {}",
+                    receipt_tree::render_receipt_line(receipt_outcome, receipts)
+                );
+            }
+        }
+        if response.final_execution_status == near_primitives::views::TxExecutionStatus::Final {
+            return Ok(response);
+        }
+        std::thread::sleep(std::time::Duration::from_secs(1));
+    }
+}
+
+fn split_outcome(
+    outcome: &near_primitives::views::FinalExecutionOutcomeViewEnum,
+) -> (
+    &near_primitives::views::FinalExecutionOutcomeView,
+    &[near_primitives::views::ReceiptView],
+) {
+    match outcome {
+        near_primitives::views::FinalExecutionOutcomeViewEnum::FinalExecutionOutcome(
+            final_outcome,
+        ) => (final_outcome, &[]),
+        near_primitives::views::FinalExecutionOutcomeViewEnum::FinalExecutionOutcomeWithReceipt(
+            outcome_with_receipts,
+        ) => (
+            &outcome_with_receipts.final_outcome,
+            &outcome_with_receipts.receipts,
+        ),
+    }
+}
+
+fn print_transaction_tree(response: &near_jsonrpc_client::methods::tx::RpcTransactionResponse) {
+    eprintln!(
+        "\nTransaction status: {:?}",
+        response.final_execution_status
+    );
+    match &response.final_execution_outcome {
+        Some(outcome) => {
+            let (final_outcome, receipts) = split_outcome(outcome);
+            eprintln!(
+                "\n{}",
+                receipt_tree::render_receipt_tree(final_outcome, receipts)
+            );
+        }
+        None => eprintln!("The transaction has not been executed yet."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_status_keeps_the_receipts() {
+        let transaction_hash = near_primitives::hash::CryptoHash::hash_bytes(b"transaction");
+        let receipt_id = near_primitives::hash::CryptoHash::hash_bytes(b"receipt");
+        let public_key = "ed25519:8nDkVwdpkE3Ft2sCtdH1dYjUqNcbUDDE8Ew4wmSUiupd";
+        let execution_outcome = |id, executor_id, receipt_ids: &[_]| {
+            serde_json::json!({
+                "proof": [],
+                "block_hash": near_primitives::hash::CryptoHash::default(),
+                "id": id,
+                "outcome": {
+                    "logs": [],
+                    "receipt_ids": receipt_ids,
+                    "gas_burnt": 2_500_000_000_000u64,
+                    "tokens_burnt": "0",
+                    "executor_id": executor_id,
+                    "status": {"SuccessValue": ""},
+                }
+            })
+        };
+        let result = serde_json::json!({
+            "final_execution_status": "FINAL",
+            "status": {"SuccessValue": ""},
+            "transaction": {
+                "signer_id": "alice.testnet",
+                "public_key": public_key,
+                "nonce": 1,
+                "receiver_id": "bob.testnet",
+                "actions": [{"Transfer": {"deposit": "1000000000000000000000000"}}],
+                "signature": "ed25519:3s1dvZdQtcAjBksMHFrysqvF63wnyMHPA4owNQmCJZ2EBakZEKdtMsLqrHdKWQjJbSRN6kRknN2WdwSBLWGCokXj",
+                "hash": transaction_hash,
+            },
+            "transaction_outcome": execution_outcome(transaction_hash, "alice.testnet", &[receipt_id]),
+            "receipts_outcome": [execution_outcome(receipt_id, "bob.testnet", &[])],
+            "receipts": [{
+                "predecessor_id": "alice.testnet",
+                "receiver_id": "bob.testnet",
+                "receipt_id": receipt_id,
+                "receipt": {"Action": {
+                    "signer_id": "alice.testnet",
+                    "signer_public_key": public_key,
+                    "gas_price": "103000000",
+                    "output_data_receivers": [],
+                    "input_data_ids": [],
+                    "actions": [{"Transfer": {"deposit": "1000000000000000000000000"}}],
+                }},
+            }],
+        });
+        let rpc_url = crate::test_utils::serve_json_rpc(move |method, params| {
+            assert_eq!(method, "EXPERIMENTAL_tx_status");
+            assert_eq!(params["tx_hash"], transaction_hash.to_string());
+            result.clone()
+        });
+
+        let response = get_transaction_info_with_receipts(
+            &crate::test_utils::network_config(rpc_url),
+            transaction_hash,
+            near_primitives::views::TxExecutionStatus::Final,
+        )
+        .unwrap();
+        assert_eq!(
+            response.final_execution_status,
+            near_primitives::views::TxExecutionStatus::Final
+        );
+        let (final_outcome, receipts) =
+            split_outcome(response.final_execution_outcome.as_ref().unwrap());
+        assert_eq!(final_outcome.transaction.hash, transaction_hash);
+        assert_eq!(receipts.len(), 1);
+        assert_eq!(receipts[0].receipt_id, receipt_id);
+        assert!(
+            receipt_tree::render_receipt_tree(final_outcome, receipts).contains(&format!(
+                "Receipt {receipt_id}: alice.testnet → bob.testnet"
+            ))
+        );
+    }
+}
@@ -0,0 +1,305 @@
+use std::fmt::Write;
+
+/// A node of a rendered tree: the title line and the details written under it.
+pub struct TreeNode {
+    pub title: String,
+    pub actions: Vec<String>,
+    pub gas_burnt: near_primitives::types::Gas,
+    pub status: String,
+    pub logs: Vec<String>,
+    pub children: Vec<near_primitives::hash::CryptoHash>,
+}
+
+/// Renders the transaction and its receipts as a tree: every receipt is placed under
+/// the transaction or the receipt that produced it.
+pub fn render_receipt_tree(
+    outcome: &near_primitives::views::FinalExecutionOutcomeView,
+    receipts: &[near_primitives::views::ReceiptView],
+) -> String {
+    let transaction = &outcome.transaction;
+    let root = TreeNode {
+        title: format!(
+            "Transaction {}: {} → {}",
+            transaction.hash, transaction.signer_id, transaction.receiver_id
+        ),
+        actions: transaction.actions.iter().map(describe_action).collect(),
+        gas_burnt: outcome.transaction_outcome.outcome.gas_burnt,
+        status: describe_status(&outcome.transaction_outcome.outcome.status),
+        logs: outcome.transaction_outcome.outcome.logs.clone(),
+        children: outcome.transaction_outcome.outcome.receipt_ids.clone(),
+    };
+    render_tree(root, |receipt_id| {
+        let receipt_outcome = outcome
+            .receipts_outcome
+            .iter()
+            .find(|receipt_outcome| &receipt_outcome.id == receipt_id)?;
+        let receipt = receipts
+            .iter()
+            .find(|receipt| &receipt.receipt_id == receipt_id);
+        let actions = match receipt.map(|receipt| &receipt.receipt) {
+            Some(near_primitives::views::ReceiptEnumView::Action { actions, .. }) => {
+                actions.iter().map(describe_action).collect()
+            }
+            _ => vec![],
+        };
+        Some(TreeNode {
+            title: format!(
+                "Receipt {receipt_id}: {}",
+                route(receipt, &receipt_outcome.outcome)
+            ),
+            actions,
+            gas_burnt: receipt_outcome.outcome.gas_burnt,
+            status: describe_status(&receipt_outcome.outcome.status),
+            logs: receipt_outcome.outcome.logs.clone(),
+            children: receipt_outcome.outcome.receipt_ids.clone(),
+        })
+    })
+}
+
+/// Renders the tree under the root node; the child nodes are looked up by their IDs (`None`
+/// for the receipts that are not executed yet). A node reached twice is only expanded once.
+pub fn render_tree(
+    root: TreeNode,
+    node: impl Fn(&near_primitives::hash::CryptoHash) -> Option<TreeNode>,
+) -> String {
+    let mut tree = format!("{}\n", root.title);
+    let details_prefix = if root.children.is_empty() {
+        "    "
+    } else {
+        "│   "
+    };
+    write_details(&mut tree, details_prefix, &root);
+
+    let mut visited = std::collections::HashSet::new();
+    write_children(&mut tree, "", &root.children, &node, &mut visited);
+    tree
+}
+
+/// A single line describing an executed receipt, used to report the progress of a transaction.
+pub fn render_receipt_line(
+    receipt_outcome: &near_primitives::views::ExecutionOutcomeWithIdView,
+    receipts: &[near_primitives::views::ReceiptView],
+) -> String {
+    let receipt = receipts
+        .iter()
+        .find(|receipt| receipt.receipt_id == receipt_outcome.id);
+    format!(
+        "Receipt {}: {} | gas burnt: {} | {}",
+        receipt_outcome.id,
+        route(receipt, &receipt_outcome.outcome),
+        crate::common::NearGas::from_gas(receipt_outcome.outcome.gas_burnt),
+        describe_status(&receipt_outcome.outcome.status)
+    )
+}
+
+fn write_children(
+    tree: &mut String,
+    prefix: &str,
+    children: &[near_primitives::hash::CryptoHash],
+    node: &impl Fn(&near_primitives::hash::CryptoHash) -> Option<TreeNode>,
+    visited: &mut std::collections::HashSet<near_primitives::hash::CryptoHash>,
+) {
+    for (index, child_id) in children.iter().enumerate() {
+        let is_last = index + 1 == children.len();
+        let (branch, child_prefix) = if is_last {
+            ("└── ", format!("{prefix}    "))
+        } else {
+            ("├── ", format!("{prefix}│   "))
+        };
+        let Some(child) = node(child_id) else {
+            let _ = writeln!(tree, "{prefix}{branch}Receipt {child_id}: pending");
+            continue;
+        };
+        let _ = writeln!(tree, "{prefix}{branch}{}", child.title);
+        if !visited.insert(*child_id) {
+            continue;
+        }
+        let details_prefix = if child.children.is_empty() {
+            format!("{child_prefix}    ")
+        } else {
+            format!("{child_prefix}│   ")
+        };
+        write_details(tree, &details_prefix, &child);
+        write_children(tree, &child_prefix, &child.children, node, visited);
+    }
+}
+
+fn write_details(tree: &mut String, prefix: &str, node: &TreeNode) {
+    if !node.actions.is_empty() {
+        let _ = writeln!(tree, "{prefix}actions:   {}", node.actions.join(", "));
+    }
+    let _ = writeln!(
+        tree,
+        "{prefix}gas burnt: {}",
+        crate::common::NearGas::from_gas(node.gas_burnt)
+    );
+    let _ = writeln!(tree, "{prefix}status:    {}", node.status);
+    if !node.logs.is_empty() {
+        let _ = writeln!(tree, "{prefix}logs:");
+        for log in &node.logs {
+            let _ = writeln!(tree, "{prefix}  {log}");
+        }
+    }
+}
+
+fn route(
+    receipt: Option<&near_primitives::views::ReceiptView>,
+    outcome: &near_primitives::views::ExecutionOutcomeView,
+) -> String {
+    match receipt {
+        Some(receipt) => format!("{} → {}", receipt.predecessor_id, receipt.receiver_id),
+        None => format!("executed by {}", outcome.executor_id),
+    }
+}
+
+pub fn describe_action(action: &near_primitives::views::ActionView) -> String {
+    match action {
+        near_primitives::views::ActionView::CreateAccount => "create account".to_string(),
+        near_primitives::views::ActionView::DeployContract { .. } => "deploy contract".to_string(),
+        near_primitives::views::ActionView::FunctionCall {
+            method_name,
+            gas,
+            deposit,
+            ..
+        } => format!(
+            "call {method_name} (gas: {}, deposit: {})",
+            crate::common::NearGas::from_gas(*gas),
+            crate::types::near_token::NearToken::from_yoctonear(*deposit)
+        ),
+        near_primitives::views::ActionView::Transfer { deposit } => format!(
+            "transfer {}",
+            crate::types::near_token::NearToken::from_yoctonear(*deposit)
+        ),
+        near_primitives::views::ActionView::Stake { stake, .. } => format!(
+            "stake {}",
+            crate::types::near_token::NearToken::from_yoctonear(*stake)
+        ),
+        near_primitives::views::ActionView::AddKey { public_key, .. } => {
+            format!("add key {public_key}")
+        }
+        near_primitives::views::ActionView::DeleteKey { public_key } => {
+            format!("delete key {public_key}")
+        }
+        near_primitives::views::ActionView::DeleteAccount { beneficiary_id } => {
+            format!("delete account (beneficiary: {beneficiary_id})")
+        }
+        near_primitives::views::ActionView::Delegate {
+            delegate_action, ..
+        } => format!(
+            "delegate action of {} to {}",
+            delegate_action.sender_id, delegate_action.receiver_id
+        ),
+    }
+}
+
+pub fn describe_status(status: &near_primitives::views::ExecutionStatusView) -> String {
+    match status {
+        near_primitives::views::ExecutionStatusView::Unknown => "pending".to_string(),
+        near_primitives::views::ExecutionStatusView::Failure(tx_execution_error) => {
+            format!("failure: {tx_execution_error}")
+        }
+        near_primitives::views::ExecutionStatusView::SuccessValue(value) if value.is_empty() => {
+            "success".to_string()
+        }
+        near_primitives::views::ExecutionStatusView::SuccessValue(value) => {
+            match String::from_utf8(value.clone()) {
+                Ok(value) => format!("success, returned {value}"),
+                Err(_) => format!(
+                    "success, returned <non-printable data ({})>",
+                    bytesize::ByteSize(value.len() as u64)
+                ),
+            }
+        }
+        near_primitives::views::ExecutionStatusView::SuccessReceiptId(receipt_id) => {
+            format!("success, continued in receipt {receipt_id}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn execution_outcome(
+        id: near_primitives::hash::CryptoHash,
+        executor_id: &str,
+        receipt_ids: &[near_primitives::hash::CryptoHash],
+        logs: &[&str],
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "proof": [],
+            "block_hash": near_primitives::hash::CryptoHash::default(),
+            "id": id,
+            "outcome": {
+                "logs": logs,
+                "receipt_ids": receipt_ids,
+                "gas_burnt": 2_500_000_000_000u64,
+                "tokens_burnt": "0",
+                "executor_id": executor_id,
+                "status": {"SuccessValue": ""},
+            }
+        })
+    }
+
+    #[test]
+    fn render_nested_receipts() {
+        let transaction_hash = near_primitives::hash::CryptoHash::hash_bytes(b"transaction");
+        let call_receipt_id = near_primitives::hash::CryptoHash::hash_bytes(b"call");
+        let refund_receipt_id = near_primitives::hash::CryptoHash::hash_bytes(b"refund");
+        let outcome: near_primitives::views::FinalExecutionOutcomeView =
+            serde_json::from_value(serde_json::json!({
+                "status": {"SuccessValue": ""},
+                "transaction": {
+                    "signer_id": "alice.testnet",
+                    "public_key": "ed25519:8nDkVwdpkE3Ft2sCtdH1dYjUqNcbUDDE8Ew4wmSUiupd",
+                    "nonce": 1,
+                    "receiver_id": "bob.testnet",
+                    "actions": [{"Transfer": {"deposit": "1000000000000000000000000"}}],
+                    "signature": "ed25519:3s1dvZdQtcAjBksMHFrysqvF63wnyMHPA4owNQmCJZ2EBakZEKdtMsLqrHdKWQjJbSRN6kRknN2WdwSBLWGCokXj",
+                    "hash": transaction_hash,
+                },
+                "transaction_outcome":
+                    execution_outcome(transaction_hash, "alice.testnet", &[call_receipt_id], &[]),
+                "receipts_outcome": [
+                    execution_outcome(call_receipt_id, "bob.testnet", &[refund_receipt_id], &["hello"]),
+                    execution_outcome(refund_receipt_id, "alice.testnet", &[], &[]),
+                ],
+            }))
+            .unwrap();
+        let receipts: Vec<near_primitives::views::ReceiptView> =
+            serde_json::from_value(serde_json::json!([{
+                "predecessor_id": "alice.testnet",
+                "receiver_id": "bob.testnet",
+                "receipt_id": call_receipt_id,
+                "receipt": {"Action": {
+                    "signer_id": "alice.testnet",
+                    "signer_public_key": "ed25519:8nDkVwdpkE3Ft2sCtdH1dYjUqNcbUDDE8Ew4wmSUiupd",
+                    "gas_price": "100000000",
+                    "output_data_receivers": [],
+                    "input_data_ids": [],
+                    "actions": [{"Transfer": {"deposit": "1000000000000000000000000"}}],
+                }},
+            }]))
+            .unwrap();
+
+        let tree = render_receipt_tree(&outcome, &receipts);
+        assert_eq!(
+            tree,
+            format!(
+                "Transaction {transaction_hash}: alice.testnet → bob.testnet\n\
+                 │   actions:   transfer 1 NEAR\n\
+                 │   gas burnt: 2.5 Tgas\n\
+                 │   status:    success\n\
+                 └── Receipt {call_receipt_id}: alice.testnet → bob.testnet\n    \
+                     │   actions:   transfer 1 NEAR\n    \
+                     │   gas burnt: 2.5 Tgas\n    \
+                     │   status:    success\n    \
+                     │   logs:\n    \
+                     │     hello\n    \
+                     └── Receipt {refund_receipt_id}: executed by alice.testnet\n            \
+                             gas burnt: 2.5 Tgas\n            \
+                             status:    success\n"
+            )
+        );
+    }
+}
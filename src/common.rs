@@ -1,5 +1,5 @@
 use std::collections::VecDeque;
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryInto;
 use std::io::Write;
 use std::str::FromStr;
 
@@ -31,8 +31,11 @@ pub fn get_near_exec_path() -> String {
     strum_macros::EnumString,
     strum_macros::EnumVariantNames,
     smart_default::SmartDefault,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 #[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
 pub enum OutputFormat {
     #[default]
     Plaintext,
@@ -48,6 +51,51 @@ impl std::fmt::Display for OutputFormat {
     }
 }
 
+impl interactive_clap::ToCli for OutputFormat {
+    type CliVariant = OutputFormat;
+}
+
+impl OutputFormat {
+    pub fn is_json(&self) -> bool {
+        matches!(self, OutputFormat::Json)
+    }
+}
+
+/// The version of the JSON document printed to stdout in `--output json` mode.
+/// It must be bumped on any backward-incompatible change of the document layout.
+pub const JSON_OUTPUT_VERSION: u32 = 1;
+
+/// Prints a single machine-readable JSON document to stdout.
+///
+/// Every command prints exactly one document of the following shape:
+/// `{"version": 1, "kind": "<kind>", "result": <result>}`
+pub fn print_json_output(kind: &str, result: serde_json::Value) -> CliResult {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&serde_json::json!({
+            "version": JSON_OUTPUT_VERSION,
+            "kind": kind,
+            "result": result,
+        }))?
+    );
+    Ok(())
+}
+
+/// Renders the text as a QR code made of Unicode half blocks (for dark terminal themes).
+pub fn render_qr_code(text: &str) -> color_eyre::eyre::Result<String> {
+    let qr_code = qrcode::QrCode::new(text.as_bytes()).map_err(|err| {
+        color_eyre::eyre::eyre!(
+            "{} bytes cannot be encoded into a QR code ({err}), use a file instead",
+            text.len()
+        )
+    })?;
+    Ok(qr_code
+        .render::<qrcode::render::unicode::Dense1x2>()
+        .dark_color(qrcode::render::unicode::Dense1x2::Light)
+        .light_color(qrcode::render::unicode::Dense1x2::Dark)
+        .build())
+}
+
 #[derive(Debug, Clone)]
 pub struct BlockHashAsBase58 {
     pub inner: near_primitives::hash::CryptoHash,
@@ -141,6 +189,14 @@ impl std::fmt::Display for AccountTransferAllowance {
 }
 
 impl AccountTransferAllowance {
+    pub fn account_liquid_balance(&self) -> near_token::NearToken {
+        self.account_liquid_balance
+    }
+
+    pub fn account_locked_balance(&self) -> near_token::NearToken {
+        self.account_locked_balance
+    }
+
     pub fn liquid_storage_stake(&self) -> near_token::NearToken {
         self.storage_stake
             .saturating_sub(self.account_locked_balance)
@@ -441,69 +497,42 @@ pub struct KeyPairProperties {
     pub secret_keypair_str: String,
 }
 
+impl KeyPairProperties {
+    pub fn key_type(&self) -> &str {
+        self.public_key_str
+            .split_once(':')
+            .map_or("ed25519", |(key_type, _)| key_type)
+    }
+}
+
 pub fn get_key_pair_properties_from_seed_phrase(
     seed_phrase_hd_path: crate::types::slip10::BIP32Path,
     master_seed_phrase: String,
+    key_type: near_crypto::KeyType,
 ) -> color_eyre::eyre::Result<KeyPairProperties> {
     let master_seed = bip39::Mnemonic::parse(&master_seed_phrase)?.to_seed("");
-    let derived_private_key = slipped10::derive_key_from_path(
-        &master_seed,
-        slipped10::Curve::Ed25519,
-        &seed_phrase_hd_path.clone().into(),
-    )
-    .map_err(|err| {
-        color_eyre::Report::msg(format!(
-            "Failed to derive a key from the master key: {}",
-            err
-        ))
-    })?;
-
-    let signing_key = ed25519_dalek::SigningKey::from_bytes(&derived_private_key.key);
-
-    let public_key = signing_key.verifying_key();
-    let implicit_account_id = near_primitives::types::AccountId::try_from(hex::encode(public_key))?;
-    let public_key_str = format!("ed25519:{}", bs58::encode(&public_key).into_string());
-    let secret_keypair_str = format!(
-        "ed25519:{}",
-        bs58::encode(signing_key.to_keypair_bytes()).into_string()
-    );
-    let key_pair_properties: KeyPairProperties = KeyPairProperties {
-        seed_phrase_hd_path,
-        master_seed_phrase,
-        implicit_account_id,
-        public_key_str,
-        secret_keypair_str,
-    };
-    Ok(key_pair_properties)
+    let secret_key =
+        derive_secret_key_from_seed(&master_seed, &seed_phrase_hd_path.clone().into(), key_type)?;
+    get_key_pair_properties_from_secret_key(secret_key, seed_phrase_hd_path, master_seed_phrase)
 }
 
 pub fn get_public_key_from_seed_phrase(
     seed_phrase_hd_path: slipped10::BIP32Path,
     master_seed_phrase: &str,
+    key_type: near_crypto::KeyType,
 ) -> color_eyre::eyre::Result<near_crypto::PublicKey> {
     let master_seed = bip39::Mnemonic::parse(master_seed_phrase)?.to_seed("");
-    let derived_private_key = slipped10::derive_key_from_path(
-        &master_seed,
-        slipped10::Curve::Ed25519,
-        &seed_phrase_hd_path,
-    )
-    .map_err(|err| {
-        color_eyre::Report::msg(format!(
-            "Failed to derive a key from the master key: {}",
-            err
-        ))
-    })?;
-    let signing_key = ed25519_dalek::SigningKey::from_bytes(&derived_private_key.key);
-    let public_key_str = format!(
-        "ed25519:{}",
-        bs58::encode(&signing_key.verifying_key()).into_string()
-    );
-    Ok(near_crypto::PublicKey::from_str(&public_key_str)?)
+    Ok(derive_secret_key_from_seed(&master_seed, &seed_phrase_hd_path, key_type)?.public_key())
 }
 
-pub fn generate_keypair() -> color_eyre::eyre::Result<KeyPairProperties> {
-    let generate_keypair: crate::utils_command::generate_keypair_subcommand::CliGenerateKeypair =
-        crate::utils_command::generate_keypair_subcommand::CliGenerateKeypair::default();
+pub fn generate_keypair(
+    key_type: near_crypto::KeyType,
+) -> color_eyre::eyre::Result<KeyPairProperties> {
+    let generate_keypair = crate::utils_command::generate_keypair_subcommand::CliGenerateKeypair {
+        key_type: crate::types::key_type::KeyType(key_type),
+        ..Default::default()
+    };
+    let seed_phrase_hd_path = generate_keypair.seed_phrase_hd_path();
     let (master_seed_phrase, master_seed) =
         if let Some(master_seed_phrase) = generate_keypair.master_seed_phrase.as_deref() {
             (
@@ -517,35 +546,151 @@ pub fn generate_keypair() -> color_eyre::eyre::Result<KeyPairProperties> {
             (master_seed_phrase, mnemonic.to_seed(""))
         };
 
-    let derived_private_key = slipped10::derive_key_from_path(
+    let secret_key = derive_secret_key_from_seed(
         &master_seed,
-        slipped10::Curve::Ed25519,
-        &generate_keypair.seed_phrase_hd_path.clone().into(),
-    )
-    .map_err(|err| {
-        color_eyre::Report::msg(format!(
-            "Failed to derive a key from the master key: {}",
-            err
-        ))
-    })?;
+        &seed_phrase_hd_path.clone().into(),
+        generate_keypair.key_type.into(),
+    )?;
+    get_key_pair_properties_from_secret_key(secret_key, seed_phrase_hd_path, master_seed_phrase)
+}
+
+/// NEAR wallets derive ED25519 keys with SLIP-10 at `m/44'/397'/0'`, while secp256k1 keys use the
+/// BIP-32 path of the first Ethereum account, so the same seed phrase gives the same key as
+/// Ethereum tooling.
+pub fn default_seed_phrase_hd_path(
+    key_type: near_crypto::KeyType,
+) -> crate::types::slip10::BIP32Path {
+    match key_type {
+        near_crypto::KeyType::ED25519 => "m/44'/397'/0'",
+        near_crypto::KeyType::SECP256K1 => "m/44'/60'/0'/0/0",
+    }
+    .parse()
+    .expect("Default HD path is expected to be valid")
+}
+
+pub fn input_key_type() -> color_eyre::eyre::Result<Option<crate::types::key_type::KeyType>> {
+    let variants = vec![
+        crate::types::key_type::KeyType(near_crypto::KeyType::ED25519),
+        crate::types::key_type::KeyType(near_crypto::KeyType::SECP256K1),
+    ];
+    Ok(Some(
+        Select::new(
+            "What type of key do you want to derive from the seed phrase?",
+            variants,
+        )
+        .prompt()?,
+    ))
+}
+
+/// The prompt starts with the default HD path of the key type.
+pub fn input_seed_phrase_hd_path(
+    key_type: near_crypto::KeyType,
+) -> color_eyre::eyre::Result<Option<crate::types::slip10::BIP32Path>> {
+    Ok(Some(
+        inquire::CustomType::new(
+            "Enter seed phrase HD Path (if you are not sure, leave the default):",
+        )
+        .with_starting_input(&default_seed_phrase_hd_path(key_type).to_string())
+        .prompt()?,
+    ))
+}
+
+fn derive_secret_key_from_seed(
+    master_seed: &[u8],
+    seed_phrase_hd_path: &slipped10::BIP32Path,
+    key_type: near_crypto::KeyType,
+) -> color_eyre::eyre::Result<near_crypto::SecretKey> {
+    match key_type {
+        near_crypto::KeyType::ED25519 => {
+            let derived_private_key = slipped10::derive_key_from_path(
+                master_seed,
+                slipped10::Curve::Ed25519,
+                seed_phrase_hd_path,
+            )
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to derive a key from the master key: {}",
+                    err
+                ))
+            })?;
+            let signing_key = ed25519_dalek::SigningKey::from_bytes(&derived_private_key.key);
+            Ok(near_crypto::SecretKey::ED25519(
+                near_crypto::ED25519SecretKey(signing_key.to_keypair_bytes()),
+            ))
+        }
+        near_crypto::KeyType::SECP256K1 => Ok(near_crypto::SecretKey::SECP256K1(
+            derive_secp256k1_key_from_path(master_seed, seed_phrase_hd_path)?,
+        )),
+    }
+}
 
-    let signing_key = ed25519_dalek::SigningKey::from_bytes(&derived_private_key.key);
+/// BIP-32 derivation of a secp256k1 private key (SLIP-10 only covers ED25519 for NEAR keys).
+fn derive_secp256k1_key_from_path(
+    master_seed: &[u8],
+    seed_phrase_hd_path: &slipped10::BIP32Path,
+) -> color_eyre::eyre::Result<secp256k1::SecretKey> {
+    const HARDENED_INDEX: u32 = 1 << 31;
 
-    let public = signing_key.verifying_key();
-    let implicit_account_id = near_primitives::types::AccountId::try_from(hex::encode(public))?;
-    let public_key_str = format!("ed25519:{}", bs58::encode(&public).into_string());
-    let secret_keypair_str = format!(
-        "ed25519:{}",
-        bs58::encode(signing_key.to_keypair_bytes()).into_string()
-    );
-    let key_pair_properties: KeyPairProperties = KeyPairProperties {
-        seed_phrase_hd_path: generate_keypair.seed_phrase_hd_path,
+    let hmac_sha512 = |key: &[u8], data: &[u8]| -> ([u8; 32], [u8; 32]) {
+        let tag = ring::hmac::sign(&ring::hmac::Key::new(ring::hmac::HMAC_SHA512, key), data);
+        let (left, right) = tag.as_ref().split_at(32);
+        (
+            left.try_into().expect("HMAC-SHA512 output is 64 bytes"),
+            right.try_into().expect("HMAC-SHA512 output is 64 bytes"),
+        )
+    };
+    let derivation_error = || {
+        color_eyre::eyre::eyre!("Failed to derive a key from the master key: the derived key is invalid, try another HD path")
+    };
+
+    let (key, mut chain_code) = hmac_sha512(b"Bitcoin seed", master_seed);
+    let mut secret_key = secp256k1::SecretKey::from_slice(&key).map_err(|_| derivation_error())?;
+    let secp = secp256k1::Secp256k1::signing_only();
+    for depth in 0..seed_phrase_hd_path.depth() {
+        let index = *seed_phrase_hd_path
+            .index(depth)
+            .expect("Depth is expected to be within the path");
+        let mut data = Vec::with_capacity(37);
+        if index >= HARDENED_INDEX {
+            data.push(0);
+            data.extend_from_slice(&secret_key.secret_bytes());
+        } else {
+            data.extend_from_slice(
+                &secp256k1::PublicKey::from_secret_key(&secp, &secret_key).serialize(),
+            );
+        }
+        data.extend_from_slice(&index.to_be_bytes());
+        let (tweak, child_chain_code) = hmac_sha512(&chain_code, &data);
+        let tweak = secp256k1::Scalar::from_be_bytes(tweak).map_err(|_| derivation_error())?;
+        secret_key = secret_key
+            .add_tweak(&tweak)
+            .map_err(|_| derivation_error())?;
+        chain_code = child_chain_code;
+    }
+    Ok(secret_key)
+}
+
+fn get_key_pair_properties_from_secret_key(
+    secret_key: near_crypto::SecretKey,
+    seed_phrase_hd_path: crate::types::slip10::BIP32Path,
+    master_seed_phrase: String,
+) -> color_eyre::eyre::Result<KeyPairProperties> {
+    let public_key = secret_key.public_key();
+    let implicit_account_id = match &public_key {
+        near_crypto::PublicKey::ED25519(public_key) => {
+            near_primitives::utils::derive_near_implicit_account_id(public_key)
+        }
+        near_crypto::PublicKey::SECP256K1(public_key) => {
+            near_primitives::utils::derive_eth_implicit_account_id(public_key)
+        }
+    };
+    Ok(KeyPairProperties {
+        seed_phrase_hd_path,
         master_seed_phrase,
         implicit_account_id,
-        public_key_str,
-        secret_keypair_str,
-    };
-    Ok(key_pair_properties)
+        public_key_str: public_key.to_string(),
+        secret_keypair_str: secret_key.to_string(),
+    })
 }
 
 pub fn print_full_signed_transaction(transaction: near_primitives::transaction::SignedTransaction) {
@@ -1117,7 +1262,7 @@ pub fn convert_invalid_tx_error_to_cli_result(
     }
 }
 
-fn get_near_usd_exchange_rate(coingecko_url: &url::Url) -> color_eyre::Result<f64> {
+pub fn get_near_usd_exchange_rate(coingecko_url: &url::Url) -> color_eyre::Result<f64> {
     #[derive(serde::Deserialize)]
     struct CoinGeckoResponse {
         near: CoinGeckoNearData,
@@ -1153,7 +1298,7 @@ fn get_near_usd_exchange_rate(coingecko_url: &url::Url) -> color_eyre::Result<f6
     Err(color_eyre::eyre::eyre!(last_error_message))
 }
 
-fn calculate_usd_amount(tokens: u128, price: f64) -> Option<rust_decimal::Decimal> {
+pub fn calculate_usd_amount(tokens: u128, price: f64) -> Option<rust_decimal::Decimal> {
     let tokens_decimal = rust_decimal::Decimal::from_u128(tokens)?;
     let price_decimal = rust_decimal::Decimal::from_f64(price)?;
 
@@ -1166,6 +1311,22 @@ fn calculate_usd_amount(tokens: u128, price: f64) -> Option<rust_decimal::Decima
 pub fn print_transaction_status(
     transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
     network_config: &crate::config::NetworkConfig,
+    output_format: &OutputFormat,
+) -> crate::CliResult {
+    print_transaction_status_with_json_fields(
+        transaction_info,
+        network_config,
+        output_format,
+        serde_json::Map::new(),
+    )
+}
+
+/// Prints the transaction status, adding `json_fields` to the JSON output (`--output json`).
+pub fn print_transaction_status_with_json_fields(
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+    network_config: &crate::config::NetworkConfig,
+    output_format: &OutputFormat,
+    json_fields: serde_json::Map<String, serde_json::Value>,
 ) -> crate::CliResult {
     let near_usd_exchange_rate: Option<Result<f64, color_eyre::eyre::Error>> = network_config
         .coingecko_url
@@ -1207,6 +1368,8 @@ pub fn print_transaction_status(
             eprintln!("--- Result -------------------------");
             if bytes_result.is_empty() {
                 eprintln!("Empty result");
+            } else if output_format.is_json() {
+                eprintln!("The returned value is included into the JSON output");
             } else if let Ok(json_result) =
                 serde_json::from_slice::<serde_json::Value>(bytes_result)
             {
@@ -1227,8 +1390,8 @@ pub fn print_transaction_status(
     eprintln!(
         "Transaction fee: {}{}",
         crate::types::near_token::NearToken::from_yoctonear(total_tokens_burnt),
-        match near_usd_exchange_rate {
-            Some(Ok(exchange_rate)) => calculate_usd_amount(total_tokens_burnt, exchange_rate).map_or_else(
+        match &near_usd_exchange_rate {
+            Some(Ok(exchange_rate)) => calculate_usd_amount(total_tokens_burnt, *exchange_rate).map_or_else(
                 || format!(" (USD equivalent is too big to be displayed, using ${:.2} USD/NEAR exchange rate)", exchange_rate),
                 |amount| format!(" (approximately ${:.8} USD, using ${:.2} USD/NEAR exchange rate)", amount, exchange_rate)
             ),
@@ -1237,16 +1400,104 @@ pub fn print_transaction_status(
         }
     );
 
-    eprintln!("Transaction ID: {id}\nTo see the transaction in the transaction explorer, please open this url in your browser:\n{path}{id}\n",
-        id=transaction_info.transaction_outcome.id,
-        path=network_config.explorer_transaction_url
-    );
+    print_transaction_id(&transaction_info.transaction_outcome.id, network_config);
+
+    if output_format.is_json() {
+        let mut json = transaction_outcome_to_json(
+            transaction_info,
+            network_config,
+            return_value.as_ref().err(),
+        );
+        if let Some(json) = json.as_object_mut() {
+            json.extend(json_fields);
+        }
+        print_json_output("transaction", json)?;
+    }
 
     return_value
 }
 
+/// Prints the transaction ID with the link to the transaction explorer of the network (if any).
+pub fn print_transaction_id(
+    transaction_id: &near_primitives::hash::CryptoHash,
+    network_config: &crate::config::NetworkConfig,
+) {
+    match &network_config.explorer_transaction_url {
+        Some(explorer_transaction_url) => eprintln!("Transaction ID: {transaction_id}\nTo see the transaction in the transaction explorer, please open this url in your browser:\n{explorer_transaction_url}{transaction_id}\n"),
+        None => eprintln!("Transaction ID: {transaction_id}\n"),
+    }
+}
+
+/// A stable JSON representation of the transaction outcome used in `--output json` mode.
+pub fn transaction_outcome_to_json(
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+    network_config: &crate::config::NetworkConfig,
+    error: Option<&color_eyre::eyre::Report>,
+) -> serde_json::Value {
+    let mut total_gas_burnt = transaction_info.transaction_outcome.outcome.gas_burnt;
+    let mut total_tokens_burnt = transaction_info.transaction_outcome.outcome.tokens_burnt;
+    let mut receipts = Vec::new();
+    for receipt in transaction_info.receipts_outcome.iter() {
+        total_gas_burnt += receipt.outcome.gas_burnt;
+        total_tokens_burnt += receipt.outcome.tokens_burnt;
+        receipts.push(serde_json::json!({
+            "receipt_id": receipt.id.to_string(),
+            "executor_id": receipt.outcome.executor_id,
+            "logs": receipt.outcome.logs,
+            "gas_burnt": receipt.outcome.gas_burnt,
+            "tokens_burnt": receipt.outcome.tokens_burnt.to_string(),
+        }));
+    }
+
+    let (status, return_value, return_value_base64) = match &transaction_info.status {
+        near_primitives::views::FinalExecutionStatus::SuccessValue(bytes_result) => (
+            "success",
+            if bytes_result.is_empty() {
+                serde_json::Value::Null
+            } else if let Ok(json_result) =
+                serde_json::from_slice::<serde_json::Value>(bytes_result)
+            {
+                json_result
+            } else if let Ok(string_result) = String::from_utf8(bytes_result.clone()) {
+                serde_json::Value::String(string_result)
+            } else {
+                serde_json::Value::Null
+            },
+            serde_json::Value::String(near_primitives::serialize::to_base64(bytes_result)),
+        ),
+        near_primitives::views::FinalExecutionStatus::Failure(_) => {
+            ("failure", serde_json::Value::Null, serde_json::Value::Null)
+        }
+        near_primitives::views::FinalExecutionStatus::NotStarted
+        | near_primitives::views::FinalExecutionStatus::Started => {
+            ("pending", serde_json::Value::Null, serde_json::Value::Null)
+        }
+    };
+
+    serde_json::json!({
+        "transaction_hash": transaction_info.transaction_outcome.id.to_string(),
+        "signer_id": transaction_info.transaction.signer_id,
+        "receiver_id": transaction_info.transaction.receiver_id,
+        "status": status,
+        "error": error.map(|err| err.to_string()),
+        "return_value": return_value,
+        "return_value_base64": return_value_base64,
+        "receipts": receipts,
+        "total_gas_burnt": total_gas_burnt,
+        "total_tokens_burnt": total_tokens_burnt.to_string(),
+        "explorer_url": network_config
+            .explorer_transaction_url
+            .as_ref()
+            .map(|explorer_transaction_url| format!(
+                "{explorer_transaction_url}{}",
+                transaction_info.transaction_outcome.id
+            )),
+    })
+}
+
 pub fn save_access_key_to_keychain(
     network_config: crate::config::NetworkConfig,
+    credentials_home_dir: std::path::PathBuf,
     key_pair_properties_buf: &str,
     public_key_str: &str,
     account_id: &str,
@@ -1260,10 +1511,37 @@ pub fn save_access_key_to_keychain(
         .wrap_err("Failed to open keychain")?
         .set_password(key_pair_properties_buf)
         .wrap_err("Failed to save password to keychain. You may need to install the secure keychain package by following this instruction: https://github.com/jaraco/keyring#using-keyring-on-headless-linux-systems")?;
+    add_keychain_entry(
+        &credentials_home_dir,
+        KeychainEntry {
+            network_name: network_config.network_name,
+            account_id: account_id.parse()?,
+            public_key: public_key_str.parse()?,
+        },
+    )?;
 
     Ok("The data for the access key is saved in the keychain".to_string())
 }
 
+pub fn save_access_key_to_encrypted_keystore(
+    network_config: crate::config::NetworkConfig,
+    credentials_home_dir: std::path::PathBuf,
+    key_pair_properties_buf: &str,
+    public_key_str: &str,
+    account_id: &str,
+) -> color_eyre::eyre::Result<String> {
+    let key_path = crate::encrypted_keystore::EncryptedKeystore::new(&credentials_home_dir).save(
+        &network_config.network_name,
+        &account_id.parse()?,
+        &public_key_str.parse()?,
+        key_pair_properties_buf,
+    )?;
+    Ok(format!(
+        "The data for the access key is saved in the encrypted keystore file {}",
+        key_path.display()
+    ))
+}
+
 pub fn save_access_key_to_legacy_keychain(
     network_config: crate::config::NetworkConfig,
     credentials_home_dir: std::path::PathBuf,
@@ -1318,12 +1596,234 @@ pub fn save_access_key_to_legacy_keychain(
     }
 }
 
-pub fn try_external_subcommand_execution(error: clap::Error) -> CliResult {
+/// Removes the access key from the keychain, returns `false` if the keychain does not have it.
+pub fn remove_access_key_from_keychain(
+    network_name: &str,
+    credentials_home_dir: &std::path::Path,
+    public_key: &near_crypto::PublicKey,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<bool> {
+    let service_name = format!("near-{network_name}-{account_id}");
+    let is_removed = match keyring::Entry::new(&service_name, &format!("{account_id}:{public_key}"))
+        .wrap_err("Failed to open keychain")?
+        .delete_password()
+    {
+        Ok(()) => true,
+        Err(keyring::Error::NoEntry) => false,
+        Err(err) => return Err(err).wrap_err("Failed to remove the access key from keychain"),
+    };
+    remove_keychain_entry(
+        credentials_home_dir,
+        &KeychainEntry {
+            network_name: network_name.to_string(),
+            account_id: account_id.clone(),
+            public_key: public_key.clone(),
+        },
+    )?;
+    Ok(is_removed)
+}
+
+/// Checks whether the keychain has the access key (the keychain may ask for permission).
+pub fn is_access_key_in_keychain(
+    network_name: &str,
+    public_key: &near_crypto::PublicKey,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<bool> {
+    match keyring::Entry::new(
+        &format!("near-{network_name}-{account_id}"),
+        &format!("{account_id}:{public_key}"),
+    )
+    .and_then(|entry| entry.get_password())
+    {
+        Ok(_) => Ok(true),
+        Err(keyring::Error::NoEntry) => Ok(false),
+        Err(err) => Err(err).wrap_err("Failed to read keychain"),
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct LegacyKeychainFile {
+    public_key: near_crypto::PublicKey,
+}
+
+/// The public key of the access key in a file of the legacy keychain.
+pub fn get_public_key_from_legacy_key_file(
+    path: &std::path::Path,
+) -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+    let data = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read the file {:?}", path))?;
+    let key_file: LegacyKeychainFile =
+        serde_json::from_str(&data).wrap_err("The file does not contain an access key")?;
+    Ok(key_file.public_key)
+}
+
+/// The files of the legacy keychain with the keys of the account:
+/// `<network>/<account-id>.json` and `<network>/<account-id>/<public-key>.json`.
+fn get_legacy_keychain_files(
+    network_name: &str,
+    credentials_home_dir: &std::path::Path,
+    account_id: &near_primitives::types::AccountId,
+) -> Vec<(std::path::PathBuf, near_crypto::PublicKey)> {
+    let network_dir = credentials_home_dir.join(network_name);
+    let account_dir = network_dir.join(account_id.as_str());
+    std::iter::once(network_dir.join(format!("{account_id}.json")))
+        .chain(
+            account_dir
+                .read_dir()
+                .map(Iterator::flatten)
+                .into_iter()
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.extension()
+                        .map_or(false, |extension| extension == "json")
+                }),
+        )
+        .filter_map(|path| {
+            let data = std::fs::read_to_string(&path).ok()?;
+            let key_file: LegacyKeychainFile = serde_json::from_str(&data).ok()?;
+            Some((path, key_file.public_key))
+        })
+        .collect()
+}
+
+/// The public keys of the account saved in the legacy keychain.
+pub fn get_public_keys_from_legacy_keychain(
+    network_name: &str,
+    credentials_home_dir: &std::path::Path,
+    account_id: &near_primitives::types::AccountId,
+) -> Vec<near_crypto::PublicKey> {
+    let mut public_keys: Vec<near_crypto::PublicKey> = vec![];
+    for (_, public_key) in get_legacy_keychain_files(network_name, credentials_home_dir, account_id)
+    {
+        if !public_keys.contains(&public_key) {
+            public_keys.push(public_key);
+        }
+    }
+    public_keys
+}
+
+/// Removes the files of the legacy keychain with the access key, returns the removed files.
+pub fn remove_access_key_from_legacy_keychain(
+    network_name: &str,
+    credentials_home_dir: &std::path::Path,
+    public_key: &near_crypto::PublicKey,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Vec<std::path::PathBuf>> {
+    let mut removed_files = vec![];
+    for (path, _) in get_legacy_keychain_files(network_name, credentials_home_dir, account_id)
+        .into_iter()
+        .filter(|(_, key_file_public_key)| key_file_public_key == public_key)
+    {
+        std::fs::remove_file(&path)
+            .wrap_err_with(|| format!("Failed to remove the file: {:?}", path))?;
+        removed_files.push(path);
+    }
+    Ok(removed_files)
+}
+
+pub struct LegacyKeyFile {
+    pub network_name: String,
+    pub account_id: near_primitives::types::AccountId,
+    pub path: std::path::PathBuf,
+}
+
+/// The legacy keychain keeps the keys in `<network>/<account-id>.json`
+/// and `<network>/<account-id>/<public-key>.json` files.
+pub fn find_legacy_key_files(
+    credentials_home_dir: &std::path::Path,
+) -> color_eyre::eyre::Result<Vec<LegacyKeyFile>> {
+    let Ok(network_dirs) = credentials_home_dir.read_dir() else {
+        return Ok(vec![]);
+    };
+    let keystore_path =
+        crate::encrypted_keystore::EncryptedKeystore::keystore_dir(credentials_home_dir);
+    let mut legacy_key_files = vec![];
+    for network_dir in network_dirs {
+        let network_dir = network_dir?.path();
+        if !network_dir.is_dir() || network_dir == keystore_path {
+            continue;
+        }
+        let Some(network_name) = file_name(&network_dir) else {
+            continue;
+        };
+        for entry in network_dir.read_dir()? {
+            let path = entry?.path();
+            if path.is_dir() {
+                let Some(account_id) = file_name(&path)
+                    .and_then(|name| name.parse::<near_primitives::types::AccountId>().ok())
+                else {
+                    continue;
+                };
+                for key_file in path.read_dir()? {
+                    let key_file = key_file?.path();
+                    if is_json_file(&key_file) {
+                        legacy_key_files.push(LegacyKeyFile {
+                            network_name: network_name.clone(),
+                            account_id: account_id.clone(),
+                            path: key_file,
+                        });
+                    }
+                }
+            } else if is_json_file(&path) {
+                let Some(account_id) = path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .and_then(|stem| stem.parse().ok())
+                else {
+                    continue;
+                };
+                legacy_key_files.push(LegacyKeyFile {
+                    network_name: network_name.clone(),
+                    account_id,
+                    path,
+                });
+            }
+        }
+    }
+    Ok(legacy_key_files)
+}
+
+fn file_name(path: &std::path::Path) -> Option<String> {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(str::to_string)
+}
+
+fn is_json_file(path: &std::path::Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .map_or(false, |extension| extension == "json")
+}
+
+pub fn try_external_subcommand_execution(
+    error: clap::Error,
+    config: crate::config::Config,
+) -> CliResult {
+    let mut offline = false;
+    let mut output_format = OutputFormat::default();
     let (subcommand, args) = {
         let mut args = std::env::args().skip(1);
-        let subcommand = args
-            .next()
-            .ok_or_else(|| color_eyre::eyre::eyre!("subcommand is not provided"))?;
+        // The global options given before the subcommand are passed to the extension in its context.
+        let subcommand = loop {
+            let arg = args
+                .next()
+                .ok_or_else(|| color_eyre::eyre::eyre!("subcommand is not provided"))?;
+            match arg.as_str() {
+                "--offline" => offline = true,
+                "--output" => {
+                    output_format = args
+                        .next()
+                        .ok_or_else(|| color_eyre::eyre::eyre!("--output value is not provided"))?
+                        .parse()?
+                }
+                _ => match arg.strip_prefix("--output=") {
+                    Some(value) => output_format = value.parse()?,
+                    None => break arg,
+                },
+            }
+        };
         (subcommand, args.collect::<Vec<String>>())
     };
     let is_top_level_command_known = crate::commands::TopLevelCommandDiscriminants::iter()
@@ -1341,14 +1841,24 @@ pub fn try_external_subcommand_execution(error: clap::Error) -> CliResult {
 
     let command = path.ok_or_else(|| {
         color_eyre::eyre::eyre!(
-            "{} command or {} extension does not exist",
+            "{} command or {} extension does not exist (you can install extensions with `near extension install {}`)",
             subcommand,
-            subcommand_exe
+            subcommand_exe,
+            subcommand
         )
     })?;
 
+    let extension_context = crate::extension::ExtensionContext::new(&crate::GlobalContext {
+        config,
+        offline,
+        output_format,
+    });
     let err = match cargo_util::ProcessBuilder::new(command)
         .args(&args)
+        .env(
+            crate::extension::CONTEXT_ENV_VAR,
+            serde_json::to_string(&extension_context)?,
+        )
         .exec_replace()
     {
         Ok(()) => return Ok(()),
@@ -1363,7 +1873,7 @@ pub fn try_external_subcommand_execution(error: clap::Error) -> CliResult {
     Err(color_eyre::eyre::eyre!(err))
 }
 
-fn is_executable<P: AsRef<std::path::Path>>(path: P) -> bool {
+pub fn is_executable<P: AsRef<std::path::Path>>(path: P) -> bool {
     #[cfg(target_family = "unix")]
     {
         use std::os::unix::prelude::*;
@@ -1375,11 +1885,21 @@ fn is_executable<P: AsRef<std::path::Path>>(path: P) -> bool {
     path.as_ref().is_file()
 }
 
-fn path_directories() -> Vec<std::path::PathBuf> {
+/// The directory managed by `near extension` commands; extensions installed there take
+/// precedence over the ones on `PATH`.
+pub fn get_extensions_dir() -> Option<std::path::PathBuf> {
+    dirs::data_dir().map(|data_dir| data_dir.join("near-cli").join("extensions"))
+}
+
+pub fn path_directories() -> Vec<std::path::PathBuf> {
+    let extensions_bin_dir = get_extensions_dir().map(|extensions_dir| extensions_dir.join("bin"));
     if let Some(val) = std::env::var_os("PATH") {
-        std::env::split_paths(&val).collect()
+        extensions_bin_dir
+            .into_iter()
+            .chain(std::env::split_paths(&val))
+            .collect()
     } else {
-        Vec::new()
+        extensions_bin_dir.into_iter().collect()
     }
 }
 
@@ -1553,6 +2073,49 @@ pub fn fetch_historically_delegated_staking_pools(
         .collect())
 }
 
+#[derive(Debug, serde::Deserialize)]
+struct TokenContract {
+    contract_id: near_primitives::types::AccountId,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TokensResponse {
+    tokens: Vec<TokenContract>,
+}
+
+fn fetch_token_contracts(
+    fastnear_url: &url::Url,
+    account_id: &near_primitives::types::AccountId,
+    standard: &str,
+) -> color_eyre::Result<std::collections::BTreeSet<near_primitives::types::AccountId>> {
+    let request = reqwest::blocking::get(
+        fastnear_url.join(&format!("v1/account/{}/{}", account_id, standard))?,
+    )?;
+    let response: TokensResponse = request.json()?;
+
+    Ok(response
+        .tokens
+        .into_iter()
+        .map(|token| token.contract_id)
+        .collect())
+}
+
+#[tracing::instrument(name = "Getting FT contracts held by the account ...", skip_all)]
+pub fn fetch_ft_contracts(
+    fastnear_url: &url::Url,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::Result<std::collections::BTreeSet<near_primitives::types::AccountId>> {
+    fetch_token_contracts(fastnear_url, account_id, "ft")
+}
+
+#[tracing::instrument(name = "Getting NFT contracts held by the account ...", skip_all)]
+pub fn fetch_nft_contracts(
+    fastnear_url: &url::Url,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::Result<std::collections::BTreeSet<near_primitives::types::AccountId>> {
+    fetch_token_contracts(fastnear_url, account_id, "nft")
+}
+
 #[tracing::instrument(name = "Getting currently active staking pools ...", skip_all)]
 pub fn fetch_currently_active_staking_pools(
     json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
@@ -2223,6 +2786,14 @@ pub impl near_primitives::views::CallResult {
         })
     }
 
+    fn parse_result_from_borsh(
+        &self,
+        schema: &borsh::schema::BorshSchemaContainer,
+    ) -> Result<serde_json::Value, color_eyre::eyre::Error> {
+        crate::commands::contract::call_function::borsh_schema::deserialize(&self.result, schema)
+            .wrap_err("Failed to decode the Borsh view-function call return value")
+    }
+
     fn print_logs(&self) {
         eprintln!("--------------");
         if self.logs.is_empty() {
@@ -2349,6 +2920,108 @@ pub fn is_used_account_list_exist(credentials_home_dir: &std::path::Path) -> boo
     get_used_account_list_path(credentials_home_dir).exists()
 }
 
+pub fn save_used_account_list(
+    credentials_home_dir: &std::path::Path,
+    used_account_list: &VecDeque<UsedAccount>,
+) -> color_eyre::eyre::Result<()> {
+    let used_account_list_path = get_used_account_list_path(credentials_home_dir);
+    std::fs::write(
+        &used_account_list_path,
+        serde_json::to_string(used_account_list)?,
+    )
+    .wrap_err_with(|| {
+        format!(
+            "Failed to write to file: {}",
+            used_account_list_path.display()
+        )
+    })
+}
+
+/// An access key saved in the keychain by near CLI. The keychain cannot be listed, so near CLI
+/// keeps the list of the keys it has saved there (the keys themselves are not in the list).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct KeychainEntry {
+    pub network_name: String,
+    pub account_id: near_primitives::types::AccountId,
+    pub public_key: near_crypto::PublicKey,
+}
+
+fn get_keychain_entry_list_path(credentials_home_dir: &std::path::Path) -> std::path::PathBuf {
+    credentials_home_dir.join("keychain-entries.json")
+}
+
+pub fn get_keychain_entry_list(
+    credentials_home_dir: &std::path::Path,
+) -> color_eyre::eyre::Result<Vec<KeychainEntry>> {
+    let keychain_entry_list_path = get_keychain_entry_list_path(credentials_home_dir);
+    let keychain_entry_list_buf = match std::fs::read_to_string(&keychain_entry_list_path) {
+        Ok(keychain_entry_list_buf) => keychain_entry_list_buf,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => {
+            return Err(err).wrap_err_with(|| {
+                format!(
+                    "Failed to read the file: {}",
+                    keychain_entry_list_path.display()
+                )
+            })
+        }
+    };
+    serde_json::from_str(&keychain_entry_list_buf).wrap_err_with(|| {
+        format!(
+            "The list of the keychain access keys is corrupted, fix or remove the file: {}",
+            keychain_entry_list_path.display()
+        )
+    })
+}
+
+fn save_keychain_entry_list(
+    credentials_home_dir: &std::path::Path,
+    keychain_entry_list: &[KeychainEntry],
+) -> crate::CliResult {
+    let keychain_entry_list_path = get_keychain_entry_list_path(credentials_home_dir);
+    std::fs::create_dir_all(credentials_home_dir).wrap_err_with(|| {
+        format!(
+            "Failed to create a folder: {}",
+            credentials_home_dir.display()
+        )
+    })?;
+    std::fs::write(
+        &keychain_entry_list_path,
+        serde_json::to_string(keychain_entry_list)?,
+    )
+    .wrap_err_with(|| {
+        format!(
+            "Failed to write to file: {}",
+            keychain_entry_list_path.display()
+        )
+    })
+}
+
+pub fn add_keychain_entry(
+    credentials_home_dir: &std::path::Path,
+    keychain_entry: KeychainEntry,
+) -> crate::CliResult {
+    let mut keychain_entry_list = get_keychain_entry_list(credentials_home_dir)?;
+    if !keychain_entry_list.contains(&keychain_entry) {
+        keychain_entry_list.push(keychain_entry);
+        save_keychain_entry_list(credentials_home_dir, &keychain_entry_list)?;
+    }
+    Ok(())
+}
+
+pub fn remove_keychain_entry(
+    credentials_home_dir: &std::path::Path,
+    keychain_entry: &KeychainEntry,
+) -> crate::CliResult {
+    let mut keychain_entry_list = get_keychain_entry_list(credentials_home_dir)?;
+    let keychain_entry_list_len = keychain_entry_list.len();
+    keychain_entry_list.retain(|entry| entry != keychain_entry);
+    if keychain_entry_list.len() != keychain_entry_list_len {
+        save_keychain_entry_list(credentials_home_dir, &keychain_entry_list)?;
+    }
+    Ok(())
+}
+
 pub fn input_signer_account_id_from_used_account_list(
     credentials_home_dir: &std::path::Path,
     message: &str,
@@ -2404,3 +3077,102 @@ fn input_account_id_from_used_account_list(
     update_used_account_list(credentials_home_dir, account_id.as_ref(), account_is_signer);
     Ok(Some(account_id))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derive_secp256k1_key_from_bip32_test_vector() {
+        // BIP-32 test vector 1, chain m/0'/1/2'/2/1000000000
+        let master_seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let secret_key = derive_secp256k1_key_from_path(
+            &master_seed,
+            &slipped10::BIP32Path::from_str("m/0'/1/2'/2/1000000000").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            hex::encode(secret_key.secret_bytes()),
+            "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8"
+        );
+    }
+
+    #[test]
+    fn secp256k1_key_pair_matches_ethereum_tooling() {
+        let key_pair_properties = get_key_pair_properties_from_seed_phrase(
+            default_seed_phrase_hd_path(near_crypto::KeyType::SECP256K1),
+            "test test test test test test test test test test test junk".to_string(),
+            near_crypto::KeyType::SECP256K1,
+        )
+        .unwrap();
+        // The first account of the well-known Hardhat development mnemonic
+        assert_eq!(
+            key_pair_properties.implicit_account_id.as_str(),
+            "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266"
+        );
+        assert_eq!(key_pair_properties.key_type(), "secp256k1");
+    }
+
+    #[test]
+    fn generated_keypair_uses_default_hd_path_of_key_type() {
+        for key_type in [
+            near_crypto::KeyType::ED25519,
+            near_crypto::KeyType::SECP256K1,
+        ] {
+            let key_pair_properties = generate_keypair(key_type).unwrap();
+            assert_eq!(
+                key_pair_properties.seed_phrase_hd_path.to_string(),
+                default_seed_phrase_hd_path(key_type).to_string()
+            );
+            assert_eq!(
+                get_public_key_from_seed_phrase(
+                    key_pair_properties.seed_phrase_hd_path.into(),
+                    &key_pair_properties.master_seed_phrase,
+                    key_type,
+                )
+                .unwrap()
+                .to_string(),
+                key_pair_properties.public_key_str
+            );
+        }
+    }
+
+    #[test]
+    fn keychain_entry_list_is_updated_and_corruption_is_reported() {
+        let credentials_home_dir = std::env::temp_dir().join(format!(
+            "near-cli-rs-keychain-entries-test-{}",
+            std::process::id()
+        ));
+        let keychain_entry = KeychainEntry {
+            network_name: "testnet".to_string(),
+            account_id: "alice.testnet".parse().unwrap(),
+            public_key: "ed25519:E1MTJoGHbV5qQD7QoeeeNPi2qgTJYCqe2bF4U3fopvLo"
+                .parse()
+                .unwrap(),
+        };
+
+        assert!(get_keychain_entry_list(&credentials_home_dir)
+            .unwrap()
+            .is_empty());
+        add_keychain_entry(&credentials_home_dir, keychain_entry.clone()).unwrap();
+        add_keychain_entry(&credentials_home_dir, keychain_entry.clone()).unwrap();
+        assert_eq!(
+            get_keychain_entry_list(&credentials_home_dir).unwrap(),
+            vec![keychain_entry.clone()]
+        );
+        remove_keychain_entry(&credentials_home_dir, &keychain_entry).unwrap();
+        assert!(get_keychain_entry_list(&credentials_home_dir)
+            .unwrap()
+            .is_empty());
+
+        std::fs::write(get_keychain_entry_list_path(&credentials_home_dir), "[{").unwrap();
+        assert!(get_keychain_entry_list(&credentials_home_dir).is_err());
+        assert!(add_keychain_entry(&credentials_home_dir, keychain_entry).is_err());
+        assert_eq!(
+            std::fs::read_to_string(get_keychain_entry_list_path(&credentials_home_dir)).unwrap(),
+            "[{"
+        );
+
+        std::fs::remove_dir_all(&credentials_home_dir).unwrap();
+    }
+}
@@ -42,7 +42,7 @@ impl From<NetworkConfigV1> for NetworkConfigV2 {
                 network_name: network_config.network_name,
                 rpc_url: network_config.rpc_url,
                 wallet_url: network_config.wallet_url,
-                explorer_transaction_url: network_config.explorer_transaction_url,
+                explorer_transaction_url: Some(network_config.explorer_transaction_url),
                 rpc_api_key: network_config.rpc_api_key,
                 linkdrop_account_id: network_config.linkdrop_account_id,
                 near_social_db_contract_account_id: network_config
@@ -52,12 +52,16 @@ impl From<NetworkConfigV1> for NetworkConfigV2 {
                 fastnear_url: Some("https://api.fastnear.com".parse().unwrap()),
                 staking_pools_factory_account_id: Some("poolv1.near".parse().unwrap()),
                 coingecko_url: Some("https://api.coingecko.com/".parse().unwrap()),
+                remote_signer_url: None,
+                remote_signer_api_key: None,
+                indexer_api_url: Some("https://api.nearblocks.io/".parse().unwrap()),
+                indexer_api_key: None,
             },
             "testnet" => NetworkConfigV2 {
                 network_name: network_config.network_name,
                 rpc_url: network_config.rpc_url,
                 wallet_url: network_config.wallet_url,
-                explorer_transaction_url: network_config.explorer_transaction_url,
+                explorer_transaction_url: Some(network_config.explorer_transaction_url),
                 rpc_api_key: network_config.rpc_api_key,
                 linkdrop_account_id: network_config.linkdrop_account_id,
                 near_social_db_contract_account_id: network_config
@@ -67,12 +71,16 @@ impl From<NetworkConfigV1> for NetworkConfigV2 {
                 fastnear_url: None,
                 staking_pools_factory_account_id: Some("pool.f863973.m0".parse().unwrap()),
                 coingecko_url: None,
+                remote_signer_url: None,
+                remote_signer_api_key: None,
+                indexer_api_url: Some("https://api-testnet.nearblocks.io/".parse().unwrap()),
+                indexer_api_key: None,
             },
             _ => NetworkConfigV2 {
                 network_name: network_config.network_name,
                 rpc_url: network_config.rpc_url,
                 wallet_url: network_config.wallet_url,
-                explorer_transaction_url: network_config.explorer_transaction_url,
+                explorer_transaction_url: Some(network_config.explorer_transaction_url),
                 rpc_api_key: network_config.rpc_api_key,
                 linkdrop_account_id: network_config.linkdrop_account_id,
                 near_social_db_contract_account_id: network_config
@@ -82,6 +90,10 @@ impl From<NetworkConfigV1> for NetworkConfigV2 {
                 fastnear_url: None,
                 staking_pools_factory_account_id: None,
                 coingecko_url: None,
+                remote_signer_url: None,
+                remote_signer_api_key: None,
+                indexer_api_url: None,
+                indexer_api_key: None,
             },
         }
     }
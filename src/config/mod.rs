@@ -25,9 +25,9 @@ impl Default for Config {
                 network_name: "mainnet".to_string(),
                 rpc_url: "https://archival-rpc.mainnet.near.org".parse().unwrap(),
                 wallet_url: "https://app.mynearwallet.com/".parse().unwrap(),
-                explorer_transaction_url: "https://explorer.near.org/transactions/"
-                    .parse()
-                    .unwrap(),
+                explorer_transaction_url: Some(
+                    "https://explorer.near.org/transactions/".parse().unwrap(),
+                ),
                 rpc_api_key: None,
                 linkdrop_account_id: Some("near".parse().unwrap()),
                 near_social_db_contract_account_id: Some("social.near".parse().unwrap()),
@@ -36,6 +36,10 @@ impl Default for Config {
                 fastnear_url: Some("https://api.fastnear.com/".parse().unwrap()),
                 staking_pools_factory_account_id: Some("poolv1.near".parse().unwrap()),
                 coingecko_url: Some("https://api.coingecko.com/".parse().unwrap()),
+                remote_signer_url: None,
+                remote_signer_api_key: None,
+                indexer_api_url: Some("https://api.nearblocks.io/".parse().unwrap()),
+                indexer_api_key: None,
             },
         );
         network_connection.insert(
@@ -44,9 +48,11 @@ impl Default for Config {
                 network_name: "testnet".to_string(),
                 rpc_url: "https://archival-rpc.testnet.near.org".parse().unwrap(),
                 wallet_url: "https://testnet.mynearwallet.com/".parse().unwrap(),
-                explorer_transaction_url: "https://explorer.testnet.near.org/transactions/"
-                    .parse()
-                    .unwrap(),
+                explorer_transaction_url: Some(
+                    "https://explorer.testnet.near.org/transactions/"
+                        .parse()
+                        .unwrap(),
+                ),
                 rpc_api_key: None,
                 linkdrop_account_id: Some("testnet".parse().unwrap()),
                 near_social_db_contract_account_id: Some("v1.social08.testnet".parse().unwrap()),
@@ -55,6 +61,10 @@ impl Default for Config {
                 fastnear_url: None,
                 staking_pools_factory_account_id: Some("pool.f863973.m0".parse().unwrap()),
                 coingecko_url: None,
+                remote_signer_url: None,
+                remote_signer_api_key: None,
+                indexer_api_url: Some("https://api-testnet.nearblocks.io/".parse().unwrap()),
+                indexer_api_key: None,
             },
         );
 
@@ -77,10 +87,12 @@ impl Config {
         migrations::ConfigVersion::V2(self)
     }
 
-    pub fn get_config_toml() -> color_eyre::eyre::Result<Self> {
-        if let Some(mut path_config_toml) = dirs::config_dir() {
-            path_config_toml.extend(&["near-cli", "config.toml"]);
+    pub fn get_config_toml_path() -> Option<std::path::PathBuf> {
+        dirs::config_dir().map(|config_dir| config_dir.join("near-cli").join("config.toml"))
+    }
 
+    pub fn get_config_toml() -> color_eyre::eyre::Result<Self> {
+        if let Some(path_config_toml) = Self::get_config_toml_path() {
             if !path_config_toml.is_file() {
                 Self::write_config_toml(crate::config::Config::default())?;
             };
@@ -131,7 +143,8 @@ pub struct NetworkConfig {
     pub rpc_url: url::Url,
     pub rpc_api_key: Option<crate::types::api_key::ApiKey>,
     pub wallet_url: url::Url,
-    pub explorer_transaction_url: url::Url,
+    /// `None` for the networks without a transaction explorer (e.g. a sandbox)
+    pub explorer_transaction_url: Option<url::Url>,
     // https://github.com/near/near-cli-rs/issues/116
     pub linkdrop_account_id: Option<near_primitives::types::AccountId>,
     // https://docs.near.org/social/contract
@@ -141,6 +154,10 @@ pub struct NetworkConfig {
     pub fastnear_url: Option<url::Url>,
     pub staking_pools_factory_account_id: Option<near_primitives::types::AccountId>,
     pub coingecko_url: Option<url::Url>,
+    pub remote_signer_url: Option<url::Url>,
+    pub remote_signer_api_key: Option<crate::types::api_key::ApiKey>,
+    pub indexer_api_url: Option<url::Url>,
+    pub indexer_api_key: Option<crate::types::api_key::ApiKey>,
 }
 
 impl NetworkConfig {
@@ -0,0 +1,506 @@
+//! Password-encrypted file-based keystore, an alternative to the secure keychain on machines
+//! without a keychain service (e.g. headless CI runners).
+//!
+//! Every access key is kept in its own file
+//! `<credentials-home-dir>/encrypted-keystore/<network>/<account-id>/<public-key>.json`.
+//! The key data is encrypted with AES-256-GCM, the encryption key is derived from the passphrase
+//! with Argon2id, and the Argon2id parameters are recorded in the file next to the salt. The
+//! account ID and the public key stay in plaintext, so the keys can be listed without the
+//! passphrase, and they are authenticated as the additional data of the cipher.
+use color_eyre::eyre::{ContextCompat, WrapErr};
+
+/// The passphrase of the keystore, if set, is used instead of prompting for it.
+pub const PASSPHRASE_ENV_VAR: &str = "NEAR_KEYSTORE_PASSPHRASE";
+/// The number of an open file descriptor to read the passphrase of the keystore from.
+pub const PASSPHRASE_FD_ENV_VAR: &str = "NEAR_KEYSTORE_PASSPHRASE_FD";
+
+const KEYSTORE_DIR_NAME: &str = "encrypted-keystore";
+const KEYSTORE_FILE_VERSION: u8 = 1;
+/// The Argon2id parameters of the new keys (the OWASP recommendation for Argon2id)
+const ARGON2ID_PARAMS: Argon2idParams = Argon2idParams {
+    memory_cost_kib: 19 * 1024,
+    iterations: 2,
+    parallelism: 1,
+};
+const SALT_LEN: usize = 16;
+
+static PASSPHRASE: std::sync::Mutex<Option<String>> = std::sync::Mutex::new(None);
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct KeystoreFile {
+    version: u8,
+    account_id: near_primitives::types::AccountId,
+    public_key: near_crypto::PublicKey,
+    kdf: KdfParams,
+    cipher: CipherParams,
+    /// base64-encoded encrypted key data (with the authentication tag appended)
+    ciphertext: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "algorithm", rename_all = "kebab-case")]
+enum KdfParams {
+    Argon2id {
+        #[serde(flatten)]
+        params: Argon2idParams,
+        /// base64-encoded salt
+        salt: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct Argon2idParams {
+    memory_cost_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "algorithm", rename_all = "kebab-case")]
+enum CipherParams {
+    #[serde(rename = "aes-256-gcm")]
+    Aes256Gcm {
+        /// base64-encoded nonce
+        nonce: String,
+    },
+}
+
+/// An access key of the keystore, as listed without the passphrase.
+#[derive(Debug, Clone)]
+pub struct KeystoreKey {
+    pub network_name: String,
+    pub account_id: near_primitives::types::AccountId,
+    pub public_key: near_crypto::PublicKey,
+    pub path: std::path::PathBuf,
+}
+
+#[derive(Debug, Clone)]
+pub struct EncryptedKeystore {
+    path: std::path::PathBuf,
+}
+
+impl EncryptedKeystore {
+    pub fn new(credentials_home_dir: &std::path::Path) -> Self {
+        Self {
+            path: Self::keystore_dir(credentials_home_dir),
+        }
+    }
+
+    pub fn keystore_dir(credentials_home_dir: &std::path::Path) -> std::path::PathBuf {
+        credentials_home_dir.join(KEYSTORE_DIR_NAME)
+    }
+
+    fn account_path(
+        &self,
+        network_name: &str,
+        account_id: &near_primitives::types::AccountId,
+    ) -> std::path::PathBuf {
+        self.path.join(network_name).join(account_id.as_str())
+    }
+
+    fn key_path(
+        &self,
+        network_name: &str,
+        account_id: &near_primitives::types::AccountId,
+        public_key: &near_crypto::PublicKey,
+    ) -> std::path::PathBuf {
+        self.account_path(network_name, account_id)
+            .join(format!("{}.json", public_key.to_string().replace(':', "_")))
+    }
+
+    /// The public keys of the account saved in the keystore (the passphrase is not required).
+    pub fn public_keys(
+        &self,
+        network_name: &str,
+        account_id: &near_primitives::types::AccountId,
+    ) -> color_eyre::eyre::Result<Vec<near_crypto::PublicKey>> {
+        let account_path = self.account_path(network_name, account_id);
+        if !account_path.exists() {
+            return Ok(vec![]);
+        }
+        let mut public_keys = vec![];
+        for entry in account_path
+            .read_dir()
+            .wrap_err_with(|| format!("Failed to read the directory {:?}", &account_path))?
+        {
+            let path = entry?.path();
+            if path
+                .extension()
+                .map_or(false, |extension| extension == "json")
+            {
+                public_keys.push(read_keystore_file(&path)?.public_key);
+            }
+        }
+        Ok(public_keys)
+    }
+
+    /// All the keys of the keystore (the passphrase is not required).
+    pub fn keys(&self) -> color_eyre::eyre::Result<Vec<KeystoreKey>> {
+        let Ok(network_dirs) = self.path.read_dir() else {
+            return Ok(vec![]);
+        };
+        let mut keys = vec![];
+        for network_dir in network_dirs {
+            let network_dir = network_dir?.path();
+            let Some(network_name) = network_dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .map(str::to_string)
+            else {
+                continue;
+            };
+            for account_dir in network_dir.read_dir().into_iter().flatten() {
+                for key_path in account_dir?.path().read_dir().into_iter().flatten() {
+                    let path = key_path?.path();
+                    if path
+                        .extension()
+                        .map_or(false, |extension| extension == "json")
+                    {
+                        let keystore_file = read_keystore_file(&path)?;
+                        keys.push(KeystoreKey {
+                            network_name: network_name.clone(),
+                            account_id: keystore_file.account_id,
+                            public_key: keystore_file.public_key,
+                            path,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(keys)
+    }
+
+    pub fn contains(
+        &self,
+        network_name: &str,
+        account_id: &near_primitives::types::AccountId,
+        public_key: &near_crypto::PublicKey,
+    ) -> bool {
+        self.key_path(network_name, account_id, public_key).exists()
+    }
+
+    /// Encrypts and saves the key data (the same JSON that is saved to the keychains).
+    pub fn save(
+        &self,
+        network_name: &str,
+        account_id: &near_primitives::types::AccountId,
+        public_key: &near_crypto::PublicKey,
+        key_pair_properties_buf: &str,
+    ) -> color_eyre::eyre::Result<std::path::PathBuf> {
+        let passphrase = self.passphrase_for_saving()?;
+        let keystore_file = encrypt(
+            account_id,
+            public_key,
+            key_pair_properties_buf,
+            &passphrase,
+            ARGON2ID_PARAMS,
+        )?;
+
+        let key_path = self.key_path(network_name, account_id, public_key);
+        std::fs::create_dir_all(key_path.parent().wrap_err("Invalid keystore path")?)?;
+        write_private_file(&key_path, &serde_json::to_vec_pretty(&keystore_file)?)
+            .wrap_err_with(|| format!("Failed to write to file: {:?}", &key_path))?;
+        Ok(key_path)
+    }
+
+    /// Loads and decrypts the key data.
+    pub fn load(
+        &self,
+        network_name: &str,
+        account_id: &near_primitives::types::AccountId,
+        public_key: &near_crypto::PublicKey,
+    ) -> color_eyre::eyre::Result<String> {
+        let key_path = self.key_path(network_name, account_id, public_key);
+        let keystore_file = read_keystore_file(&key_path).wrap_err_with(|| {
+            format!(
+                "The access key {public_key} of <{account_id}> on network <{network_name}> is not found in the encrypted keystore"
+            )
+        })?;
+        decrypt(&keystore_file, &passphrase(false)?)
+            .wrap_err("Failed to decrypt the access key: the passphrase is incorrect or the keystore file is damaged")
+    }
+
+    /// Removes the key file, if any (the passphrase is not required).
+    pub fn remove(
+        &self,
+        network_name: &str,
+        account_id: &near_primitives::types::AccountId,
+        public_key: &near_crypto::PublicKey,
+    ) -> color_eyre::eyre::Result<Option<std::path::PathBuf>> {
+        let key_path = self.key_path(network_name, account_id, public_key);
+        if !key_path.exists() {
+            return Ok(None);
+        }
+        std::fs::remove_file(&key_path)
+            .wrap_err_with(|| format!("Failed to remove the file: {:?}", &key_path))?;
+        Ok(Some(key_path))
+    }
+
+    /// All the keys of the keystore share the same passphrase: a new keystore asks for the
+    /// passphrase twice, otherwise the passphrase is checked against an existing key.
+    fn passphrase_for_saving(&self) -> color_eyre::eyre::Result<String> {
+        let Some(existing_key_path) = self.any_key_path() else {
+            return passphrase(true);
+        };
+        let passphrase = passphrase(false)?;
+        decrypt(&read_keystore_file(&existing_key_path)?, &passphrase).wrap_err_with(|| {
+            format!(
+                "The passphrase does not match the existing encrypted keystore {:?}",
+                &self.path
+            )
+        })?;
+        Ok(passphrase)
+    }
+
+    fn any_key_path(&self) -> Option<std::path::PathBuf> {
+        let networks = self.path.read_dir().ok()?;
+        networks
+            .filter_map(Result::ok)
+            .filter_map(|network| network.path().read_dir().ok())
+            .flatten()
+            .filter_map(Result::ok)
+            .filter_map(|account| account.path().read_dir().ok())
+            .flatten()
+            .filter_map(Result::ok)
+            .map(|key| key.path())
+            .find(|path| {
+                path.extension()
+                    .map_or(false, |extension| extension == "json")
+            })
+    }
+}
+
+/// The passphrase is taken from the environment variable, or from the file descriptor,
+/// or asked interactively. It is only asked once per run.
+fn passphrase(with_confirmation: bool) -> color_eyre::eyre::Result<String> {
+    let mut cached_passphrase = PASSPHRASE
+        .lock()
+        .map_err(|_| color_eyre::eyre::eyre!("The keystore passphrase lock is poisoned"))?;
+    if let Some(passphrase) = cached_passphrase.as_ref() {
+        return Ok(passphrase.clone());
+    }
+
+    let passphrase = if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV_VAR) {
+        passphrase
+    } else if let Ok(fd) = std::env::var(PASSPHRASE_FD_ENV_VAR) {
+        let fd: u32 = fd.parse().wrap_err_with(|| {
+            format!("{PASSPHRASE_FD_ENV_VAR} must be a file descriptor number")
+        })?;
+        let passphrase = std::fs::read_to_string(format!("/dev/fd/{fd}")).wrap_err_with(|| {
+            format!("Failed to read the keystore passphrase from the file descriptor {fd}")
+        })?;
+        passphrase.trim_end_matches(['\r', '\n']).to_string()
+    } else {
+        let prompt = inquire::Password::new("Enter the passphrase of the encrypted keystore:")
+            .with_display_mode(inquire::PasswordDisplayMode::Masked);
+        if with_confirmation {
+            prompt
+                .with_custom_confirmation_message("Confirm the passphrase:")
+                .prompt()?
+        } else {
+            prompt.without_confirmation().prompt()?
+        }
+    };
+    if passphrase.is_empty() {
+        color_eyre::eyre::bail!("The passphrase of the encrypted keystore must not be empty");
+    }
+    *cached_passphrase = Some(passphrase.clone());
+    Ok(passphrase)
+}
+
+fn read_keystore_file(path: &std::path::Path) -> color_eyre::eyre::Result<KeystoreFile> {
+    let data =
+        std::fs::read(path).wrap_err_with(|| format!("Failed to read the file {:?}", path))?;
+    let keystore_file: KeystoreFile = serde_json::from_slice(&data)
+        .wrap_err_with(|| format!("Error reading data from file: {:?}", path))?;
+    if keystore_file.version != KEYSTORE_FILE_VERSION {
+        color_eyre::eyre::bail!(
+            "Unsupported encrypted keystore file version {} in {:?}",
+            keystore_file.version,
+            path
+        );
+    }
+    Ok(keystore_file)
+}
+
+#[cfg(unix)]
+fn write_private_file(path: &std::path::Path, data: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?
+        .write_all(data)
+}
+
+#[cfg(not(unix))]
+fn write_private_file(path: &std::path::Path, data: &[u8]) -> std::io::Result<()> {
+    std::fs::write(path, data)
+}
+
+fn additional_data(
+    account_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+) -> ring::aead::Aad<Vec<u8>> {
+    ring::aead::Aad::from(format!("{account_id}:{public_key}").into_bytes())
+}
+
+fn derive_key(
+    passphrase: &str,
+    salt: &[u8],
+    params: Argon2idParams,
+) -> color_eyre::eyre::Result<ring::aead::LessSafeKey> {
+    let mut key = [0u8; 32];
+    let argon2_params = argon2::Params::new(
+        params.memory_cost_kib,
+        params.iterations,
+        params.parallelism,
+        Some(key.len()),
+    )
+    .map_err(|err| color_eyre::eyre::eyre!("Invalid Argon2id parameters: {err}"))?;
+    argon2::Argon2::new(
+        argon2::Algorithm::Argon2id,
+        argon2::Version::V0x13,
+        argon2_params,
+    )
+    .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+    .map_err(|err| color_eyre::eyre::eyre!("Failed to derive the encryption key: {err}"))?;
+    let unbound_key = ring::aead::UnboundKey::new(&ring::aead::AES_256_GCM, &key)
+        .map_err(|_| color_eyre::eyre::eyre!("Failed to create the encryption key"))?;
+    Ok(ring::aead::LessSafeKey::new(unbound_key))
+}
+
+fn encrypt(
+    account_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+    plaintext: &str,
+    passphrase: &str,
+    kdf_params: Argon2idParams,
+) -> color_eyre::eyre::Result<KeystoreFile> {
+    use ring::rand::SecureRandom;
+
+    let random = ring::rand::SystemRandom::new();
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; ring::aead::NONCE_LEN];
+    random
+        .fill(&mut salt)
+        .and_then(|()| random.fill(&mut nonce))
+        .map_err(|_| color_eyre::eyre::eyre!("Failed to generate random bytes"))?;
+
+    let mut in_out = plaintext.as_bytes().to_vec();
+    derive_key(passphrase, &salt, kdf_params)?
+        .seal_in_place_append_tag(
+            ring::aead::Nonce::assume_unique_for_key(nonce),
+            additional_data(account_id, public_key),
+            &mut in_out,
+        )
+        .map_err(|_| color_eyre::eyre::eyre!("Failed to encrypt the access key"))?;
+
+    Ok(KeystoreFile {
+        version: KEYSTORE_FILE_VERSION,
+        account_id: account_id.clone(),
+        public_key: public_key.clone(),
+        kdf: KdfParams::Argon2id {
+            params: kdf_params,
+            salt: near_primitives::serialize::to_base64(&salt),
+        },
+        cipher: CipherParams::Aes256Gcm {
+            nonce: near_primitives::serialize::to_base64(&nonce),
+        },
+        ciphertext: near_primitives::serialize::to_base64(&in_out),
+    })
+}
+
+fn decrypt(keystore_file: &KeystoreFile, passphrase: &str) -> color_eyre::eyre::Result<String> {
+    let KdfParams::Argon2id { params, salt } = &keystore_file.kdf;
+    let CipherParams::Aes256Gcm { nonce } = &keystore_file.cipher;
+    let salt = near_primitives::serialize::from_base64(salt)
+        .map_err(|err| color_eyre::eyre::eyre!("Invalid salt: {err}"))?;
+    let nonce = ring::aead::Nonce::try_assume_unique_for_key(
+        &near_primitives::serialize::from_base64(nonce)
+            .map_err(|err| color_eyre::eyre::eyre!("Invalid nonce: {err}"))?,
+    )
+    .map_err(|_| color_eyre::eyre::eyre!("Invalid nonce length"))?;
+    let mut in_out = near_primitives::serialize::from_base64(&keystore_file.ciphertext)
+        .map_err(|err| color_eyre::eyre::eyre!("Invalid ciphertext: {err}"))?;
+
+    let plaintext = derive_key(passphrase, &salt, *params)?
+        .open_in_place(
+            nonce,
+            additional_data(&keystore_file.account_id, &keystore_file.public_key),
+            &mut in_out,
+        )
+        .map_err(|_| color_eyre::eyre::eyre!("Failed to decrypt the access key"))?;
+    Ok(String::from_utf8(plaintext.to_vec())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cheap parameters to keep the tests fast
+    const TEST_ARGON2ID_PARAMS: Argon2idParams = Argon2idParams {
+        memory_cost_kib: 64,
+        iterations: 1,
+        parallelism: 1,
+    };
+
+    #[test]
+    fn encrypt_and_decrypt() {
+        let account_id: near_primitives::types::AccountId = "alice.testnet".parse().unwrap();
+        let public_key: near_crypto::PublicKey =
+            "ed25519:8nDkVwdpkE3Ft2sCtdH1dYjUqNcbUDDE8Ew4wmSUiupd"
+                .parse()
+                .unwrap();
+        let keystore_file = encrypt(
+            &account_id,
+            &public_key,
+            "secret key data",
+            "passphrase",
+            TEST_ARGON2ID_PARAMS,
+        )
+        .unwrap();
+        assert!(!keystore_file.ciphertext.contains("secret"));
+        assert_eq!(
+            decrypt(&keystore_file, "passphrase").unwrap(),
+            "secret key data"
+        );
+        assert!(decrypt(&keystore_file, "wrong passphrase").is_err());
+
+        // The account ID and the public key are authenticated
+        let mut tampered_keystore_file = keystore_file.clone();
+        tampered_keystore_file.account_id = "mallory.testnet".parse().unwrap();
+        assert!(decrypt(&tampered_keystore_file, "passphrase").is_err());
+    }
+
+    #[test]
+    fn kdf_params_are_recorded_in_the_file() {
+        let keystore_file = encrypt(
+            &"alice.testnet".parse().unwrap(),
+            &"ed25519:8nDkVwdpkE3Ft2sCtdH1dYjUqNcbUDDE8Ew4wmSUiupd"
+                .parse()
+                .unwrap(),
+            "secret key data",
+            "passphrase",
+            TEST_ARGON2ID_PARAMS,
+        )
+        .unwrap();
+        let kdf = serde_json::to_value(&keystore_file).unwrap()["kdf"].clone();
+        assert_eq!(kdf["algorithm"], "argon2id");
+        assert_eq!(kdf["memory_cost_kib"], 64);
+        assert_eq!(kdf["iterations"], 1);
+        assert_eq!(kdf["parallelism"], 1);
+
+        // The recorded parameters are used for decryption
+        let keystore_file: KeystoreFile =
+            serde_json::from_value(serde_json::to_value(&keystore_file).unwrap()).unwrap();
+        assert_eq!(
+            decrypt(&keystore_file, "passphrase").unwrap(),
+            "secret key data"
+        );
+    }
+}
@@ -0,0 +1,294 @@
+//! Handoff between `near` and its extensions.
+//!
+//! `near <name> ...` runs the `near-<name>` executable (see `near extension`) with the rest of the
+//! arguments, and passes the resolved [`GlobalContext`](crate::GlobalContext) to it in the
+//! `NEAR_CLI_CONTEXT` environment variable as a JSON document:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "config_path": "/home/user/.config/near-cli/config.toml",
+//!   "config": {
+//!     "credentials_home_dir": "/home/user/.near-credentials",
+//!     "network_connection": { "mainnet": { "network_name": "mainnet", "rpc_url": "...", ... }, ... }
+//!   },
+//!   "offline": false,
+//!   "output_format": "plaintext",
+//!   "network_name": "testnet"
+//! }
+//! ```
+//!
+//! - `config` is the configuration `near` has loaded (and migrated to the latest version), so the
+//!   extension does not have to parse `config.toml` itself; `config_path` is where it is stored.
+//! - `offline` and `output_format` come from `--offline` and `--output` given before the name of
+//!   the extension (`near --output json <name> ...`).
+//! - `network_name` is the network preselected with the `NEAR_NETWORK` (or `NEAR_ENV`)
+//!   environment variable, if it is one of the configured networks. Otherwise the extension
+//!   selects the network itself, as `near` commands do with `network-config <name>`.
+//! - `version` is bumped on backward-incompatible changes only; new fields may be added at any
+//!   time, so extensions must ignore the fields they do not know.
+//!
+//! Environment variables can be read by other processes of the user (e.g. `/proc/<pid>/environ`)
+//! and are inherited by every process the extension runs, so the API keys of the configuration
+//! (`rpc_api_key`, `remote_signer_api_key` and `indexer_api_key`) are left out of the context.
+//! Extensions that need them read them from `config.toml`.
+//!
+//! Extensions written in Rust get the context with [`get_global_context`], which takes the API
+//! keys from `config.toml` and falls back to `config.toml` entirely when the extension is run
+//! directly, and can reuse the transaction pipeline of
+//! `near` (network selection, [`TransactionContext`](crate::commands::TransactionContext) and
+//! the [`SignWith`](crate::transaction_signature_options::SignWith) options) by
+//! ending their command with [`NetworkForTransactionArgs`](crate::network_for_transaction::NetworkForTransactionArgs):
+//!
+//! ```no_run
+//! #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+//! #[interactive_clap(input_context = near_cli_rs::GlobalContext)]
+//! #[interactive_clap(output_context = PingContext)]
+//! struct Ping {
+//!     /// What is the staking pool account ID?
+//!     staking_pool_account_id: near_cli_rs::types::account_id::AccountId,
+//!     /// What is the signer account ID?
+//!     signer_account_id: near_cli_rs::types::account_id::AccountId,
+//!     #[interactive_clap(named_arg)]
+//!     /// Select network
+//!     network_config: near_cli_rs::network_for_transaction::NetworkForTransactionArgs,
+//! }
+//!
+//! #[derive(Clone)]
+//! struct PingContext(near_cli_rs::commands::ActionContext);
+//!
+//! impl PingContext {
+//!     fn from_previous_context(
+//!         previous_context: near_cli_rs::GlobalContext,
+//!         scope: &<Ping as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+//!     ) -> color_eyre::eyre::Result<Self> {
+//!         let signer_id: near_primitives::types::AccountId = scope.signer_account_id.clone().into();
+//!         let receiver_id: near_primitives::types::AccountId =
+//!             scope.staking_pool_account_id.clone().into();
+//!         Ok(Self(near_cli_rs::commands::ActionContext {
+//!             global_context: previous_context,
+//!             interacting_with_account_ids: vec![signer_id.clone(), receiver_id.clone()],
+//!             get_prepopulated_transaction_after_getting_network_callback: std::sync::Arc::new(
+//!                 move |_network_config| {
+//!                     Ok(near_cli_rs::commands::PrepopulatedTransaction {
+//!                         signer_id: signer_id.clone(),
+//!                         receiver_id: receiver_id.clone(),
+//!                         actions: vec![near_primitives::transaction::Action::FunctionCall(
+//!                             Box::new(near_primitives::transaction::FunctionCallAction {
+//!                                 method_name: "ping".to_string(),
+//!                                 args: vec![],
+//!                                 gas: near_gas::NearGas::from_tgas(30).as_gas(),
+//!                                 deposit: 0,
+//!                             }),
+//!                         )],
+//!                     })
+//!                 },
+//!             ),
+//!             on_before_signing_callback: std::sync::Arc::new(|_, _| Ok(())),
+//!             on_before_sending_transaction_callback: std::sync::Arc::new(|_, _| Ok(String::new())),
+//!             on_after_sending_transaction_callback: std::sync::Arc::new(|_, _| Ok(())),
+//!             transaction_bundle: None,
+//!         }))
+//!     }
+//! }
+//!
+//! impl From<PingContext> for near_cli_rs::commands::ActionContext {
+//!     fn from(item: PingContext) -> Self {
+//!         item.0
+//!     }
+//! }
+//!
+//! fn main() -> near_cli_rs::CliResult {
+//!     let global_context = near_cli_rs::extension::get_global_context()?;
+//!     let cli_ping = Ping::parse();
+//!     match <Ping as interactive_clap::FromCli>::from_cli(Some(cli_ping), global_context) {
+//!         interactive_clap::ResultFromCli::Err(_, err) => Err(err),
+//!         _ => Ok(()),
+//!     }
+//! }
+//! ```
+use color_eyre::eyre::WrapErr;
+
+/// The environment variable with the [`ExtensionContext`] of an extension run by `near`.
+pub const CONTEXT_ENV_VAR: &str = "NEAR_CLI_CONTEXT";
+
+/// The version of the [`ExtensionContext`] layout.
+pub const CONTEXT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ExtensionContext {
+    pub version: u32,
+    pub config_path: Option<std::path::PathBuf>,
+    pub config: crate::config::Config,
+    pub offline: bool,
+    pub output_format: crate::common::OutputFormat,
+    pub network_name: Option<String>,
+}
+
+impl ExtensionContext {
+    pub fn new(global_context: &crate::GlobalContext) -> Self {
+        let network_name = std::env::var("NEAR_NETWORK")
+            .or_else(|_| std::env::var("NEAR_ENV"))
+            .ok()
+            .filter(|network_name| {
+                global_context
+                    .config
+                    .network_connection
+                    .contains_key(network_name)
+            });
+        Self {
+            version: CONTEXT_VERSION,
+            config_path: crate::config::Config::get_config_toml_path(),
+            config: without_api_keys(global_context.config.clone()),
+            offline: global_context.offline,
+            output_format: global_context.output_format.clone(),
+            network_name,
+        }
+    }
+
+    /// Returns the context passed by `near`, or `None` if the extension was not run by `near`.
+    pub fn from_env() -> color_eyre::eyre::Result<Option<Self>> {
+        match std::env::var(CONTEXT_ENV_VAR) {
+            Ok(context_json) => Self::from_json(&context_json).map(Some),
+            Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(err) => Err(err).wrap_err_with(|| format!("Failed to read {CONTEXT_ENV_VAR}")),
+        }
+    }
+
+    fn from_json(context_json: &str) -> color_eyre::eyre::Result<Self> {
+        let context: Self = serde_json::from_str(context_json)
+            .wrap_err_with(|| format!("Failed to parse {CONTEXT_ENV_VAR}"))?;
+        if context.version > CONTEXT_VERSION {
+            color_eyre::eyre::bail!(
+                "The extension supports the context of version {CONTEXT_VERSION}, but near CLI has passed version {}. Please, update the extension.",
+                context.version
+            );
+        }
+        Ok(context)
+    }
+}
+
+fn without_api_keys(mut config: crate::config::Config) -> crate::config::Config {
+    for (_, network_config) in config.network_connection.iter_mut() {
+        network_config.rpc_api_key = None;
+        network_config.remote_signer_api_key = None;
+        network_config.indexer_api_key = None;
+    }
+    config
+}
+
+/// Puts back the API keys left out of the context, taking them from the networks of the same name
+/// in `config_toml`.
+fn with_api_keys_from(
+    mut config: crate::config::Config,
+    config_toml: &crate::config::Config,
+) -> crate::config::Config {
+    for (network_name, network_config) in config.network_connection.iter_mut() {
+        let Some(network_config_toml) = config_toml.network_connection.get(network_name) else {
+            continue;
+        };
+        network_config.rpc_api_key = network_config
+            .rpc_api_key
+            .take()
+            .or_else(|| network_config_toml.rpc_api_key.clone());
+        network_config.remote_signer_api_key = network_config
+            .remote_signer_api_key
+            .take()
+            .or_else(|| network_config_toml.remote_signer_api_key.clone());
+        network_config.indexer_api_key = network_config
+            .indexer_api_key
+            .take()
+            .or_else(|| network_config_toml.indexer_api_key.clone());
+    }
+    config
+}
+
+impl From<ExtensionContext> for crate::GlobalContext {
+    fn from(item: ExtensionContext) -> Self {
+        Self {
+            config: item.config,
+            offline: item.offline,
+            output_format: item.output_format,
+        }
+    }
+}
+
+/// The context of an extension: the one passed by `near` (with the API keys from `config.toml`),
+/// or the default one built from `config.toml` when the extension is run directly.
+pub fn get_global_context() -> color_eyre::eyre::Result<crate::GlobalContext> {
+    if let Some(mut extension_context) = ExtensionContext::from_env()? {
+        extension_context.config = with_api_keys_from(
+            extension_context.config,
+            &crate::config::Config::get_config_toml()?,
+        );
+        return Ok(extension_context.into());
+    }
+    Ok(crate::GlobalContext {
+        config: crate::config::Config::get_config_toml()?,
+        offline: false,
+        output_format: crate::common::OutputFormat::default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extension_context_round_trip() {
+        let global_context = crate::GlobalContext {
+            config: crate::config::Config::default(),
+            offline: true,
+            output_format: crate::common::OutputFormat::Json,
+        };
+        let context_json = serde_json::to_string(&ExtensionContext::new(&global_context)).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&context_json).unwrap();
+        assert_eq!(value["version"], CONTEXT_VERSION);
+        assert_eq!(value["output_format"], "json");
+
+        let restored: crate::GlobalContext =
+            ExtensionContext::from_json(&context_json).unwrap().into();
+        assert!(restored.offline);
+        assert!(restored.output_format.is_json());
+        assert_eq!(
+            restored.config.network_names(),
+            global_context.config.network_names()
+        );
+
+        let mut newer_context = value;
+        newer_context["version"] = (CONTEXT_VERSION + 1).into();
+        newer_context["unknown_field"] = true.into();
+        assert!(ExtensionContext::from_json(&newer_context.to_string()).is_err());
+        newer_context["version"] = CONTEXT_VERSION.into();
+        assert!(ExtensionContext::from_json(&newer_context.to_string()).is_ok());
+    }
+
+    #[test]
+    fn api_keys_are_left_out_of_the_context() {
+        let api_key: crate::types::api_key::ApiKey = "secret".parse().unwrap();
+        let mut config = crate::config::Config::default();
+        for (_, network_config) in config.network_connection.iter_mut() {
+            network_config.rpc_api_key = Some(api_key.clone());
+            network_config.remote_signer_api_key = Some(api_key.clone());
+            network_config.indexer_api_key = Some(api_key.clone());
+        }
+        let global_context = crate::GlobalContext {
+            config: config.clone(),
+            offline: false,
+            output_format: crate::common::OutputFormat::default(),
+        };
+        let context_json = serde_json::to_string(&ExtensionContext::new(&global_context)).unwrap();
+        assert!(!context_json.contains("secret"));
+
+        let extension_context = ExtensionContext::from_json(&context_json).unwrap();
+        let restored_config = with_api_keys_from(extension_context.config, &config);
+        for network_config in restored_config.network_connection.values() {
+            assert_eq!(network_config.rpc_api_key.as_ref(), Some(&api_key));
+            assert_eq!(
+                network_config.remote_signer_api_key.as_ref(),
+                Some(&api_key)
+            );
+            assert_eq!(network_config.indexer_api_key.as_ref(), Some(&api_key));
+        }
+    }
+}
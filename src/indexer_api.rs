@@ -0,0 +1,794 @@
+//! Indexer API client, used to view the activity history of an account.
+//!
+//! The history comes from the NearBlocks API (<https://api.nearblocks.io/api-docs>): the
+//! transactions and receipts of the account (`GET <indexer_api_url>/v1/account/<account_id>/txns`)
+//! and its FT transfers (`GET <indexer_api_url>/v1/account/<account_id>/ft-txns`), requested with
+//! `per_page`, `cursor`, `after_date` and `before_date`. If an API key is configured, it is sent as
+//! `Authorization: Bearer <api key>`. Every page holds the next `limit` items of both lists, and
+//! its cursor holds the cursors of both lists.
+//!
+//! The responses are converted into [`ActivityItem`]s, each describing a single event that
+//! involves the account: a transaction or a receipt, a NEAR or FT transfer, or an access key
+//! change.
+use color_eyre::eyre::{ContextCompat, WrapErr};
+
+/// The default indexer API of the networks it is available for.
+pub fn default_indexer_api_url(network_name: &str) -> Option<url::Url> {
+    match network_name {
+        "mainnet" => Some("https://api.nearblocks.io/".parse().unwrap()),
+        "testnet" => Some("https://api-testnet.nearblocks.io/".parse().unwrap()),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityKind {
+    Transaction,
+    NearTransfer,
+    FtTransfer,
+    AccessKeyAdded,
+    AccessKeyDeleted,
+}
+
+impl std::fmt::Display for ActivityKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Transaction => write!(f, "transaction"),
+            Self::NearTransfer => write!(f, "NEAR transfer"),
+            Self::FtTransfer => write!(f, "FT transfer"),
+            Self::AccessKeyAdded => write!(f, "access key added"),
+            Self::AccessKeyDeleted => write!(f, "access key deleted"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    Incoming,
+    Outgoing,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ActivityItem {
+    pub kind: ActivityKind,
+    pub direction: Direction,
+    pub block_height: near_primitives::types::BlockHeight,
+    /// nanoseconds since the Unix epoch
+    #[serde(with = "near_primitives::serialize::dec_format")]
+    pub block_timestamp: u64,
+    pub transaction_hash: near_primitives::hash::CryptoHash,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub receipt_id: Option<near_primitives::hash::CryptoHash>,
+    /// the predecessor of incoming items and the receiver of outgoing items
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub counterparty_account_id: Option<near_primitives::types::AccountId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method_name: Option<String>,
+    /// yoctoNEAR for NEAR transfers and the smallest units of the token for FT transfers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ft_contract_account_id: Option<near_primitives::types::AccountId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ft_symbol: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ft_decimals: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<near_crypto::PublicKey>,
+}
+
+impl ActivityItem {
+    /// The amount with its currency (NEAR or the FT symbol), if the item carries one.
+    pub fn amount_to_string(&self) -> Option<String> {
+        let amount: u128 = self.amount.as_ref()?.parse().ok()?;
+        Some(match self.kind {
+            ActivityKind::FtTransfer => crate::types::ft_properties::FungibleToken::from_params_ft(
+                amount,
+                self.ft_decimals.unwrap_or(0),
+                self.ft_symbol.clone().unwrap_or_default(),
+            )
+            .to_string(),
+            _ => crate::types::near_token::NearToken::from_yoctonear(amount).to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ActivityPage {
+    pub items: Vec<ActivityItem>,
+    #[serde(default)]
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ActivityQuery {
+    pub limit: u64,
+    pub cursor: Option<String>,
+    pub from_date: Option<crate::types::date::Date>,
+    pub to_date: Option<crate::types::date::Date>,
+}
+
+impl ActivityQuery {
+    /// Whether the item is within the dates of the query (the API date filters are only used to
+    /// narrow the requests down).
+    fn contains(&self, item: &ActivityItem) -> bool {
+        self.from_date.map_or(true, |from_date| {
+            item.block_timestamp >= from_date.start_timestamp_nanosec()
+        }) && self.to_date.map_or(true, |to_date| {
+            item.block_timestamp < to_date.next_day().start_timestamp_nanosec()
+        })
+    }
+}
+
+/// The cursors of the transactions and FT transfers lists, `<txns cursor>:<ft-txns cursor>` (an
+/// empty cursor means the list is over).
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PageCursor {
+    txns: Option<String>,
+    ft_txns: Option<String>,
+}
+
+impl std::fmt::Display for PageCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}",
+            self.txns.as_deref().unwrap_or_default(),
+            self.ft_txns.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+impl std::str::FromStr for PageCursor {
+    type Err = color_eyre::eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (txns, ft_txns) = s
+            .split_once(':')
+            .wrap_err_with(|| format!("The cursor <{s}> is not a cursor of the account history"))?;
+        let cursor = |cursor: &str| (!cursor.is_empty()).then(|| cursor.to_string());
+        Ok(Self {
+            txns: cursor(txns),
+            ft_txns: cursor(ft_txns),
+        })
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct NearBlocksBlock {
+    block_height: near_primitives::types::BlockHeight,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct NearBlocksTxnsPage {
+    #[serde(default)]
+    cursor: Option<serde_json::Value>,
+    #[serde(default)]
+    txns: Vec<NearBlocksTxn>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct NearBlocksTxn {
+    #[serde(default)]
+    receipt_id: Option<near_primitives::hash::CryptoHash>,
+    predecessor_account_id: near_primitives::types::AccountId,
+    receiver_account_id: near_primitives::types::AccountId,
+    transaction_hash: near_primitives::hash::CryptoHash,
+    #[serde(with = "near_primitives::serialize::dec_format")]
+    block_timestamp: u64,
+    block: NearBlocksBlock,
+    #[serde(default)]
+    actions: Vec<NearBlocksAction>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct NearBlocksAction {
+    action: String,
+    #[serde(default)]
+    method: Option<String>,
+    #[serde(default)]
+    deposit: Option<Box<serde_json::value::RawValue>>,
+    #[serde(default)]
+    args: Option<serde_json::Value>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct NearBlocksFtTxnsPage {
+    #[serde(default)]
+    cursor: Option<serde_json::Value>,
+    #[serde(default)]
+    txns: Vec<NearBlocksFtTxn>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct NearBlocksFtTxn {
+    #[serde(default)]
+    receipt_id: Option<near_primitives::hash::CryptoHash>,
+    #[serde(default)]
+    involved_account_id: Option<near_primitives::types::AccountId>,
+    /// the signed change of the account balance in the smallest units of the token
+    delta_amount: Box<serde_json::value::RawValue>,
+    transaction_hash: near_primitives::hash::CryptoHash,
+    #[serde(with = "near_primitives::serialize::dec_format")]
+    block_timestamp: u64,
+    block: NearBlocksBlock,
+    ft: NearBlocksFt,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct NearBlocksFt {
+    contract: near_primitives::types::AccountId,
+    #[serde(default)]
+    symbol: Option<String>,
+    #[serde(default)]
+    decimals: Option<u8>,
+}
+
+/// NearBlocks returns some amounts as strings and others as numbers (e.g. `1e24`). The numbers are
+/// kept as the raw JSON text and converted to integers exactly, since yoctoNEAR amounts do not fit
+/// into a floating point number.
+fn amount_to_string(amount: &serde_json::value::RawValue) -> Option<String> {
+    let amount = amount.get();
+    if amount.starts_with('"') {
+        return serde_json::from_str(amount).ok();
+    }
+    decimal_to_integer_string(amount)
+}
+
+/// Converts a JSON number (`-1.5e3`) to an integer string (`-1500`), if it is an integer.
+fn decimal_to_integer_string(number: &str) -> Option<String> {
+    let (sign, number) = match number.strip_prefix('-') {
+        Some(number) => ("-", number),
+        None => ("", number),
+    };
+    let (mantissa, exponent) = match number.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (
+            mantissa,
+            exponent
+                .strip_prefix('+')
+                .unwrap_or(exponent)
+                .parse::<i64>()
+                .ok()?,
+        ),
+        None => (number, 0),
+    };
+    let (integer_part, fraction_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let mut digits = format!("{integer_part}{fraction_part}");
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let exponent = exponent - i64::try_from(fraction_part.len()).ok()?;
+    if exponent >= 0 {
+        // An amount never has this many digits.
+        if exponent > 64 {
+            return None;
+        }
+        digits.push_str(&"0".repeat(exponent as usize));
+    } else {
+        let fraction_len = usize::try_from(-exponent).ok()?.min(digits.len());
+        let fraction = digits.split_off(digits.len() - fraction_len);
+        if fraction.chars().any(|c| c != '0') {
+            return None;
+        }
+    }
+    let digits = digits.trim_start_matches('0');
+    Some(if digits.is_empty() {
+        "0".to_string()
+    } else {
+        format!("{sign}{digits}")
+    })
+}
+
+fn cursor_to_string(cursor: Option<serde_json::Value>) -> Option<String> {
+    match cursor? {
+        serde_json::Value::String(cursor) if !cursor.is_empty() => Some(cursor),
+        serde_json::Value::Number(cursor) => Some(cursor.to_string()),
+        _ => None,
+    }
+}
+
+impl NearBlocksTxn {
+    fn into_activity_item(self, account_id: &near_primitives::types::AccountId) -> ActivityItem {
+        let (direction, counterparty_account_id) = if &self.predecessor_account_id == account_id {
+            (Direction::Outgoing, self.receiver_account_id)
+        } else {
+            (Direction::Incoming, self.predecessor_account_id)
+        };
+        let mut item = ActivityItem {
+            kind: ActivityKind::Transaction,
+            direction,
+            block_height: self.block.block_height,
+            block_timestamp: self.block_timestamp,
+            transaction_hash: self.transaction_hash,
+            receipt_id: self.receipt_id,
+            counterparty_account_id: Some(counterparty_account_id),
+            method_name: None,
+            amount: None,
+            ft_contract_account_id: None,
+            ft_symbol: None,
+            ft_decimals: None,
+            public_key: None,
+        };
+        let public_key = |action: &NearBlocksAction| {
+            action
+                .args
+                .as_ref()
+                .and_then(|args| args.get("public_key"))
+                .and_then(|public_key| public_key.as_str())
+                .and_then(|public_key| public_key.parse().ok())
+        };
+        if let Some(action) = self
+            .actions
+            .iter()
+            .find(|action| action.action == "FUNCTION_CALL")
+        {
+            item.method_name.clone_from(&action.method);
+        } else if let Some(action) = self
+            .actions
+            .iter()
+            .find(|action| action.action == "ADD_KEY")
+        {
+            item.kind = ActivityKind::AccessKeyAdded;
+            item.public_key = public_key(action);
+        } else if let Some(action) = self
+            .actions
+            .iter()
+            .find(|action| action.action == "DELETE_KEY")
+        {
+            item.kind = ActivityKind::AccessKeyDeleted;
+            item.public_key = public_key(action);
+        } else if let [action] = &self.actions[..] {
+            if action.action == "TRANSFER" {
+                item.kind = ActivityKind::NearTransfer;
+                item.amount = action.deposit.as_deref().and_then(amount_to_string);
+            }
+        }
+        item
+    }
+}
+
+impl NearBlocksFtTxn {
+    fn into_activity_item(self) -> ActivityItem {
+        let delta_amount = amount_to_string(&self.delta_amount).unwrap_or_default();
+        let (direction, amount) = match delta_amount.strip_prefix('-') {
+            Some(amount) => (Direction::Outgoing, amount.to_string()),
+            None => (Direction::Incoming, delta_amount),
+        };
+        ActivityItem {
+            kind: ActivityKind::FtTransfer,
+            direction,
+            block_height: self.block.block_height,
+            block_timestamp: self.block_timestamp,
+            transaction_hash: self.transaction_hash,
+            receipt_id: self.receipt_id,
+            counterparty_account_id: self.involved_account_id,
+            method_name: None,
+            amount: Some(amount),
+            ft_contract_account_id: Some(self.ft.contract),
+            ft_symbol: self.ft.symbol,
+            ft_decimals: self.ft.decimals,
+            public_key: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexerClient {
+    url: url::Url,
+    api_key: Option<crate::types::api_key::ApiKey>,
+}
+
+impl IndexerClient {
+    pub fn new(url: url::Url, api_key: Option<crate::types::api_key::ApiKey>) -> Self {
+        Self { url, api_key }
+    }
+
+    pub fn from_network_config(
+        network_config: &crate::config::NetworkConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        let url = network_config
+            .indexer_api_url
+            .clone()
+            .or_else(|| default_indexer_api_url(&network_config.network_name))
+            .wrap_err_with(|| {
+                format!(
+                    "There is no indexer API configured for network <{}>. Set `indexer_api_url` (and optionally `indexer_api_key`) of the network connection in the config file to a NearBlocks API server.",
+                    network_config.network_name
+                )
+            })?;
+        Ok(Self::new(url, network_config.indexer_api_key.clone()))
+    }
+
+    #[tracing::instrument(name = "Fetching the account activity from the indexer ...", skip_all)]
+    pub fn fetch_activity(
+        &self,
+        account_id: &near_primitives::types::AccountId,
+        query: &ActivityQuery,
+    ) -> color_eyre::eyre::Result<ActivityPage> {
+        let cursor = match &query.cursor {
+            Some(cursor) => cursor.parse()?,
+            None => PageCursor {
+                txns: None,
+                ft_txns: None,
+            },
+        };
+        let is_first_page = query.cursor.is_none();
+        let mut items = vec![];
+        let mut next_cursor = PageCursor {
+            txns: None,
+            ft_txns: None,
+        };
+
+        if is_first_page || cursor.txns.is_some() {
+            let page: NearBlocksTxnsPage =
+                self.get(account_id, "txns", query, cursor.txns.as_deref())?;
+            items.extend(
+                page.txns
+                    .into_iter()
+                    .map(|txn| txn.into_activity_item(account_id)),
+            );
+            next_cursor.txns = cursor_to_string(page.cursor);
+        }
+        if is_first_page || cursor.ft_txns.is_some() {
+            let page: NearBlocksFtTxnsPage =
+                self.get(account_id, "ft-txns", query, cursor.ft_txns.as_deref())?;
+            items.extend(
+                page.txns
+                    .into_iter()
+                    .map(NearBlocksFtTxn::into_activity_item),
+            );
+            next_cursor.ft_txns = cursor_to_string(page.cursor);
+        }
+
+        items.retain(|item| query.contains(item));
+        sort_by_time(&mut items);
+        Ok(ActivityPage {
+            items,
+            next_cursor: (next_cursor.txns.is_some() || next_cursor.ft_txns.is_some())
+                .then(|| next_cursor.to_string()),
+        })
+    }
+
+    fn get<T: serde::de::DeserializeOwned>(
+        &self,
+        account_id: &near_primitives::types::AccountId,
+        list: &str,
+        query: &ActivityQuery,
+        cursor: Option<&str>,
+    ) -> color_eyre::eyre::Result<T> {
+        let mut url = self.url.clone();
+        url.path_segments_mut()
+            .map_err(|_| {
+                color_eyre::eyre::eyre!("The indexer API URL <{}> cannot be a base", self.url)
+            })?
+            .pop_if_empty()
+            .extend(["v1", "account", account_id.as_str(), list]);
+        {
+            let mut query_pairs = url.query_pairs_mut();
+            query_pairs.append_pair("per_page", &query.limit.to_string());
+            query_pairs.append_pair("order", "desc");
+            if let Some(cursor) = cursor {
+                query_pairs.append_pair("cursor", cursor);
+            }
+            // The API bounds are exclusive, the items are filtered by the exact dates afterwards.
+            if let Some(from_date) = &query.from_date {
+                query_pairs.append_pair("after_date", &from_date.previous_day().to_string());
+            }
+            if let Some(to_date) = &query.to_date {
+                query_pairs.append_pair("before_date", &to_date.next_day().to_string());
+            }
+        }
+        let mut request = reqwest::blocking::Client::new().get(url.clone());
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+        let response = request
+            .send()
+            .wrap_err_with(|| format!("Failed to connect to the indexer API <{}>", self.url))?;
+        let status = response.status();
+        let body = response.bytes()?;
+        if !status.is_success() {
+            color_eyre::eyre::bail!(
+                "The indexer API responded with HTTP status {status}: {}",
+                String::from_utf8_lossy(&body)
+            );
+        }
+        serde_json::from_slice(&body).wrap_err_with(|| {
+            format!(
+                "The indexer API responded with an unexpected body: {}",
+                String::from_utf8_lossy(&body)
+            )
+        })
+    }
+}
+
+/// Sorts the activity items from the newest to the oldest.
+///
+/// The transactions and the FT transfers are paginated separately, so the items of several pages
+/// have to be sorted again once they are put together.
+pub fn sort_by_time(items: &mut [ActivityItem]) {
+    items.sort_by(|a, b| b.block_timestamp.cmp(&a.block_timestamp));
+}
+
+/// Serializes the activity items to CSV (with a header line).
+pub fn to_csv(items: &[ActivityItem]) -> String {
+    fn field(value: Option<String>) -> String {
+        let value = value.unwrap_or_default();
+        if value.contains([',', '"', '\n']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value
+        }
+    }
+    let mut csv = "time,block_height,kind,direction,counterparty_account_id,method_name,amount,ft_contract_account_id,ft_symbol,ft_decimals,public_key,transaction_hash,receipt_id\n".to_string();
+    for item in items {
+        let row = [
+            Some(crate::types::date::format_timestamp(item.block_timestamp)),
+            Some(item.block_height.to_string()),
+            serde_json::to_value(item.kind)
+                .ok()
+                .and_then(|kind| kind.as_str().map(str::to_string)),
+            serde_json::to_value(item.direction)
+                .ok()
+                .and_then(|direction| direction.as_str().map(str::to_string)),
+            item.counterparty_account_id
+                .as_ref()
+                .map(ToString::to_string),
+            item.method_name.clone(),
+            item.amount.clone(),
+            item.ft_contract_account_id
+                .as_ref()
+                .map(ToString::to_string),
+            item.ft_symbol.clone(),
+            item.ft_decimals.map(|decimals| decimals.to_string()),
+            item.public_key.as_ref().map(ToString::to_string),
+            Some(item.transaction_hash.to_string()),
+            item.receipt_id.as_ref().map(ToString::to_string),
+        ];
+        csv.push_str(&row.into_iter().map(field).collect::<Vec<_>>().join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Serves the responses of the handler for the request targets (the path with the query) and
+    /// sends the request targets with the `Authorization` header back through the channel.
+    fn serve(
+        handler: impl Fn(&str) -> serde_json::Value + Send + 'static,
+    ) -> (std::net::SocketAddr, std::sync::mpsc::Receiver<String>) {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let address = crate::test_utils::serve_http(move |request| {
+            let mut target = request.target.clone();
+            if let Some(authorization) = &request.authorization {
+                target.push_str(&format!(" authorization: {authorization}"));
+            }
+            sender.send(target).unwrap();
+            handler(&request.target)
+        });
+        (address, receiver)
+    }
+
+    fn nearblocks_txn(
+        predecessor_account_id: &str,
+        receiver_account_id: &str,
+        block_timestamp: &str,
+        actions: serde_json::Value,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "receipt_id": near_primitives::hash::CryptoHash::hash_bytes(block_timestamp.as_bytes()),
+            "predecessor_account_id": predecessor_account_id,
+            "receiver_account_id": receiver_account_id,
+            "transaction_hash": near_primitives::hash::CryptoHash::default(),
+            "included_in_block_hash": near_primitives::hash::CryptoHash::default(),
+            "block_timestamp": block_timestamp,
+            "block": {"block_height": 100},
+            "actions": actions,
+            "outcomes": {"status": true},
+        })
+    }
+
+    #[test]
+    fn activity_pages_are_fetched_from_nearblocks() {
+        let (address, requests) = serve(|target| {
+            let path = target.split('?').next().unwrap();
+            let is_next_page = target.contains("cursor=");
+            match path {
+                "/nearblocks/v1/account/alice.testnet/txns" => serde_json::json!({
+                    "cursor": 7,
+                    "txns": [
+                        nearblocks_txn(
+                            "bob.testnet",
+                            "alice.testnet",
+                            "1700000000000000000",
+                            serde_json::json!([{"action": "TRANSFER", "method": null, "deposit": 1e24}]),
+                        ),
+                        nearblocks_txn(
+                            "alice.testnet",
+                            "counter.testnet",
+                            "1700000300000000000",
+                            serde_json::json!([{"action": "FUNCTION_CALL", "method": "increment", "deposit": 0}]),
+                        ),
+                        nearblocks_txn(
+                            "alice.testnet",
+                            "alice.testnet",
+                            "1600000000000000000",
+                            serde_json::json!([{"action": "ADD_KEY", "method": null, "deposit": 0, "args": {
+                                "public_key": "ed25519:8nDkVwdpkE3Ft2sCtdH1dYjUqNcbUDDE8Ew4wmSUiupd",
+                            }}]),
+                        ),
+                    ],
+                }),
+                "/nearblocks/v1/account/alice.testnet/ft-txns" if is_next_page => {
+                    serde_json::json!({"txns": []})
+                }
+                "/nearblocks/v1/account/alice.testnet/ft-txns" => serde_json::json!({
+                    "cursor": "42",
+                    "txns": [{
+                        "affected_account_id": "alice.testnet",
+                        "involved_account_id": "bob.testnet",
+                        "delta_amount": "-1500000",
+                        "cause": "TRANSFER",
+                        "transaction_hash": near_primitives::hash::CryptoHash::default(),
+                        "block_timestamp": "1700000100000000000",
+                        "block": {"block_height": 101},
+                        "ft": {"contract": "usdc.testnet", "name": "USD Coin", "symbol": "USDC", "decimals": 6},
+                    }],
+                }),
+                path => panic!("unexpected request of {path}"),
+            }
+        });
+        // The base URL path is kept even without a trailing slash.
+        let indexer_client = IndexerClient::new(
+            format!("http://{address}/nearblocks").parse().unwrap(),
+            Some("secret".parse().unwrap()),
+        );
+        let account_id = "alice.testnet".parse().unwrap();
+
+        let page = indexer_client
+            .fetch_activity(
+                &account_id,
+                &ActivityQuery {
+                    limit: 3,
+                    from_date: Some("2023-11-01".parse().unwrap()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            requests.recv().unwrap(),
+            "/nearblocks/v1/account/alice.testnet/txns?per_page=3&order=desc&after_date=2023-10-31 authorization: Bearer secret"
+        );
+        assert_eq!(
+            requests.recv().unwrap(),
+            "/nearblocks/v1/account/alice.testnet/ft-txns?per_page=3&order=desc&after_date=2023-10-31 authorization: Bearer secret"
+        );
+        // The key added before the start date is filtered out, the rest is sorted by time.
+        assert_eq!(
+            page.items
+                .iter()
+                .map(|item| (item.kind, item.direction))
+                .collect::<Vec<_>>(),
+            vec![
+                (ActivityKind::Transaction, Direction::Outgoing),
+                (ActivityKind::FtTransfer, Direction::Outgoing),
+                (ActivityKind::NearTransfer, Direction::Incoming),
+            ]
+        );
+        assert_eq!(page.items[0].method_name.as_deref(), Some("increment"));
+        assert_eq!(
+            page.items[0].counterparty_account_id.as_ref().unwrap(),
+            "counter.testnet"
+        );
+        assert_eq!(page.items[1].amount_to_string().unwrap(), "1.5 USDC");
+        assert_eq!(page.items[2].amount_to_string().unwrap(), "1 NEAR");
+        assert_eq!(page.next_cursor.as_deref(), Some("7:42"));
+        assert_eq!(
+            to_csv(&page.items[1..2]).lines().nth(1).unwrap(),
+            "2023-11-14 22:15:00,101,ft_transfer,outgoing,bob.testnet,,1500000,usdc.testnet,USDC,6,,11111111111111111111111111111111,"
+        );
+
+        let page = indexer_client
+            .fetch_activity(
+                &account_id,
+                &ActivityQuery {
+                    limit: 3,
+                    cursor: Some(":42".to_string()),
+                    to_date: Some("2023-11-14".parse().unwrap()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        // The transactions list is over, so only the FT transfers are requested.
+        assert_eq!(
+            requests.recv().unwrap(),
+            "/nearblocks/v1/account/alice.testnet/ft-txns?per_page=3&order=desc&cursor=42&before_date=2023-11-15 authorization: Bearer secret"
+        );
+        assert_eq!(page, ActivityPage::default());
+    }
+
+    #[test]
+    fn key_changes_are_reported_with_the_key() {
+        let txn: NearBlocksTxn = serde_json::from_str(
+            &nearblocks_txn(
+                "alice.testnet",
+                "alice.testnet",
+                "1700000000000000000",
+                serde_json::json!([{"action": "DELETE_KEY", "args": {
+                    "public_key": "ed25519:8nDkVwdpkE3Ft2sCtdH1dYjUqNcbUDDE8Ew4wmSUiupd",
+                }}]),
+            )
+            .to_string(),
+        )
+        .unwrap();
+        let item = txn.into_activity_item(&"alice.testnet".parse().unwrap());
+        assert_eq!(item.kind, ActivityKind::AccessKeyDeleted);
+        assert_eq!(
+            item.public_key.unwrap().to_string(),
+            "ed25519:8nDkVwdpkE3Ft2sCtdH1dYjUqNcbUDDE8Ew4wmSUiupd"
+        );
+        assert!("no-separator".parse::<PageCursor>().is_err());
+    }
+
+    #[test]
+    fn amounts_are_kept_exact() {
+        let amount = |json: &str| {
+            amount_to_string(&serde_json::value::RawValue::from_string(json.to_string()).unwrap())
+        };
+        assert_eq!(
+            amount("1234567890123456789012345").as_deref(),
+            Some("1234567890123456789012345")
+        );
+        assert_eq!(
+            amount("1.234567890123456789012345e24").as_deref(),
+            Some("1234567890123456789012345")
+        );
+        assert_eq!(
+            amount(r#""1234567890123456789012345""#).as_deref(),
+            Some("1234567890123456789012345")
+        );
+        assert_eq!(
+            amount("1e+24").as_deref(),
+            Some("1000000000000000000000000")
+        );
+        assert_eq!(amount("-1500000").as_deref(), Some("-1500000"));
+        assert_eq!(amount("-1.5E3").as_deref(), Some("-1500"));
+        assert_eq!(amount("1500e-2").as_deref(), Some("15"));
+        assert_eq!(amount("150e-2"), None);
+        assert_eq!(amount("0").as_deref(), Some("0"));
+        assert_eq!(amount("null"), None);
+    }
+
+    #[test]
+    fn items_of_several_pages_are_sorted_by_time() {
+        let item = |block_timestamp| ActivityItem {
+            kind: ActivityKind::Transaction,
+            direction: Direction::Outgoing,
+            block_height: 100,
+            block_timestamp,
+            transaction_hash: near_primitives::hash::CryptoHash::default(),
+            receipt_id: None,
+            counterparty_account_id: None,
+            method_name: None,
+            amount: None,
+            ft_contract_account_id: None,
+            ft_symbol: None,
+            ft_decimals: None,
+            public_key: None,
+        };
+        let mut items = vec![item(30), item(10), item(40), item(20)];
+        sort_by_time(&mut items);
+        assert_eq!(
+            items
+                .iter()
+                .map(|item| item.block_timestamp)
+                .collect::<Vec<_>>(),
+            vec![40, 30, 20, 10]
+        );
+    }
+}
@@ -4,10 +4,16 @@ pub use common::CliResult;
 pub mod commands;
 pub mod common;
 pub mod config;
+pub mod encrypted_keystore;
+pub mod extension;
+pub mod indexer_api;
 pub mod js_command_match;
 pub mod network;
 pub mod network_for_transaction;
 pub mod network_view_at_block;
+pub mod remote_signer;
+#[cfg(test)]
+mod test_utils;
 pub mod transaction_signature_options;
 pub mod types;
 pub mod utils_command;
@@ -16,4 +22,5 @@ pub mod utils_command;
 pub struct GlobalContext {
     pub config: crate::config::Config,
     pub offline: bool,
+    pub output_format: crate::common::OutputFormat,
 }
@@ -33,6 +33,10 @@ struct Cmd {
     /// Offline mode
     #[interactive_clap(long)]
     offline: bool,
+    /// Output format: plaintext (default) or json (a machine-readable document is printed to stdout)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    output: Option<crate::common::OutputFormat>,
     #[interactive_clap(subcommand)]
     top_level: crate::commands::TopLevelCommand,
 }
@@ -48,6 +52,7 @@ impl CmdContext {
         Ok(Self(crate::GlobalContext {
             config: previous_context.0,
             offline: scope.offline,
+            output_format: scope.output.clone().unwrap_or_default(),
         }))
     }
 }
@@ -148,7 +153,7 @@ fn main() -> crate::common::CliResult {
             if let clap::error::ErrorKind::UnknownArgument
             | clap::error::ErrorKind::InvalidSubcommand = error.kind()
             {
-                return crate::common::try_external_subcommand_execution(error);
+                return crate::common::try_external_subcommand_execution(error, config);
             }
             error.exit();
         }
@@ -217,6 +222,7 @@ fn main() -> crate::common::CliResult {
                 );
                 let self_update_cli_cmd = CliCmd {
                     offline: false,
+                    output: None,
                     top_level:
                         Some(crate::commands::CliTopLevelCommand::Extensions(
                             crate::commands::extensions::CliExtensionsCommands {
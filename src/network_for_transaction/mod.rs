@@ -22,6 +22,7 @@ pub struct NetworkForTransactionArgsContext {
         crate::transaction_signature_options::OnBeforeSendingTransactionCallback,
     on_after_sending_transaction_callback:
         crate::transaction_signature_options::OnAfterSendingTransactionCallback,
+    transaction_bundle: Option<crate::types::transaction_bundle::TransactionBundle>,
 }
 
 impl NetworkForTransactionArgsContext {
@@ -29,15 +30,28 @@ impl NetworkForTransactionArgsContext {
         previous_context: crate::commands::ActionContext,
         scope: &<NetworkForTransactionArgs as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
+        if let Some(transaction_bundle) = &previous_context.transaction_bundle {
+            if transaction_bundle.network_name != scope.network_name {
+                color_eyre::eyre::bail!(
+                    "The transaction bundle is prepared for the network <{}>, not <{}>",
+                    transaction_bundle.network_name,
+                    scope.network_name
+                );
+            }
+        }
         let network_connection = previous_context
             .global_context
             .config
             .network_connection
             .clone();
-        let network_config = network_connection
+        let mut network_config = network_connection
             .get(&scope.network_name)
             .wrap_err("Failed to get network config!")?
             .clone();
+        if previous_context.transaction_bundle.is_some() {
+            // The bundle is sent with `broadcast-bundle` as a transaction, not to the relayer
+            network_config.meta_transaction_relayer_url = None;
+        }
         let prepopulated_transaction = (previous_context
             .get_prepopulated_transaction_after_getting_network_callback)(
             &network_config
@@ -51,6 +65,7 @@ impl NetworkForTransactionArgsContext {
                 .on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: previous_context
                 .on_after_sending_transaction_callback,
+            transaction_bundle: previous_context.transaction_bundle,
         })
     }
 }
@@ -64,6 +79,7 @@ impl From<NetworkForTransactionArgsContext> for crate::commands::TransactionCont
             on_before_signing_callback: item.on_before_signing_callback,
             on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: item.on_after_sending_transaction_callback,
+            transaction_bundle: item.transaction_bundle,
         }
     }
 }
@@ -142,6 +158,9 @@ impl NetworkForTransactionArgs {
     fn input_network_name(
         context: &crate::commands::ActionContext,
     ) -> color_eyre::eyre::Result<Option<String>> {
+        if let Some(transaction_bundle) = &context.transaction_bundle {
+            return Ok(Some(transaction_bundle.network_name.clone()));
+        }
         crate::common::input_network_name(
             &context.global_context.config,
             &context.interacting_with_account_ids,
@@ -163,3 +182,123 @@ impl NetworkForTransactionArgs {
         self.transaction_signature_options.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn action_context(
+        actions: Vec<near_primitives::transaction::Action>,
+        transaction_bundle_network_name: Option<&str>,
+    ) -> crate::commands::ActionContext {
+        let mut config = crate::config::Config::default();
+        for (_, network_config) in config.network_connection.iter_mut() {
+            network_config.meta_transaction_relayer_url =
+                Some("https://relayer.example.com/".parse().unwrap());
+        }
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: "alice.testnet".parse().unwrap(),
+            public_key: near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "")
+                .public_key(),
+            nonce: 42,
+            receiver_id: "bob.testnet".parse().unwrap(),
+            block_hash: near_primitives::hash::CryptoHash::hash_bytes(b"block"),
+            actions,
+        };
+        crate::commands::ActionContext {
+            global_context: crate::GlobalContext {
+                config,
+                offline: false,
+                output_format: crate::common::OutputFormat::Plaintext,
+            },
+            interacting_with_account_ids: vec![],
+            get_prepopulated_transaction_after_getting_network_callback: std::sync::Arc::new({
+                let unsigned_transaction = unsigned_transaction.clone();
+                move |_network_config| {
+                    Ok(crate::commands::PrepopulatedTransaction::from(
+                        unsigned_transaction.clone(),
+                    ))
+                }
+            }),
+            on_before_signing_callback: std::sync::Arc::new(|_, _| Ok(())),
+            on_before_sending_transaction_callback: std::sync::Arc::new(|_, _| Ok(String::new())),
+            on_after_sending_transaction_callback: std::sync::Arc::new(|_, _| Ok(())),
+            transaction_bundle: transaction_bundle_network_name.map(|network_name| {
+                crate::types::transaction_bundle::TransactionBundle {
+                    version: crate::types::transaction_bundle::TRANSACTION_BUNDLE_VERSION,
+                    network_name: network_name.to_string(),
+                    block_height: 100,
+                    expires_at_block_height: 86500,
+                    unsigned_transaction: unsigned_transaction.into(),
+                    signed_transaction: None,
+                }
+            }),
+        }
+    }
+
+    fn transfer() -> Vec<near_primitives::transaction::Action> {
+        vec![near_primitives::transaction::Action::Transfer(
+            near_primitives::transaction::TransferAction { deposit: 1 },
+        )]
+    }
+
+    fn network_scope(
+        network_name: &str,
+    ) -> <NetworkForTransactionArgs as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope{
+        InteractiveClapContextScopeForNetworkForTransactionArgs {
+            network_name: network_name.to_string(),
+        }
+    }
+
+    #[test]
+    fn transaction_bundle_selects_the_network_and_skips_the_relayer() {
+        let context = NetworkForTransactionArgsContext::from_previous_context(
+            action_context(transfer(), None),
+            &network_scope("testnet"),
+        )
+        .unwrap();
+        assert!(context
+            .network_config
+            .meta_transaction_relayer_url
+            .is_some());
+
+        let context = action_context(transfer(), Some("testnet"));
+        assert_eq!(
+            NetworkForTransactionArgs::input_network_name(&context).unwrap(),
+            Some("testnet".to_string())
+        );
+        let transaction_context: crate::commands::TransactionContext =
+            NetworkForTransactionArgsContext::from_previous_context(
+                context.clone(),
+                &network_scope("testnet"),
+            )
+            .unwrap()
+            .into();
+        assert!(transaction_context
+            .network_config
+            .meta_transaction_relayer_url
+            .is_none());
+        assert!(transaction_context.transaction_bundle.is_some());
+
+        assert!(NetworkForTransactionArgsContext::from_previous_context(
+            context,
+            &network_scope("mainnet")
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn transaction_without_actions_is_cancelled() {
+        let cli_network_args = CliNetworkForTransactionArgs {
+            network_name: Some("testnet".to_string()),
+            transaction_signature_options: None,
+        };
+        assert!(matches!(
+            <NetworkForTransactionArgs as interactive_clap::FromCli>::from_cli(
+                Some(cli_network_args),
+                action_context(vec![], Some("testnet")),
+            ),
+            interactive_clap::ResultFromCli::Cancel(_)
+        ));
+    }
+}
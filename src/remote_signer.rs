@@ -0,0 +1,430 @@
+//! Remote signer protocol, used to sign transactions with keys that never leave an external
+//! signing service (e.g. a daemon in front of an HSM).
+//!
+//! The CLI sends `POST <remote_signer_url>` with a JSON [`SignRequest`] that carries the
+//! borsh-serialized `Transaction` or `DelegateAction`, so the signer can inspect what it signs,
+//! and expects a JSON [`SignResponse`] back. The endpoint is either `http(s)://...` or
+//! `unix://<socket path>` (plain HTTP/1.1 over a Unix domain socket). If an API key is configured,
+//! it is sent as `Authorization: Bearer <api key>`.
+//!
+//! [`ReferenceSigner`] is a minimal implementation of the signing side that keeps the keys in
+//! memory. It is meant for tests and as a starting point for real signing services.
+use std::io::{BufRead, Read, Write};
+
+use color_eyre::eyre::{ContextCompat, WrapErr};
+use near_primitives::borsh;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadKind {
+    Transaction,
+    DelegateAction,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SignRequest {
+    pub signer_id: near_primitives::types::AccountId,
+    pub public_key: near_crypto::PublicKey,
+    pub payload_kind: PayloadKind,
+    /// base64-encoded borsh-serialized `Transaction` or `DelegateAction`
+    pub payload: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct SignResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<near_crypto::Signature>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl SignRequest {
+    pub fn for_transaction(
+        transaction: &near_primitives::transaction::Transaction,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self {
+            signer_id: transaction.signer_id.clone(),
+            public_key: transaction.public_key.clone(),
+            payload_kind: PayloadKind::Transaction,
+            payload: near_primitives::serialize::to_base64(
+                &borsh::to_vec(transaction)
+                    .wrap_err("Transaction is not expected to fail on serialization")?,
+            ),
+        })
+    }
+
+    pub fn for_delegate_action(
+        delegate_action: &near_primitives::action::delegate::DelegateAction,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self {
+            signer_id: delegate_action.sender_id.clone(),
+            public_key: delegate_action.public_key.clone(),
+            payload_kind: PayloadKind::DelegateAction,
+            payload: near_primitives::serialize::to_base64(
+                &borsh::to_vec(delegate_action)
+                    .wrap_err("Delegate action is not expected to fail on serialization")?,
+            ),
+        })
+    }
+
+    /// Returns the hash that has to be signed, after checking that the payload belongs to the
+    /// declared signer and public key.
+    pub fn hash_to_sign(&self) -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+        use near_primitives::signable_message::{SignableMessage, SignableMessageType};
+
+        let payload = near_primitives::serialize::from_base64(&self.payload)
+            .map_err(|err| color_eyre::eyre::eyre!("The payload is not valid base64: {err}"))?;
+        let (signer_id, public_key, hash) = match self.payload_kind {
+            PayloadKind::Transaction => {
+                let transaction: near_primitives::transaction::Transaction =
+                    borsh::from_slice(&payload)
+                        .wrap_err("The payload is not a borsh-serialized transaction")?;
+                let hash = transaction.get_hash_and_size().0;
+                (transaction.signer_id, transaction.public_key, hash)
+            }
+            PayloadKind::DelegateAction => {
+                let delegate_action: near_primitives::action::delegate::DelegateAction =
+                    borsh::from_slice(&payload)
+                        .wrap_err("The payload is not a borsh-serialized delegate action")?;
+                let signable =
+                    SignableMessage::new(&delegate_action, SignableMessageType::DelegateAction);
+                let hash = near_primitives::hash::hash(
+                    &borsh::to_vec(&signable)
+                        .wrap_err("Delegate action is not expected to fail on serialization")?,
+                );
+                (delegate_action.sender_id, delegate_action.public_key, hash)
+            }
+        };
+        if signer_id != self.signer_id || public_key != self.public_key {
+            color_eyre::eyre::bail!(
+                "The payload is signed by <{signer_id}> with the key {public_key}, but the request declares <{}> with the key {}",
+                self.signer_id,
+                self.public_key
+            );
+        }
+        Ok(hash)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    url: url::Url,
+    api_key: Option<crate::types::api_key::ApiKey>,
+}
+
+impl RemoteSigner {
+    pub fn new(url: url::Url, api_key: Option<crate::types::api_key::ApiKey>) -> Self {
+        Self { url, api_key }
+    }
+
+    pub fn from_network_config(
+        network_config: &crate::config::NetworkConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        let url = network_config.remote_signer_url.clone().wrap_err_with(|| {
+            format!(
+                "There is no remote signer configured for network <{}>. Set `remote_signer_url` (and optionally `remote_signer_api_key`) of the network connection in the config file.",
+                network_config.network_name
+            )
+        })?;
+        Ok(Self::new(url, network_config.remote_signer_api_key.clone()))
+    }
+
+    pub fn url(&self) -> &url::Url {
+        &self.url
+    }
+
+    /// Sends the request to the remote signer and checks that the returned signature is valid
+    /// for the requested public key.
+    pub fn sign(&self, request: &SignRequest) -> color_eyre::eyre::Result<near_crypto::Signature> {
+        let hash = request.hash_to_sign()?;
+        let body = serde_json::to_vec(request)?;
+        let (status, response_body) = match self.url.scheme() {
+            "http" | "https" => self.post_http(body)?,
+            "unix" => self.post_unix_socket(&body)?,
+            scheme => color_eyre::eyre::bail!(
+                "Unsupported remote signer URL scheme <{scheme}> (expected http, https or unix)"
+            ),
+        };
+        let response: SignResponse =
+            serde_json::from_slice(&response_body).wrap_err_with(|| {
+                format!(
+                    "The remote signer responded with HTTP status {status} and an unexpected body: {}",
+                    String::from_utf8_lossy(&response_body)
+                )
+            })?;
+        if let Some(error) = response.error {
+            color_eyre::eyre::bail!("The remote signer refused to sign: {error}");
+        }
+        let signature = response.signature.wrap_err_with(|| {
+            format!("The remote signer responded with HTTP status {status} and no signature")
+        })?;
+        if !signature.verify(hash.as_ref(), &request.public_key) {
+            color_eyre::eyre::bail!(
+                "The remote signer returned a signature that does not match the public key {}",
+                request.public_key
+            );
+        }
+        Ok(signature)
+    }
+
+    fn post_http(&self, body: Vec<u8>) -> color_eyre::eyre::Result<(u16, Vec<u8>)> {
+        let mut request = reqwest::blocking::Client::new()
+            .post(self.url.clone())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body);
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+        let response = request
+            .send()
+            .wrap_err_with(|| format!("Failed to connect to the remote signer <{}>", self.url))?;
+        let status = response.status().as_u16();
+        Ok((status, response.bytes()?.to_vec()))
+    }
+
+    #[cfg(unix)]
+    fn post_unix_socket(&self, body: &[u8]) -> color_eyre::eyre::Result<(u16, Vec<u8>)> {
+        let mut stream = std::os::unix::net::UnixStream::connect(self.url.path())
+            .wrap_err_with(|| format!("Failed to connect to the remote signer <{}>", self.url))?;
+        let mut request = format!(
+            "POST / HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+            body.len()
+        );
+        if let Some(api_key) = &self.api_key {
+            request.push_str(&format!("Authorization: Bearer {api_key}\r\n"));
+        }
+        request.push_str("\r\n");
+        stream.write_all(request.as_bytes())?;
+        stream.write_all(body)?;
+
+        let mut reader = std::io::BufReader::new(stream);
+        let mut status_line = String::new();
+        reader.read_line(&mut status_line)?;
+        let status = status_line
+            .split_whitespace()
+            .nth(1)
+            .and_then(|status| status.parse().ok())
+            .wrap_err_with(|| format!("Invalid response from the remote signer: {status_line}"))?;
+        let headers = read_http_headers(&mut reader)?;
+        let body = read_http_body(&mut reader, &headers)?;
+        Ok((status, body))
+    }
+
+    #[cfg(not(unix))]
+    fn post_unix_socket(&self, _body: &[u8]) -> color_eyre::eyre::Result<(u16, Vec<u8>)> {
+        color_eyre::eyre::bail!("Unix socket remote signers are not supported on this platform")
+    }
+}
+
+/// Reference implementation of the signing side of the protocol, with the keys kept in memory.
+#[derive(Debug, Clone, Default)]
+pub struct ReferenceSigner {
+    keys: std::collections::HashMap<
+        near_crypto::PublicKey,
+        (near_primitives::types::AccountId, near_crypto::SecretKey),
+    >,
+    api_key: Option<String>,
+}
+
+impl ReferenceSigner {
+    pub fn new(api_key: Option<String>) -> Self {
+        Self {
+            keys: Default::default(),
+            api_key,
+        }
+    }
+
+    pub fn add_key(
+        &mut self,
+        account_id: near_primitives::types::AccountId,
+        secret_key: near_crypto::SecretKey,
+    ) {
+        self.keys
+            .insert(secret_key.public_key(), (account_id, secret_key));
+    }
+
+    pub fn sign(&self, request: &SignRequest) -> color_eyre::eyre::Result<near_crypto::Signature> {
+        let (account_id, secret_key) = self
+            .keys
+            .get(&request.public_key)
+            .filter(|(account_id, _)| account_id == &request.signer_id)
+            .wrap_err_with(|| {
+                format!(
+                    "There is no key {} for <{}>",
+                    request.public_key, request.signer_id
+                )
+            })?;
+        let hash = request.hash_to_sign()?;
+        eprintln!(
+            "Signing {:?} of <{account_id}> with the key {}",
+            request.payload_kind, request.public_key
+        );
+        Ok(secret_key.sign(hash.as_ref()))
+    }
+
+    pub fn serve_tcp(&self, listener: std::net::TcpListener) -> color_eyre::eyre::Result<()> {
+        for stream in listener.incoming() {
+            if let Err(err) = self.serve_connection(stream?) {
+                eprintln!("Failed to serve a connection: {err:#}");
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    pub fn serve_unix(
+        &self,
+        listener: std::os::unix::net::UnixListener,
+    ) -> color_eyre::eyre::Result<()> {
+        for stream in listener.incoming() {
+            if let Err(err) = self.serve_connection(stream?) {
+                eprintln!("Failed to serve a connection: {err:#}");
+            }
+        }
+        Ok(())
+    }
+
+    /// Serves a single HTTP/1.1 request on the stream.
+    pub fn serve_connection<S: Read + Write>(&self, stream: S) -> color_eyre::eyre::Result<()> {
+        let mut reader = std::io::BufReader::new(stream);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        let headers = read_http_headers(&mut reader)?;
+        let body = read_http_body(&mut reader, &headers)?;
+
+        let (status, response) = if !request_line.starts_with("POST ") {
+            (
+                "405 Method Not Allowed",
+                error_response("Only POST is supported"),
+            )
+        } else if self.api_key.as_ref().is_some_and(|api_key| {
+            headers.get("authorization") != Some(&format!("Bearer {api_key}"))
+        }) {
+            ("401 Unauthorized", error_response("Invalid API key"))
+        } else {
+            match serde_json::from_slice::<SignRequest>(&body)
+                .wrap_err("Invalid sign request")
+                .and_then(|request| self.sign(&request))
+            {
+                Ok(signature) => (
+                    "200 OK",
+                    SignResponse {
+                        signature: Some(signature),
+                        error: None,
+                    },
+                ),
+                Err(err) => ("400 Bad Request", error_response(&format!("{err:#}"))),
+            }
+        };
+        let response = serde_json::to_vec(&response)?;
+
+        let stream = reader.get_mut();
+        write!(
+            stream,
+            "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            response.len()
+        )?;
+        stream.write_all(&response)?;
+        stream.flush()?;
+        Ok(())
+    }
+}
+
+fn error_response(error: &str) -> SignResponse {
+    SignResponse {
+        signature: None,
+        error: Some(error.to_string()),
+    }
+}
+
+/// Reads the HTTP headers (with lowercase names) up to the empty line.
+fn read_http_headers(
+    reader: &mut impl BufRead,
+) -> color_eyre::eyre::Result<std::collections::HashMap<String, String>> {
+    let mut headers = std::collections::HashMap::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+    Ok(headers)
+}
+
+fn read_http_body(
+    reader: &mut impl BufRead,
+    headers: &std::collections::HashMap<String, String>,
+) -> color_eyre::eyre::Result<Vec<u8>> {
+    let mut body = vec![];
+    if let Some(content_length) = headers.get("content-length") {
+        body.resize(
+            content_length
+                .parse()
+                .wrap_err("Invalid Content-Length header")?,
+            0,
+        );
+        reader.read_exact(&mut body)?;
+    } else {
+        reader.read_to_end(&mut body)?;
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_with_reference_signer() {
+        let account_id: near_primitives::types::AccountId = "alice.testnet".parse().unwrap();
+        let secret_key = near_crypto::SecretKey::from_random(near_crypto::KeyType::ED25519);
+        let api_key = "secret-api-key";
+        let mut reference_signer = ReferenceSigner::new(Some(api_key.to_string()));
+        reference_signer.add_key(account_id.clone(), secret_key.clone());
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url: url::Url = format!("http://{}/", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        std::thread::spawn(move || reference_signer.serve_tcp(listener));
+
+        let transaction = near_primitives::transaction::Transaction {
+            signer_id: account_id.clone(),
+            public_key: secret_key.public_key(),
+            nonce: 1,
+            receiver_id: "bob.testnet".parse().unwrap(),
+            block_hash: Default::default(),
+            actions: vec![near_primitives::transaction::Action::Transfer(
+                near_primitives::transaction::TransferAction { deposit: 1 },
+            )],
+        };
+        let request = SignRequest::for_transaction(&transaction).unwrap();
+
+        let signature = RemoteSigner::new(url.clone(), Some(api_key.parse().unwrap()))
+            .sign(&request)
+            .unwrap();
+        assert!(signature.verify(
+            transaction.get_hash_and_size().0.as_ref(),
+            &secret_key.public_key()
+        ));
+
+        let err = RemoteSigner::new(url.clone(), None)
+            .sign(&request)
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid API key"));
+
+        let mut foreign_transaction = transaction;
+        foreign_transaction.signer_id = "mallory.testnet".parse().unwrap();
+        let err = RemoteSigner::new(url, Some(api_key.parse().unwrap()))
+            .sign(&SignRequest::for_transaction(&foreign_transaction).unwrap())
+            .unwrap_err();
+        assert!(err.to_string().contains("There is no key"));
+    }
+}
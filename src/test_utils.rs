@@ -0,0 +1,81 @@
+//! Helpers shared by the unit tests.
+use std::io::{BufRead, Read, Write};
+
+/// A request received by [`serve_http`].
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    /// The path with the query
+    pub target: String,
+    /// The value of the `Authorization` header
+    pub authorization: Option<String>,
+    pub body: Vec<u8>,
+}
+
+/// A stand-in HTTP server answering every request with the JSON returned by the handler.
+pub fn serve_http(
+    handler: impl Fn(&HttpRequest) -> serde_json::Value + Send + 'static,
+) -> std::net::SocketAddr {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut reader = std::io::BufReader::new(stream.unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut request = HttpRequest {
+                target: request_line.split(' ').nth(1).unwrap().to_string(),
+                authorization: None,
+                body: vec![],
+            };
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 || line.trim_end().is_empty() {
+                    break;
+                }
+                let (name, value) = line.split_once(':').unwrap();
+                match name.to_lowercase().as_str() {
+                    "content-length" => content_length = value.trim().parse().unwrap(),
+                    "authorization" => request.authorization = Some(value.trim().to_string()),
+                    _ => {}
+                }
+            }
+            request.body = vec![0; content_length];
+            reader.read_exact(&mut request.body).unwrap();
+            let response = serde_json::to_vec(&handler(&request)).unwrap();
+            let stream = reader.get_mut();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                response.len()
+            )
+            .unwrap();
+            stream.write_all(&response).unwrap();
+        }
+    });
+    address
+}
+
+/// A stand-in JSON RPC server answering every request with the result of the handler for its
+/// method and params.
+pub fn serve_json_rpc(
+    handler: impl Fn(&str, &serde_json::Value) -> serde_json::Value + Send + 'static,
+) -> url::Url {
+    let address = serve_http(move |request| {
+        let request: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": request["id"],
+            "result": handler(request["method"].as_str().unwrap(), &request["params"]),
+        })
+    });
+    format!("http://{address}/").parse().unwrap()
+}
+
+/// The testnet connection of the default config with the RPC server replaced.
+pub fn network_config(rpc_url: url::Url) -> crate::config::NetworkConfig {
+    crate::config::NetworkConfig {
+        rpc_url,
+        ..crate::config::Config::default().network_connection["testnet"].clone()
+    }
+}
@@ -1,7 +1,11 @@
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(input_context = super::SubmitContext)]
 #[interactive_clap(output_context = DisplayContext)]
-pub struct Display;
+pub struct Display {
+    #[interactive_clap(long)]
+    /// Print a QR code as well (e.g. to take the signed transaction from an air-gapped machine)
+    qr_code: bool,
+}
 
 #[derive(Debug, Clone)]
 pub struct DisplayContext;
@@ -9,7 +13,7 @@ pub struct DisplayContext;
 impl DisplayContext {
     pub fn from_previous_context(
         previous_context: super::SubmitContext,
-        _scope: &<Display as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+        scope: &<Display as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         let storage_message = (previous_context.on_before_sending_transaction_callback)(
             &previous_context.signed_transaction_or_signed_delegate_action,
@@ -17,31 +21,109 @@ impl DisplayContext {
         )
         .map_err(color_eyre::Report::msg)?;
 
-        match previous_context.signed_transaction_or_signed_delegate_action {
-            super::SignedTransactionOrSignedDelegateAction::SignedTransaction(
-                signed_transaction,
+        match (
+            previous_context.signed_transaction_or_signed_delegate_action,
+            previous_context.transaction_bundle,
+        ) {
+            (
+                super::SignedTransactionOrSignedDelegateAction::SignedTransaction(
+                    signed_transaction,
+                ),
+                Some(transaction_bundle),
             ) => {
+                let transaction_bundle =
+                    transaction_bundle.with_signed_transaction(signed_transaction)?;
+                if previous_context.global_context.output_format.is_json() {
+                    crate::common::print_json_output(
+                        "transaction_bundle",
+                        serde_json::to_value(&transaction_bundle)?,
+                    )?;
+                }
+                let transaction_bundle_payload = transaction_bundle.to_payload();
+                eprintln!("\nSigned transaction bundle:\n{transaction_bundle_payload}\n");
+                if scope.qr_code {
+                    eprintln!(
+                        "{}",
+                        crate::common::render_qr_code(&transaction_bundle_payload)?
+                    );
+                }
                 eprintln!(
-                    "\nSigned transaction (serialized as base64):\n{}\n",
+                    "This signed transaction bundle is ready to be sent to the network <{}> from an online machine:\n$ {} transaction broadcast-bundle '<signed transaction bundle>'\n",
+                    transaction_bundle.network_name,
+                    crate::common::get_near_exec_path()
+                );
+                eprintln!("{storage_message}");
+            }
+            (
+                super::SignedTransactionOrSignedDelegateAction::SignedTransaction(
+                    signed_transaction,
+                ),
+                None,
+            ) => {
+                let signed_transaction_as_base64 =
                     crate::types::signed_transaction::SignedTransactionAsBase64::from(
-                        signed_transaction
-                    )
+                        signed_transaction.clone(),
+                    );
+                if previous_context.global_context.output_format.is_json() {
+                    crate::common::print_json_output(
+                        "signed_transaction",
+                        serde_json::json!({
+                            "transaction_hash": signed_transaction.get_hash().to_string(),
+                            "signed_transaction_base64": signed_transaction_as_base64.to_string(),
+                        }),
+                    )?;
+                }
+                eprintln!(
+                    "\nSigned transaction (serialized as base64):\n{}\n",
+                    signed_transaction_as_base64
                 );
+                if scope.qr_code {
+                    eprintln!(
+                        "{}",
+                        crate::common::render_qr_code(&signed_transaction_as_base64.to_string())?
+                    );
+                }
                 eprintln!(
                     "This base64-encoded signed transaction is ready to be sent to the network. You can call RPC server directly, or use a helper command on near CLI:\n$ {} transaction send-signed-transaction\n",
                     crate::common::get_near_exec_path()
                 );
                 eprintln!("{storage_message}");
             }
-            super::SignedTransactionOrSignedDelegateAction::SignedDelegateAction(
-                signed_delegate_action,
+            (super::SignedTransactionOrSignedDelegateAction::SignedDelegateAction(_), Some(_)) => {
+                color_eyre::eyre::bail!(
+                    "A transaction bundle can only be signed as a transaction, not as a delegate action (meta-transaction)"
+                );
+            }
+            (
+                super::SignedTransactionOrSignedDelegateAction::SignedDelegateAction(
+                    signed_delegate_action,
+                ),
+                None,
             ) => {
+                let signed_delegate_action_as_base64 =
+                    crate::types::signed_delegate_action::SignedDelegateActionAsBase64::from(
+                        signed_delegate_action,
+                    );
+                if previous_context.global_context.output_format.is_json() {
+                    crate::common::print_json_output(
+                        "signed_delegate_action",
+                        serde_json::json!({
+                            "signed_delegate_action_base64": signed_delegate_action_as_base64.to_string(),
+                        }),
+                    )?;
+                }
                 eprintln!(
                     "\nSigned delegate action (serialized as base64):\n{}\n",
-                    crate::types::signed_delegate_action::SignedDelegateActionAsBase64::from(
-                        signed_delegate_action
-                    )
+                    signed_delegate_action_as_base64
                 );
+                if scope.qr_code {
+                    eprintln!(
+                        "{}",
+                        crate::common::render_qr_code(
+                            &signed_delegate_action_as_base64.to_string()
+                        )?
+                    );
+                }
                 eprintln!(
                     "This base64-encoded signed delegate action is ready to be sent to the meta-transaction relayer. There is a helper command on near CLI that can do that:\n$ {} transaction send-meta-transaction\n",
                     crate::common::get_near_exec_path()
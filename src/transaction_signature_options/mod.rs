@@ -6,12 +6,15 @@ pub mod save_to_file;
 pub mod send;
 pub mod sign_later;
 pub mod sign_with_access_key_file;
+pub mod sign_with_encrypted_keystore;
 pub mod sign_with_keychain;
 #[cfg(feature = "ledger")]
 pub mod sign_with_ledger;
 pub mod sign_with_legacy_keychain;
 pub mod sign_with_private_key;
+pub mod sign_with_remote_signer;
 pub mod sign_with_seed_phrase;
+pub mod simulate;
 
 pub const META_TRANSACTION_VALID_FOR_DEFAULT: u64 = 1000;
 
@@ -30,12 +33,22 @@ pub enum SignWith {
     ))]
     /// Sign the transaction with a key saved in legacy keychain (compatible with the old near CLI)
     SignWithLegacyKeychain(self::sign_with_legacy_keychain::SignLegacyKeychain),
+    #[strum_discriminants(strum(
+        message = "sign-with-encrypted-keystore     - Sign the transaction with a key saved in the password-encrypted keystore"
+    ))]
+    /// Sign the transaction with a key saved in the password-encrypted keystore
+    SignWithEncryptedKeystore(self::sign_with_encrypted_keystore::SignEncryptedKeystore),
     #[cfg(feature = "ledger")]
     #[strum_discriminants(strum(
         message = "sign-with-ledger                 - Sign the transaction with Ledger Nano device"
     ))]
     /// Sign the transaction with Ledger Nano device
     SignWithLedger(self::sign_with_ledger::SignLedger),
+    #[strum_discriminants(strum(
+        message = "sign-with-remote-signer          - Sign the transaction with a remote signing service (e.g. an HSM bridge)"
+    ))]
+    /// Sign the transaction with a remote signing service (e.g. an HSM bridge)
+    SignWithRemoteSigner(self::sign_with_remote_signer::SignRemoteSigner),
     #[strum_discriminants(strum(
         message = "sign-with-plaintext-private-key  - Sign the transaction with a plaintext private key"
     ))]
@@ -78,6 +91,11 @@ pub enum Submit {
     ))]
     /// Print the signed transaction to terminal (if you want to send it later)
     Display(self::display::Display),
+    #[strum_discriminants(strum(
+        message = "simulate         - Simulate the transaction on a sandbox node with the current network state (nothing is sent to the network)"
+    ))]
+    /// Simulate the transaction on a sandbox node with the current network state (nothing is sent to the network)
+    Simulate(self::simulate::Simulate),
 }
 
 #[derive(Debug, Deserialize)]
@@ -107,6 +125,7 @@ pub struct SubmitContext {
     pub signed_transaction_or_signed_delegate_action: SignedTransactionOrSignedDelegateAction,
     pub on_before_sending_transaction_callback: OnBeforeSendingTransactionCallback,
     pub on_after_sending_transaction_callback: OnAfterSendingTransactionCallback,
+    pub transaction_bundle: Option<crate::types::transaction_bundle::TransactionBundle>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -141,34 +160,49 @@ pub fn get_signed_delegate_action(
 ) -> near_primitives::action::delegate::SignedDelegateAction {
     use near_primitives::signable_message::{SignableMessage, SignableMessageType};
 
+    let signer_id = unsigned_transaction.signer_id.clone();
+    let delegate_action = get_delegate_action(unsigned_transaction, max_block_height);
+
+    // create a new signature here signing the delegate action + discriminant
+    let signable = SignableMessage::new(&delegate_action, SignableMessageType::DelegateAction);
+    let signer = near_crypto::InMemorySigner::from_secret_key(signer_id, private_key);
+    let signature = signable.sign(&signer);
+
+    print_signed_delegate_action_info(public_key, &signature, max_block_height);
+
+    near_primitives::action::delegate::SignedDelegateAction {
+        delegate_action,
+        signature,
+    }
+}
+
+pub fn get_delegate_action(
+    unsigned_transaction: near_primitives::transaction::Transaction,
+    max_block_height: u64,
+) -> near_primitives::action::delegate::DelegateAction {
     let actions = unsigned_transaction
         .actions
         .into_iter()
         .map(near_primitives::action::delegate::NonDelegateAction::try_from)
         .collect::<Result<_, _>>()
         .expect("Internal error: can not convert the action to non delegate action (delegate action can not be delegated again).");
-    let delegate_action = near_primitives::action::delegate::DelegateAction {
-        sender_id: unsigned_transaction.signer_id.clone(),
+    near_primitives::action::delegate::DelegateAction {
+        sender_id: unsigned_transaction.signer_id,
         receiver_id: unsigned_transaction.receiver_id,
         actions,
         nonce: unsigned_transaction.nonce,
         max_block_height,
         public_key: unsigned_transaction.public_key,
-    };
-
-    // create a new signature here signing the delegate action + discriminant
-    let signable = SignableMessage::new(&delegate_action, SignableMessageType::DelegateAction);
-    let signer =
-        near_crypto::InMemorySigner::from_secret_key(unsigned_transaction.signer_id, private_key);
-    let signature = signable.sign(&signer);
+    }
+}
 
+pub fn print_signed_delegate_action_info(
+    public_key: &near_crypto::PublicKey,
+    signature: &near_crypto::Signature,
+    max_block_height: u64,
+) {
     eprintln!("\nYour delegating action was signed successfully.");
     eprintln!("Note that the signed transaction is valid until block {max_block_height}. You can change the validity of a transaction by setting a flag in the command: --meta-transaction-valid-for 2000");
     eprintln!("Public key: {}", public_key);
     eprintln!("Signature: {}", signature);
-
-    near_primitives::action::delegate::SignedDelegateAction {
-        delegate_action,
-        signature,
-    }
 }
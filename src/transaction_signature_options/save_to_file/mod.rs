@@ -28,9 +28,36 @@ impl SaveToFileContext {
         )
         .map_err(color_eyre::Report::msg)?;
 
-        match previous_context.signed_transaction_or_signed_delegate_action {
-            super::SignedTransactionOrSignedDelegateAction::SignedTransaction(
-                signed_transaction,
+        match (
+            previous_context.signed_transaction_or_signed_delegate_action,
+            previous_context.transaction_bundle,
+        ) {
+            (
+                super::SignedTransactionOrSignedDelegateAction::SignedTransaction(
+                    signed_transaction,
+                ),
+                Some(transaction_bundle),
+            ) => {
+                transaction_bundle
+                    .with_signed_transaction(signed_transaction)?
+                    .save_to_file(&file_path)?;
+                eprintln!(
+                    "\nThe file {:?} was created successfully. It has a signed transaction bundle.",
+                    &file_path
+                );
+
+                eprintln!(
+                    "Take this file to an online machine and send the transaction to the network with:\n$ {} transaction broadcast-bundle {:?}\n",
+                    crate::common::get_near_exec_path(),
+                    &file_path
+                );
+                eprintln!("{storage_message}");
+            }
+            (
+                super::SignedTransactionOrSignedDelegateAction::SignedTransaction(
+                    signed_transaction,
+                ),
+                None,
             ) => {
                 let signed_transaction_as_base64 =
                     crate::types::signed_transaction::SignedTransactionAsBase64::from(
@@ -53,8 +80,16 @@ impl SaveToFileContext {
                 );
                 eprintln!("{storage_message}");
             }
-            super::SignedTransactionOrSignedDelegateAction::SignedDelegateAction(
-                signed_delegate_action,
+            (super::SignedTransactionOrSignedDelegateAction::SignedDelegateAction(_), Some(_)) => {
+                color_eyre::eyre::bail!(
+                    "A transaction bundle can only be signed as a transaction, not as a delegate action (meta-transaction)"
+                );
+            }
+            (
+                super::SignedTransactionOrSignedDelegateAction::SignedDelegateAction(
+                    signed_delegate_action,
+                ),
+                None,
             ) => {
                 let signed_delegate_action_as_base64 =
                     crate::types::signed_delegate_action::SignedDelegateActionAsBase64::from(
@@ -35,6 +35,7 @@ impl SendContext {
                 crate::common::print_transaction_status(
                     &transaction_info,
                     &previous_context.network_config,
+                    &previous_context.global_context.output_format,
                 )?;
 
                 (previous_context.on_after_sending_transaction_callback)(
@@ -53,7 +54,17 @@ impl SendContext {
                         .expect("Internal error: Meta-transaction relayer URL must be Some() at this point"),
                 ){
                     Ok(relayer_response) => {
-                        if relayer_response.status().is_success() {
+                        if previous_context.global_context.output_format.is_json() {
+                            let status = relayer_response.status();
+                            crate::common::print_json_output(
+                                "relayer_response",
+                                serde_json::json!({
+                                    "status_code": status.as_u16(),
+                                    "success": status.is_success(),
+                                    "response_text": relayer_response.text().map_err(color_eyre::Report::msg)?,
+                                }),
+                            )?;
+                        } else if relayer_response.status().is_success() {
                             let response_text = relayer_response.text().map_err(color_eyre::Report::msg)?;
                             eprintln!("\nRelayer Response text: {}", response_text);
                         } else {
@@ -35,6 +35,7 @@ pub struct SignAccessKeyFileContext {
         crate::transaction_signature_options::OnBeforeSendingTransactionCallback,
     on_after_sending_transaction_callback:
         crate::transaction_signature_options::OnAfterSendingTransactionCallback,
+    transaction_bundle: Option<crate::types::transaction_bundle::TransactionBundle>,
 }
 
 impl SignAccessKeyFileContext {
@@ -53,7 +54,11 @@ impl SignAccessKeyFileContext {
         let account_json: super::AccountKeyPair = serde_json::from_str(&data)
             .wrap_err_with(|| format!("Error reading data from file: {:?}", &scope.file_path))?;
 
-        let (nonce, block_hash, block_height) = if previous_context.global_context.offline {
+        let (nonce, block_hash, block_height) = if let Some(transaction_bundle) =
+            &previous_context.transaction_bundle
+        {
+            transaction_bundle.get_nonce_and_block(&account_json.public_key)?
+        } else if previous_context.global_context.offline {
             (
                 scope
                     .nonce
@@ -125,6 +130,7 @@ impl SignAccessKeyFileContext {
                     .on_before_sending_transaction_callback,
                 on_after_sending_transaction_callback: previous_context
                     .on_after_sending_transaction_callback,
+                transaction_bundle: previous_context.transaction_bundle,
             });
         }
 
@@ -145,6 +151,7 @@ impl SignAccessKeyFileContext {
                 .on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: previous_context
                 .on_after_sending_transaction_callback,
+            transaction_bundle: previous_context.transaction_bundle,
         })
     }
 }
@@ -158,6 +165,7 @@ impl From<SignAccessKeyFileContext> for super::SubmitContext {
                 .signed_transaction_or_signed_delegate_action,
             on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: item.on_after_sending_transaction_callback,
+            transaction_bundle: item.transaction_bundle,
         }
     }
 }
@@ -166,7 +174,7 @@ impl SignAccessKeyFile {
     fn input_nonce(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<u64>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<u64>::new("Enter a nonce for the access key:").prompt()?,
             ));
@@ -177,7 +185,7 @@ impl SignAccessKeyFile {
     fn input_block_hash(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::crypto_hash::CryptoHash>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<crate::types::crypto_hash::CryptoHash>::new(
                     "Enter recent block hash:",
@@ -191,7 +199,7 @@ impl SignAccessKeyFile {
     fn input_block_height(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<near_primitives::types::BlockHeight>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<near_primitives::types::BlockHeight>::new(
                     "Enter recent block height:",
@@ -0,0 +1,287 @@
+use color_eyre::eyre::{ContextCompat, WrapErr};
+use inquire::{CustomType, Select};
+
+use crate::common::JsonRpcClientExt;
+use crate::common::RpcQueryResponseExt;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::commands::TransactionContext)]
+#[interactive_clap(output_context = SignEncryptedKeystoreContext)]
+pub struct SignEncryptedKeystore {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    signer_public_key: Option<crate::types::public_key::PublicKey>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    nonce: Option<u64>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    block_hash: Option<crate::types::crypto_hash::CryptoHash>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    block_height: Option<near_primitives::types::BlockHeight>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    meta_transaction_valid_for: Option<u64>,
+    #[interactive_clap(subcommand)]
+    submit: super::Submit,
+}
+
+#[derive(Clone)]
+pub struct SignEncryptedKeystoreContext {
+    network_config: crate::config::NetworkConfig,
+    global_context: crate::GlobalContext,
+    signed_transaction_or_signed_delegate_action: super::SignedTransactionOrSignedDelegateAction,
+    on_before_sending_transaction_callback:
+        crate::transaction_signature_options::OnBeforeSendingTransactionCallback,
+    on_after_sending_transaction_callback:
+        crate::transaction_signature_options::OnAfterSendingTransactionCallback,
+    transaction_bundle: Option<crate::types::transaction_bundle::TransactionBundle>,
+}
+
+impl SignEncryptedKeystoreContext {
+    #[tracing::instrument(
+        name = "Signing the transaction with a key saved in the encrypted keystore ...",
+        skip_all
+    )]
+    pub fn from_previous_context(
+        previous_context: crate::commands::TransactionContext,
+        scope: &<SignEncryptedKeystore as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let network_config = previous_context.network_config.clone();
+        let signer_id = previous_context.prepopulated_transaction.signer_id.clone();
+        let keystore = crate::encrypted_keystore::EncryptedKeystore::new(
+            &previous_context.global_context.config.credentials_home_dir,
+        );
+
+        let public_key: near_crypto::PublicKey = if previous_context.global_context.offline
+            || previous_context.transaction_bundle.is_some()
+        {
+            scope
+                .signer_public_key
+                .clone()
+                .wrap_err("Signer public key is required to sign a transaction in offline mode")?
+                .into()
+        } else {
+            let stored_public_keys =
+                keystore.public_keys(&network_config.network_name, &signer_id)?;
+            let access_key_list = network_config
+                .json_rpc_client()
+                .blocking_call_view_access_key_list(
+                    &signer_id,
+                    near_primitives::types::Finality::Final.into(),
+                )
+                .wrap_err_with(|| format!("Failed to fetch access key list for {}", signer_id))?
+                .access_key_list_view()?;
+            access_key_list
+                .keys
+                .into_iter()
+                .filter(|key| {
+                    matches!(
+                        key.access_key.permission,
+                        near_primitives::views::AccessKeyPermissionView::FullAccess
+                    )
+                })
+                .map(|key| key.public_key)
+                .find(|public_key| stored_public_keys.contains(public_key))
+                .wrap_err_with(|| {
+                    format!(
+                        "There are no full access keys of <{}> on network <{}> found in the encrypted keystore. Save or migrate the key to the encrypted keystore before signing transactions with it.",
+                        signer_id, network_config.network_name
+                    )
+                })?
+        };
+
+        let key_pair_properties_buf =
+            keystore.load(&network_config.network_name, &signer_id, &public_key)?;
+        let account_json: super::AccountKeyPair =
+            serde_json::from_str(&key_pair_properties_buf).wrap_err("Error reading data")?;
+
+        let (nonce, block_hash, block_height) = if let Some(transaction_bundle) =
+            &previous_context.transaction_bundle
+        {
+            transaction_bundle.get_nonce_and_block(&public_key)?
+        } else if previous_context.global_context.offline {
+            (
+                scope
+                    .nonce
+                    .wrap_err("Nonce is required to sign a transaction in offline mode")?,
+                scope
+                    .block_hash
+                    .wrap_err("Block Hash is required to sign a transaction in offline mode")?
+                    .0,
+                scope
+                    .block_height
+                    .wrap_err("Block Height is required to sign a transaction in offline mode")?,
+            )
+        } else {
+            let rpc_query_response = network_config
+                .json_rpc_client()
+                .blocking_call_view_access_key(
+                    &signer_id,
+                    &account_json.public_key,
+                    near_primitives::types::BlockReference::latest()
+                )
+                .wrap_err_with(||
+                    format!("Cannot sign a transaction due to an error while fetching the most recent nonce value on network <{}>", network_config.network_name)
+                )?;
+            (
+                rpc_query_response
+                    .access_key_view()
+                    .wrap_err("Error current_nonce")?
+                    .nonce
+                    + 1,
+                rpc_query_response.block_hash,
+                rpc_query_response.block_height,
+            )
+        };
+
+        let mut unsigned_transaction = near_primitives::transaction::Transaction {
+            public_key: account_json.public_key.clone(),
+            block_hash,
+            nonce,
+            signer_id,
+            receiver_id: previous_context.prepopulated_transaction.receiver_id,
+            actions: previous_context.prepopulated_transaction.actions,
+        };
+
+        (previous_context.on_before_signing_callback)(&mut unsigned_transaction, &network_config)?;
+
+        if network_config.meta_transaction_relayer_url.is_some() {
+            let max_block_height = block_height
+                + scope
+                    .meta_transaction_valid_for
+                    .unwrap_or(super::META_TRANSACTION_VALID_FOR_DEFAULT);
+
+            let signed_delegate_action = super::get_signed_delegate_action(
+                unsigned_transaction,
+                &account_json.public_key,
+                account_json.private_key,
+                max_block_height,
+            );
+
+            return Ok(Self {
+                network_config: previous_context.network_config,
+                global_context: previous_context.global_context,
+                signed_transaction_or_signed_delegate_action: signed_delegate_action.into(),
+                on_before_sending_transaction_callback: previous_context
+                    .on_before_sending_transaction_callback,
+                on_after_sending_transaction_callback: previous_context
+                    .on_after_sending_transaction_callback,
+                transaction_bundle: previous_context.transaction_bundle,
+            });
+        }
+
+        let signature = account_json
+            .private_key
+            .sign(unsigned_transaction.get_hash_and_size().0.as_ref());
+
+        let signed_transaction = near_primitives::transaction::SignedTransaction::new(
+            signature.clone(),
+            unsigned_transaction,
+        );
+
+        eprintln!("\nYour transaction was signed successfully.");
+        eprintln!("Public key: {}", account_json.public_key);
+        eprintln!("Signature: {}", signature);
+
+        Ok(Self {
+            network_config: previous_context.network_config,
+            global_context: previous_context.global_context,
+            signed_transaction_or_signed_delegate_action: signed_transaction.into(),
+            on_before_sending_transaction_callback: previous_context
+                .on_before_sending_transaction_callback,
+            on_after_sending_transaction_callback: previous_context
+                .on_after_sending_transaction_callback,
+            transaction_bundle: previous_context.transaction_bundle,
+        })
+    }
+}
+
+impl From<SignEncryptedKeystoreContext> for super::SubmitContext {
+    fn from(item: SignEncryptedKeystoreContext) -> Self {
+        Self {
+            network_config: item.network_config,
+            global_context: item.global_context,
+            signed_transaction_or_signed_delegate_action: item
+                .signed_transaction_or_signed_delegate_action,
+            on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
+            on_after_sending_transaction_callback: item.on_after_sending_transaction_callback,
+            transaction_bundle: item.transaction_bundle,
+        }
+    }
+}
+
+impl SignEncryptedKeystore {
+    fn input_signer_public_key(
+        context: &crate::commands::TransactionContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::public_key::PublicKey>> {
+        if let Some(transaction_bundle) = &context.transaction_bundle {
+            return Ok(Some(
+                transaction_bundle
+                    .unsigned_transaction
+                    .inner
+                    .public_key
+                    .clone()
+                    .into(),
+            ));
+        }
+        if context.global_context.offline {
+            let public_keys = crate::encrypted_keystore::EncryptedKeystore::new(
+                &context.global_context.config.credentials_home_dir,
+            )
+            .public_keys(
+                &context.network_config.network_name,
+                &context.prepopulated_transaction.signer_id,
+            )?;
+            if public_keys.is_empty() {
+                color_eyre::eyre::bail!(
+                    "There are no access keys of <{}> found in the encrypted keystore",
+                    context.prepopulated_transaction.signer_id
+                );
+            }
+            let selected_public_key = Select::new("Choose public_key:", public_keys).prompt()?;
+            return Ok(Some(selected_public_key.into()));
+        }
+        Ok(None)
+    }
+
+    fn input_nonce(
+        context: &crate::commands::TransactionContext,
+    ) -> color_eyre::eyre::Result<Option<u64>> {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
+            return Ok(Some(
+                CustomType::<u64>::new("Enter a nonce for the access key:").prompt()?,
+            ));
+        }
+        Ok(None)
+    }
+
+    fn input_block_hash(
+        context: &crate::commands::TransactionContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::crypto_hash::CryptoHash>> {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
+            return Ok(Some(
+                CustomType::<crate::types::crypto_hash::CryptoHash>::new(
+                    "Enter recent block hash:",
+                )
+                .prompt()?,
+            ));
+        }
+        Ok(None)
+    }
+
+    fn input_block_height(
+        context: &crate::commands::TransactionContext,
+    ) -> color_eyre::eyre::Result<Option<near_primitives::types::BlockHeight>> {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
+            return Ok(Some(
+                CustomType::<near_primitives::types::BlockHeight>::new(
+                    "Enter recent block height:",
+                )
+                .prompt()?,
+            ));
+        }
+        Ok(None)
+    }
+}
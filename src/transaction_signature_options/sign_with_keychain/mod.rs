@@ -37,6 +37,7 @@ pub struct SignKeychainContext {
         crate::transaction_signature_options::OnBeforeSendingTransactionCallback,
     on_after_sending_transaction_callback:
         crate::transaction_signature_options::OnAfterSendingTransactionCallback,
+    transaction_bundle: Option<crate::types::transaction_bundle::TransactionBundle>,
 }
 
 impl From<super::sign_with_legacy_keychain::SignLegacyKeychainContext> for SignKeychainContext {
@@ -48,6 +49,7 @@ impl From<super::sign_with_legacy_keychain::SignLegacyKeychainContext> for SignK
                 .signed_transaction_or_signed_delegate_action,
             on_before_sending_transaction_callback: value.on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: value.on_after_sending_transaction_callback,
+            transaction_bundle: value.transaction_bundle,
         }
     }
 }
@@ -69,7 +71,9 @@ impl SignKeychainContext {
             previous_context.prepopulated_transaction.signer_id.as_str()
         ));
 
-        let password = if previous_context.global_context.offline {
+        let account_json: super::AccountKeyPair = if previous_context.global_context.offline
+            || previous_context.transaction_bundle.is_some()
+        {
             let res = keyring::Entry::new(
                 &service_name,
                 &format!(
@@ -83,7 +87,7 @@ impl SignKeychainContext {
             .get_password();
 
             match res {
-                Ok(password) => password,
+                Ok(password) => serde_json::from_str(&password).wrap_err("Error reading data")?,
                 Err(err) => {
                     match matches!(err, keyring::Error::NoEntry) {
                         true => eprintln!("Warning: no access key found in keychain"),
@@ -95,44 +99,11 @@ impl SignKeychainContext {
                 }
             }
         } else {
-            let access_key_list = network_config
-                .json_rpc_client()
-                .blocking_call_view_access_key_list(
-                    &previous_context.prepopulated_transaction.signer_id,
-                    near_primitives::types::Finality::Final.into(),
-                )
-                .wrap_err_with(|| {
-                    format!(
-                        "Failed to fetch access key list for {}",
-                        previous_context.prepopulated_transaction.signer_id
-                    )
-                })?
-                .access_key_list_view()?;
-
-            let res = access_key_list
-                .keys
-                .into_iter()
-                .filter(|key| {
-                    matches!(
-                        key.access_key.permission,
-                        near_primitives::views::AccessKeyPermissionView::FullAccess
-                    )
-                })
-                .map(|key| key.public_key)
-                .find_map(|public_key| {
-                    let keyring = keyring::Entry::new(
-                        &service_name,
-                        &format!(
-                            "{}:{}",
-                            previous_context.prepopulated_transaction.signer_id, public_key
-                        ),
-                    )
-                    .ok()?;
-                    keyring.get_password().ok()
-                });
-
-            match res {
-                Some(password) => password,
+            match get_full_access_key_pair(
+                &network_config,
+                &previous_context.prepopulated_transaction.signer_id,
+            )? {
+                Some(account_json) => account_json,
                 None => {
                     // no access keys found, try the legacy keychain
                     warning_message("no access keys found in keychain, trying legacy keychain");
@@ -141,28 +112,49 @@ impl SignKeychainContext {
             }
         };
 
-        let account_json: super::AccountKeyPair =
-            serde_json::from_str(&password).wrap_err("Error reading data")?;
-
-        let rpc_query_response = network_config
-            .json_rpc_client()
-            .blocking_call_view_access_key(
-                &previous_context.prepopulated_transaction.signer_id,
-                &account_json.public_key,
-                near_primitives::types::BlockReference::latest(),
+        let (nonce, block_hash, block_height) = if let Some(transaction_bundle) =
+            &previous_context.transaction_bundle
+        {
+            transaction_bundle.get_nonce_and_block(&account_json.public_key)?
+        } else if previous_context.global_context.offline {
+            (
+                scope
+                    .nonce
+                    .wrap_err("Nonce is required to sign a transaction in offline mode")?,
+                scope
+                    .block_hash
+                    .wrap_err("Block Hash is required to sign a transaction in offline mode")?
+                    .0,
+                scope
+                    .block_height
+                    .wrap_err("Block Height is required to sign a transaction in offline mode")?,
+            )
+        } else {
+            let rpc_query_response = network_config
+                .json_rpc_client()
+                .blocking_call_view_access_key(
+                    &previous_context.prepopulated_transaction.signer_id,
+                    &account_json.public_key,
+                    near_primitives::types::BlockReference::latest(),
+                )
+                .wrap_err_with(||
+                    format!("Cannot sign a transaction due to an error while fetching the most recent nonce value on network <{}>", network_config.network_name)
+                )?;
+            (
+                rpc_query_response
+                    .access_key_view()
+                    .wrap_err("Error current_nonce")?
+                    .nonce
+                    + 1,
+                rpc_query_response.block_hash,
+                rpc_query_response.block_height,
             )
-            .wrap_err_with(||
-                format!("Cannot sign a transaction due to an error while fetching the most recent nonce value on network <{}>", network_config.network_name)
-            )?;
-        let current_nonce = rpc_query_response
-            .access_key_view()
-            .wrap_err("Error current_nonce")?
-            .nonce;
+        };
 
         let mut unsigned_transaction = near_primitives::transaction::Transaction {
             public_key: account_json.public_key.clone(),
-            block_hash: rpc_query_response.block_hash,
-            nonce: current_nonce + 1,
+            block_hash,
+            nonce,
             signer_id: previous_context.prepopulated_transaction.signer_id,
             receiver_id: previous_context.prepopulated_transaction.receiver_id,
             actions: previous_context.prepopulated_transaction.actions,
@@ -175,7 +167,7 @@ impl SignKeychainContext {
             .sign(unsigned_transaction.get_hash_and_size().0.as_ref());
 
         if network_config.meta_transaction_relayer_url.is_some() {
-            let max_block_height = rpc_query_response.block_height
+            let max_block_height = block_height
                 + scope
                     .meta_transaction_valid_for
                     .unwrap_or(super::META_TRANSACTION_VALID_FOR_DEFAULT);
@@ -195,6 +187,7 @@ impl SignKeychainContext {
                     .on_before_sending_transaction_callback,
                 on_after_sending_transaction_callback: previous_context
                     .on_after_sending_transaction_callback,
+                transaction_bundle: previous_context.transaction_bundle,
             });
         }
 
@@ -215,10 +208,46 @@ impl SignKeychainContext {
                 .on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: previous_context
                 .on_after_sending_transaction_callback,
+            transaction_bundle: previous_context.transaction_bundle,
         })
     }
 }
 
+/// Finds a full access key of the signer saved in the keychain.
+pub fn get_full_access_key_pair(
+    network_config: &crate::config::NetworkConfig,
+    signer_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Option<super::AccountKeyPair>> {
+    let service_name = format!("near-{}-{}", network_config.network_name, signer_id);
+    let access_key_list = network_config
+        .json_rpc_client()
+        .blocking_call_view_access_key_list(
+            signer_id,
+            near_primitives::types::Finality::Final.into(),
+        )
+        .wrap_err_with(|| format!("Failed to fetch access key list for {}", signer_id))?
+        .access_key_list_view()?;
+
+    access_key_list
+        .keys
+        .into_iter()
+        .filter(|key| {
+            matches!(
+                key.access_key.permission,
+                near_primitives::views::AccessKeyPermissionView::FullAccess
+            )
+        })
+        .map(|key| key.public_key)
+        .find_map(|public_key| {
+            let keyring =
+                keyring::Entry::new(&service_name, &format!("{}:{}", signer_id, public_key))
+                    .ok()?;
+            keyring.get_password().ok()
+        })
+        .map(|password| serde_json::from_str(&password).wrap_err("Error reading data"))
+        .transpose()
+}
+
 #[tracing::instrument(name = "Warning:", skip_all)]
 fn warning_message(instrument_message: &str) {
     tracing::Span::current().pb_set_message(instrument_message);
@@ -257,6 +286,7 @@ impl From<SignKeychainContext> for super::SubmitContext {
                 .signed_transaction_or_signed_delegate_action,
             on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: item.on_after_sending_transaction_callback,
+            transaction_bundle: item.transaction_bundle,
         }
     }
 }
@@ -265,6 +295,16 @@ impl SignKeychain {
     fn input_signer_public_key(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::public_key::PublicKey>> {
+        if let Some(transaction_bundle) = &context.transaction_bundle {
+            return Ok(Some(
+                transaction_bundle
+                    .unsigned_transaction
+                    .inner
+                    .public_key
+                    .clone()
+                    .into(),
+            ));
+        }
         if context.global_context.offline {
             return Ok(Some(
                 CustomType::<crate::types::public_key::PublicKey>::new("Enter public_key:")
@@ -277,7 +317,7 @@ impl SignKeychain {
     fn input_nonce(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<u64>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<u64>::new("Enter a nonce for the access key:").prompt()?,
             ));
@@ -288,7 +328,7 @@ impl SignKeychain {
     fn input_block_hash(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::crypto_hash::CryptoHash>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<crate::types::crypto_hash::CryptoHash>::new(
                     "Enter recent block hash:",
@@ -302,7 +342,7 @@ impl SignKeychain {
     fn input_block_height(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<near_primitives::types::BlockHeight>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<near_primitives::types::BlockHeight>::new(
                     "Enter recent block height:",
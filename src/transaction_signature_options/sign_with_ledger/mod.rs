@@ -42,6 +42,7 @@ pub struct SignLedgerContext {
         crate::transaction_signature_options::OnBeforeSendingTransactionCallback,
     on_after_sending_transaction_callback:
         crate::transaction_signature_options::OnAfterSendingTransactionCallback,
+    transaction_bundle: Option<crate::types::transaction_bundle::TransactionBundle>,
 }
 
 impl SignLedgerContext {
@@ -57,7 +58,12 @@ impl SignLedgerContext {
         let seed_phrase_hd_path: slipped10::BIP32Path = scope.seed_phrase_hd_path.clone().into();
         let public_key: near_crypto::PublicKey = scope.signer_public_key.clone().into();
 
-        let (nonce, block_hash) = if previous_context.global_context.offline {
+        let (nonce, block_hash) = if let Some(transaction_bundle) =
+            &previous_context.transaction_bundle
+        {
+            let (nonce, block_hash, _) = transaction_bundle.get_nonce_and_block(&public_key)?;
+            (nonce, block_hash)
+        } else if previous_context.global_context.offline {
             (
                 scope
                     .nonce
@@ -133,6 +139,7 @@ impl SignLedgerContext {
                 .on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: previous_context
                 .on_after_sending_transaction_callback,
+            transaction_bundle: previous_context.transaction_bundle,
         })
     }
 }
@@ -146,6 +153,7 @@ impl From<SignLedgerContext> for super::SubmitContext {
                 .signed_transaction_or_signed_delegate_action,
             on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: item.on_after_sending_transaction_callback,
+            transaction_bundle: item.transaction_bundle,
         }
     }
 }
@@ -253,7 +261,7 @@ impl SignLedger {
     fn input_nonce(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<u64>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<u64>::new("Enter a nonce for the access key:").prompt()?,
             ));
@@ -264,7 +272,7 @@ impl SignLedger {
     fn input_block_hash(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::crypto_hash::CryptoHash>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<crate::types::crypto_hash::CryptoHash>::new(
                     "Enter recent block hash:",
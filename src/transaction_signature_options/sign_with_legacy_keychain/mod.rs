@@ -41,6 +41,7 @@ pub struct SignLegacyKeychainContext {
         crate::transaction_signature_options::OnBeforeSendingTransactionCallback,
     pub(crate) on_after_sending_transaction_callback:
         crate::transaction_signature_options::OnAfterSendingTransactionCallback,
+    pub(crate) transaction_bundle: Option<crate::types::transaction_bundle::TransactionBundle>,
 }
 
 impl SignLegacyKeychainContext {
@@ -54,10 +55,6 @@ impl SignLegacyKeychainContext {
     ) -> color_eyre::eyre::Result<Self> {
         let network_config = previous_context.network_config.clone();
 
-        let file_name = format!(
-            "{}.json",
-            &previous_context.prepopulated_transaction.signer_id
-        );
         let mut path =
             std::path::PathBuf::from(&previous_context.global_context.config.credentials_home_dir);
 
@@ -65,7 +62,9 @@ impl SignLegacyKeychainContext {
             let dir_name = network_config.network_name.clone();
             path.push(&dir_name);
 
-            if previous_context.global_context.offline {
+            if previous_context.global_context.offline
+                || previous_context.transaction_bundle.is_some()
+            {
                 path.push(
                     previous_context
                         .prepopulated_transaction
@@ -85,70 +84,11 @@ impl SignLegacyKeychainContext {
                 ));
                 path
             } else {
-                path.push(file_name);
-                if path.exists() {
-                    path
-                } else {
-                    let access_key_list = network_config
-                        .json_rpc_client()
-                        .blocking_call_view_access_key_list(
-                            &previous_context.prepopulated_transaction.signer_id,
-                            near_primitives::types::Finality::Final.into(),
-                        )
-                        .wrap_err_with(|| {
-                            format!(
-                                "Failed to fetch access KeyList for {}",
-                                previous_context.prepopulated_transaction.signer_id
-                            )
-                        })?
-                        .access_key_list_view()?;
-                    let mut path = std::path::PathBuf::from(
-                        &previous_context.global_context.config.credentials_home_dir,
-                    );
-                    path.push(dir_name);
-                    path.push(
-                        &previous_context
-                            .prepopulated_transaction
-                            .signer_id
-                            .to_string(),
-                    );
-                    let mut data_path = std::path::PathBuf::new();
-                    'outer: for access_key in access_key_list.keys {
-                        let account_public_key = access_key.public_key.to_string();
-                        let is_full_access_key: bool = match &access_key.access_key.permission {
-                            near_primitives::views::AccessKeyPermissionView::FullAccess => true,
-                            near_primitives::views::AccessKeyPermissionView::FunctionCall {
-                                allowance: _,
-                                receiver_id: _,
-                                method_names: _,
-                            } => false,
-                        };
-                        let dir = path
-                        .read_dir()
-                        .wrap_err("There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain.")?;
-                        for entry in dir {
-                            if let Ok(entry) = entry {
-                                if entry
-                                    .path()
-                                    .file_stem()
-                                    .unwrap()
-                                    .to_str()
-                                    .unwrap()
-                                    .contains(account_public_key.rsplit(':').next().unwrap())
-                                    && is_full_access_key
-                                {
-                                    data_path.push(entry.path());
-                                    break 'outer;
-                                }
-                            } else {
-                                return Err(color_eyre::Report::msg(
-                                "There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain."
-                            ));
-                            };
-                        }
-                    }
-                    data_path
-                }
+                find_full_access_key_file(
+                    &previous_context.global_context.config.credentials_home_dir,
+                    &network_config,
+                    &previous_context.prepopulated_transaction.signer_id,
+                )?
             }
         };
         let data = std::fs::read_to_string(&data_path).wrap_err_with(|| {
@@ -160,7 +100,11 @@ impl SignLegacyKeychainContext {
         let account_json: super::AccountKeyPair = serde_json::from_str(&data)
             .wrap_err_with(|| format!("Error reading data from file: {:?}", &data_path))?;
 
-        let (nonce, block_hash, block_height) = if previous_context.global_context.offline {
+        let (nonce, block_hash, block_height) = if let Some(transaction_bundle) =
+            &previous_context.transaction_bundle
+        {
+            transaction_bundle.get_nonce_and_block(&account_json.public_key)?
+        } else if previous_context.global_context.offline {
             (
                 scope
                     .nonce
@@ -227,6 +171,7 @@ impl SignLegacyKeychainContext {
                     .on_before_sending_transaction_callback,
                 on_after_sending_transaction_callback: previous_context
                     .on_after_sending_transaction_callback,
+                transaction_bundle: previous_context.transaction_bundle,
             });
         }
 
@@ -251,10 +196,74 @@ impl SignLegacyKeychainContext {
                 .on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: previous_context
                 .on_after_sending_transaction_callback,
+            transaction_bundle: previous_context.transaction_bundle,
         })
     }
 }
 
+/// Finds the file of a full access key of the signer in the legacy keychain: the account key
+/// file `<network>/<account>.json` or a full access key file in `<network>/<account>/`.
+pub fn find_full_access_key_file(
+    credentials_home_dir: &std::path::Path,
+    network_config: &crate::config::NetworkConfig,
+    signer_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<std::path::PathBuf> {
+    let mut path = std::path::PathBuf::from(credentials_home_dir);
+    let dir_name = network_config.network_name.clone();
+    path.push(&dir_name);
+    path.push(format!("{}.json", signer_id));
+    if path.exists() {
+        return Ok(path);
+    }
+    let access_key_list = network_config
+        .json_rpc_client()
+        .blocking_call_view_access_key_list(
+            signer_id,
+            near_primitives::types::Finality::Final.into(),
+        )
+        .wrap_err_with(|| format!("Failed to fetch access KeyList for {}", signer_id))?
+        .access_key_list_view()?;
+    let mut path = std::path::PathBuf::from(credentials_home_dir);
+    path.push(dir_name);
+    path.push(signer_id.to_string());
+    let mut data_path = std::path::PathBuf::new();
+    'outer: for access_key in access_key_list.keys {
+        let account_public_key = access_key.public_key.to_string();
+        let is_full_access_key: bool = match &access_key.access_key.permission {
+            near_primitives::views::AccessKeyPermissionView::FullAccess => true,
+            near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                allowance: _,
+                receiver_id: _,
+                method_names: _,
+            } => false,
+        };
+        let dir = path
+            .read_dir()
+            .wrap_err("There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain.")?;
+        for entry in dir {
+            if let Ok(entry) = entry {
+                if entry
+                    .path()
+                    .file_stem()
+                    .unwrap()
+                    .to_str()
+                    .unwrap()
+                    .contains(account_public_key.rsplit(':').next().unwrap())
+                    && is_full_access_key
+                {
+                    data_path.push(entry.path());
+                    break 'outer;
+                }
+            } else {
+                return Err(color_eyre::Report::msg(
+                    "There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain."
+                ));
+            };
+        }
+    }
+    Ok(data_path)
+}
+
 impl From<SignLegacyKeychainContext> for super::SubmitContext {
     fn from(item: SignLegacyKeychainContext) -> Self {
         Self {
@@ -264,6 +273,7 @@ impl From<SignLegacyKeychainContext> for super::SubmitContext {
                 .signed_transaction_or_signed_delegate_action,
             on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: item.on_after_sending_transaction_callback,
+            transaction_bundle: item.transaction_bundle,
         }
     }
 }
@@ -272,6 +282,16 @@ impl SignLegacyKeychain {
     fn input_signer_public_key(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::public_key::PublicKey>> {
+        if let Some(transaction_bundle) = &context.transaction_bundle {
+            return Ok(Some(
+                transaction_bundle
+                    .unsigned_transaction
+                    .inner
+                    .public_key
+                    .clone()
+                    .into(),
+            ));
+        }
         if context.global_context.offline {
             let network_config = context.network_config.clone();
 
@@ -288,7 +308,9 @@ impl SignLegacyKeychain {
             let key_list = signer_dir
                 .filter_map(|entry| entry.ok())
                 .filter_map(|entry| entry.file_name().into_string().ok())
-                .filter(|file_name_str| file_name_str.starts_with("ed25519_"))
+                .filter(|file_name_str| {
+                    file_name_str.starts_with("ed25519_") || file_name_str.starts_with("secp256k1_")
+                })
                 .map(|file_name_str| file_name_str.replace(".json", "").replace('_', ":"))
                 .collect::<Vec<_>>();
 
@@ -304,7 +326,7 @@ impl SignLegacyKeychain {
     fn input_nonce(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<u64>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<u64>::new("Enter a nonce for the access key:").prompt()?,
             ));
@@ -315,7 +337,7 @@ impl SignLegacyKeychain {
     fn input_block_hash(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::crypto_hash::CryptoHash>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<crate::types::crypto_hash::CryptoHash>::new(
                     "Enter recent block hash:",
@@ -329,7 +351,7 @@ impl SignLegacyKeychain {
     fn input_block_height(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<near_primitives::types::BlockHeight>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<near_primitives::types::BlockHeight>::new(
                     "Enter recent block height:",
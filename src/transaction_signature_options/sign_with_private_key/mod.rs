@@ -39,6 +39,7 @@ pub struct SignPrivateKeyContext {
         crate::transaction_signature_options::OnBeforeSendingTransactionCallback,
     on_after_sending_transaction_callback:
         crate::transaction_signature_options::OnAfterSendingTransactionCallback,
+    transaction_bundle: Option<crate::types::transaction_bundle::TransactionBundle>,
 }
 
 impl SignPrivateKeyContext {
@@ -54,7 +55,11 @@ impl SignPrivateKeyContext {
         let signer_secret_key: near_crypto::SecretKey = scope.signer_private_key.clone().into();
         let public_key: near_crypto::PublicKey = scope.signer_public_key.clone().into();
 
-        let (nonce, block_hash, block_height) = if previous_context.global_context.offline {
+        let (nonce, block_hash, block_height) = if let Some(transaction_bundle) =
+            &previous_context.transaction_bundle
+        {
+            transaction_bundle.get_nonce_and_block(&public_key)?
+        } else if previous_context.global_context.offline {
             (
                 scope
                     .nonce
@@ -123,6 +128,7 @@ impl SignPrivateKeyContext {
                     .on_before_sending_transaction_callback,
                 on_after_sending_transaction_callback: previous_context
                     .on_after_sending_transaction_callback,
+                transaction_bundle: previous_context.transaction_bundle,
             });
         }
 
@@ -143,6 +149,7 @@ impl SignPrivateKeyContext {
                 .on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: previous_context
                 .on_after_sending_transaction_callback,
+            transaction_bundle: previous_context.transaction_bundle,
         })
     }
 }
@@ -156,6 +163,7 @@ impl From<SignPrivateKeyContext> for super::SubmitContext {
                 .signed_transaction_or_signed_delegate_action,
             on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: item.on_after_sending_transaction_callback,
+            transaction_bundle: item.transaction_bundle,
         }
     }
 }
@@ -164,7 +172,7 @@ impl SignPrivateKey {
     fn input_nonce(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<u64>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<u64>::new("Enter a nonce for the access key:").prompt()?,
             ));
@@ -175,7 +183,7 @@ impl SignPrivateKey {
     fn input_block_hash(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::crypto_hash::CryptoHash>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<crate::types::crypto_hash::CryptoHash>::new(
                     "Enter recent block hash:",
@@ -189,7 +197,7 @@ impl SignPrivateKey {
     fn input_block_height(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<near_primitives::types::BlockHeight>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<near_primitives::types::BlockHeight>::new(
                     "Enter recent block height:",
@@ -0,0 +1,226 @@
+use color_eyre::eyre::{ContextCompat, WrapErr};
+use inquire::CustomType;
+
+use crate::common::JsonRpcClientExt;
+use crate::common::RpcQueryResponseExt;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::commands::TransactionContext)]
+#[interactive_clap(output_context = SignRemoteSignerContext)]
+pub struct SignRemoteSigner {
+    #[interactive_clap(long)]
+    /// Enter the public key of the access key held by the remote signer:
+    signer_public_key: crate::types::public_key::PublicKey,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    nonce: Option<u64>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    block_hash: Option<crate::types::crypto_hash::CryptoHash>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    block_height: Option<near_primitives::types::BlockHeight>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    meta_transaction_valid_for: Option<u64>,
+    #[interactive_clap(subcommand)]
+    submit: super::Submit,
+}
+
+#[derive(Clone)]
+pub struct SignRemoteSignerContext {
+    network_config: crate::config::NetworkConfig,
+    global_context: crate::GlobalContext,
+    signed_transaction_or_signed_delegate_action: super::SignedTransactionOrSignedDelegateAction,
+    on_before_sending_transaction_callback:
+        crate::transaction_signature_options::OnBeforeSendingTransactionCallback,
+    on_after_sending_transaction_callback:
+        crate::transaction_signature_options::OnAfterSendingTransactionCallback,
+    transaction_bundle: Option<crate::types::transaction_bundle::TransactionBundle>,
+}
+
+impl SignRemoteSignerContext {
+    #[tracing::instrument(name = "Signing the transaction with the remote signer ...", skip_all)]
+    pub fn from_previous_context(
+        previous_context: crate::commands::TransactionContext,
+        scope: &<SignRemoteSigner as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let network_config = previous_context.network_config.clone();
+        let remote_signer =
+            crate::remote_signer::RemoteSigner::from_network_config(&network_config)?;
+        let public_key: near_crypto::PublicKey = scope.signer_public_key.clone().into();
+
+        let (nonce, block_hash, block_height) = if let Some(transaction_bundle) =
+            &previous_context.transaction_bundle
+        {
+            transaction_bundle.get_nonce_and_block(&public_key)?
+        } else if previous_context.global_context.offline {
+            (
+                scope
+                    .nonce
+                    .wrap_err("Nonce is required to sign a transaction in offline mode")?,
+                scope
+                    .block_hash
+                    .wrap_err("Block Hash is required to sign a transaction in offline mode")?
+                    .0,
+                scope
+                    .block_height
+                    .wrap_err("Block Height is required to sign a transaction in offline mode")?,
+            )
+        } else {
+            let rpc_query_response = network_config
+                .json_rpc_client()
+                .blocking_call_view_access_key(
+                    &previous_context.prepopulated_transaction.signer_id,
+                    &public_key,
+                    near_primitives::types::BlockReference::latest()
+                )
+                .wrap_err_with(||
+                    format!("Cannot sign a transaction due to an error while fetching the most recent nonce value on network <{}>", network_config.network_name)
+                )?;
+            (
+                rpc_query_response
+                    .access_key_view()
+                    .wrap_err("Error current_nonce")?
+                    .nonce
+                    + 1,
+                rpc_query_response.block_hash,
+                rpc_query_response.block_height,
+            )
+        };
+
+        let mut unsigned_transaction = near_primitives::transaction::Transaction {
+            public_key: public_key.clone(),
+            block_hash,
+            nonce,
+            signer_id: previous_context.prepopulated_transaction.signer_id,
+            receiver_id: previous_context.prepopulated_transaction.receiver_id,
+            actions: previous_context.prepopulated_transaction.actions,
+        };
+
+        (previous_context.on_before_signing_callback)(&mut unsigned_transaction, &network_config)?;
+
+        if network_config.meta_transaction_relayer_url.is_some() {
+            let max_block_height = block_height
+                + scope
+                    .meta_transaction_valid_for
+                    .unwrap_or(super::META_TRANSACTION_VALID_FOR_DEFAULT);
+
+            let delegate_action =
+                super::get_delegate_action(unsigned_transaction, max_block_height);
+            let signature = remote_signer
+                .sign(&crate::remote_signer::SignRequest::for_delegate_action(
+                    &delegate_action,
+                )?)
+                .wrap_err_with(|| {
+                    format!(
+                        "Failed to sign the delegate action with the remote signer <{}>",
+                        remote_signer.url()
+                    )
+                })?;
+            super::print_signed_delegate_action_info(&public_key, &signature, max_block_height);
+
+            return Ok(Self {
+                network_config: previous_context.network_config,
+                global_context: previous_context.global_context,
+                signed_transaction_or_signed_delegate_action:
+                    near_primitives::action::delegate::SignedDelegateAction {
+                        delegate_action,
+                        signature,
+                    }
+                    .into(),
+                on_before_sending_transaction_callback: previous_context
+                    .on_before_sending_transaction_callback,
+                on_after_sending_transaction_callback: previous_context
+                    .on_after_sending_transaction_callback,
+                transaction_bundle: previous_context.transaction_bundle,
+            });
+        }
+
+        let signature = remote_signer
+            .sign(&crate::remote_signer::SignRequest::for_transaction(
+                &unsigned_transaction,
+            )?)
+            .wrap_err_with(|| {
+                format!(
+                    "Failed to sign the transaction with the remote signer <{}>",
+                    remote_signer.url()
+                )
+            })?;
+
+        let signed_transaction = near_primitives::transaction::SignedTransaction::new(
+            signature.clone(),
+            unsigned_transaction,
+        );
+
+        eprintln!("\nYour transaction was signed successfully.");
+        eprintln!("Public key: {}", public_key);
+        eprintln!("Signature: {}", signature);
+
+        Ok(Self {
+            network_config: previous_context.network_config,
+            global_context: previous_context.global_context,
+            signed_transaction_or_signed_delegate_action: signed_transaction.into(),
+            on_before_sending_transaction_callback: previous_context
+                .on_before_sending_transaction_callback,
+            on_after_sending_transaction_callback: previous_context
+                .on_after_sending_transaction_callback,
+            transaction_bundle: previous_context.transaction_bundle,
+        })
+    }
+}
+
+impl From<SignRemoteSignerContext> for super::SubmitContext {
+    fn from(item: SignRemoteSignerContext) -> Self {
+        Self {
+            network_config: item.network_config,
+            global_context: item.global_context,
+            signed_transaction_or_signed_delegate_action: item
+                .signed_transaction_or_signed_delegate_action,
+            on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
+            on_after_sending_transaction_callback: item.on_after_sending_transaction_callback,
+            transaction_bundle: item.transaction_bundle,
+        }
+    }
+}
+
+impl SignRemoteSigner {
+    fn input_nonce(
+        context: &crate::commands::TransactionContext,
+    ) -> color_eyre::eyre::Result<Option<u64>> {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
+            return Ok(Some(
+                CustomType::<u64>::new("Enter a nonce for the access key:").prompt()?,
+            ));
+        }
+        Ok(None)
+    }
+
+    fn input_block_hash(
+        context: &crate::commands::TransactionContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::crypto_hash::CryptoHash>> {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
+            return Ok(Some(
+                CustomType::<crate::types::crypto_hash::CryptoHash>::new(
+                    "Enter recent block hash:",
+                )
+                .prompt()?,
+            ));
+        }
+        Ok(None)
+    }
+
+    fn input_block_height(
+        context: &crate::commands::TransactionContext,
+    ) -> color_eyre::eyre::Result<Option<near_primitives::types::BlockHeight>> {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
+            return Ok(Some(
+                CustomType::<near_primitives::types::BlockHeight>::new(
+                    "Enter recent block height:",
+                )
+                .prompt()?,
+            ));
+        }
+        Ok(None)
+    }
+}
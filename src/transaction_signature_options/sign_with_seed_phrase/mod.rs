@@ -13,8 +13,12 @@ pub struct SignSeedPhrase {
     /// Enter the seed-phrase for this account:
     master_seed_phrase: String,
     #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Type of the key to derive from the seed phrase: ed25519 (default) or secp256k1
+    key_type: Option<crate::types::key_type::KeyType>,
+    #[interactive_clap(long)]
     #[interactive_clap(skip_default_input_arg)]
-    seed_phrase_hd_path: crate::types::slip10::BIP32Path,
+    seed_phrase_hd_path: Option<crate::types::slip10::BIP32Path>,
     #[interactive_clap(long)]
     #[interactive_clap(skip_default_input_arg)]
     pub nonce: Option<u64>,
@@ -40,6 +44,7 @@ pub struct SignSeedPhraseContext {
         crate::transaction_signature_options::OnBeforeSendingTransactionCallback,
     on_after_sending_transaction_callback:
         crate::transaction_signature_options::OnAfterSendingTransactionCallback,
+    transaction_bundle: Option<crate::types::transaction_bundle::TransactionBundle>,
 }
 
 impl SignSeedPhraseContext {
@@ -50,9 +55,14 @@ impl SignSeedPhraseContext {
     ) -> color_eyre::eyre::Result<Self> {
         let network_config = previous_context.network_config.clone();
 
+        let key_type = scope.key_type.unwrap_or_default().into();
         let key_pair_properties = crate::common::get_key_pair_properties_from_seed_phrase(
-            scope.seed_phrase_hd_path.clone(),
+            scope
+                .seed_phrase_hd_path
+                .clone()
+                .unwrap_or_else(|| crate::common::default_seed_phrase_hd_path(key_type)),
             scope.master_seed_phrase.clone(),
+            key_type,
         )?;
 
         let signer_secret_key: near_crypto::SecretKey =
@@ -60,7 +70,11 @@ impl SignSeedPhraseContext {
         let signer_public_key =
             near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?;
 
-        let (nonce, block_hash, block_height) = if previous_context.global_context.offline {
+        let (nonce, block_hash, block_height) = if let Some(transaction_bundle) =
+            &previous_context.transaction_bundle
+        {
+            transaction_bundle.get_nonce_and_block(&signer_public_key)?
+        } else if previous_context.global_context.offline {
             (
                 scope
                     .nonce
@@ -129,6 +143,7 @@ impl SignSeedPhraseContext {
                     .on_before_sending_transaction_callback,
                 on_after_sending_transaction_callback: previous_context
                     .on_after_sending_transaction_callback,
+                transaction_bundle: previous_context.transaction_bundle,
             });
         }
 
@@ -149,6 +164,7 @@ impl SignSeedPhraseContext {
                 .on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: previous_context
                 .on_after_sending_transaction_callback,
+            transaction_bundle: previous_context.transaction_bundle,
         })
     }
 }
@@ -162,6 +178,7 @@ impl From<SignSeedPhraseContext> for super::SubmitContext {
                 .signed_transaction_or_signed_delegate_action,
             on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
             on_after_sending_transaction_callback: item.on_after_sending_transaction_callback,
+            transaction_bundle: item.transaction_bundle,
         }
     }
 }
@@ -170,7 +187,7 @@ impl SignSeedPhrase {
     fn input_nonce(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<u64>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<u64>::new("Enter a nonce for the access key:").prompt()?,
             ));
@@ -181,7 +198,7 @@ impl SignSeedPhrase {
     fn input_block_hash(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::crypto_hash::CryptoHash>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<crate::types::crypto_hash::CryptoHash>::new(
                     "Enter recent block hash:",
@@ -195,7 +212,7 @@ impl SignSeedPhrase {
     fn input_block_height(
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<near_primitives::types::BlockHeight>> {
-        if context.global_context.offline {
+        if context.global_context.offline && context.transaction_bundle.is_none() {
             return Ok(Some(
                 CustomType::<near_primitives::types::BlockHeight>::new(
                     "Enter recent block height:",
@@ -209,10 +226,14 @@ impl SignSeedPhrase {
     fn input_seed_phrase_hd_path(
         _context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::slip10::BIP32Path>> {
-        Ok(Some(
-            CustomType::new("Enter seed phrase HD Path (if not sure, keep the default):")
-                .with_starting_input("m/44'/397'/0'")
-                .prompt()?,
-        ))
+        // The default path depends on the key type (m/44'/397'/0' for ed25519 keys)
+        let seed_phrase_hd_path = inquire::Text::new(
+            "Enter seed phrase HD Path (if not sure, leave blank to use the default one):",
+        )
+        .prompt()?;
+        if seed_phrase_hd_path.trim().is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(seed_phrase_hd_path.trim().parse()?))
     }
 }
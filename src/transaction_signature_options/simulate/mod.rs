@@ -0,0 +1,627 @@
+use color_eyre::eyre::WrapErr;
+use tracing_indicatif::span_ext::IndicatifSpanExt;
+
+use crate::common::{JsonRpcClientExt, RpcQueryResponseExt};
+
+pub const SANDBOX_RPC_URL_DEFAULT: &str = "http://127.0.0.1:3030";
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = super::SubmitContext)]
+#[interactive_clap(output_context = SimulateContext)]
+pub struct Simulate {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// The RPC endpoint of the sandbox node to run the transaction on (default: http://127.0.0.1:3030)
+    sandbox_rpc_url: Option<crate::types::url::Url>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SimulateContext;
+
+impl SimulateContext {
+    pub fn from_previous_context(
+        previous_context: super::SubmitContext,
+        scope: &<Simulate as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let signed_transaction = match previous_context.signed_transaction_or_signed_delegate_action
+        {
+            super::SignedTransactionOrSignedDelegateAction::SignedTransaction(
+                signed_transaction,
+            ) => signed_transaction,
+            super::SignedTransactionOrSignedDelegateAction::SignedDelegateAction(_) => {
+                color_eyre::eyre::bail!(
+                    "Simulation of a signed delegate action (meta-transaction) is not supported"
+                );
+            }
+        };
+
+        let sandbox_network_config = crate::config::NetworkConfig {
+            rpc_url: match &scope.sandbox_rpc_url {
+                Some(sandbox_rpc_url) => sandbox_rpc_url.0.clone(),
+                None => SANDBOX_RPC_URL_DEFAULT.parse()?,
+            },
+            rpc_api_key: None,
+            // The transaction only exists in the sandbox
+            explorer_transaction_url: None,
+            ..previous_context.network_config.clone()
+        };
+
+        let (state, transaction_info) = simulate_transaction(
+            &previous_context.network_config,
+            &sandbox_network_config,
+            &signed_transaction,
+        )?;
+
+        eprintln!(
+            "\nThe transaction was simulated on the sandbox <{}> with the state of <{}> at block {} and was NOT sent to the network.",
+            sandbox_network_config.rpc_url,
+            previous_context.network_config.network_name,
+            state.block_hash
+        );
+        eprintln!("The sandbox does not know the blocks of the network, so the transaction was re-signed there with a temporary access key of the same permission (its hash differs from the hash of the signed transaction).");
+        let balance_changes = get_balance_changes(&sandbox_network_config, &state.balances);
+        print_balance_changes(&balance_changes);
+
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert(
+            "balance_changes".to_string(),
+            serde_json::to_value(&balance_changes)?,
+        );
+        crate::common::print_transaction_status_with_json_fields(
+            &transaction_info,
+            &sandbox_network_config,
+            &previous_context.global_context.output_format,
+            json_fields,
+        )?;
+        Ok(Self)
+    }
+}
+
+/// Runs the transaction on the sandbox with the state of the transaction accounts copied from the
+/// network.
+///
+/// The signed transaction refers to a block and a nonce of the network, which the sandbox would
+/// reject, and it cannot be signed again without the signer. So the sandbox gets a temporary access
+/// key of the signer with the same permission, and the transaction is signed with it against the
+/// latest block of the sandbox.
+fn simulate_transaction(
+    network_config: &crate::config::NetworkConfig,
+    sandbox_network_config: &crate::config::NetworkConfig,
+    signed_transaction: &near_primitives::transaction::SignedTransaction,
+) -> color_eyre::eyre::Result<(
+    TransactionState,
+    near_primitives::views::FinalExecutionOutcomeView,
+)> {
+    let state = fetch_transaction_state(network_config, signed_transaction)?;
+
+    let transaction = &signed_transaction.transaction;
+    let simulation_secret_key =
+        near_crypto::SecretKey::from_random(transaction.public_key.key_type());
+    let mut records = state.records.clone();
+    records.push(near_primitives::state_record::StateRecord::AccessKey {
+        account_id: transaction.signer_id.clone(),
+        public_key: simulation_secret_key.public_key(),
+        access_key: near_primitives::account::AccessKey {
+            nonce: 0,
+            permission: state.access_key.permission.clone(),
+        },
+    });
+    patch_sandbox_state(&sandbox_network_config.rpc_url, records)?;
+
+    let sandbox_signed_transaction =
+        sign_for_sandbox(sandbox_network_config, transaction, &simulation_secret_key)?;
+    let transaction_info =
+        simulate_signed_transaction(sandbox_network_config, &sandbox_signed_transaction)?;
+    Ok((state, transaction_info))
+}
+
+struct TransactionState {
+    block_hash: near_primitives::hash::CryptoHash,
+    /// The access key the transaction is signed with
+    access_key: near_primitives::account::AccessKey,
+    records: Vec<near_primitives::state_record::StateRecord>,
+    balances: Vec<(
+        near_primitives::types::AccountId,
+        near_primitives::types::Balance,
+    )>,
+}
+
+/// Collects the state of the signer and the receiver at the most recent final block:
+/// the accounts, the access key used for signing, and the receiver contract code and data.
+#[tracing::instrument(name = "Fetching the state of the transaction accounts ...", skip_all)]
+fn fetch_transaction_state(
+    network_config: &crate::config::NetworkConfig,
+    signed_transaction: &near_primitives::transaction::SignedTransaction,
+) -> color_eyre::eyre::Result<TransactionState> {
+    tracing::Span::current().pb_set_message(network_config.rpc_url.as_str());
+    let json_rpc_client = network_config.json_rpc_client();
+    let transaction = &signed_transaction.transaction;
+
+    let signer_account_response = json_rpc_client
+        .blocking_call_view_account(
+            &transaction.signer_id,
+            near_primitives::types::Finality::Final.into(),
+        )
+        .wrap_err_with(|| {
+            format!(
+                "Failed to fetch the account <{}> on network <{}>",
+                transaction.signer_id, network_config.network_name
+            )
+        })?;
+    let block_hash = signer_account_response.block_hash;
+    let block_reference = near_primitives::types::BlockReference::BlockId(
+        near_primitives::types::BlockId::Hash(block_hash),
+    );
+    let signer_account_view = signer_account_response.account_view()?;
+
+    let access_key_view = json_rpc_client
+        .blocking_call_view_access_key(
+            &transaction.signer_id,
+            &transaction.public_key,
+            block_reference.clone(),
+        )
+        .wrap_err_with(|| {
+            format!(
+                "Failed to fetch the access key {} of <{}>",
+                transaction.public_key, transaction.signer_id
+            )
+        })?
+        .access_key_view()?;
+    let access_key: near_primitives::account::AccessKey = access_key_view.into();
+
+    let mut records = vec![
+        near_primitives::state_record::StateRecord::Account {
+            account_id: transaction.signer_id.clone(),
+            account: (&signer_account_view).into(),
+        },
+        near_primitives::state_record::StateRecord::AccessKey {
+            account_id: transaction.signer_id.clone(),
+            public_key: transaction.public_key.clone(),
+            access_key: access_key.clone(),
+        },
+    ];
+    let mut balances = vec![(transaction.signer_id.clone(), signer_account_view.amount)];
+
+    if transaction.receiver_id != transaction.signer_id {
+        // The receiver may not exist yet (e.g. it is created by this transaction).
+        if let Ok(receiver_account_view) = json_rpc_client
+            .blocking_call_view_account(&transaction.receiver_id, block_reference.clone())
+            .map_err(color_eyre::Report::from)
+            .and_then(|response| response.account_view())
+        {
+            records.push(near_primitives::state_record::StateRecord::Account {
+                account_id: transaction.receiver_id.clone(),
+                account: (&receiver_account_view).into(),
+            });
+            balances.push((
+                transaction.receiver_id.clone(),
+                receiver_account_view.amount,
+            ));
+        }
+    }
+
+    let receiver_account_code_hash = records.iter().find_map(|record| match record {
+        near_primitives::state_record::StateRecord::Account {
+            account_id,
+            account,
+        } if account_id == &transaction.receiver_id => Some(account.code_hash()),
+        _ => None,
+    });
+    if receiver_account_code_hash.map_or(false, |code_hash| {
+        code_hash != near_primitives::hash::CryptoHash::default()
+    }) {
+        records.extend(fetch_contract_records(
+            &json_rpc_client,
+            &transaction.receiver_id,
+            block_reference,
+        )?);
+    }
+
+    Ok(TransactionState {
+        block_hash,
+        access_key,
+        records,
+        balances,
+    })
+}
+
+fn fetch_contract_records(
+    json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    contract_account_id: &near_primitives::types::AccountId,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<Vec<near_primitives::state_record::StateRecord>> {
+    let code_response = json_rpc_client
+        .blocking_call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference: block_reference.clone(),
+            request: near_primitives::views::QueryRequest::ViewCode {
+                account_id: contract_account_id.clone(),
+            },
+        })
+        .wrap_err_with(|| {
+            format!("Failed to fetch the contract code of <{contract_account_id}>")
+        })?;
+    let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(contract_code_view) =
+        code_response.kind
+    else {
+        color_eyre::eyre::bail!(
+            "Internal error: Received unexpected query kind in response to a view-code query call"
+        );
+    };
+    let mut records = vec![near_primitives::state_record::StateRecord::Contract {
+        account_id: contract_account_id.clone(),
+        code: contract_code_view.code,
+    }];
+
+    // RPC nodes refuse to return large contract states, so the simulation continues with the code only.
+    match json_rpc_client.blocking_call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+        block_reference,
+        request: near_primitives::views::QueryRequest::ViewState {
+            account_id: contract_account_id.clone(),
+            prefix: near_primitives::types::StoreKey::from(vec![]),
+            include_proof: false,
+        },
+    }) {
+        Ok(near_jsonrpc_primitives::types::query::RpcQueryResponse {
+            kind: near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(view_state),
+            ..
+        }) => {
+            records.extend(view_state.values.into_iter().map(|state_item| {
+                near_primitives::state_record::StateRecord::Data {
+                    account_id: contract_account_id.clone(),
+                    data_key: state_item.key,
+                    value: state_item.value,
+                }
+            }));
+        }
+        Ok(_) => {}
+        Err(err) => {
+            eprintln!(
+                "WARNING: The contract state of <{contract_account_id}> is not available ({err}), the transaction is simulated with an empty contract state."
+            );
+        }
+    }
+    Ok(records)
+}
+
+#[tracing::instrument(name = "Patching the sandbox state ...", skip_all)]
+pub fn patch_sandbox_state(
+    sandbox_rpc_url: &url::Url,
+    records: Vec<near_primitives::state_record::StateRecord>,
+) -> crate::CliResult {
+    tracing::Span::current().pb_set_message(sandbox_rpc_url.as_str());
+    near_jsonrpc_client::JsonRpcClient::connect(sandbox_rpc_url.as_str())
+        .blocking_call(
+            near_jsonrpc_client::methods::sandbox_patch_state::RpcSandboxPatchStateRequest {
+                records,
+            },
+        )
+        .wrap_err_with(|| {
+            format!("Failed to patch the state of the sandbox <{sandbox_rpc_url}>")
+        })?;
+    Ok(())
+}
+
+/// Signs the transaction with the temporary access key, using its nonce and the latest block of
+/// the sandbox.
+#[tracing::instrument(name = "Signing the transaction for the sandbox ...", skip_all)]
+fn sign_for_sandbox(
+    sandbox_network_config: &crate::config::NetworkConfig,
+    transaction: &near_primitives::transaction::Transaction,
+    simulation_secret_key: &near_crypto::SecretKey,
+) -> color_eyre::eyre::Result<near_primitives::transaction::SignedTransaction> {
+    let public_key = simulation_secret_key.public_key();
+    let rpc_query_response = sandbox_network_config
+        .json_rpc_client()
+        .blocking_call_view_access_key(
+            &transaction.signer_id,
+            &public_key,
+            near_primitives::types::BlockReference::latest(),
+        )
+        .wrap_err_with(|| {
+            format!(
+                "Failed to fetch the temporary access key of <{}> from the sandbox <{}>",
+                transaction.signer_id, sandbox_network_config.rpc_url
+            )
+        })?;
+    let transaction = near_primitives::transaction::Transaction {
+        public_key,
+        nonce: rpc_query_response.access_key_view()?.nonce + 1,
+        block_hash: rpc_query_response.block_hash,
+        ..transaction.clone()
+    };
+    let signature = simulation_secret_key.sign(transaction.get_hash_and_size().0.as_ref());
+    Ok(near_primitives::transaction::SignedTransaction::new(
+        signature,
+        transaction,
+    ))
+}
+
+#[tracing::instrument(name = "Simulating the transaction on the sandbox ...", skip_all)]
+fn simulate_signed_transaction(
+    sandbox_network_config: &crate::config::NetworkConfig,
+    signed_transaction: &near_primitives::transaction::SignedTransaction,
+) -> color_eyre::eyre::Result<near_primitives::views::FinalExecutionOutcomeView> {
+    tracing::Span::current().pb_set_message(sandbox_network_config.rpc_url.as_str());
+    sandbox_network_config
+        .json_rpc_client()
+        .blocking_call(
+            near_jsonrpc_client::methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
+                signed_transaction: signed_transaction.clone(),
+            },
+        )
+        .map_err(|err| match crate::common::rpc_transaction_error(&err) {
+            Ok(message) => color_eyre::eyre::eyre!(message),
+            Err(report) => color_eyre::Report::msg(report),
+        })
+        .wrap_err("The sandbox failed to execute the transaction")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+struct BalanceChange {
+    account_id: near_primitives::types::AccountId,
+    #[serde(with = "near_primitives::serialize::dec_format")]
+    balance_before: near_primitives::types::Balance,
+    /// `None` if the account does not exist after the transaction
+    #[serde(with = "near_primitives::serialize::dec_format")]
+    balance_after: Option<near_primitives::types::Balance>,
+}
+
+/// The balances of the accounts on the sandbox after the transaction.
+fn get_balance_changes(
+    sandbox_network_config: &crate::config::NetworkConfig,
+    balances: &[(
+        near_primitives::types::AccountId,
+        near_primitives::types::Balance,
+    )],
+) -> Vec<BalanceChange> {
+    balances
+        .iter()
+        .map(|(account_id, balance_before)| BalanceChange {
+            account_id: account_id.clone(),
+            balance_before: *balance_before,
+            balance_after: sandbox_network_config
+                .json_rpc_client()
+                .blocking_call_view_account(
+                    account_id,
+                    near_primitives::types::BlockReference::latest(),
+                )
+                .map_err(color_eyre::Report::from)
+                .and_then(|response| response.account_view())
+                .map(|account_view| account_view.amount)
+                .ok(),
+        })
+        .collect()
+}
+
+fn print_balance_changes(balance_changes: &[BalanceChange]) {
+    eprintln!("\n--- Balance changes ----------------");
+    for BalanceChange {
+        account_id,
+        balance_before,
+        balance_after,
+    } in balance_changes
+    {
+        match balance_after {
+            Some(balance_after) => {
+                let (sign, difference) = if balance_after >= balance_before {
+                    ('+', balance_after - balance_before)
+                } else {
+                    ('-', balance_before - balance_after)
+                };
+                eprintln!(
+                    "<{account_id}>: {} -> {} ({sign}{})",
+                    crate::types::near_token::NearToken::from_yoctonear(*balance_before),
+                    crate::types::near_token::NearToken::from_yoctonear(*balance_after),
+                    crate::types::near_token::NearToken::from_yoctonear(difference),
+                );
+            }
+            None => eprintln!("<{account_id}>: the account does not exist after the transaction"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{network_config, serve_json_rpc};
+
+    #[test]
+    fn transaction_is_signed_again_for_the_sandbox() {
+        let network_block_hash = near_primitives::hash::CryptoHash::hash_bytes(b"network");
+        let sandbox_block_hash = near_primitives::hash::CryptoHash::hash_bytes(b"sandbox");
+        let permission = serde_json::json!({"FunctionCall": {
+            "allowance": null, "receiver_id": "bob.testnet", "method_names": []
+        }});
+
+        let network_permission = permission.clone();
+        let network_rpc_url = serve_json_rpc(move |method, params| {
+            assert_eq!(method, "query");
+            let block = serde_json::json!({"block_height": 100, "block_hash": network_block_hash});
+            let mut result = match params["request_type"].as_str().unwrap() {
+                "view_account" => serde_json::json!({
+                    "amount": "10000000000000000000000000",
+                    "locked": "0",
+                    "code_hash": near_primitives::hash::CryptoHash::default(),
+                    "storage_usage": 182,
+                    "storage_paid_at": 0,
+                }),
+                "view_access_key" => {
+                    serde_json::json!({"nonce": 100_000_005, "permission": network_permission})
+                }
+                request_type => panic!("unexpected {request_type} query"),
+            };
+            result
+                .as_object_mut()
+                .unwrap()
+                .extend(block.as_object().unwrap().clone());
+            result
+        });
+
+        let (patches_sender, patches) = std::sync::mpsc::channel();
+        let (transactions_sender, transactions) = std::sync::mpsc::channel();
+        let sandbox_rpc_url = serve_json_rpc(move |method, params| match method {
+            "sandbox_patch_state" => {
+                patches_sender.send(params["records"].clone()).unwrap();
+                serde_json::json!({})
+            }
+            "query" => {
+                assert_eq!(params["request_type"], "view_access_key");
+                serde_json::json!({
+                    "nonce": 0,
+                    "permission": permission,
+                    "block_height": 3,
+                    "block_hash": sandbox_block_hash,
+                })
+            }
+            "broadcast_tx_commit" => {
+                let signed_transaction: near_primitives::transaction::SignedTransaction =
+                    borsh::BorshDeserialize::try_from_slice(
+                        &near_primitives::serialize::from_base64(params[0].as_str().unwrap())
+                            .unwrap(),
+                    )
+                    .unwrap();
+                transactions_sender
+                    .send(signed_transaction.clone())
+                    .unwrap();
+                let outcome = near_primitives::views::ExecutionOutcomeView {
+                    logs: vec![],
+                    receipt_ids: vec![],
+                    gas_burnt: 0,
+                    tokens_burnt: 0,
+                    executor_id: signed_transaction.transaction.signer_id.clone(),
+                    status: near_primitives::views::ExecutionStatusView::SuccessValue(vec![]),
+                    metadata: Default::default(),
+                };
+                serde_json::to_value(near_primitives::views::FinalExecutionOutcomeView {
+                    status: near_primitives::views::FinalExecutionStatus::SuccessValue(vec![]),
+                    transaction_outcome: near_primitives::views::ExecutionOutcomeWithIdView {
+                        proof: vec![],
+                        block_hash: sandbox_block_hash,
+                        id: signed_transaction.get_hash(),
+                        outcome,
+                    },
+                    transaction: signed_transaction.into(),
+                    receipts_outcome: vec![],
+                })
+                .unwrap()
+            }
+            method => panic!("unexpected {method} call"),
+        });
+
+        let signer = near_crypto::InMemorySigner::from_seed(
+            "alice.testnet".parse().unwrap(),
+            near_crypto::KeyType::ED25519,
+            "alice.testnet",
+        );
+        let signed_transaction = near_primitives::transaction::Transaction {
+            signer_id: signer.account_id.clone(),
+            public_key: signer.public_key.clone(),
+            nonce: 100_000_006,
+            receiver_id: "alice.testnet".parse().unwrap(),
+            block_hash: network_block_hash,
+            actions: vec![near_primitives::transaction::Action::Transfer(
+                near_primitives::transaction::TransferAction { deposit: 1 },
+            )],
+        }
+        .sign(&signer);
+
+        let (state, transaction_info) = simulate_transaction(
+            &network_config(network_rpc_url),
+            &network_config(sandbox_rpc_url),
+            &signed_transaction,
+        )
+        .unwrap();
+        assert_eq!(state.block_hash, network_block_hash);
+
+        // The signer account, its key as on the network and the temporary key are patched.
+        let records: Vec<near_primitives::state_record::StateRecord> =
+            serde_json::from_value(patches.recv().unwrap()).unwrap();
+        let [near_primitives::state_record::StateRecord::Account { account_id, .. }, near_primitives::state_record::StateRecord::AccessKey {
+            public_key,
+            access_key,
+            ..
+        }, near_primitives::state_record::StateRecord::AccessKey {
+            public_key: simulation_public_key,
+            access_key: simulation_access_key,
+            ..
+        }] = &records[..]
+        else {
+            panic!("unexpected state records: {records:?}");
+        };
+        assert_eq!(account_id, &signer.account_id);
+        assert_eq!(public_key, &signer.public_key);
+        assert_eq!(access_key.nonce, 100_000_005);
+        assert_ne!(simulation_public_key, &signer.public_key);
+        assert_eq!(simulation_access_key.nonce, 0);
+        assert_eq!(simulation_access_key.permission, access_key.permission);
+
+        let sandbox_transaction = transactions.recv().unwrap();
+        assert_eq!(
+            sandbox_transaction.transaction.block_hash,
+            sandbox_block_hash
+        );
+        assert_eq!(sandbox_transaction.transaction.nonce, 1);
+        assert_eq!(
+            &sandbox_transaction.transaction.public_key,
+            simulation_public_key
+        );
+        assert_eq!(
+            sandbox_transaction.transaction.actions,
+            signed_transaction.transaction.actions
+        );
+        assert!(sandbox_transaction.signature.verify(
+            sandbox_transaction.get_hash().as_ref(),
+            simulation_public_key
+        ));
+        assert_eq!(
+            transaction_info.transaction.hash,
+            sandbox_transaction.get_hash()
+        );
+    }
+
+    #[test]
+    fn balance_changes_are_serialized_with_the_deleted_accounts() {
+        let sandbox_rpc_url = serve_json_rpc(|method, params| {
+            assert_eq!(method, "query");
+            assert_eq!(params["request_type"], "view_account");
+            match params["account_id"].as_str().unwrap() {
+                "alice.testnet" => serde_json::json!({
+                    "amount": "9000000000000000000000000",
+                    "locked": "0",
+                    "code_hash": near_primitives::hash::CryptoHash::default(),
+                    "storage_usage": 182,
+                    "storage_paid_at": 0,
+                    "block_height": 3,
+                    "block_hash": near_primitives::hash::CryptoHash::default(),
+                }),
+                account_id => serde_json::json!({
+                    "error": format!("account {account_id} does not exist while viewing"),
+                    "block_height": 3,
+                    "block_hash": near_primitives::hash::CryptoHash::default(),
+                }),
+            }
+        });
+        let balance_changes = get_balance_changes(
+            &network_config(sandbox_rpc_url),
+            &[
+                ("alice.testnet".parse().unwrap(), 10u128.pow(25)),
+                ("bob.testnet".parse().unwrap(), 10u128.pow(24)),
+            ],
+        );
+        assert_eq!(
+            serde_json::to_value(&balance_changes).unwrap(),
+            serde_json::json!([
+                {
+                    "account_id": "alice.testnet",
+                    "balance_before": "10000000000000000000000000",
+                    "balance_after": "9000000000000000000000000",
+                },
+                {
+                    "account_id": "bob.testnet",
+                    "balance_before": "1000000000000000000000000",
+                    "balance_after": null,
+                },
+            ])
+        );
+    }
+}
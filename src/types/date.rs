@@ -0,0 +1,92 @@
+/// A calendar date in the `YYYY-MM-DD` format (UTC).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date(chrono::NaiveDate);
+
+impl Date {
+    /// The date of the timestamp (nanoseconds since the Unix epoch).
+    pub fn from_timestamp_nanosec(timestamp_nanosec: u64) -> Self {
+        Self(datetime_from_timestamp_nanosec(timestamp_nanosec).date_naive())
+    }
+
+    /// The timestamp (nanoseconds since the Unix epoch) of the start of the day.
+    pub fn start_timestamp_nanosec(&self) -> u64 {
+        self.0
+            .and_time(chrono::NaiveTime::MIN)
+            .and_utc()
+            .timestamp_nanos_opt()
+            .map_or(0, |timestamp_nanosec| timestamp_nanosec.max(0) as u64)
+    }
+
+    pub fn next_day(&self) -> Self {
+        Self(self.0.succ_opt().unwrap_or(self.0))
+    }
+
+    pub fn previous_day(&self) -> Self {
+        Self(self.0.pred_opt().unwrap_or(self.0))
+    }
+}
+
+/// Formats the timestamp (nanoseconds since the Unix epoch) as `YYYY-MM-DD HH:MM:SS` in UTC.
+pub fn format_timestamp(timestamp_nanosec: u64) -> String {
+    datetime_from_timestamp_nanosec(timestamp_nanosec)
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
+
+fn datetime_from_timestamp_nanosec(timestamp_nanosec: u64) -> chrono::DateTime<chrono::Utc> {
+    chrono::DateTime::from_timestamp_nanos(i64::try_from(timestamp_nanosec).unwrap_or(i64::MAX))
+}
+
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0.format("%Y-%m-%d"))
+    }
+}
+
+impl std::str::FromStr for Date {
+    type Err = color_eyre::eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+            .map(Self)
+            .map_err(|err| {
+                color_eyre::eyre::eyre!("The date <{s}> is not a valid YYYY-MM-DD date: {err}")
+            })
+    }
+}
+
+impl interactive_clap::ToCli for Date {
+    type CliVariant = Date;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_are_parsed_and_checked() {
+        let date: Date = "2024-02-29".parse().unwrap();
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert_eq!(date.next_day().to_string(), "2024-03-01");
+        assert_eq!(date.previous_day().to_string(), "2024-02-28");
+        assert!("2023-02-29".parse::<Date>().is_err());
+        assert!("2023-13-01".parse::<Date>().is_err());
+        assert!("2023-11-00".parse::<Date>().is_err());
+        assert!("2023/11/01".parse::<Date>().is_err());
+        assert!("2023-11-01".parse::<Date>().unwrap() < date);
+    }
+
+    #[test]
+    fn dates_and_timestamps_are_converted_in_utc() {
+        let timestamp_nanosec = 1_700_000_000_123_456_789;
+        assert_eq!(format_timestamp(timestamp_nanosec), "2023-11-14 22:13:20");
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00");
+        let date = Date::from_timestamp_nanosec(timestamp_nanosec);
+        assert_eq!(date.to_string(), "2023-11-14");
+        assert_eq!(date.start_timestamp_nanosec(), 1_699_920_000_000_000_000);
+        assert_eq!(
+            date.next_day().start_timestamp_nanosec(),
+            1_699_920_000_000_000_000 + 86_400_000_000_000
+        );
+    }
+}
@@ -145,10 +145,24 @@ impl interactive_clap::ToCli for FungibleToken {
     type CliVariant = FungibleToken;
 }
 
-#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, serde::Deserialize)]
+/// Fungible token metadata (NEP-148). Only `symbol` and `decimals` are required, so tokens that
+/// return a partial metadata can still be transferred.
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, PartialOrd, serde::Serialize, serde::Deserialize,
+)]
 pub struct FtMetadata {
+    #[serde(default)]
+    pub spec: String,
+    #[serde(default)]
+    pub name: String,
     pub symbol: String,
     pub decimals: u8,
+    #[serde(default)]
+    pub icon: Option<String>,
+    #[serde(default)]
+    pub reference: Option<String>,
+    #[serde(default)]
+    pub reference_hash: Option<String>,
 }
 
 #[tracing::instrument(name = "Getting FT metadata ...", skip_all)]
@@ -207,4 +221,17 @@ mod tests {
         assert_eq!(ft_token.to_string(), "0.123456 USDC".to_string());
         assert_eq!(ft_token.symbol, "USDC".to_string());
     }
+    #[test]
+    fn ft_metadata_with_only_symbol_and_decimals() {
+        let ft_metadata: FtMetadata =
+            serde_json::from_value(serde_json::json!({"symbol": "USDC", "decimals": 6})).unwrap();
+        assert_eq!(
+            ft_metadata,
+            FtMetadata {
+                symbol: "USDC".to_string(),
+                decimals: 6,
+                ..Default::default()
+            }
+        );
+    }
 }
@@ -0,0 +1,35 @@
+#[derive(Debug, Copy, Clone)]
+pub struct KeyType(pub near_crypto::KeyType);
+
+impl Default for KeyType {
+    fn default() -> Self {
+        Self(near_crypto::KeyType::ED25519)
+    }
+}
+
+impl From<KeyType> for near_crypto::KeyType {
+    fn from(item: KeyType) -> Self {
+        item.0
+    }
+}
+
+impl std::fmt::Display for KeyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::str::FromStr for KeyType {
+    type Err = color_eyre::eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key_type = near_crypto::KeyType::from_str(s).map_err(|_| {
+            color_eyre::eyre::eyre!("Unknown key type <{s}> (expected ed25519 or secp256k1)")
+        })?;
+        Ok(Self(key_type))
+    }
+}
+
+impl interactive_clap::ToCli for KeyType {
+    type CliVariant = KeyType;
+}
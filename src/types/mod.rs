@@ -2,9 +2,12 @@ pub mod account_id;
 pub mod api_key;
 pub mod base64_bytes;
 pub mod crypto_hash;
+pub mod date;
 pub mod file_bytes;
 pub mod ft_properties;
 pub mod json;
+pub mod key_type;
+pub mod multisig_request_id;
 pub mod near_allowance;
 pub mod near_token;
 pub mod path_buf;
@@ -16,5 +19,6 @@ pub mod signed_delegate_action;
 pub mod signed_transaction;
 pub mod slip10;
 pub mod transaction;
+pub mod transaction_bundle;
 pub mod url;
 pub mod vec_string;
@@ -0,0 +1,47 @@
+/// The ID of a pending request of a multisig account (`RequestId` of the NEAR multisig contract).
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct MultisigRequestId(pub u32);
+
+impl std::fmt::Display for MultisigRequestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::str::FromStr for MultisigRequestId {
+    type Err = color_eyre::eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let request_id = s.parse().map_err(|err| {
+            color_eyre::eyre::eyre!(
+                "The request ID must be a number from 0 to {}: {err}",
+                u32::MAX
+            )
+        })?;
+        Ok(Self(request_id))
+    }
+}
+
+impl interactive_clap::ToCli for MultisigRequestId {
+    type CliVariant = MultisigRequestId;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_serialize() {
+        let request_id: MultisigRequestId = "42".parse().unwrap();
+        assert_eq!(request_id, MultisigRequestId(42));
+        assert_eq!(
+            serde_json::json!({ "request_id": request_id }).to_string(),
+            r#"{"request_id":42}"#
+        );
+        assert!("4294967296".parse::<MultisigRequestId>().is_err());
+        assert!("-1".parse::<MultisigRequestId>().is_err());
+    }
+}
@@ -43,3 +43,23 @@ impl From<near_primitives::transaction::SignedTransaction> for SignedTransaction
         Self { inner: value }
     }
 }
+
+impl serde::ser::Serialize for SignedTransactionAsBase64 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for SignedTransactionAsBase64 {
+    fn deserialize<D>(deserializer: D) -> Result<SignedTransactionAsBase64, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        <String as serde::de::Deserialize>::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
@@ -43,3 +43,23 @@ impl std::fmt::Display for TransactionAsBase64 {
         write!(f, "{}", base64_unsigned_transaction)
     }
 }
+
+impl serde::ser::Serialize for TransactionAsBase64 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for TransactionAsBase64 {
+    fn deserialize<D>(deserializer: D) -> Result<TransactionAsBase64, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        <String as serde::de::Deserialize>::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,246 @@
+//! A transaction bundle carries a transaction between an online machine and an offline (air-gapped)
+//! one:
+//!
+//! 1. `transaction prepare-offline` (online) fetches a fresh nonce and a recent block hash for the
+//!    unsigned transaction and saves the bundle to a file or prints it as text or a QR code;
+//! 2. `transaction sign-transaction` (offline) signs the transaction of the bundle with the
+//!    prepared nonce and block hash, and adds the signed transaction to the bundle;
+//! 3. `transaction broadcast-bundle` (online) sends the signed transaction to the network of the
+//!    bundle.
+use color_eyre::eyre::WrapErr;
+
+/// The version of the [`TransactionBundle`] layout.
+pub const TRANSACTION_BUNDLE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TransactionBundle {
+    pub version: u32,
+    pub network_name: String,
+    /// The height of the block the transaction refers to (with its hash).
+    pub block_height: near_primitives::types::BlockHeight,
+    /// The transaction is rejected by the network after this block.
+    pub expires_at_block_height: near_primitives::types::BlockHeight,
+    pub unsigned_transaction: super::transaction::TransactionAsBase64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signed_transaction: Option<super::signed_transaction::SignedTransactionAsBase64>,
+}
+
+impl TransactionBundle {
+    /// The nonce, the block hash and the block height prepared for the transaction; the access key
+    /// the transaction is signed with has to be the one they were prepared for.
+    pub fn get_nonce_and_block(
+        &self,
+        public_key: &near_crypto::PublicKey,
+    ) -> color_eyre::eyre::Result<(
+        near_primitives::types::Nonce,
+        near_primitives::hash::CryptoHash,
+        near_primitives::types::BlockHeight,
+    )> {
+        let unsigned_transaction = &self.unsigned_transaction.inner;
+        if &unsigned_transaction.public_key != public_key {
+            color_eyre::eyre::bail!(
+                "The transaction bundle is prepared for the access key <{}>, but the transaction is signed with <{public_key}>",
+                unsigned_transaction.public_key
+            );
+        }
+        Ok((
+            unsigned_transaction.nonce,
+            unsigned_transaction.block_hash,
+            self.block_height,
+        ))
+    }
+
+    pub fn with_signed_transaction(
+        &self,
+        signed_transaction: near_primitives::transaction::SignedTransaction,
+    ) -> color_eyre::eyre::Result<Self> {
+        if signed_transaction.transaction.get_hash_and_size().0
+            != self.unsigned_transaction.inner.get_hash_and_size().0
+        {
+            color_eyre::eyre::bail!(
+                "The signed transaction differs from the unsigned transaction of the bundle"
+            );
+        }
+        Ok(Self {
+            signed_transaction: Some(signed_transaction.into()),
+            ..self.clone()
+        })
+    }
+
+    /// The bundle as a single line of text (this is also the payload of the QR code).
+    pub fn to_payload(&self) -> String {
+        serde_json::to_string(self)
+            .expect("Transaction bundle is not expected to fail on serialization")
+    }
+
+    pub fn save_to_file(&self, file_path: &std::path::Path) -> color_eyre::eyre::Result<()> {
+        std::fs::write(file_path, serde_json::to_vec_pretty(self)?)
+            .wrap_err_with(|| format!("Failed to write to file: {file_path:?}"))
+    }
+
+    /// Reads the bundle given on the command line: the payload itself or a path to the bundle file.
+    fn from_payload_or_file(s: &str) -> Result<Self, String> {
+        let payload = if s.trim_start().starts_with('{') {
+            s.to_string()
+        } else {
+            std::fs::read_to_string(s)
+                .map_err(|err| format!("the transaction bundle file {s:?} cannot be read: {err}"))?
+        };
+        let transaction_bundle: Self = serde_json::from_str(&payload)
+            .map_err(|err| format!("the transaction bundle cannot be parsed: {err}"))?;
+        if transaction_bundle.version > TRANSACTION_BUNDLE_VERSION {
+            return Err(format!(
+                "the transaction bundle of version {} is not supported, please update near CLI",
+                transaction_bundle.version
+            ));
+        }
+        Ok(transaction_bundle)
+    }
+}
+
+/// `transaction sign-transaction` input: an unsigned transaction encoded in base64 or a
+/// transaction bundle (the payload itself or a path to the bundle file).
+#[derive(Debug, Clone)]
+pub struct UnsignedTransactionOrBundle {
+    input: String,
+    pub unsigned_transaction: near_primitives::transaction::Transaction,
+    pub transaction_bundle: Option<TransactionBundle>,
+}
+
+impl std::str::FromStr for UnsignedTransactionOrBundle {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(unsigned_transaction) = s.parse::<super::transaction::TransactionAsBase64>() {
+            return Ok(Self {
+                input: s.to_string(),
+                unsigned_transaction: unsigned_transaction.into(),
+                transaction_bundle: None,
+            });
+        }
+        let transaction_bundle = TransactionBundle::from_payload_or_file(s)?;
+        if transaction_bundle.signed_transaction.is_some() {
+            return Err("the transaction bundle is already signed".to_string());
+        }
+        Ok(Self {
+            input: s.to_string(),
+            unsigned_transaction: transaction_bundle.unsigned_transaction.inner.clone(),
+            transaction_bundle: Some(transaction_bundle),
+        })
+    }
+}
+
+impl std::fmt::Display for UnsignedTransactionOrBundle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.input)
+    }
+}
+
+impl interactive_clap::ToCli for UnsignedTransactionOrBundle {
+    type CliVariant = UnsignedTransactionOrBundle;
+}
+
+/// `transaction broadcast-bundle` input: a signed transaction bundle (the payload itself or a path
+/// to the bundle file).
+#[derive(Debug, Clone)]
+pub struct SignedTransactionBundle {
+    input: String,
+    pub transaction_bundle: TransactionBundle,
+    pub signed_transaction: near_primitives::transaction::SignedTransaction,
+}
+
+impl std::str::FromStr for SignedTransactionBundle {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let transaction_bundle = TransactionBundle::from_payload_or_file(s)?;
+        let signed_transaction = transaction_bundle
+            .signed_transaction
+            .clone()
+            .ok_or_else(|| {
+                "the transaction bundle is not signed yet (use `transaction sign-transaction`)"
+                    .to_string()
+            })?
+            .into();
+        Ok(Self {
+            input: s.to_string(),
+            transaction_bundle,
+            signed_transaction,
+        })
+    }
+}
+
+impl std::fmt::Display for SignedTransactionBundle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.input)
+    }
+}
+
+impl interactive_clap::ToCli for SignedTransactionBundle {
+    type CliVariant = SignedTransactionBundle;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_bundle_round_trip() {
+        let signer = near_crypto::InMemorySigner::from_seed(
+            "alice.testnet".parse().unwrap(),
+            near_crypto::KeyType::ED25519,
+            "alice.testnet",
+        );
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: "alice.testnet".parse().unwrap(),
+            public_key: signer.public_key.clone(),
+            nonce: 42,
+            receiver_id: "bob.testnet".parse().unwrap(),
+            block_hash: near_primitives::hash::CryptoHash::hash_bytes(b"block"),
+            actions: vec![near_primitives::transaction::Action::Transfer(
+                near_primitives::transaction::TransferAction { deposit: 1 },
+            )],
+        };
+        let transaction_bundle = TransactionBundle {
+            version: TRANSACTION_BUNDLE_VERSION,
+            network_name: "testnet".to_string(),
+            block_height: 100,
+            expires_at_block_height: 86500,
+            unsigned_transaction: unsigned_transaction.clone().into(),
+            signed_transaction: None,
+        };
+
+        let parsed: UnsignedTransactionOrBundle = transaction_bundle.to_payload().parse().unwrap();
+        let parsed_bundle = parsed.transaction_bundle.unwrap();
+        assert_eq!(parsed.unsigned_transaction, unsigned_transaction);
+        assert_eq!(
+            parsed_bundle
+                .get_nonce_and_block(&signer.public_key)
+                .unwrap(),
+            (42, unsigned_transaction.block_hash, 100)
+        );
+        let other_public_key =
+            near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "").public_key();
+        assert!(parsed_bundle
+            .get_nonce_and_block(&other_public_key)
+            .is_err());
+        assert!(transaction_bundle
+            .to_payload()
+            .parse::<SignedTransactionBundle>()
+            .is_err());
+
+        let signature =
+            near_crypto::Signer::sign(&signer, unsigned_transaction.get_hash_and_size().0.as_ref());
+        let signed_transaction =
+            near_primitives::transaction::SignedTransaction::new(signature, unsigned_transaction);
+        let signed_bundle: SignedTransactionBundle = parsed_bundle
+            .with_signed_transaction(signed_transaction.clone())
+            .unwrap()
+            .to_payload()
+            .parse()
+            .unwrap();
+        assert_eq!(signed_bundle.signed_transaction, signed_transaction);
+        assert!(signed_bundle
+            .to_string()
+            .parse::<UnsignedTransactionOrBundle>()
+            .is_err());
+    }
+}
@@ -1,15 +1,16 @@
-use std::str::FromStr;
-
 /// Generate a key pair of private and public keys (use it anywhere you need
-/// Ed25519 keys)
+/// Ed25519 or secp256k1 keys)
 #[derive(Debug, Clone, clap::Parser)]
 pub struct CliGenerateKeypair {
     #[clap(long)]
     pub master_seed_phrase: Option<String>,
+    #[clap(long, default_value = "ed25519")]
+    pub key_type: crate::types::key_type::KeyType,
     #[clap(long, default_value = "12")]
     pub new_master_seed_phrase_words_count: usize,
-    #[clap(long, default_value = "m/44'/397'/0'")]
-    pub seed_phrase_hd_path: crate::types::slip10::BIP32Path,
+    /// The default is the HD path of the key type (see `default_seed_phrase_hd_path`)
+    #[clap(long)]
+    pub seed_phrase_hd_path: Option<crate::types::slip10::BIP32Path>,
     #[clap(long, default_value = "plaintext")]
     pub format: crate::common::OutputFormat,
 }
@@ -18,10 +19,18 @@ impl Default for CliGenerateKeypair {
     fn default() -> Self {
         Self {
             master_seed_phrase: None,
+            key_type: Default::default(),
             new_master_seed_phrase_words_count: 12,
-            seed_phrase_hd_path: crate::types::slip10::BIP32Path::from_str("m/44'/397'/0'")
-                .unwrap(),
+            seed_phrase_hd_path: None,
             format: crate::common::OutputFormat::Json,
         }
     }
 }
+
+impl CliGenerateKeypair {
+    pub fn seed_phrase_hd_path(&self) -> crate::types::slip10::BIP32Path {
+        self.seed_phrase_hd_path
+            .clone()
+            .unwrap_or_else(|| crate::common::default_seed_phrase_hd_path(self.key_type.into()))
+    }
+}